@@ -8,6 +8,7 @@ fn instruction_size(i: &sh4a::Instruction) -> u32 {
     match i {
         sh4a::Instruction::Nop
         | sh4a::Instruction::MovT { .. }
+        | sh4a::Instruction::MovRT { .. }
         | sh4a::Instruction::MovRR { .. }
         | sh4a::Instruction::MovIR { .. }
         | sh4a::Instruction::MovRPR { .. }
@@ -17,12 +18,19 @@ fn instruction_size(i: &sh4a::Instruction) -> u32 {
         | sh4a::Instruction::PushPR { .. }
         | sh4a::Instruction::PopL { .. }
         | sh4a::Instruction::PopPR { .. }
+        | sh4a::Instruction::CmpEq { .. }
         | sh4a::Instruction::CmpGt { .. }
+        | sh4a::Instruction::CmpGe { .. }
+        | sh4a::Instruction::CmpHi { .. }
+        | sh4a::Instruction::CmpHs { .. }
         | sh4a::Instruction::CmpPl { .. }
+        | sh4a::Instruction::AddImmediate { .. }
+        | sh4a::Instruction::Add { .. }
         | sh4a::Instruction::Sub { .. }
+        | sh4a::Instruction::Neg { .. }
         | sh4a::Instruction::ShldRR { .. }
         | sh4a::Instruction::OrRR { .. } => 1,
-        sh4a::Instruction::MovImmR { .. } => {
+        sh4a::Instruction::MovImmR { .. } | sh4a::Instruction::MovLabelAddress { .. } => {
             // 1. Load of the Value
             // 2. Nop
             // 3. Jump over Value
@@ -59,10 +67,6 @@ fn instruction_size(i: &sh4a::Instruction) -> u32 {
             // 2. Nop needed after Branch
             2
         }
-        other => {
-            dbg!(&other);
-            todo!()
-        }
     }
 }
 
@@ -92,9 +96,23 @@ fn generate_block(block: sh4a::Block, offsets: &HashMap<String, u32>) -> (String
     (block.name, result)
 }
 
-fn initial_block(main_block: String) -> sh4a::Block {
+fn initial_block(init_block: String, main_block: String) -> sh4a::Block {
+    let init_reg = sh4a::GeneralPurposeRegister::new(4);
+
     let mut result = Vec::new();
     //result.extend(stack_instr);
+    // The PR is saved around the Call to the Initialization, because main returns to the OS
+    // using the original PR
+    result.extend(vec![
+        sh4a::Instruction::MovLabelAddress {
+            label: init_block,
+            dest: init_reg.clone(),
+        },
+        sh4a::Instruction::PushPR,
+        sh4a::Instruction::JumpSubroutine { target: init_reg },
+        sh4a::Instruction::Nop,
+        sh4a::Instruction::PopPR,
+    ]);
     result.extend(vec![sh4a::Instruction::JumpLabel { label: main_block }]);
 
     sh4a::Block {
@@ -103,8 +121,17 @@ fn initial_block(main_block: String) -> sh4a::Block {
     }
 }
 
-pub fn assemble(main_block: String, blocks: Vec<sh4a::Block>) -> Vec<u8> {
-    let initial_block = initial_block(main_block);
+/// Assembles the given Blocks into the final Code, which starts with a Call to the init Block,
+/// that initializes the global Variables, followed by a Jump to the main Block.
+/// The read-only Data is placed after all the Code and can be referenced by its Name like any
+/// other Label
+pub fn assemble(
+    init_block: String,
+    main_block: String,
+    blocks: Vec<sh4a::Block>,
+    data: Vec<(String, ir::ConstantData)>,
+) -> Vec<u8> {
+    let initial_block = initial_block(init_block, main_block);
 
     let block_lengths = std::iter::once(initial_block.clone())
         .chain(blocks.iter().cloned())
@@ -126,6 +153,16 @@ pub fn assemble(main_block: String, blocks: Vec<sh4a::Block>) -> Vec<u8> {
             }
         }
 
+        for (name, constant) in data.iter() {
+            let alignment = constant.alignment.max(1) as u32;
+            if address % alignment != 0 {
+                address += alignment - (address % alignment);
+            }
+
+            tmp.insert(name.clone(), address);
+            address += constant.content.len() as u32;
+        }
+
         tmp
     };
 
@@ -136,6 +173,10 @@ pub fn assemble(main_block: String, blocks: Vec<sh4a::Block>) -> Vec<u8> {
             let offset = *block_offsets.get(&name).unwrap();
             (offset, data)
         })
+        .chain(data.into_iter().map(|(name, constant)| {
+            let offset = *block_offsets.get(&name).unwrap();
+            (offset, constant.content)
+        }))
         .collect();
 
     let mut result = Vec::new();
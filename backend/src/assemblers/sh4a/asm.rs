@@ -2,6 +2,9 @@ use std::collections::HashMap;
 
 use isas::sh4a;
 
+/// The Address at which the Code of an Add-In is mapped into Memory on the fx-CG50
+const CODE_BASE: u32 = 0x0030_0000;
+
 #[derive(Debug)]
 pub enum Instruction {
     Nop,
@@ -20,6 +23,11 @@ pub enum Instruction {
         base: u8,
         target: u8,
     },
+    /// mov.l Rm, @(R0, Rn)
+    MovLRR0PR {
+        base: u8,
+        src: u8,
+    },
     /// mov.l Rm, @-Rn
     MovLRDR {
         base: u8,
@@ -35,15 +43,42 @@ pub enum Instruction {
         disp: u8,
         target: u8,
     },
+    /// sts.l PR, @-Rn
+    StsLPR {
+        base: u8,
+    },
+    /// lds.l @Rm+, PR
+    LdsLPR {
+        base: u8,
+    },
     /// movt Rn
     MovT {
         target: u8,
     },
+    /// movrt Rn
+    MovRT {
+        target: u8,
+    },
+    /// add #imm,Rn
+    AddI {
+        dest: u8,
+        immediate: u8,
+    },
+    /// add Rm,Rn
+    Add {
+        dest: u8,
+        src2: u8,
+    },
     /// sub Rm,Rn
     Sub {
         dest: u8,
         src2: u8,
     },
+    /// neg Rm,Rn
+    Neg {
+        dest: u8,
+        src: u8,
+    },
     /// shld Rm,Rn
     Shld {
         dest: u8,
@@ -54,11 +89,31 @@ pub enum Instruction {
         dest: u8,
         src2: u8,
     },
+    /// cmp/eq Rm,Rn
+    CmpEq {
+        left: u8,
+        right: u8,
+    },
     /// cmp/gt Rm,Rn
     CmpGt {
         left: u8,
         right: u8,
     },
+    /// cmp/ge Rm,Rn
+    CmpGe {
+        left: u8,
+        right: u8,
+    },
+    /// cmp/hi Rm,Rn
+    CmpHi {
+        left: u8,
+        right: u8,
+    },
+    /// cmp/hs Rm,Rn
+    CmpHs {
+        left: u8,
+        right: u8,
+    },
     /// cmp/pl Rn
     CmpPl {
         reg: u8,
@@ -107,26 +162,45 @@ impl Instruction {
                 let imm_part_2: u16 = u16::from_be_bytes([imm_parts[3], imm_parts[2]]).to_be();
                 let imm_part_1: u16 = u16::from_be_bytes([imm_parts[1], imm_parts[0]]).to_be();
 
-                let jump_disp = if pc % 4 != 0 { todo!() } else { 2 };
-                let load_disp = if pc % 4 != 0 { todo!() } else { 1 };
+                if pc % 4 != 0 {
+                    // The Value needs to be 4-Byte aligned, so it is placed right after the Nop
+                    // of the Branch and the Branch jumps to a Nop after the Value
+                    return vec![
+                        Self::MovLPcDispR {
+                            target: dest,
+                            disp: 1,
+                        },
+                        Self::BraDisp { disp: 2 },
+                        Self::Nop,
+                        Self::Raw(imm_part_1),
+                        Self::Raw(imm_part_2),
+                        Self::Nop,
+                    ];
+                }
 
-                let mut result = vec![
+                vec![
                     Self::MovLPcDispR {
                         target: dest,
-                        disp: load_disp,
+                        disp: 1,
                     },
                     Self::Nop,
-                    Self::BraDisp { disp: jump_disp },
+                    Self::BraDisp { disp: 2 },
                     Self::Nop,
-                ];
-
-                if pc % 4 != 0 {
-                    todo!()
-                }
-
-                result.extend([Self::Raw(imm_part_1), Self::Raw(imm_part_2)]);
+                    Self::Raw(imm_part_1),
+                    Self::Raw(imm_part_2),
+                ]
+            }
+            sh4a::Instruction::MovLabelAddress { label, dest } => {
+                let label_offset = *offsets.get(&label).unwrap();
 
-                result
+                Self::from_instr(
+                    sh4a::Instruction::MovImmR {
+                        immediate: CODE_BASE + label_offset,
+                        dest,
+                    },
+                    pc,
+                    offsets,
+                )
             }
             sh4a::Instruction::MovRR { src, dest } => {
                 let src = src.register().to_be();
@@ -140,11 +214,22 @@ impl Instruction {
 
                 vec![Self::MovLR0PRR { base, target }]
             }
+            sh4a::Instruction::MovLRR0PR { base, src } => {
+                let base = base.register().to_be();
+                let src = src.register().to_be();
+
+                vec![Self::MovLRR0PR { base, src }]
+            }
             sh4a::Instruction::MovT { dest } => {
                 let target = dest.register().to_be();
 
                 vec![Self::MovT { target }]
             }
+            sh4a::Instruction::MovRT { dest } => {
+                let target = dest.register().to_be();
+
+                vec![Self::MovRT { target }]
+            }
             sh4a::Instruction::PushL { reg } => {
                 let base = 0x0f;
                 let src = reg.register().to_be();
@@ -157,12 +242,40 @@ impl Instruction {
 
                 vec![Self::MovLRIR { base, dest }]
             }
+            sh4a::Instruction::PushPR => {
+                let base = 0x0f;
+
+                vec![Self::StsLPR { base }]
+            }
+            sh4a::Instruction::PopPR => {
+                let base = 0x0f;
+
+                vec![Self::LdsLPR { base }]
+            }
+            sh4a::Instruction::AddImmediate { reg, immediate } => {
+                let immediate = immediate.to_be_bytes()[0];
+                let dest = reg.register().to_be();
+
+                vec![Self::AddI { dest, immediate }]
+            }
+            sh4a::Instruction::Add { dest, src2 } => {
+                let dest = dest.register().to_be();
+                let src2 = src2.register().to_be();
+
+                vec![Self::Add { dest, src2 }]
+            }
             sh4a::Instruction::Sub { dest, src2 } => {
                 let dest = dest.register().to_be();
                 let src2 = src2.register().to_be();
 
                 vec![Self::Sub { dest, src2 }]
             }
+            sh4a::Instruction::Neg { dest, src } => {
+                let dest = dest.register().to_be();
+                let src = src.register().to_be();
+
+                vec![Self::Neg { dest, src }]
+            }
             sh4a::Instruction::ShldRR { target, shift_reg } => {
                 let dest = target.register().to_be();
                 let shift = shift_reg.register().to_be();
@@ -175,80 +288,51 @@ impl Instruction {
 
                 vec![Self::OrRR { dest, src2 }]
             }
+            sh4a::Instruction::CmpEq { left, right } => {
+                let left = left.register().to_be();
+                let right = right.register().to_be();
+
+                vec![Self::CmpEq { left, right }]
+            }
             sh4a::Instruction::CmpGt { left, right } => {
                 let left = left.register().to_be();
                 let right = right.register().to_be();
 
                 vec![Self::CmpGt { left, right }]
             }
+            sh4a::Instruction::CmpGe { left, right } => {
+                let left = left.register().to_be();
+                let right = right.register().to_be();
+
+                vec![Self::CmpGe { left, right }]
+            }
+            sh4a::Instruction::CmpHi { left, right } => {
+                let left = left.register().to_be();
+                let right = right.register().to_be();
+
+                vec![Self::CmpHi { left, right }]
+            }
+            sh4a::Instruction::CmpHs { left, right } => {
+                let left = left.register().to_be();
+                let right = right.register().to_be();
+
+                vec![Self::CmpHs { left, right }]
+            }
             sh4a::Instruction::CmpPl { src } => {
                 let reg = src.register().to_be();
 
                 vec![Self::CmpPl { reg }]
             }
             sh4a::Instruction::JumpLabel { label } => {
-                let raw_target_pc: u32 = *offsets.get(&label).unwrap();
-                let target_pc: i64 = raw_target_pc.try_into().unwrap();
-
-                let jump_pc: i64 = (pc + 4).try_into().unwrap();
-                let pc_difference: i32 = (target_pc - (jump_pc + 4)).try_into().unwrap();
-                let pc_diff_parts = pc_difference.to_be_bytes();
-                let pc_diff_part_2: u16 =
-                    u16::from_be_bytes([pc_diff_parts[3], pc_diff_parts[2]]).to_be();
-                let pc_diff_part_1: u16 =
-                    u16::from_be_bytes([pc_diff_parts[1], pc_diff_parts[0]]).to_be();
-
-                let needs_padding = pc % 4 != 0;
-                let disp = if needs_padding { todo!() } else { 1 };
-
-                let mut result = vec![
-                    Self::MovLPcDispR { target: 4, disp },
-                    Self::Nop,
-                    Self::BraPCR { reg: 4 },
-                    Self::Nop,
-                ];
+                let target_pc: u32 = *offsets.get(&label).unwrap();
 
-                if needs_padding {
-                    result.push(Self::Nop);
-                }
-
-                result.push(Self::Raw(pc_diff_part_1));
-                result.push(Self::Raw(pc_diff_part_2));
-
-                result
+                Self::jump(target_pc, pc)
             }
             sh4a::Instruction::BranchTrueLabel { label } => {
-                let raw_target_pc: u32 = *offsets.get(&label).unwrap();
-                let target_pc: i64 = raw_target_pc.try_into().unwrap();
-
-                let jump_pc: i64 = (pc + 8).try_into().unwrap();
-                let pc_difference: i32 = (target_pc - (jump_pc + 4)).try_into().unwrap();
-                let pc_diff_parts = pc_difference.to_be_bytes();
-                let pc_diff_part_1: u16 =
-                    u16::from_be_bytes([pc_diff_parts[0], pc_diff_parts[1]]).to_be();
-                let pc_diff_part_2: u16 =
-                    u16::from_be_bytes([pc_diff_parts[2], pc_diff_parts[3]]).to_be();
-
-                let jump_instr = {
-                    let needs_padding = pc % 4 != 0;
-                    let disp = if needs_padding { todo!() } else { 2 };
-
-                    let mut tmp = vec![
-                        Self::MovLPcDispR { target: 4, disp },
-                        Self::Nop,
-                        Self::BraPCR { reg: 4 },
-                        Self::Nop,
-                    ];
-
-                    if needs_padding {
-                        tmp.push(Self::Nop);
-                    }
-
-                    tmp.push(Self::Raw(pc_diff_part_1));
-                    tmp.push(Self::Raw(pc_diff_part_2));
+                let target_pc: u32 = *offsets.get(&label).unwrap();
 
-                    tmp
-                };
+                // The Jump itself starts after the Branch and its Nop
+                let jump_instr = Self::jump(target_pc, pc + 4);
 
                 let br_distance: i8 = jump_instr.len().try_into().unwrap();
 
@@ -277,6 +361,41 @@ impl Instruction {
         }
     }
 
+    /// Generates an unconditional Jump, starting at the given PC, to the target PC.
+    ///
+    /// The Distance to the Target is loaded from the Value placed after the Jump, which needs to
+    /// be 4-Byte aligned and is therefore possibly preceded by an extra Nop
+    fn jump(target_pc: u32, pc: u32) -> Vec<Self> {
+        let target_pc: i64 = target_pc.into();
+
+        let jump_pc: i64 = (pc + 4).into();
+        let pc_difference: i32 = (target_pc - (jump_pc + 4)).try_into().unwrap();
+        let pc_diff_parts = pc_difference.to_be_bytes();
+        let pc_diff_part_2: u16 = u16::from_be_bytes([pc_diff_parts[3], pc_diff_parts[2]]).to_be();
+        let pc_diff_part_1: u16 = u16::from_be_bytes([pc_diff_parts[1], pc_diff_parts[0]]).to_be();
+
+        let needs_padding = (pc + 8) % 4 != 0;
+        let value_pc = if needs_padding { pc + 10 } else { pc + 8 };
+        // mov.l @(disp, PC) loads from (PC & 0xFFFFFFFC) + 4 + disp * 4
+        let disp: u8 = ((value_pc - ((pc & !3) + 4)) / 4).try_into().unwrap();
+
+        let mut result = vec![
+            Self::MovLPcDispR { target: 4, disp },
+            Self::Nop,
+            Self::BraPCR { reg: 4 },
+            Self::Nop,
+        ];
+
+        if needs_padding {
+            result.push(Self::Nop);
+        }
+
+        result.push(Self::Raw(pc_diff_part_1));
+        result.push(Self::Raw(pc_diff_part_2));
+
+        result
+    }
+
     pub fn into_bytes(self) -> u16 {
         match self {
             Self::Nop => 0x0009,
@@ -298,6 +417,12 @@ impl Instruction {
 
                 0x000e | (target << 8) | (base << 4)
             }
+            Self::MovLRR0PR { base, src } => {
+                let base = base as u16;
+                let src = src as u16;
+
+                0x0006 | (base << 8) | (src << 4)
+            }
             Self::MovLRDR { base, src } => {
                 let base = base as u16;
                 let src = src as u16;
@@ -316,17 +441,50 @@ impl Instruction {
 
                 0xd000 | (target << 8) | disp
             }
+            Self::StsLPR { base } => {
+                let base = base as u16;
+
+                0x4022 | (base << 8)
+            }
+            Self::LdsLPR { base } => {
+                let base = base as u16;
+
+                0x4026 | (base << 8)
+            }
             Self::MovT { target } => {
                 let target = target as u16;
 
                 0x0029 | (target << 8)
             }
+            Self::MovRT { target } => {
+                let target = target as u16;
+
+                0x0039 | (target << 8)
+            }
+            Self::AddI { dest, immediate } => {
+                let immediate = immediate as u16;
+                let dest = dest as u16;
+
+                0x7000 | (dest << 8) | immediate
+            }
+            Self::Add { dest, src2 } => {
+                let dest = dest as u16;
+                let src2 = src2 as u16;
+
+                0x300c | (dest << 8) | (src2 << 4)
+            }
             Self::Sub { dest, src2 } => {
                 let dest = dest as u16;
                 let src2 = src2 as u16;
 
                 0x3008 | (dest << 8) | (src2 << 4)
             }
+            Self::Neg { dest, src } => {
+                let dest = dest as u16;
+                let src = src as u16;
+
+                0x600b | (dest << 8) | (src << 4)
+            }
             Self::Shld { dest, shift } => {
                 let dest = dest as u16;
                 let shift = shift as u16;
@@ -339,12 +497,36 @@ impl Instruction {
 
                 0x200b | (dest << 8) | (src2 << 4)
             }
+            Self::CmpEq { left, right } => {
+                let left = left as u16;
+                let right = right as u16;
+
+                0x3000 | (left << 8) | (right << 4)
+            }
             Self::CmpGt { left, right } => {
                 let left = left as u16;
                 let right = right as u16;
 
                 0x3007 | (left << 8) | (right << 4)
             }
+            Self::CmpGe { left, right } => {
+                let left = left as u16;
+                let right = right as u16;
+
+                0x3003 | (left << 8) | (right << 4)
+            }
+            Self::CmpHi { left, right } => {
+                let left = left as u16;
+                let right = right as u16;
+
+                0x3006 | (left << 8) | (right << 4)
+            }
+            Self::CmpHs { left, right } => {
+                let left = left as u16;
+                let right = right as u16;
+
+                0x3002 | (left << 8) | (right << 4)
+            }
             Self::CmpPl { reg } => {
                 let reg = reg as u16;
 
@@ -104,37 +104,58 @@ impl Backend {
 
         std::fs::write(format!("./{}.dot", func.name), func_dot);
 
+        // The Address for a Struct that is returned through Memory is stored right after the
+        // Frame-Record, because x8 is not preserved across other Calls
+        let indirect_result = matches!(
+            codegen::return_target(func.return_ty.clone()),
+            ArgTarget::IndirectResult
+        )
+        .then_some(16);
+
         let stack_allocation = util::stack::allocate_stack(
             func,
             &register_map,
             util::stack::AllocateConfig {
                 alloc_space: |space| {
-                    vec![asm::Instruction::StpPreIndex {
-                        first: asm::GPRegister::DWord(29),
-                        second: asm::GPRegister::DWord(30),
-                        base: asm::GpOrSpRegister::SP,
-                        offset: -(space as i16),
-                    }]
+                    vec![
+                        asm::Instruction::StpPreIndex {
+                            first: asm::GPRegister::DWord(29),
+                            second: asm::GPRegister::DWord(30),
+                            base: asm::GpOrSpRegister::SP,
+                            offset: -(space as i16),
+                        },
+                        asm::Instruction::AddImmediate {
+                            dest: asm::GPRegister::DWord(29),
+                            src: asm::GpOrSpRegister::SP,
+                            immediate: 0,
+                            shift: 0,
+                        },
+                    ]
                 },
                 dealloc_space: |space| {
-                    vec![asm::Instruction::LdpPostIndex {
-                        first: asm::GPRegister::DWord(29),
-                        second: asm::GPRegister::DWord(30),
-                        base: asm::GpOrSpRegister::SP,
-                        offset: space as i16,
-                    }]
+                    // Dynamic Stack-Allocations moved the Stack-Pointer, so it is reset to the
+                    // Frame first
+                    vec![
+                        asm::Instruction::Literal("mov sp, x29".to_string()),
+                        asm::Instruction::LdpPostIndex {
+                            first: asm::GPRegister::DWord(29),
+                            second: asm::GPRegister::DWord(30),
+                            base: asm::GpOrSpRegister::SP,
+                            offset: space as i16,
+                        },
+                    ]
                 },
                 store_on_stack: |register, offset| match register {
                     ArmRegister::GeneralPurpose(n) => {
                         vec![asm::Instruction::StoreRegisterUnscaled {
                             reg: asm::GPRegister::DWord(*n),
-                            base: asm::GpOrSpRegister::SP,
+                            base: codegen::util::FRAME_POINTER,
                             offset: asm::Imm9Signed::new(offset).unwrap(),
                         }]
                     }
                     ArmRegister::FloatingPoint(n) => vec![asm::Instruction::StoreFPUnscaled {
                         reg: asm::FPRegister::DoublePrecision(*n),
-                        base: asm::GpOrSpRegister::SP,
+                        base: codegen::util::FRAME_POINTER,
                         offset: asm::Imm9Signed::new(offset).unwrap(),
                     }],
                 },
@@ -142,21 +163,30 @@ impl Backend {
                     ArmRegister::GeneralPurpose(n) => {
                         vec![asm::Instruction::LoadRegisterUnscaled {
                             reg: asm::GPRegister::DWord(*n),
-                            base: asm::GpOrSpRegister::SP,
+                            base: codegen::util::FRAME_POINTER,
                             offset: asm::Imm9Signed::new(offset).unwrap(),
                         }]
                     }
                     ArmRegister::FloatingPoint(n) => vec![asm::Instruction::LoadFPUnscaled {
                         reg: asm::FPRegister::DoublePrecision(*n),
-                        base: asm::GpOrSpRegister::SP,
+                        base: codegen::util::FRAME_POINTER,
                         offset: asm::Imm9Signed::new(offset).unwrap(),
                     }],
                 },
                 type_align_size: |ty| match ty {
-                    ir::Type::I32 => (4, 4),
-                    ir::Type::I64 => (8, 8),
+                    ir::Type::I8 | ir::Type::U8 => (1, 1),
+                    ir::Type::I16 | ir::Type::U16 => (2, 2),
+                    ir::Type::I32 | ir::Type::U32 => (4, 4),
+                    ir::Type::I64 | ir::Type::U64 => (8, 8),
                     ir::Type::Pointer(_) => (8, 8),
                     ir::Type::Float => (4, 4),
+                    ir::Type::Double => (8, 8),
+                    ir::Type::Struct { .. } => {
+                        // Structs might be stored from entire Registers, so the Memory for them
+                        // is rounded up to a Multiple of the Register-Size
+                        let (size, align) = codegen::size_align(ty);
+                        (align, size.next_multiple_of(8))
+                    }
                     ir::Type::Void => {
                         // TODO
                         // This should probably not happen
@@ -168,40 +198,61 @@ impl Backend {
                     }
                 },
                 stack_alignment: 16,
-                stack_base: 16,
+                stack_base: if indirect_result.is_some() { 32 } else { 16 },
                 _marker: PhantomData {},
             },
         );
 
+        let asm_ctx = codegen::Context {
+            registers: register_map.into(),
+            var: stack_allocation.var_offsets,
+            pre_ret_instr: stack_allocation.pre_return_instr.clone(),
+            stack_allocs: stack_allocation.allocations,
+            stack_size: stack_allocation.stack_size,
+            indirect_result,
+        };
+
         let arg_moves = {
             let starting_statements = func.block.get_statements();
-            let mut statement_iter = starting_statements.into_iter();
+            let mut statement_iter = starting_statements.into_iter().peekable();
 
             let mut args_moves = Vec::new();
+            if let Some(offset) = indirect_result {
+                args_moves.push(asm::Instruction::StoreRegisterUnscaled {
+                    reg: asm::GPRegister::DWord(8),
+                    base: codegen::util::FRAME_POINTER,
+                    offset: asm::Imm9Signed::new(offset).unwrap(),
+                });
+            }
+
             let arg_targets = codegen::arguments(func.arguments.iter().map(|(_, t)| t.clone()));
-            for ((arg, arg_src), s) in func
-                .arguments
-                .iter()
-                .zip(arg_targets.iter())
-                .zip(statement_iter.by_ref())
-            {
-                let target_reg = match s {
-                    ir::Statement::Assignment { target, value } => {
-                        assert_eq!(arg.0, target.name());
-                        assert_eq!(value, ir::Value::Unknown);
-
-                        match register_map.get(&target).unwrap() {
-                            ArmRegister::GeneralPurpose(n) => asm::GPRegister::DWord(*n),
-                            ArmRegister::FloatingPoint(_n) => todo!("Floating Point Register"),
-                        }
-                    }
-                    other => {
-                        dbg!(&other);
-                        //todo!()
-                        // TODO
-                        // Figure out this part of the Code
-                        continue;
+            for (arg, arg_src) in func.arguments.iter().zip(arg_targets.iter()) {
+                // Previous Arguments might already be spilled before the next Argument is
+                // defined, so the Spills are performed in between the Moves
+                while let Some(stmnt @ ir::Statement::SaveVariable { .. }) = statement_iter.peek() {
+                    args_moves.extend(codegen::statement::to_asm(stmnt.clone(), &asm_ctx));
+                    let _ = statement_iter.next();
+                }
+
+                let (target, target_reg) = match statement_iter.peek() {
+                    Some(ir::Statement::Assignment {
+                        target,
+                        value: ir::Value::Unknown,
+                    }) if target.name() == arg.0 => {
+                        let reg = match asm_ctx.registers.get_reg(target).unwrap() {
+                            asm::Register::GeneralPurpose(
+                                asm::GPRegister::DWord(n) | asm::GPRegister::Word(n),
+                            ) => asm::GPRegister::DWord(n),
+                            asm::Register::FloatingPoint(_) => todo!("Floating Point Register"),
+                        };
+                        let target = target.clone();
+                        let _ = statement_iter.next();
+
+                        (target, reg)
                     }
+                    // Arguments that are never used have been removed by the Optimizer, so there
+                    // is nothing to move them into
+                    _ => continue,
                 };
 
                 match arg_src {
@@ -211,6 +262,61 @@ impl Backend {
                             src: asm::GPRegister::DWord(*n),
                         });
                     }
+                    // Structs passed in Registers are stored in the Memory reserved for them in
+                    // the Stack-Frame and the Variable holds their Address
+                    ArgTarget::GPRegisters { .. } | ArgTarget::FPRegisters { .. } => {
+                        let offset = *asm_ctx.stack_allocs.get(&target).unwrap();
+
+                        args_moves.push(codegen::util::stack_address(target_reg.clone(), offset));
+                        args_moves.extend(codegen::util::store_struct(arg_src, target_reg));
+                    }
+                    // Structs passed on the Stack are used directly from the Memory of the
+                    // Caller, which is right above our own Stack-Frame
+                    ArgTarget::Stack(offset) if matches!(target.ty, ir::Type::Struct { .. }) => {
+                        let offset = asm_ctx.stack_size + offset;
+                        if !(0..4096).contains(&offset) {
+                            panic!("Stack-Argument Offset {} is out of Range", offset);
+                        }
+
+                        args_moves.push(asm::Instruction::AddImmediate {
+                            dest: target_reg,
+                            src: codegen::util::FRAME_POINTER,
+                            immediate: offset as u16,
+                            shift: 0,
+                        });
+                    }
+                    ArgTarget::Reference(inner) => match inner.as_ref() {
+                        ArgTarget::GPRegister(n) => {
+                            args_moves.push(asm::Instruction::MovRegister {
+                                dest: target_reg,
+                                src: asm::GPRegister::DWord(*n),
+                            });
+                        }
+                        // The Address of the Copy is stored in the Memory of the Caller, right
+                        // above our own Stack-Frame
+                        ArgTarget::Stack(offset) => {
+                            let offset = asm_ctx.stack_size + offset;
+                            if !(0..4096).contains(&offset) {
+                                panic!("Stack-Argument Offset {} is out of Range", offset);
+                            }
+
+                            args_moves.push(asm::Instruction::AddImmediate {
+                                dest: target_reg.clone(),
+                                src: codegen::util::FRAME_POINTER,
+                                immediate: offset as u16,
+                                shift: 0,
+                            });
+                            args_moves.push(asm::Instruction::LoadRegisterUnscaled {
+                                reg: target_reg.clone(),
+                                base: asm::GpOrSpRegister::GP(target_reg),
+                                offset: asm::Imm9Signed::new(0).unwrap(),
+                            });
+                        }
+                        other => {
+                            dbg!(&other);
+                            todo!()
+                        }
+                    },
                     other => {
                         dbg!(&other);
                         todo!()
@@ -224,13 +330,6 @@ impl Backend {
             args_moves
         };
 
-        let asm_ctx = codegen::Context {
-            registers: register_map.into(),
-            var: stack_allocation.var_offsets,
-            pre_ret_instr: stack_allocation.pre_return_instr.clone(),
-            stack_allocs: stack_allocation.allocations,
-        };
-
         dbg!(&func.name);
         let mut asm_blocks: Vec<_> = func
             .block
@@ -282,6 +381,7 @@ impl Backend {
                 ir::Statement::Assignment { target, .. } => Some(target),
                 _ => None,
             })
+            .filter(|v| !v.is_tmp())
             .map(|v| (v.name().to_string(), v.ty))
             .collect();
 
@@ -356,6 +456,7 @@ impl Backend {
                 ir::Statement::Call {
                     name: init_name.to_string(),
                     arguments: Vec::new(),
+                    variadic: None,
                 },
                 ir::Statement::Assignment {
                     target: res_var.clone(),
@@ -363,6 +464,7 @@ impl Backend {
                         name: "main".to_string(),
                         arguments: Vec::new(),
                         return_ty: ir::Type::I32,
+                        variadic: None,
                     }),
                 },
                 ir::Statement::Return(Some(res_var)),
@@ -423,8 +525,40 @@ impl register_allocation::Register for ArmRegister {
     }
 }
 
+/// Escapes the Bytes so they can be used in a String of the Assembler, all non printable Bytes
+/// are written as octal Escapes
+fn escape_string(content: &[u8]) -> String {
+    content
+        .iter()
+        .map(|byte| match byte {
+            b'"' => "\\\"".to_string(),
+            b'\\' => "\\\\".to_string(),
+            0x20..=0x7e => (*byte as char).to_string(),
+            _ => format!("\\{:03o}", byte),
+        })
+        .collect()
+}
+
 impl Target for Backend {
     fn generate(&self, program: ir::Program, conf: TargetConfig) {
+        let static_allocs: Vec<(String, usize, usize)> = program
+            .global
+            .get_statements()
+            .into_iter()
+            .filter_map(|s| match s {
+                ir::Statement::Assignment {
+                    value:
+                        ir::Value::Expression(ir::Expression::StaticAlloc {
+                            name,
+                            size,
+                            alignment,
+                        }),
+                    ..
+                } => Some((name, size, alignment)),
+                _ => None,
+            })
+            .collect();
+
         let (g_init_name, global_blocks, global_vars) =
             self.global_init(program.global.clone(), &conf);
 
@@ -458,15 +592,22 @@ impl Target for Backend {
         let (g_var_blocks, g_var_decls): (Vec<_>, Vec<_>) = global_vars
             .into_iter()
             .map(|(g_var, g_type)| {
-                let instr = match g_type {
-                    ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) => {
-                        vec![asm::Instruction::Literal(".quad 0".to_string())]
+                let (instr, alignment): (_, usize) = match g_type {
+                    ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) | ir::Type::Double => {
+                        (vec![asm::Instruction::Literal(".quad 0".to_string())], 8)
                     }
-                    ir::Type::I32 | ir::Type::U32 => {
+                    ir::Type::I32 | ir::Type::U32 | ir::Type::Float => (
                         vec![
                             //asm::Instruction::Literal(".data".to_string()),
                             asm::Instruction::Literal(".long 0".to_string()),
-                        ]
+                        ],
+                        4,
+                    ),
+                    ir::Type::I16 | ir::Type::U16 => {
+                        (vec![asm::Instruction::Literal(".short 0".to_string())], 2)
+                    }
+                    ir::Type::I8 | ir::Type::U8 => {
+                        (vec![asm::Instruction::Literal(".byte 0".to_string())], 1)
                     }
                     other => {
                         dbg!(&other);
@@ -474,11 +615,21 @@ impl Target for Backend {
                     }
                 };
 
+                // An explicit Alignment of the Global is always stricter than the one of its Type
+                let alignment = program
+                    .global_alignments
+                    .get(&g_var)
+                    .copied()
+                    .unwrap_or(alignment);
+
                 (
-                    asm::Block {
-                        name: g_var.clone(),
-                        instructions: instr,
-                    },
+                    (
+                        asm::Block {
+                            name: g_var.clone(),
+                            instructions: instr,
+                        },
+                        alignment,
+                    ),
                     g_var,
                 )
             })
@@ -496,10 +647,54 @@ impl Target for Backend {
             dbg!(&decl);
             //asm_text.push_str(&format!(".global {}\n", &decl));
         }
-        for block in g_var_blocks {
+        for (block, alignment) in g_var_blocks {
+            asm_text.push_str(&format!(".p2align {}\n", alignment.trailing_zeros()));
+
             let block_text = block.to_text();
             asm_text.push_str(&block_text);
         }
+        for (label, size, alignment) in static_allocs {
+            asm_text.push_str(&format!(".p2align {}\n", alignment.trailing_zeros()));
+
+            let block = asm::Block {
+                name: label,
+                instructions: vec![asm::Instruction::Literal(format!(".space {}", size))],
+            };
+            asm_text.push_str(&block.to_text());
+        }
+
+        let mut constants: Vec<_> = program.constants.into_iter().collect();
+        constants.sort_by(|(first, _), (second, _)| first.cmp(second));
+        for (label, constant) in constants {
+            let block = if constant.is_c_string() {
+                asm_text.push_str(".cstring\n");
+
+                let content = &constant.content[..constant.content.len() - 1];
+                asm::Block {
+                    name: label,
+                    instructions: vec![asm::Instruction::Literal(format!(
+                        ".asciz \"{}\"",
+                        escape_string(content)
+                    ))],
+                }
+            } else {
+                asm_text.push_str(".const\n");
+                asm_text.push_str(&format!(
+                    ".p2align {}\n",
+                    constant.alignment.trailing_zeros()
+                ));
+
+                let bytes: Vec<_> = constant.content.iter().map(|b| b.to_string()).collect();
+                asm::Block {
+                    name: label,
+                    instructions: vec![asm::Instruction::Literal(format!(
+                        ".byte {}",
+                        bytes.join(", ")
+                    ))],
+                }
+            };
+            asm_text.push_str(&block.to_text());
+        }
 
         let asm_path = conf.build_dir.join("code.s");
         std::fs::write(&asm_path, asm_text).unwrap();
@@ -7,7 +7,7 @@ mod to_asm;
 pub use to_asm::*;
 
 mod expression;
-mod statement;
+pub mod statement;
 
 pub mod util;
 
@@ -18,6 +18,11 @@ pub struct Context {
     pub pre_ret_instr: Vec<asm::Instruction>,
     pub var: HashMap<String, isize>,
     pub stack_allocs: HashMap<ir::Variable, isize>,
+    /// The Size of the Stack-Frame allocated by the Function itself
+    pub stack_size: usize,
+    /// The Offset in the Stack-Frame at which the Address from x8 is stored, if the Function
+    /// returns a Struct through the Memory provided by the Caller
+    pub indirect_result: Option<i16>,
 }
 
 pub struct RegisterMap {
@@ -36,7 +41,7 @@ impl RegisterMap {
 
         let result = match raw {
             ArmRegister::GeneralPurpose(n) => match &var.ty {
-                ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) => {
+                ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) | ir::Type::Struct { .. } => {
                     asm::Register::GeneralPurpose(asm::GPRegister::DWord(*n))
                 }
                 _ => asm::Register::GeneralPurpose(asm::GPRegister::Word(*n)),
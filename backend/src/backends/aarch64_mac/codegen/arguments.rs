@@ -5,6 +5,131 @@ pub enum ArgTarget {
     GPRegister(u8),
     FPRegister(u8),
     Stack(usize),
+    /// A Struct that is split into 8-Byte Chunks, which are stored in the given Number of
+    /// consecutive General-Purpose Registers
+    GPRegisters {
+        first: u8,
+        count: u8,
+    },
+    /// A Homogeneous Floating-point Aggregate, where every Member is stored in its own
+    /// SIMD-Register, starting at the first one
+    FPRegisters {
+        first: u8,
+        count: u8,
+        member: Type,
+    },
+    /// A Struct that is passed as the Address of a Copy of it, where the Address itself is
+    /// passed like a normal Pointer-Argument
+    Reference(Box<ArgTarget>),
+    /// A Struct that is returned by writing it into the Memory, whose Address was passed by the
+    /// Caller in x8
+    IndirectResult,
+}
+
+/// The Size and Alignment in Bytes of the given Type, where Structs already carry the Layout
+/// determined by the Frontend
+pub fn size_align(ty: &Type) -> (usize, usize) {
+    match ty {
+        Type::Void => (0, 1),
+        Type::I8 | Type::U8 => (1, 1),
+        Type::I16 | Type::U16 => (2, 2),
+        Type::I32 | Type::U32 | Type::Float => (4, 4),
+        Type::I64 | Type::U64 | Type::Double | Type::LongDouble => (8, 8),
+        Type::Pointer(_) => (8, 8),
+        Type::Array(inner, count) => {
+            let (size, align) = size_align(inner);
+            (size * count, align)
+        }
+        Type::Struct {
+            size, alignment, ..
+        } => (*size, *alignment),
+    }
+}
+
+/// Checks if the Type is a Homogeneous Floating-point Aggregate, which is a Struct consisting of
+/// 1 to 4 Members of the same Floating-Point Type, including the Members of nested Structs and
+/// Arrays. Returns the Type of the Members and their Count
+fn homogeneous_aggregate(ty: &Type) -> Option<(Type, u8)> {
+    fn base_types(ty: &Type, result: &mut Vec<Type>) {
+        match ty {
+            Type::Struct { members, .. } => {
+                for (_, memb_ty) in members {
+                    base_types(memb_ty, result);
+                }
+            }
+            Type::Array(inner, count) => {
+                for _ in 0..*count {
+                    base_types(inner, result);
+                }
+            }
+            other => result.push(other.clone()),
+        }
+    }
+
+    let mut bases = Vec::new();
+    base_types(ty, &mut bases);
+
+    let first = bases.first()?.clone();
+    if !first.is_float() || bases.len() > 4 || bases.iter().any(|b| b != &first) {
+        return None;
+    }
+
+    Some((first, bases.len() as u8))
+}
+
+/// Determines how a Struct is passed, while updating the Register-Counters accordingly
+fn struct_argument(ty: &Type, ngrn: &mut u8, nsrn: &mut u8, nsaa: &mut usize) -> ArgTarget {
+    let (size, align) = size_align(ty);
+
+    if let Some((member, count)) = homogeneous_aggregate(ty) {
+        if *nsrn + count <= 8 {
+            let first = *nsrn;
+            *nsrn += count;
+            return ArgTarget::FPRegisters {
+                first,
+                count,
+                member,
+            };
+        }
+
+        // An HFA is never split between Registers and the Stack
+        *nsrn = 8;
+        *nsaa = nsaa.next_multiple_of(align.max(8));
+        let target = ArgTarget::Stack(*nsaa);
+        *nsaa += size.next_multiple_of(8);
+        return target;
+    }
+
+    if size > 16 {
+        let target = if *ngrn < 8 {
+            *ngrn += 1;
+            ArgTarget::GPRegister(*ngrn - 1)
+        } else {
+            *nsaa = nsaa.next_multiple_of(8);
+            let target = ArgTarget::Stack(*nsaa);
+            *nsaa += 8;
+            target
+        };
+        return ArgTarget::Reference(Box::new(target));
+    }
+
+    // Structs with an Alignment of 16 Bytes start at an even Register
+    if align == 16 {
+        *ngrn = ngrn.next_multiple_of(2);
+    }
+
+    let count = size.div_ceil(8) as u8;
+    if *ngrn + count <= 8 {
+        let first = *ngrn;
+        *ngrn += count;
+        return ArgTarget::GPRegisters { first, count };
+    }
+
+    *ngrn = 8;
+    *nsaa = nsaa.next_multiple_of(align.max(8));
+    let target = ArgTarget::Stack(*nsaa);
+    *nsaa += size.next_multiple_of(8);
+    target
 }
 
 pub fn arguments<AI, IAI>(arguments: IAI) -> Vec<ArgTarget>
@@ -21,9 +146,8 @@ where
 
     for arg in arguments {
         match arg {
-            Type::Struct { members } => {
-                dbg!(&members);
-                todo!("Passing Struct Argument")
+            ty @ Type::Struct { .. } => {
+                result.push(struct_argument(&ty, &mut ngrn, &mut nsrn, &mut nsaa));
             }
             Type::Float | Type::Double | Type::LongDouble if nsrn < 8 => {
                 result.push(ArgTarget::FPRegister(nsrn));
@@ -61,6 +185,16 @@ where
     result
 }
 
+/// Determines where a Value of the given Type is returned, which is the same Location as for
+/// the first Argument of that Type, except for Structs that would be passed by Reference, as
+/// those are written into the Memory provided by the Caller instead
+pub fn return_target(ty: Type) -> ArgTarget {
+    match arguments(std::iter::once(ty)).remove(0) {
+        ArgTarget::Reference(_) => ArgTarget::IndirectResult,
+        other => other,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +242,161 @@ mod tests {
 
         assert_eq!(expected, result);
     }
+
+    fn struct_ty(members: Vec<Type>) -> Type {
+        let mut size: usize = 0;
+        let mut alignment = 1;
+        for memb_ty in members.iter() {
+            let (memb_size, memb_align) = size_align(memb_ty);
+            size = size.next_multiple_of(memb_align) + memb_size;
+            alignment = alignment.max(memb_align);
+        }
+
+        Type::Struct {
+            members: members
+                .into_iter()
+                .enumerate()
+                .map(|(i, ty)| (format!("m{}", i), ty))
+                .collect(),
+            size: size.next_multiple_of(alignment),
+            alignment,
+        }
+    }
+
+    #[test]
+    fn small_struct_registers() {
+        let expected = vec![
+            ArgTarget::GPRegister(0),
+            ArgTarget::GPRegisters { first: 1, count: 2 },
+            ArgTarget::GPRegister(3),
+        ];
+
+        let result = arguments(vec![
+            Type::I32,
+            struct_ty(vec![Type::I32, Type::I32, Type::I32]),
+            Type::I64,
+        ]);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn homogeneous_float_aggregate() {
+        let expected = vec![
+            ArgTarget::FPRegisters {
+                first: 0,
+                count: 4,
+                member: Type::Float,
+            },
+            ArgTarget::FPRegisters {
+                first: 4,
+                count: 2,
+                member: Type::Double,
+            },
+            ArgTarget::FPRegister(6),
+        ];
+
+        let result = arguments(vec![
+            struct_ty(vec![
+                struct_ty(vec![Type::Float, Type::Float]),
+                Type::Array(Box::new(Type::Float), 2),
+            ]),
+            struct_ty(vec![Type::Double, Type::Double]),
+            Type::Float,
+        ]);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn homogeneous_aggregate_on_stack() {
+        let hfa = struct_ty(vec![Type::Float; 4]);
+
+        let result = arguments(vec![hfa.clone(), hfa.clone(), hfa, Type::I32]);
+
+        assert_eq!(ArgTarget::Stack(0), result[2]);
+        assert_eq!(ArgTarget::GPRegister(0), result[3]);
+    }
+
+    #[test]
+    fn struct_layout_from_frontend() {
+        // An explicitly aligned Member makes the Struct larger than its Members alone
+        let aligned = Type::Struct {
+            members: vec![("x".to_string(), Type::I32)],
+            size: 16,
+            alignment: 16,
+        };
+
+        assert_eq!((16, 16), size_align(&aligned));
+
+        let result = arguments(vec![Type::I32, aligned]);
+
+        assert_eq!(ArgTarget::GPRegisters { first: 2, count: 2 }, result[1]);
+    }
+
+    #[test]
+    fn mixed_float_struct() {
+        let expected = vec![ArgTarget::GPRegisters { first: 0, count: 2 }];
+
+        let result = arguments(vec![struct_ty(vec![Type::Float, Type::Double])]);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn large_struct_reference() {
+        let expected = vec![
+            ArgTarget::Reference(Box::new(ArgTarget::GPRegister(0))),
+            ArgTarget::GPRegister(1),
+        ];
+
+        let result = arguments(vec![struct_ty(vec![Type::I64; 3]), Type::I32]);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn large_struct_reference_on_stack() {
+        let mut args = vec![Type::I64; 8];
+        args.push(struct_ty(vec![Type::I64; 3]));
+
+        let result = arguments(args);
+
+        assert_eq!(
+            ArgTarget::Reference(Box::new(ArgTarget::Stack(0))),
+            result[8]
+        );
+    }
+
+    #[test]
+    fn struct_not_split() {
+        let mut args = vec![Type::I64; 7];
+        args.push(struct_ty(vec![Type::I64, Type::I64]));
+        args.push(Type::I64);
+
+        let result = arguments(args);
+
+        assert_eq!(ArgTarget::Stack(0), result[7]);
+        assert_eq!(ArgTarget::Stack(16), result[8]);
+    }
+
+    #[test]
+    fn struct_returns() {
+        assert_eq!(
+            ArgTarget::GPRegisters { first: 0, count: 2 },
+            return_target(struct_ty(vec![Type::I32; 4]))
+        );
+        assert_eq!(
+            ArgTarget::FPRegisters {
+                first: 0,
+                count: 2,
+                member: Type::Float
+            },
+            return_target(struct_ty(vec![Type::Float; 2]))
+        );
+        assert_eq!(
+            ArgTarget::IndirectResult,
+            return_target(struct_ty(vec![Type::I32; 5]))
+        );
+    }
 }
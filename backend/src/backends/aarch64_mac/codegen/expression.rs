@@ -70,8 +70,12 @@ pub fn convert_assigned(
                                 todo!()
                             }
                         }
-                        asm::Register::FloatingPoint(_n) => {
-                            todo!()
+                        // Converting between Single- and Double-Precision
+                        asm::Register::FloatingPoint(base_reg) => {
+                            instructions.push(asm::Instruction::FloatingPointConvert {
+                                src: base_reg,
+                                dest: t_reg,
+                            });
                         }
                     };
                 }
@@ -86,10 +90,7 @@ pub fn convert_assigned(
         ir::Expression::AdressOf {
             base: ir::Operand::Variable(var),
         } => {
-            dbg!(&var);
-
             let offset = *ctx.var.get(var.name()).unwrap();
-            dbg!(&offset);
 
             let t_reg = match t_reg {
                 asm::Register::GeneralPurpose(r) => r,
@@ -102,7 +103,7 @@ pub fn convert_assigned(
             if (0..4096).contains(&offset) {
                 let addr_instr = asm::Instruction::AddImmediate {
                     dest: t_reg,
-                    src: asm::GpOrSpRegister::SP,
+                    src: util::FRAME_POINTER,
                     immediate: offset as u16,
                     shift: 0,
                 };
@@ -112,7 +113,7 @@ pub fn convert_assigned(
                 panic!()
             }
         }
-        ir::Expression::StackAlloc { .. } => {
+        ir::Expression::StackAlloc { alignment, .. } => {
             let alloc_offset = *ctx.stack_allocs.get(&target_var).unwrap();
 
             let t_reg = match t_reg {
@@ -125,16 +126,100 @@ pub fn convert_assigned(
 
             if (0..4096).contains(&alloc_offset) {
                 instructions.push(asm::Instruction::AddImmediate {
-                    dest: t_reg,
-                    src: asm::GpOrSpRegister::SP,
+                    dest: t_reg.clone(),
+                    src: util::FRAME_POINTER,
                     immediate: alloc_offset as u16,
                     shift: 0,
                 });
             } else {
                 panic!()
             }
+
+            // The Frame-Pointer is only 16-Byte aligned, so the Address is rounded up in the
+            // additional Space that was reserved for the Allocation
+            if alignment > 16 {
+                instructions.push(asm::Instruction::Literal(format!(
+                    "add {}, {}, #{}",
+                    t_reg,
+                    t_reg,
+                    alignment - 1
+                )));
+                instructions.push(asm::Instruction::Literal(format!(
+                    "and {}, {}, #-{}",
+                    t_reg, t_reg, alignment
+                )));
+            }
+        }
+        ir::Expression::DynamicStackAlloc { size, alignment } => {
+            let t_reg = match t_reg {
+                asm::Register::GeneralPurpose(r) => r,
+                other => {
+                    dbg!(&other);
+                    panic!("Addresses should never be stored in a Floating Point Register")
+                }
+            };
+
+            let size_reg = match size {
+                Operand::Variable(size_var) => match ctx.registers.get_reg(&size_var).unwrap() {
+                    asm::Register::GeneralPurpose(
+                        asm::GPRegister::DWord(n) | asm::GPRegister::Word(n),
+                    ) => asm::GPRegister::DWord(n),
+                    other => {
+                        dbg!(&other);
+                        panic!("Sizes should never be stored in a Floating Point Register")
+                    }
+                },
+                Operand::Constant(con) => {
+                    instructions.extend(util::constant_to_asm(
+                        &con,
+                        asm::Register::GeneralPurpose(asm::GPRegister::DWord(9)),
+                    ));
+                    asm::GPRegister::DWord(9)
+                }
+            };
+
+            // The Stack-Pointer always has to stay 16-Byte aligned, so the Size is rounded up
+            instructions.push(asm::Instruction::Literal(format!(
+                "add x9, {}, #15",
+                size_reg
+            )));
+            instructions.push(asm::Instruction::Literal("and x9, x9, #-16".to_string()));
+            instructions.push(asm::Instruction::Literal("sub sp, sp, x9".to_string()));
+            if alignment > 16 {
+                instructions.push(asm::Instruction::Literal("mov x9, sp".to_string()));
+                instructions.push(asm::Instruction::Literal(format!(
+                    "and x9, x9, #-{}",
+                    alignment
+                )));
+                instructions.push(asm::Instruction::Literal("mov sp, x9".to_string()));
+            }
+
+            instructions.push(asm::Instruction::AddImmediate {
+                dest: t_reg,
+                src: asm::GpOrSpRegister::SP,
+                immediate: 0,
+                shift: 0,
+            });
         }
-        ir::Expression::ReadMemory { address, read_ty } => {
+        ir::Expression::StackSave => {
+            let t_reg = match t_reg {
+                asm::Register::GeneralPurpose(r) => r,
+                other => {
+                    dbg!(&other);
+                    panic!("Addresses should never be stored in a Floating Point Register")
+                }
+            };
+
+            instructions.push(asm::Instruction::AddImmediate {
+                dest: t_reg,
+                src: asm::GpOrSpRegister::SP,
+                immediate: 0,
+                shift: 0,
+            });
+        }
+        ir::Expression::ReadMemory {
+            address, read_ty, ..
+        } => {
             dbg!(&address, &read_ty);
 
             let base_reg = match address {
@@ -164,44 +249,129 @@ pub fn convert_assigned(
                 &mut instructions,
             );
         }
-        ir::Expression::ReadGlobalVariable { name } => {
-            let addr_register = asm::GPRegister::DWord(9);
+        ir::Expression::FunctionAddress { name }
+        | ir::Expression::ConstantAddress { name }
+        | ir::Expression::GlobalAddress { name } => {
+            let target = match t_reg {
+                asm::Register::GeneralPurpose(asm::GPRegister::DWord(n)) => {
+                    asm::GPRegister::DWord(n)
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
 
             instructions.push(asm::Instruction::Literal(format!(
                 "adrp {}, {}@PAGE",
-                addr_register, name,
+                target, name,
             )));
             instructions.push(asm::Instruction::Literal(format!(
                 "add {}, {}, {}@PAGEOFF",
-                addr_register, addr_register, name
+                target, target, name
             )));
-
-            match (t_reg, target_var.ty) {
-                (asm::Register::GeneralPurpose(target), ir::Type::Pointer(_))
-                | (asm::Register::GeneralPurpose(target), ir::Type::U32)
-                | (asm::Register::GeneralPurpose(target), ir::Type::I32) => {
-                    instructions.push(asm::Instruction::LoadRegisterUnscaled {
-                        reg: target,
-                        base: asm::GpOrSpRegister::GP(addr_register),
-                        offset: asm::Imm9Signed::new(0).unwrap(),
-                    });
+        }
+        ir::Expression::StaticAlloc { name, .. } => {
+            let target = match t_reg {
+                asm::Register::GeneralPurpose(r) => r,
+                other => {
+                    dbg!(&other);
+                    panic!("Addresses should never be stored in a Floating Point Register")
                 }
+            };
+            instructions.push(asm::Instruction::Literal(format!(
+                "adrp {}, {}@PAGE",
+                target, name,
+            )));
+            instructions.push(asm::Instruction::Literal(format!(
+                "add {}, {}, {}@PAGEOFF",
+                target, target, name
+            )));
+        }
+        ir::Expression::VarArgsStart => {
+            // The Caller placed the variable Arguments right at its Stack-Pointer, which is
+            // directly above our own Stack-Frame
+            let t_reg = match t_reg {
+                asm::Register::GeneralPurpose(r) => r,
                 other => {
                     dbg!(&other);
-                    todo!()
+                    panic!("Addresses should never be stored in a Floating Point Register")
                 }
             };
+
+            if (0..4096).contains(&ctx.stack_size) {
+                instructions.push(asm::Instruction::AddImmediate {
+                    dest: t_reg,
+                    src: util::FRAME_POINTER,
+                    immediate: ctx.stack_size as u16,
+                    shift: 0,
+                });
+            } else {
+                // The Offset does not fit into the Immediate, so it is loaded into the Target
+                // first and then added to the Frame-Pointer
+                instructions.extend(util::constant_to_asm(
+                    &ir::Constant::I64(ctx.stack_size as i64),
+                    asm::Register::GeneralPurpose(t_reg.clone()),
+                ));
+                instructions.push(asm::Instruction::AddRegisterShifted {
+                    dest: t_reg.clone(),
+                    src1: asm::GPRegister::DWord(29),
+                    src2: t_reg,
+                    shift: asm::Shift::LSL,
+                    amount: 0,
+                });
+            }
+        }
+        ir::Expression::ReadGlobalVariable { name } => {
+            let addr_register = asm::GPRegister::DWord(9);
+
+            instructions.push(asm::Instruction::Literal(format!(
+                "adrp {}, {}@PAGE",
+                addr_register, name,
+            )));
+            instructions.push(asm::Instruction::Literal(format!(
+                "add {}, {}, {}@PAGEOFF",
+                addr_register, addr_register, name
+            )));
+
+            load::load(
+                t_reg,
+                target_var.ty,
+                asm::GpOrSpRegister::GP(addr_register),
+                0,
+                &mut instructions,
+            );
         }
         ir::Expression::FunctionCall {
             name,
             arguments,
             return_ty,
+            variadic,
+        } => {
+            function_call::to_asm(
+                function_call::CallTarget::Name(name),
+                arguments,
+                variadic,
+                return_ty,
+                Some(t_reg),
+                ctx.stack_allocs.get(&target_var).copied(),
+                ctx,
+                &mut instructions,
+            );
+        }
+        ir::Expression::IndirectFunctionCall {
+            target,
+            arguments,
+            return_ty,
+            variadic,
         } => {
             function_call::to_asm(
-                name,
+                function_call::CallTarget::Address(target),
                 arguments,
+                variadic,
                 return_ty,
                 Some(t_reg),
+                ctx.stack_allocs.get(&target_var).copied(),
                 ctx,
                 &mut instructions,
             );
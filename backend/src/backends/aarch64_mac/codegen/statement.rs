@@ -6,7 +6,10 @@ use crate::backends::aarch64_mac::{
     ArmRegister,
 };
 
-use super::{block_name, expression, function_call, load, write, Context};
+use super::{
+    block_name, expression, function_call, load, return_target, size_align, util, write, ArgTarget,
+    Context,
+};
 
 pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
     let mut instructions = Vec::new();
@@ -22,17 +25,34 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
                 Some(offset) => {
                     match src_reg {
                         asm::Register::GeneralPurpose(gp) => match &var.ty {
-                            ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) => {
+                            ir::Type::I64
+                            | ir::Type::U64
+                            | ir::Type::Pointer(_)
+                            | ir::Type::Struct { .. } => {
                                 instructions.push(asm::Instruction::StoreRegisterUnscaled {
                                     reg: gp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset,
                                 });
                             }
                             ir::Type::I32 | ir::Type::U32 => {
                                 instructions.push(asm::Instruction::StoreRegisterUnscaled {
                                     reg: gp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
+                                    offset,
+                                });
+                            }
+                            ir::Type::I16 | ir::Type::U16 => {
+                                instructions.push(asm::Instruction::StoreHalfWordUnscaled {
+                                    reg: gp,
+                                    base: util::FRAME_POINTER,
+                                    offset,
+                                });
+                            }
+                            ir::Type::I8 | ir::Type::U8 => {
+                                instructions.push(asm::Instruction::StoreByteUnscaled {
+                                    reg: gp,
+                                    base: util::FRAME_POINTER,
                                     offset,
                                 });
                             }
@@ -49,14 +69,14 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
                             ir::Type::Float => {
                                 instructions.push(asm::Instruction::StoreFPUnscaled {
                                     reg: fp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset,
                                 });
                             }
                             ir::Type::Double => {
                                 instructions.push(asm::Instruction::StoreFPUnscaled {
                                     reg: fp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset,
                                 });
                             }
@@ -82,17 +102,20 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
 
                     match src_reg {
                         asm::Register::GeneralPurpose(gp) => match &var.ty {
-                            ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) => {
+                            ir::Type::I64
+                            | ir::Type::U64
+                            | ir::Type::Pointer(_)
+                            | ir::Type::Struct { .. } => {
                                 instructions.push(asm::Instruction::StoreRegisterRegisterOffset {
                                     reg: gp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset: offset_reg,
                                 });
                             }
                             ir::Type::I32 | ir::Type::U32 => {
                                 instructions.push(asm::Instruction::StoreRegisterRegisterOffset {
                                     reg: gp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset: offset_reg,
                                 });
                             }
@@ -109,7 +132,7 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
                             ir::Type::Float => {
                                 instructions.push(asm::Instruction::StoreFPRegisterOffset {
                                     reg: fp,
-                                    base: asm::GpOrSpRegister::SP,
+                                    base: util::FRAME_POINTER,
                                     offset: offset_reg,
                                 });
                             }
@@ -123,47 +146,64 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
             };
         }
         Statement::SaveGlobalVariable { name, value } => {
-            todo!();
+            let addr_reg = asm::GPRegister::DWord(9);
 
-            match ctx.registers.get_reg(&value).unwrap() {
-                asm::Register::GeneralPurpose(gp) => {
-                    let addr_reg = asm::GPRegister::DWord(9);
+            instructions.push(asm::Instruction::Literal(format!(
+                "adrp {}, {}@PAGE",
+                addr_reg, name,
+            )));
+            instructions.push(asm::Instruction::Literal(format!(
+                "add {}, {}, {}@PAGEOFF",
+                addr_reg, addr_reg, name
+            )));
 
-                    instructions.push(asm::Instruction::Literal(format!(
-                        "adrp {}, {}@PAGE",
-                        addr_reg, name,
-                    )));
-                    instructions.push(asm::Instruction::Literal(format!(
-                        "add {}, {}, {}@PAGEOFF",
-                        addr_reg, addr_reg, name
-                    )));
-
-                    match value.ty {
-                        ir::Type::Pointer(_)
-                        | ir::Type::U64
-                        | ir::Type::I64
-                        | ir::Type::U32
-                        | ir::Type::I32 => {
-                            //instructions
-                            //    .push(asm::Instruction::Literal(format!("str {}, [x9]", gp)));
-
-                            instructions.push(asm::Instruction::StoreRegisterUnscaled {
-                                reg: gp,
-                                base: asm::GpOrSpRegister::GP(addr_reg),
-                                offset: asm::Imm9Signed::new(0).unwrap(),
-                            });
-                        }
-                        other => {
-                            dbg!(&other);
-                            todo!()
-                        }
-                    };
-                }
-                asm::Register::FloatingPoint(fp) => {
-                    dbg!(&fp);
-
-                    todo!("Save Floating Point global")
-                }
+            let base = asm::GpOrSpRegister::GP(addr_reg);
+            let offset = asm::Imm9Signed::new(0).unwrap();
+            match ctx.registers.get_reg(&value).unwrap() {
+                asm::Register::GeneralPurpose(gp) => match value.ty {
+                    ir::Type::Pointer(_)
+                    | ir::Type::U64
+                    | ir::Type::I64
+                    | ir::Type::U32
+                    | ir::Type::I32 => {
+                        instructions.push(asm::Instruction::StoreRegisterUnscaled {
+                            reg: gp,
+                            base,
+                            offset,
+                        });
+                    }
+                    ir::Type::I16 | ir::Type::U16 => {
+                        instructions.push(asm::Instruction::StoreHalfWordUnscaled {
+                            reg: gp,
+                            base,
+                            offset,
+                        });
+                    }
+                    ir::Type::I8 | ir::Type::U8 => {
+                        instructions.push(asm::Instruction::StoreByteUnscaled {
+                            reg: gp,
+                            base,
+                            offset,
+                        });
+                    }
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                },
+                asm::Register::FloatingPoint(fp) => match value.ty {
+                    ir::Type::Float | ir::Type::Double => {
+                        instructions.push(asm::Instruction::StoreFPUnscaled {
+                            reg: fp,
+                            base,
+                            offset,
+                        });
+                    }
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                },
             };
         }
         Statement::Assignment {
@@ -206,30 +246,21 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
             let t_reg = ctx.registers.get_reg(&target).unwrap();
 
             match t_reg {
-                asm::Register::GeneralPurpose(t_reg) => match con {
-                    Constant::I32(val) => {
-                        if val < (i16::MAX as i32) && val >= 0 {
-                            instructions.push(asm::Instruction::Movz {
-                                dest: t_reg,
-                                shift: 0,
-                                immediate: val as u16,
-                            });
-                        } else {
-                            todo!()
-                        }
-                    }
-                    Constant::I64(val) => {
-                        instructions.push(asm::Instruction::MovI64 {
+                asm::Register::GeneralPurpose(t_reg) => {
+                    instructions.extend(util::constant_to_asm(
+                        &con,
+                        asm::Register::GeneralPurpose(t_reg),
+                    ));
+                }
+                asm::Register::FloatingPoint(t_reg) => match con {
+                    Constant::F32(f_val) => {
+                        let immediate = FloatImm8::new(f_val);
+
+                        instructions.push(asm::Instruction::FMovImmediate {
                             dest: t_reg,
-                            value: val,
+                            imm: immediate,
                         });
                     }
-                    other => {
-                        dbg!(&other);
-                        todo!()
-                    }
-                },
-                asm::Register::FloatingPoint(t_reg) => match con {
                     Constant::F64(f_val) => {
                         let immediate = FloatImm8::new(f_val as f32);
 
@@ -275,6 +306,41 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
                 target: target_name,
             });
         }
+        Statement::Return(Some(ret_var)) if matches!(ret_var.ty, ir::Type::Struct { .. }) => {
+            let address = match ctx.registers.get_reg(&ret_var).unwrap() {
+                asm::Register::GeneralPurpose(gp) => gp,
+                other => {
+                    dbg!(&other);
+                    panic!("Addresses should never be stored in a Floating Point Register")
+                }
+            };
+
+            let (size, _) = size_align(&ret_var.ty);
+            match return_target(ret_var.ty.clone()) {
+                ArgTarget::IndirectResult => {
+                    let offset = ctx
+                        .indirect_result
+                        .expect("Function returns Struct through Memory");
+
+                    instructions.push(asm::Instruction::LoadRegisterUnscaled {
+                        reg: asm::GPRegister::DWord(9),
+                        base: util::FRAME_POINTER,
+                        offset: asm::Imm9Signed::new(offset).unwrap(),
+                    });
+                    instructions.push(asm::Instruction::MovRegister {
+                        dest: asm::GPRegister::DWord(10),
+                        src: address,
+                    });
+                    instructions.extend(util::copy_memory(9, 10, 11, size));
+                }
+                target => {
+                    instructions.extend(util::load_struct(&target, size, address));
+                }
+            };
+
+            instructions.extend(ctx.pre_ret_instr.clone());
+            instructions.push(asm::Instruction::Return);
+        }
         Statement::Return(Some(ret_var)) => {
             let ret_var_reg = ctx.registers.get_reg(&ret_var).unwrap();
             match ret_var_reg {
@@ -305,16 +371,51 @@ pub fn to_asm(stmnt: ir::Statement, ctx: &Context) -> Vec<asm::Instruction> {
             instructions.extend(ctx.pre_ret_instr.clone());
             instructions.push(asm::Instruction::Return);
         }
-        ir::Statement::WriteMemory { target, value } => {
+        ir::Statement::StackRestore { value } => {
+            let saved_reg = match ctx.registers.get_reg(&value).unwrap() {
+                asm::Register::GeneralPurpose(asm::GPRegister::DWord(n)) => {
+                    asm::GPRegister::DWord(n)
+                }
+                other => {
+                    dbg!(&other);
+                    panic!("The Stack-Pointer should always be saved in a 64-bit Register")
+                }
+            };
+
+            instructions.push(asm::Instruction::Literal(format!("mov sp, {}", saved_reg)));
+        }
+        ir::Statement::WriteMemory { target, value, .. } => {
             let write_instr = write::write(target, value, ctx);
             instructions.extend(write_instr);
         }
-        ir::Statement::Call { name, arguments } => {
+        ir::Statement::Call {
+            name,
+            arguments,
+            variadic,
+        } => {
+            function_call::to_asm(
+                function_call::CallTarget::Name(name),
+                arguments,
+                variadic,
+                ir::Type::Void,
+                None,
+                None,
+                ctx,
+                &mut instructions,
+            );
+        }
+        ir::Statement::IndirectCall {
+            target,
+            arguments,
+            variadic,
+        } => {
             function_call::to_asm(
-                name,
+                function_call::CallTarget::Address(target),
                 arguments,
+                variadic,
                 ir::Type::Void,
                 None,
+                None,
                 ctx,
                 &mut instructions,
             );
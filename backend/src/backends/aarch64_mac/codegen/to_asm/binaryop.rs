@@ -120,9 +120,21 @@ pub fn to_asm(
                         }
                     };
                 }
-                other => {
-                    dbg!(&other);
-                    todo!()
+                (ir::Operand::Constant(_), _) => {
+                    let (l_gp, r_gp) = gp_operands(left.clone(), right.clone(), ctx, instructions);
+
+                    let (first, second) = if !swap_sites {
+                        (l_gp, r_gp)
+                    } else {
+                        (r_gp, l_gp)
+                    };
+
+                    instructions.push(asm::Instruction::CmpShifted {
+                        first,
+                        second,
+                        shift: asm::Shift::LSL,
+                        amount: 0,
+                    });
                 }
             };
 
@@ -222,10 +234,36 @@ pub fn to_asm(
                     dbg!(&left_con, &right_con);
 
                     let (left_reg, right_reg) = match (&left_con, &right_con) {
-                        (ir::Constant::I64(_), ir::Constant::I64(_)) => (
+                        // Mixed Sizes are both loaded as 64-Bit Values, like for Variables
+                        (
+                            ir::Constant::I64(_) | ir::Constant::U64(_),
+                            ir::Constant::I32(_)
+                            | ir::Constant::U32(_)
+                            | ir::Constant::I64(_)
+                            | ir::Constant::U64(_),
+                        )
+                        | (
+                            ir::Constant::I32(_) | ir::Constant::U32(_),
+                            ir::Constant::I64(_) | ir::Constant::U64(_),
+                        ) => (
                             asm::Register::GeneralPurpose(asm::GPRegister::DWord(9)),
                             asm::Register::GeneralPurpose(asm::GPRegister::DWord(10)),
                         ),
+                        (
+                            ir::Constant::I32(_) | ir::Constant::U32(_),
+                            ir::Constant::I32(_) | ir::Constant::U32(_),
+                        ) => (
+                            asm::Register::GeneralPurpose(asm::GPRegister::Word(9)),
+                            asm::Register::GeneralPurpose(asm::GPRegister::Word(10)),
+                        ),
+                        (ir::Constant::F32(_), ir::Constant::F32(_)) => (
+                            asm::Register::FloatingPoint(asm::FPRegister::SinglePrecision(30)),
+                            asm::Register::FloatingPoint(asm::FPRegister::SinglePrecision(31)),
+                        ),
+                        (ir::Constant::F64(_), ir::Constant::F64(_)) => (
+                            asm::Register::FloatingPoint(asm::FPRegister::DoublePrecision(30)),
+                            asm::Register::FloatingPoint(asm::FPRegister::DoublePrecision(31)),
+                        ),
                         (l, r) => {
                             dbg!(l, r);
                             todo!()
@@ -394,9 +432,134 @@ pub fn to_asm(
                 }
             };
         }
-        other => {
-            dbg!(&other);
-            todo!()
+        ir::BinaryOp::Bitwise(bitwise_op) => {
+            let signed = left.ty().signed();
+
+            let (first_reg, second_reg) = gp_operands(left, right, ctx, instructions);
+            let t_reg = match t_reg {
+                asm::Register::GeneralPurpose(gp) => match (&gp, &first_reg) {
+                    (asm::GPRegister::DWord(n), asm::GPRegister::Word(_)) => {
+                        asm::GPRegister::Word(*n)
+                    }
+                    _ => gp,
+                },
+                asm::Register::FloatingPoint(_) => panic!("Bitwise Operation in FP-Register"),
+            };
+
+            let instr = match bitwise_op {
+                ir::BinaryBitwiseOp::And => asm::Instruction::BitwiseAndRegisterShifted {
+                    dest: t_reg,
+                    src1: first_reg,
+                    src2: second_reg,
+                    shift: asm::Shift::LSL,
+                    amount: 0,
+                },
+                ir::BinaryBitwiseOp::Or => asm::Instruction::BitwiseOrRegisterShifted {
+                    dest: t_reg,
+                    src1: first_reg,
+                    src2: second_reg,
+                    shift: asm::Shift::LSL,
+                    amount: 0,
+                },
+                ir::BinaryBitwiseOp::Xor => asm::Instruction::BitwiseXorRegisterShifted {
+                    dest: t_reg,
+                    src1: first_reg,
+                    src2: second_reg,
+                    shift: asm::Shift::LSL,
+                    amount: 0,
+                },
+                ir::BinaryBitwiseOp::ShiftLeft => asm::Instruction::ShiftLeftRegister {
+                    dest: t_reg,
+                    src1: first_reg,
+                    src2: second_reg,
+                },
+                ir::BinaryBitwiseOp::ShiftRight if signed => {
+                    asm::Instruction::ArithmeticShiftRightRegister {
+                        dest: t_reg,
+                        src1: first_reg,
+                        src2: second_reg,
+                    }
+                }
+                ir::BinaryBitwiseOp::ShiftRight => asm::Instruction::LogicalShiftRightRegister {
+                    dest: t_reg,
+                    src1: first_reg,
+                    src2: second_reg,
+                },
+            };
+            instructions.push(instr);
         }
     };
 }
+
+/// Loads the Operands of an Integer Operation into General-Purpose Registers of the same Size,
+/// where Constants are loaded into the Scratch-Registers 9 and 10
+fn gp_operands(
+    left: ir::Operand,
+    right: ir::Operand,
+    ctx: &Context,
+    instructions: &mut Vec<asm::Instruction>,
+) -> (asm::GPRegister, asm::GPRegister) {
+    let var_reg = |var: &ir::Variable| match ctx.registers.get_reg(var).unwrap() {
+        asm::Register::GeneralPurpose(gp) => gp,
+        asm::Register::FloatingPoint(_) => panic!("Integer Operation on FP-Register"),
+    };
+    let sized = |like: &asm::GPRegister, n: u8| match like {
+        asm::GPRegister::DWord(_) => asm::GPRegister::DWord(n),
+        asm::GPRegister::Word(_) => asm::GPRegister::Word(n),
+    };
+
+    match (left, right) {
+        (ir::Operand::Variable(first), ir::Operand::Variable(second)) => {
+            match (var_reg(&first), var_reg(&second)) {
+                (asm::GPRegister::DWord(f), asm::GPRegister::Word(s)) => {
+                    (asm::GPRegister::DWord(f), asm::GPRegister::DWord(s))
+                }
+                (asm::GPRegister::Word(f), asm::GPRegister::DWord(s)) => {
+                    (asm::GPRegister::Word(f), asm::GPRegister::Word(s))
+                }
+                regs => regs,
+            }
+        }
+        (ir::Operand::Variable(var), ir::Operand::Constant(con)) => {
+            let first = var_reg(&var);
+            let second = sized(&first, 9);
+
+            instructions.extend(util::constant_to_asm(
+                &con,
+                asm::Register::GeneralPurpose(second.clone()),
+            ));
+
+            (first, second)
+        }
+        (ir::Operand::Constant(con), ir::Operand::Variable(var)) => {
+            let second = var_reg(&var);
+            let first = sized(&second, 10);
+
+            instructions.extend(util::constant_to_asm(
+                &con,
+                asm::Register::GeneralPurpose(first.clone()),
+            ));
+
+            (first, second)
+        }
+        (ir::Operand::Constant(first_con), ir::Operand::Constant(second_con)) => {
+            let (first, second) = match first_con.ty() {
+                ir::Type::I64 | ir::Type::U64 => {
+                    (asm::GPRegister::DWord(9), asm::GPRegister::DWord(10))
+                }
+                _ => (asm::GPRegister::Word(9), asm::GPRegister::Word(10)),
+            };
+
+            instructions.extend(util::constant_to_asm(
+                &first_con,
+                asm::Register::GeneralPurpose(first.clone()),
+            ));
+            instructions.extend(util::constant_to_asm(
+                &second_con,
+                asm::Register::GeneralPurpose(second.clone()),
+            ));
+
+            (first, second)
+        }
+    }
+}
@@ -1,13 +1,24 @@
 use crate::backends::aarch64_mac::{
     asm,
-    codegen::{self, ArgTarget, Context},
+    codegen::{self, util, ArgTarget, Context},
 };
 
+/// The Function that should be called
+pub enum CallTarget {
+    /// The Function with the given Name
+    Name(String),
+    /// The Function at the Address stored in the Operand
+    Address(ir::Operand),
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn to_asm(
-    name: String,
+    call_target: CallTarget,
     arguments: Vec<ir::Operand>,
+    variadic: Option<usize>,
     ret_ty: ir::Type,
     target: Option<asm::Register>,
+    result_memory: Option<isize>,
     ctx: &Context,
     instructions: &mut Vec<asm::Instruction>,
 ) {
@@ -17,6 +28,45 @@ pub fn to_asm(
     // 4. move the result into the target
     // 5. restore previous arguments from stack
 
+    // On Apple-Platforms all the variable Arguments are passed on the Stack, every one of them
+    // in its own 8-Byte slot
+    let (arguments, var_arguments) = match variadic {
+        Some(fixed) => {
+            let mut arguments = arguments;
+            let var_arguments = arguments.split_off(fixed);
+            (arguments, var_arguments)
+        }
+        None => (arguments, Vec::new()),
+    };
+    let arg_mapping = codegen::arguments(arguments.iter().map(|a| a.ty()));
+
+    // The Arguments that are passed on the Stack are placed right at the Stack-Pointer and are
+    // followed by the variable Arguments
+    let stack_args_space = arguments
+        .iter()
+        .zip(arg_mapping.iter())
+        .filter_map(|(arg, mapped)| match mapped {
+            ArgTarget::Stack(offset) => {
+                let (size, _) = codegen::size_align(&arg.ty());
+                Some(offset + size.next_multiple_of(8))
+            }
+            ArgTarget::Reference(inner) => match inner.as_ref() {
+                ArgTarget::Stack(offset) => Some(offset + 8),
+                _ => None,
+            },
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let var_space = {
+        let raw = stack_args_space + var_arguments.len() * 8;
+        if raw % 16 == 0 {
+            raw
+        } else {
+            raw + (16 - raw % 16)
+        }
+    };
+
     let arg_regs = [
         asm::GPRegister::DWord(0),
         asm::GPRegister::DWord(1),
@@ -40,9 +90,74 @@ pub fn to_asm(
         });
     }
 
+    if var_space > 0 {
+        instructions.push(asm::Instruction::Literal(format!(
+            "sub sp, sp, #{}",
+            var_space
+        )));
+    }
+
     // 2.
-    let arg_mapping = codegen::arguments(arguments.iter().map(|a| a.ty()));
-    for (arg, mapped) in arguments.into_iter().zip(arg_mapping) {
+    // The Arguments on the Stack are stored first, because loading the other Arguments might
+    // overwrite the Registers holding them
+    let (stack_arguments, arguments): (Vec<_>, Vec<_>) = arguments
+        .into_iter()
+        .zip(arg_mapping)
+        .partition(|(_, mapped)| match mapped {
+            ArgTarget::Stack(_) => true,
+            ArgTarget::Reference(inner) => matches!(inner.as_ref(), ArgTarget::Stack(_)),
+            _ => false,
+        });
+    for (arg, mapped) in stack_arguments {
+        match (arg, mapped) {
+            // Structs that do not fit into the remaining Registers, like Homogeneous
+            // Floating-point Aggregates once all the SIMD-Registers are used, are copied onto the
+            // Stack
+            (ir::Operand::Variable(var), ArgTarget::Stack(offset))
+                if matches!(var.ty, ir::Type::Struct { .. }) =>
+            {
+                let address = match ctx.registers.get_reg(&var).unwrap() {
+                    asm::Register::GeneralPurpose(gp) => gp,
+                    other => {
+                        dbg!(&other);
+                        panic!("Addresses should never be stored in a Floating Point Register")
+                    }
+                };
+                let (size, _) = codegen::size_align(&var.ty);
+
+                instructions.extend(util::copy_struct_to_stack(size, address, offset));
+            }
+            // Structs passed by Reference only store the Address of their Copy on the Stack
+            (ir::Operand::Variable(var), ArgTarget::Reference(inner)) => {
+                let offset = match *inner {
+                    ArgTarget::Stack(offset) => offset,
+                    other => unreachable!(
+                        "Only References on the Stack are stored first, not {:?}",
+                        other
+                    ),
+                };
+                let address = match ctx.registers.get_reg(&var).unwrap() {
+                    asm::Register::GeneralPurpose(gp) => gp,
+                    other => {
+                        dbg!(&other);
+                        panic!("Addresses should never be stored in a Floating Point Register")
+                    }
+                };
+
+                instructions.push(asm::Instruction::StoreRegisterUnscaled {
+                    reg: address,
+                    base: asm::GpOrSpRegister::SP,
+                    offset: asm::Imm9Signed::new(offset as i16).unwrap(),
+                });
+            }
+            other => {
+                dbg!(&other);
+                todo!()
+            }
+        };
+    }
+    for (arg, mapped) in arguments {
+        let arg_ty = arg.ty();
         let (arg_src, dword_sized) = match arg {
             ir::Operand::Variable(var) => match ctx.registers.get_reg(&var).unwrap() {
                 asm::Register::GeneralPurpose(gp) => {
@@ -54,8 +169,19 @@ pub fn to_asm(
                 }
             },
             ir::Operand::Constant(con) => {
-                dbg!(&con);
-                todo!()
+                // Register 9 should be a scratch register that can be used as seen fit
+                let (scratch, dword_sized) = match con {
+                    ir::Constant::I64(_) | ir::Constant::U64(_) => {
+                        (asm::GPRegister::DWord(9), true)
+                    }
+                    _ => (asm::GPRegister::Word(9), false),
+                };
+                instructions.extend(util::constant_to_asm(
+                    &con,
+                    asm::Register::GeneralPurpose(scratch.clone()),
+                ));
+
+                (scratch, dword_sized)
             }
         };
 
@@ -72,6 +198,24 @@ pub fn to_asm(
                     src: arg_src,
                 });
             }
+            // Struct-Arguments hold the Address of a Copy of the Struct, which can be passed
+            // along directly, if the Struct is passed by Reference
+            ArgTarget::Reference(inner) => match *inner {
+                ArgTarget::GPRegister(n) => {
+                    instructions.push(asm::Instruction::MovRegister {
+                        dest: asm::GPRegister::DWord(n),
+                        src: arg_src,
+                    });
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            },
+            mapped @ (ArgTarget::GPRegisters { .. } | ArgTarget::FPRegisters { .. }) => {
+                let (size, _) = codegen::size_align(&arg_ty);
+                instructions.extend(util::load_struct(&mapped, size, arg_src));
+            }
             other => {
                 dbg!(&other);
                 todo!()
@@ -79,13 +223,92 @@ pub fn to_asm(
         };
     }
 
+    // Structs that are returned through Memory are written to the Address in x8, which has to
+    // be set before the Stack-Pointer is moved for the variable Arguments
+    let res_target = codegen::return_target(ret_ty);
+    if res_target == ArgTarget::IndirectResult {
+        let offset = result_memory.expect("Returned Structs need Memory to be stored in");
+        instructions.push(util::stack_address(asm::GPRegister::DWord(8), offset));
+    }
+
+    for (index, arg) in var_arguments.into_iter().enumerate() {
+        let offset = asm::Imm9Signed::new((stack_args_space + index * 8) as i16).unwrap();
+
+        match arg {
+            ir::Operand::Variable(var) => match ctx.registers.get_reg(&var).unwrap() {
+                asm::Register::GeneralPurpose(gp) => {
+                    let reg = match gp {
+                        asm::GPRegister::DWord(n) | asm::GPRegister::Word(n) => {
+                            asm::GPRegister::DWord(n)
+                        }
+                    };
+
+                    instructions.push(asm::Instruction::StoreRegisterUnscaled {
+                        reg,
+                        base: asm::GpOrSpRegister::SP,
+                        offset,
+                    });
+                }
+                asm::Register::FloatingPoint(fp) => {
+                    instructions.push(asm::Instruction::StoreFPUnscaled {
+                        reg: fp,
+                        base: asm::GpOrSpRegister::SP,
+                        offset,
+                    });
+                }
+            },
+            ir::Operand::Constant(con) => {
+                // Every variable Argument takes up a full 8-Byte slot
+                let scratch = asm::GPRegister::DWord(9);
+                instructions.extend(util::constant_to_asm(
+                    &con,
+                    asm::Register::GeneralPurpose(scratch.clone()),
+                ));
+
+                instructions.push(asm::Instruction::StoreRegisterUnscaled {
+                    reg: scratch,
+                    base: asm::GpOrSpRegister::SP,
+                    offset,
+                });
+            }
+        };
+    }
+
     // 3.
-    instructions.push(asm::Instruction::BranchLinkLabel { target: name });
+    match call_target {
+        CallTarget::Name(name) => {
+            instructions.push(asm::Instruction::BranchLinkLabel { target: name });
+        }
+        CallTarget::Address(ir::Operand::Variable(var)) => {
+            // The Address is never stored in one of the Argument-Registers, so it is still
+            // valid after loading the Arguments
+            let target = match ctx.registers.get_reg(&var).unwrap() {
+                asm::Register::GeneralPurpose(asm::GPRegister::DWord(n)) => {
+                    asm::GPRegister::DWord(n)
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
+
+            instructions.push(asm::Instruction::BranchLinkRegister { target });
+        }
+        CallTarget::Address(ir::Operand::Constant(con)) => {
+            dbg!(&con);
+            todo!()
+        }
+    };
+
+    if var_space > 0 {
+        instructions.push(asm::Instruction::Literal(format!(
+            "add sp, sp, #{}",
+            var_space
+        )));
+    }
 
     // 4.
     if let Some(target) = target {
-        let mut res_mapping = codegen::arguments(std::iter::once(ret_ty));
-        let res_target = res_mapping.remove(0);
         match (target, res_target) {
             (asm::Register::GeneralPurpose(target), ArgTarget::GPRegister(reg)) => {
                 let src_reg = match &target {
@@ -109,6 +332,21 @@ pub fn to_asm(
                     src: src_reg,
                 });
             }
+            // The returned Struct is stored in the Memory reserved for it and the Target then
+            // holds its Address
+            (
+                asm::Register::GeneralPurpose(target),
+                res_target @ (ArgTarget::GPRegisters { .. }
+                | ArgTarget::FPRegisters { .. }
+                | ArgTarget::IndirectResult),
+            ) => {
+                let offset = result_memory.expect("Returned Structs need Memory to be stored in");
+                instructions.push(util::stack_address(target.clone(), offset));
+
+                if res_target != ArgTarget::IndirectResult {
+                    instructions.extend(util::store_struct(&res_target, target));
+                }
+            }
             other => {
                 dbg!(&other);
                 todo!()
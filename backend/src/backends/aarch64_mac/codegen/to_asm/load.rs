@@ -1,7 +1,7 @@
 use crate::backends::aarch64_mac::{
     self,
     asm::{self},
-    codegen::Context,
+    codegen::{self, Context},
     ArmRegister,
 };
 
@@ -14,6 +14,7 @@ pub fn load(
 ) {
     match &read_ty {
         ir::Type::Pointer(_)
+        | ir::Type::Struct { .. }
         | ir::Type::I64
         | ir::Type::U64
         | ir::Type::I32
@@ -31,7 +32,11 @@ pub fn load(
             };
 
             match &read_ty {
-                ir::Type::I64 | ir::Type::U64 | ir::Type::Pointer(_) | ir::Type::U32 => {
+                ir::Type::I64
+                | ir::Type::U64
+                | ir::Type::Pointer(_)
+                | ir::Type::Struct { .. }
+                | ir::Type::U32 => {
                     match offset {
                         offset if asm::Imm9Signed::fits(offset) => {
                             let load_instr = asm::Instruction::LoadRegisterUnscaled {
@@ -137,6 +142,21 @@ pub fn load(
                 _ => {}
             };
         }
+        ir::Type::Float | ir::Type::Double => {
+            let target_reg = match target_reg {
+                asm::Register::FloatingPoint(r) => r,
+                other => {
+                    dbg!(&other);
+                    panic!("")
+                }
+            };
+
+            instr.push(asm::Instruction::LoadFPUnscaled {
+                reg: target_reg,
+                base,
+                offset: asm::Imm9Signed::new(offset).unwrap(),
+            });
+        }
         ir::Type::Void => {
             // TODO
             // We cant load a Void Type
@@ -155,15 +175,9 @@ pub fn load_var(
     ctx: &Context,
     instr: &mut Vec<asm::Instruction>,
 ) {
-    let target_reg = match ctx.registers.get_reg(&var).unwrap() {
-        asm::Register::GeneralPurpose(gp) => gp,
-        other => {
-            dbg!(&other);
-            todo!()
-        }
-    };
+    let target_reg = ctx.registers.get_reg(&var).unwrap();
 
-    let base = asm::GpOrSpRegister::SP;
+    let base = codegen::util::FRAME_POINTER;
     let offset = match ctx.var.get(var.name()) {
         Some(o) => (*o).try_into().unwrap(),
         None => {
@@ -171,11 +185,5 @@ pub fn load_var(
         }
     };
 
-    load(
-        asm::Register::GeneralPurpose(target_reg),
-        read_ty,
-        base,
-        offset,
-        instr,
-    );
+    load(target_reg, read_ty, base, offset, instr);
 }
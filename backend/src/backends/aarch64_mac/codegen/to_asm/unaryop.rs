@@ -1,4 +1,7 @@
-use crate::backends::aarch64_mac::{asm, codegen::Context};
+use crate::backends::aarch64_mac::{
+    asm,
+    codegen::{util, Context},
+};
 
 pub fn to_asm(
     op: ir::UnaryOp,
@@ -14,31 +17,25 @@ pub fn to_asm(
                 todo!("Floating Point Registers")
             }
         },
-        ir::Operand::Constant(base_con) => match base_con {
-            ir::Constant::I64(val) => {
-                let base_reg = match &t_reg {
-                    asm::Register::GeneralPurpose(gp) => match gp {
-                        asm::GPRegister::DWord(_) => asm::GPRegister::DWord(9),
-                        asm::GPRegister::Word(_) => asm::GPRegister::Word(9),
-                    },
-                    other => {
-                        dbg!(&other);
-                        todo!()
-                    }
-                };
+        ir::Operand::Constant(base_con) => {
+            let base_reg = match &t_reg {
+                asm::Register::GeneralPurpose(gp) => match gp {
+                    asm::GPRegister::DWord(_) => asm::GPRegister::DWord(9),
+                    asm::GPRegister::Word(_) => asm::GPRegister::Word(9),
+                },
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
 
-                instr.push(asm::Instruction::MovI64 {
-                    dest: base_reg.clone(),
-                    value: val,
-                });
+            instr.extend(util::constant_to_asm(
+                &base_con,
+                asm::Register::GeneralPurpose(base_reg.clone()),
+            ));
 
-                base_reg
-            }
-            other => {
-                dbg!(&other);
-                todo!()
-            }
-        },
+            base_reg
+        }
     };
 
     match (t_reg, op) {
@@ -52,6 +49,14 @@ pub fn to_asm(
                         shift: 0,
                     });
                 }
+                ir::UnaryArithmeticOp::Decrement => {
+                    instr.push(asm::Instruction::SubImmediate {
+                        dest: t_reg,
+                        src: base_reg,
+                        immediate: 1,
+                        shift: 0,
+                    });
+                }
                 ir::UnaryArithmeticOp::Negate => {
                     instr.push(asm::Instruction::NegateRegisterShifted {
                         dest: t_reg,
@@ -60,10 +65,6 @@ pub fn to_asm(
                         amount: 0,
                     });
                 }
-                other => {
-                    dbg!(&other);
-                    todo!()
-                }
             };
         }
         other => {
@@ -1,4 +1,7 @@
-use crate::backends::aarch64_mac::{asm, codegen::Context};
+use crate::backends::aarch64_mac::{
+    asm,
+    codegen::{util, Context},
+};
 
 pub fn write(addr_op: ir::Operand, value: ir::Operand, ctx: &Context) -> Vec<asm::Instruction> {
     let mut result = Vec::with_capacity(1);
@@ -32,48 +35,86 @@ fn write_var(
     };
 
     match value {
-        ir::Operand::Constant(con) => match con {
-            ir::Constant::I32(val) => {
-                let val_register = asm::GPRegister::Word(9); // Register 9 should be a scratch register that can be used as seen fit
-                instr.push(asm::Instruction::MovI64 {
-                    dest: val_register.clone(),
-                    value: val as i64,
-                });
+        ir::Operand::Constant(con) => {
+            // Register 9 should be a scratch register that can be used as seen fit
+            let val_register = match con {
+                ir::Constant::I64(_) | ir::Constant::U64(_) => asm::GPRegister::DWord(9),
+                _ => asm::GPRegister::Word(9),
+            };
+            instr.extend(util::constant_to_asm(
+                &con,
+                asm::Register::GeneralPurpose(val_register.clone()),
+            ));
 
-                instr.push(asm::Instruction::StoreRegisterUnscaled {
+            let base = asm::GpOrSpRegister::GP(base_reg);
+            let offset = asm::Imm9Signed::new(0).unwrap();
+            let store = match con {
+                ir::Constant::I8(_) | ir::Constant::U8(_) => asm::Instruction::StoreByteUnscaled {
                     reg: val_register,
-                    base: asm::GpOrSpRegister::GP(base_reg),
-                    offset: asm::Imm9Signed::new(0).unwrap(),
-                });
-            }
-            ir::Constant::U8(val) => {
-                let val_register = asm::GPRegister::Word(9);
-                instr.push(asm::Instruction::Movz {
-                    dest: val_register.clone(),
-                    immediate: val as u16,
-                    shift: 0,
-                });
-
-                instr.push(asm::Instruction::StoreRegisterUnscaled {
+                    base,
+                    offset,
+                },
+                ir::Constant::I16(_) | ir::Constant::U16(_) => {
+                    asm::Instruction::StoreHalfWordUnscaled {
+                        reg: val_register,
+                        base,
+                        offset,
+                    }
+                }
+                ir::Constant::I32(_)
+                | ir::Constant::U32(_)
+                | ir::Constant::I64(_)
+                | ir::Constant::U64(_) => asm::Instruction::StoreRegisterUnscaled {
                     reg: val_register,
-                    base: asm::GpOrSpRegister::GP(base_reg),
-                    offset: asm::Imm9Signed::new(0).unwrap(),
-                });
-            }
-            other => {
-                dbg!(&other);
-                todo!()
-            }
-        },
+                    base,
+                    offset,
+                },
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
+            instr.push(store);
+        }
         ir::Operand::Variable(var) => {
             let value_reg = ctx.registers.get_reg(&var).unwrap();
 
+            let base = asm::GpOrSpRegister::GP(base_reg);
+            let offset = asm::Imm9Signed::new(0).unwrap();
             match (value_reg, var.ty) {
-                (asm::Register::GeneralPurpose(value), ir::Type::I32) => {
+                (
+                    asm::Register::GeneralPurpose(value),
+                    ir::Type::I32
+                    | ir::Type::U32
+                    | ir::Type::I64
+                    | ir::Type::U64
+                    | ir::Type::Pointer(_),
+                ) => {
                     instr.push(asm::Instruction::StoreRegisterUnscaled {
                         reg: value,
-                        base: asm::GpOrSpRegister::GP(base_reg),
-                        offset: asm::Imm9Signed::new(0).unwrap(),
+                        base,
+                        offset,
+                    });
+                }
+                (asm::Register::GeneralPurpose(value), ir::Type::I16 | ir::Type::U16) => {
+                    instr.push(asm::Instruction::StoreHalfWordUnscaled {
+                        reg: value,
+                        base,
+                        offset,
+                    });
+                }
+                (asm::Register::GeneralPurpose(value), ir::Type::I8 | ir::Type::U8) => {
+                    instr.push(asm::Instruction::StoreByteUnscaled {
+                        reg: value,
+                        base,
+                        offset,
+                    });
+                }
+                (asm::Register::FloatingPoint(value), ir::Type::Float | ir::Type::Double) => {
+                    instr.push(asm::Instruction::StoreFPUnscaled {
+                        reg: value,
+                        base,
+                        offset,
                     });
                 }
                 other_ty => {
@@ -1,21 +1,66 @@
 use crate::backends::aarch64_mac::asm;
 
+use super::ArgTarget;
+
+/// The Base for all Accesses to the Stack-Frame, which always points to the Frame-Record at the
+/// bottom of the Frame, while the Stack-Pointer moves for dynamic Stack-Allocations
+pub const FRAME_POINTER: asm::GpOrSpRegister = asm::GpOrSpRegister::GP(asm::GPRegister::DWord(29));
+
 pub fn constant_to_asm(con: &ir::Constant, dest: asm::Register) -> Vec<asm::Instruction> {
     match (dest, con) {
-        (asm::Register::GeneralPurpose(dest), ir::Constant::I64(val)) => {
-            let val = *val;
-            if (0..4096).contains(&val) {
-                vec![asm::Instruction::Movz {
-                    dest,
-                    immediate: val as u16,
-                    shift: 0,
-                }]
-            } else {
-                vec![asm::Instruction::MovI64 { dest, value: val }]
-            }
+        (
+            asm::Register::GeneralPurpose(dest),
+            ir::Constant::I8(_)
+            | ir::Constant::I16(_)
+            | ir::Constant::I32(_)
+            | ir::Constant::I64(_)
+            | ir::Constant::U8(_)
+            | ir::Constant::U16(_)
+            | ir::Constant::U32(_)
+            | ir::Constant::U64(_),
+        ) => {
+            let val = match con {
+                ir::Constant::I8(v) => *v as i64,
+                ir::Constant::I16(v) => *v as i64,
+                ir::Constant::I32(v) => *v as i64,
+                ir::Constant::I64(v) => *v,
+                ir::Constant::U8(v) => *v as i64,
+                ir::Constant::U16(v) => *v as i64,
+                ir::Constant::U32(v) => *v as i64,
+                ir::Constant::U64(v) => *v as i64,
+                _ => unreachable!(),
+            };
+            immediate_to_asm(dest, val)
+        }
+        // Only very few Floating-Point Values can be encoded as an Immediate, so the Bits of the
+        // Value are loaded into a scratch Register first and then moved over
+        (
+            asm::Register::FloatingPoint(dest @ asm::FPRegister::SinglePrecision(_)),
+            ir::Constant::F32(val),
+        ) => {
+            let scratch = asm::GPRegister::Word(16);
+
+            let mut instructions = immediate_to_asm(scratch.clone(), val.to_bits() as i64);
+            instructions.push(asm::Instruction::FMovGeneral { dest, src: scratch });
+            instructions
+        }
+        (
+            asm::Register::FloatingPoint(dest @ asm::FPRegister::DoublePrecision(_)),
+            ir::Constant::F64(val),
+        ) => {
+            let scratch = asm::GPRegister::DWord(16);
+
+            let mut instructions = immediate_to_asm(scratch.clone(), val.to_bits() as i64);
+            instructions.push(asm::Instruction::FMovGeneral { dest, src: scratch });
+            instructions
+        }
+        // Floating-Point Values in General-Purpose Registers, like for Variadic Arguments that
+        // are passed on the Stack, are just their Bits
+        (asm::Register::GeneralPurpose(dest), ir::Constant::F32(val)) => {
+            immediate_to_asm(dest, val.to_bits() as i64)
         }
-        (asm::Register::FloatingPoint(dest), ir::Constant::F64(val)) => {
-            vec![asm::Instruction::FMovF64 { dest, value: *val }]
+        (asm::Register::GeneralPurpose(dest), ir::Constant::F64(val)) => {
+            immediate_to_asm(dest, val.to_bits() as i64)
         }
         other => {
             dbg!(&other);
@@ -24,3 +69,351 @@ pub fn constant_to_asm(con: &ir::Constant, dest: asm::Register) -> Vec<asm::Inst
         }
     }
 }
+
+/// Loads the Value into the Register in Chunks of 16 Bits, because not every Value can be
+/// encoded as a single Immediate. The first non-zero Chunk is set using movz, which clears the
+/// rest of the Register, and the remaining non-zero Chunks are then inserted using movk
+fn immediate_to_asm(dest: asm::GPRegister, value: i64) -> Vec<asm::Instruction> {
+    let bits = match &dest {
+        asm::GPRegister::Word(_) => 32,
+        asm::GPRegister::DWord(_) => 64,
+    };
+
+    let mut chunks = (0..bits)
+        .step_by(16)
+        .map(|shift| (((value as u64) >> shift) as u16, shift as u8))
+        .filter(|(chunk, _)| *chunk != 0);
+
+    let (first, first_shift) = chunks.next().unwrap_or((0, 0));
+    let mut result = vec![asm::Instruction::Movz {
+        dest: dest.clone(),
+        immediate: first,
+        shift: first_shift,
+    }];
+    result.extend(chunks.map(|(chunk, shift)| asm::Instruction::Movk {
+        dest: dest.clone(),
+        immediate: chunk,
+        shift,
+    }));
+
+    result
+}
+
+/// Calculates the Address at the given Offset from the Frame-Pointer, like the Start of an
+/// Allocation in the Stack-Frame
+pub fn stack_address(dest: asm::GPRegister, offset: isize) -> asm::Instruction {
+    if !(0..4096).contains(&offset) {
+        panic!("Stack-Offset {} is out of Range", offset);
+    }
+
+    asm::Instruction::AddImmediate {
+        dest,
+        src: FRAME_POINTER,
+        immediate: offset as u16,
+        shift: 0,
+    }
+}
+
+/// Loads the Struct at the Address into the Registers, that it is passed in.
+///
+/// The last Chunk is always loaded as an entire Register, unless it only contains 4 Bytes, so it
+/// might read a few Bytes past the End of the Struct, which are then simply ignored
+pub fn load_struct(
+    target: &ArgTarget,
+    size: usize,
+    address: asm::GPRegister,
+) -> Vec<asm::Instruction> {
+    let base = asm::GpOrSpRegister::GP(address);
+
+    match target {
+        ArgTarget::GPRegisters { first, count } => (0..*count)
+            .map(|index| {
+                let offset = index as usize * 8;
+                let reg = if size - offset == 4 {
+                    asm::GPRegister::Word(first + index)
+                } else {
+                    asm::GPRegister::DWord(first + index)
+                };
+
+                asm::Instruction::LoadRegisterUnscaled {
+                    reg,
+                    base: base.clone(),
+                    offset: asm::Imm9Signed::new(offset as i16).unwrap(),
+                }
+            })
+            .collect(),
+        ArgTarget::FPRegisters {
+            first,
+            count,
+            member,
+        } => (0..*count)
+            .map(|index| {
+                let (reg, offset) = fp_member(member, *first, index);
+
+                asm::Instruction::LoadFPUnscaled {
+                    reg,
+                    base: base.clone(),
+                    offset,
+                }
+            })
+            .collect(),
+        other => unreachable!("Struct is not passed in Registers: {:?}", other),
+    }
+}
+
+/// Copies the Struct at the Address to the Offset from the Stack-Pointer, where it is passed on
+/// the Stack, in 8-Byte Chunks using x9 as a Scratch-Register.
+///
+/// Just like in [`load_struct`], the last Chunk might contain a few Bytes past the End of the
+/// Struct, which only end up in the Padding of its Stack-Slot
+pub fn copy_struct_to_stack(
+    size: usize,
+    address: asm::GPRegister,
+    offset: usize,
+) -> Vec<asm::Instruction> {
+    let scratch = asm::GPRegister::DWord(9);
+
+    (0..size.div_ceil(8))
+        .flat_map(|index| {
+            let chunk = index * 8;
+
+            [
+                asm::Instruction::LoadRegisterUnscaled {
+                    reg: scratch.clone(),
+                    base: asm::GpOrSpRegister::GP(address.clone()),
+                    offset: asm::Imm9Signed::new(chunk as i16).unwrap(),
+                },
+                asm::Instruction::StoreRegisterUnscaled {
+                    reg: scratch.clone(),
+                    base: asm::GpOrSpRegister::SP,
+                    offset: asm::Imm9Signed::new((offset + chunk) as i16).unwrap(),
+                },
+            ]
+        })
+        .collect()
+}
+
+/// Stores the Registers, that a Struct was passed in, into the Memory at the Address, which
+/// needs to have space for all the Registers
+pub fn store_struct(target: &ArgTarget, address: asm::GPRegister) -> Vec<asm::Instruction> {
+    let base = asm::GpOrSpRegister::GP(address);
+
+    match target {
+        ArgTarget::GPRegisters { first, count } => (0..*count)
+            .map(|index| asm::Instruction::StoreRegisterUnscaled {
+                reg: asm::GPRegister::DWord(first + index),
+                base: base.clone(),
+                offset: asm::Imm9Signed::new(index as i16 * 8).unwrap(),
+            })
+            .collect(),
+        ArgTarget::FPRegisters {
+            first,
+            count,
+            member,
+        } => (0..*count)
+            .map(|index| {
+                let (reg, offset) = fp_member(member, *first, index);
+
+                asm::Instruction::StoreFPUnscaled {
+                    reg,
+                    base: base.clone(),
+                    offset,
+                }
+            })
+            .collect(),
+        other => unreachable!("Struct is not passed in Registers: {:?}", other),
+    }
+}
+
+/// The Register and Offset of the Member with the given Index in a Homogeneous
+/// Floating-point Aggregate
+fn fp_member(member: &ir::Type, first: u8, index: u8) -> (asm::FPRegister, asm::Imm9Signed) {
+    let (reg, size) = match member {
+        ir::Type::Float => (asm::FPRegister::SinglePrecision(first + index), 4),
+        _ => (asm::FPRegister::DoublePrecision(first + index), 8),
+    };
+
+    (reg, asm::Imm9Signed::new(index as i16 * size).unwrap())
+}
+
+/// Copies the given Number of Bytes from the Memory at the Source to the Memory at the Target.
+/// Both Address-Registers are modified while copying and the Data-Register is used to hold the
+/// Chunk that is currently copied
+pub fn copy_memory(target: u8, source: u8, data: u8, size: usize) -> Vec<asm::Instruction> {
+    let mut result = Vec::new();
+
+    let mut copied = 0;
+    let mut offset = 0;
+    while copied < size {
+        let chunk = match size - copied {
+            8.. => 8,
+            4..=7 => 4,
+            2 | 3 => 2,
+            _ => 1,
+        };
+
+        // The Offsets of the Instructions are limited, so the Addresses have to be moved along
+        if offset + chunk > 255 {
+            for reg in [target, source] {
+                result.push(asm::Instruction::AddImmediate {
+                    dest: asm::GPRegister::DWord(reg),
+                    src: asm::GpOrSpRegister::GP(asm::GPRegister::DWord(reg)),
+                    immediate: offset as u16,
+                    shift: 0,
+                });
+            }
+            offset = 0;
+        }
+
+        let src = asm::GpOrSpRegister::GP(asm::GPRegister::DWord(source));
+        let dest = asm::GpOrSpRegister::GP(asm::GPRegister::DWord(target));
+        let imm = asm::Imm9Signed::new(offset as i16).unwrap();
+        let (load, store) = match chunk {
+            8 => (
+                asm::Instruction::LoadRegisterUnscaled {
+                    reg: asm::GPRegister::DWord(data),
+                    base: src,
+                    offset: imm.clone(),
+                },
+                asm::Instruction::StoreRegisterUnscaled {
+                    reg: asm::GPRegister::DWord(data),
+                    base: dest,
+                    offset: imm,
+                },
+            ),
+            4 => (
+                asm::Instruction::LoadRegisterUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: src,
+                    offset: imm.clone(),
+                },
+                asm::Instruction::StoreRegisterUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: dest,
+                    offset: imm,
+                },
+            ),
+            2 => (
+                asm::Instruction::LoadHalfWordUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: src,
+                    offset: imm.clone(),
+                },
+                asm::Instruction::StoreHalfWordUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: dest,
+                    offset: imm,
+                },
+            ),
+            _ => (
+                asm::Instruction::LoadByteUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: src,
+                    offset: imm.clone(),
+                },
+                asm::Instruction::StoreByteUnscaled {
+                    reg: asm::GPRegister::Word(data),
+                    base: dest,
+                    offset: imm,
+                },
+            ),
+        };
+        result.push(load);
+        result.push(store);
+
+        copied += chunk;
+        offset += chunk;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_constant() {
+        let expected = vec![asm::Instruction::Movz {
+            dest: asm::GPRegister::DWord(0),
+            immediate: 42,
+            shift: 0,
+        }];
+
+        let result = constant_to_asm(
+            &ir::Constant::I64(42),
+            asm::Register::GeneralPurpose(asm::GPRegister::DWord(0)),
+        );
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn large_constant() {
+        let expected = vec![
+            asm::Instruction::Movz {
+                dest: asm::GPRegister::DWord(0),
+                immediate: 0x86a0,
+                shift: 0,
+            },
+            asm::Instruction::Movk {
+                dest: asm::GPRegister::DWord(0),
+                immediate: 0x1,
+                shift: 16,
+            },
+        ];
+
+        let result = constant_to_asm(
+            &ir::Constant::I64(100000),
+            asm::Register::GeneralPurpose(asm::GPRegister::DWord(0)),
+        );
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn float_constant() {
+        // 1.5 is stored as 0x3fc00000
+        let expected = vec![
+            asm::Instruction::Movz {
+                dest: asm::GPRegister::Word(16),
+                immediate: 0x3fc0,
+                shift: 16,
+            },
+            asm::Instruction::FMovGeneral {
+                dest: asm::FPRegister::SinglePrecision(0),
+                src: asm::GPRegister::Word(16),
+            },
+        ];
+
+        let result = constant_to_asm(
+            &ir::Constant::F32(1.5),
+            asm::Register::FloatingPoint(asm::FPRegister::SinglePrecision(0)),
+        );
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn negative_word_constant() {
+        let expected = vec![
+            asm::Instruction::Movz {
+                dest: asm::GPRegister::Word(0),
+                immediate: 0xfff8,
+                shift: 0,
+            },
+            asm::Instruction::Movk {
+                dest: asm::GPRegister::Word(0),
+                immediate: 0xffff,
+                shift: 16,
+            },
+        ];
+
+        let result = constant_to_asm(
+            &ir::Constant::I32(-8),
+            asm::Register::GeneralPurpose(asm::GPRegister::Word(0)),
+        );
+
+        assert_eq!(expected, result);
+    }
+}
@@ -1,6 +1,12 @@
 //! # ABI:
 //! ## Function-Call-Arguments:
-//! TODO
+//! The first 4 Arguments are passed in R4-R7 and any further Arguments are passed on the Stack,
+//! with the 5th Argument at the lowest Address. The variable Arguments of a variadic Function are
+//! always passed on the Stack, right after the named Arguments. The Caller removes the Arguments
+//! from the Stack again after the Call returns.
+//!
+//! ## Return-Value:
+//! The Result of a Function is returned in R0
 
 // Instructions: http://shared-ptr.com/sh_insns.html
 // General SH4: https://www.st.com/resource/en/user_manual/cd00147165-sh-4-32-bit-cpu-core-architecture-stmicroelectronics.pdf
@@ -14,6 +20,10 @@ use super::{Target, TargetConfig};
 
 mod codegen;
 
+/// The Address of the static RAM that is available to an Add-In on the fx-CG50, the Code itself
+/// is mapped into read-only Memory so all the global Variables are placed in here
+const STATIC_RAM_BASE: u32 = 0x0810_0000;
+
 pub struct Backend {}
 
 impl Backend {
@@ -21,11 +31,10 @@ impl Backend {
         Self {}
     }
 
-    fn avail_registers() -> [sh4a::Register; 11] {
+    fn avail_registers() -> [sh4a::Register; 10] {
         [
-            sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(5)),
-            sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(6)),
-            sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(7)),
+            sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(2)),
+            sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(3)),
             sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(8)),
             sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(9)),
             sh4a::Register::GeneralPurpose(sh4a::GeneralPurposeRegister::new(10)),
@@ -41,6 +50,8 @@ impl Backend {
         &self,
         func: &ir::FunctionDefinition,
         register_map: HashMap<ir::Variable, sh4a::Register>,
+        functions: &HashMap<String, String>,
+        globals: &HashMap<String, u32>,
     ) -> Vec<sh4a::Block> {
         let stack_allocation = util::stack::allocate_stack(
             func,
@@ -166,25 +177,113 @@ impl Backend {
             var_offsets: stack_allocation.var_offsets,
             stack_allocs: stack_allocation.allocations,
             pre_ret_instr: stack_allocation.pre_return_instr,
+            functions: functions.clone(),
+            stack_size: stack_allocation.stack_size,
+            globals: globals.clone(),
+            stack_arguments: func
+                .arguments
+                .len()
+                .saturating_sub(codegen::ARGUMENT_REGISTERS),
         };
 
-        func.block
+        let arg_moves = codegen::argument_moves(func, &ctx);
+
+        let mut blocks: Vec<_> = func
+            .block
             .block_iter()
             .map(|b| codegen::block_to_asm(b, &ctx))
-            .collect()
+            .collect();
+
+        // The first Block is the Entry of the Function, so it sets up the Stack-Frame and gets
+        // the Arguments into their Registers before running the actual Code of the Block
+        if let Some(first) = blocks.first_mut() {
+            let instructions = stack_allocation
+                .setup_instr
+                .into_iter()
+                .chain(arg_moves)
+                .chain(std::mem::take(&mut first.instructions))
+                .collect();
+            first.instructions = instructions;
+        }
+
+        blocks
+    }
+
+    /// Assigns every global Variable its own Address in the static RAM of the Add-In, which is
+    /// aligned to its Size or to its explicit Alignment, if that is stricter
+    fn global_addresses(
+        global: &ir::BasicBlock,
+        alignments: &HashMap<String, usize>,
+    ) -> HashMap<String, u32> {
+        let mut global_vars: Vec<(String, ir::Type)> = global
+            .get_statements()
+            .into_iter()
+            .filter_map(|s| match s {
+                ir::Statement::Assignment { target, .. } => Some(target),
+                _ => None,
+            })
+            .filter(|v| !v.is_tmp())
+            .map(|v| (v.name().to_string(), v.ty))
+            .collect();
+        global_vars.sort_by(|(first, _), (second, _)| first.cmp(second));
+        global_vars.dedup_by(|(first, _), (second, _)| first == second);
+
+        let mut address = STATIC_RAM_BASE;
+        let mut result = HashMap::new();
+        for (name, ty) in global_vars {
+            let size: u32 = match ty {
+                ir::Type::I8 | ir::Type::U8 => 1,
+                ir::Type::I16 | ir::Type::U16 => 2,
+                ir::Type::I32 | ir::Type::U32 | ir::Type::Pointer(_) | ir::Type::Float => 4,
+                ir::Type::I64 | ir::Type::U64 | ir::Type::Double => 8,
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
+
+            let alignment = alignments
+                .get(&name)
+                .map(|a| *a as u32)
+                .unwrap_or(size)
+                .max(size);
+            if address % alignment != 0 {
+                address += alignment - (address % alignment);
+            }
+
+            result.insert(name, address);
+            address += size;
+        }
+
+        result
     }
 }
 
 impl Target for Backend {
     fn generate(&self, program: ir::Program, conf: TargetConfig) {
-        let global_statements = program.global.get_statements();
-        for stmnt in global_statements {
-            dbg!(&stmnt);
-        }
+        let globals = Self::global_addresses(&program.global, &program.global_alignments);
+
+        let functions: HashMap<String, String> = program
+            .functions
+            .iter()
+            .map(|(name, func)| (name.clone(), codegen::block_name(&func.block)))
+            .collect();
 
         let all_registers = Self::avail_registers();
         let mut blocks = Vec::new();
-        for (_, func) in program.functions.iter() {
+
+        // The global Block initializes all the Globals and is called before main
+        let init_func = ir::FunctionDefinition {
+            name: "g_init".to_string(),
+            block: program.global.clone(),
+            arguments: Vec::new(),
+            return_ty: ir::Type::Void,
+        };
+        let leading_block = ir::BasicBlock::new(Vec::new(), Vec::new());
+        program.global.add_predecessor(leading_block.weak_ptr());
+        let init_block_name = codegen::block_name(&init_func.block);
+
+        for func in std::iter::once(&init_func).chain(program.functions.values()) {
             let registers = util::registers::allocate_registers(
                 func,
                 &all_registers,
@@ -193,7 +292,7 @@ impl Target for Backend {
 
             util::destructure::destructure_func(func);
 
-            let tmp = self.codegen(func, registers);
+            let tmp = self.codegen(func, registers, &functions, &globals);
             blocks.extend(tmp);
         }
 
@@ -201,7 +300,11 @@ impl Target for Backend {
         let main_first_block = &main_func.block;
         let main_block_name = codegen::block_name(main_first_block);
 
-        let asm_code = assemblers::sh4a::assemble(main_block_name, blocks);
+        let mut constants: Vec<_> = program.constants.into_iter().collect();
+        constants.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+        let asm_code =
+            assemblers::sh4a::assemble(init_block_name, main_block_name, blocks, constants);
 
         let mut g3a_builder = g3a::FileBuilder::new(
             "testing".to_string(),
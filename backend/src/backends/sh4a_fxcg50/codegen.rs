@@ -4,19 +4,131 @@ use isas::sh4a;
 
 mod constants;
 mod expression;
+mod function_call;
 mod inline_asm;
 
+pub use function_call::ARGUMENT_REGISTERS;
+
 pub struct Context {
     pub registers: HashMap<ir::Variable, sh4a::Register>,
     pub var_offsets: HashMap<String, isize>,
     pub stack_allocs: HashMap<ir::Variable, isize>,
     pub pre_ret_instr: Vec<sh4a::Instruction>,
+    /// The Name of the first Block of every Function, which is the Label for its Address
+    pub functions: HashMap<String, String>,
+    /// The Size of the Stack-Frame allocated by the Function itself, not including the saved PR
+    pub stack_size: usize,
+    /// The Address of every global Variable in the static RAM of the Add-In
+    pub globals: HashMap<String, u32>,
+    /// The Number of named Arguments of the Function that were passed on the Stack
+    pub stack_arguments: usize,
 }
 
 pub fn block_name(block: &ir::BasicBlock) -> String {
     format!("block_0x{:x}", block.as_ptr() as usize)
 }
 
+/// Moves the Arguments of the Function from where the Caller placed them into the Registers
+/// allocated for them, removing the corresponding leading Statements from the first Block.
+///
+/// Arguments that also have a Slot on the Stack are stored there as well, because the
+/// Function reloads them from there, even if the leading Statement was optimized away
+pub fn argument_moves(func: &ir::FunctionDefinition, ctx: &Context) -> Vec<sh4a::Instruction> {
+    let starting_statements = func.block.get_statements();
+    let mut statement_iter = starting_statements.into_iter().peekable();
+
+    let mut instructions = Vec::new();
+    for (index, (name, _)) in func.arguments.iter().enumerate() {
+        let target = match statement_iter.peek() {
+            Some(ir::Statement::Assignment {
+                target,
+                value: ir::Value::Unknown,
+            }) if target.name() == name => {
+                let reg = match ctx.registers.get(target).unwrap().clone() {
+                    sh4a::Register::GeneralPurpose(gp) => gp,
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
+                let _ = statement_iter.next();
+
+                Some(reg)
+            }
+            // Arguments that are never used in the first Block have been removed by the
+            // Optimizer, so there is nothing to move them into
+            _ => None,
+        };
+        let stack_offset = ctx.var_offsets.get(name).copied();
+
+        let src = if index < function_call::ARGUMENT_REGISTERS {
+            let src = function_call::argument_register(index);
+
+            if let Some(target) = target {
+                instructions.push(sh4a::Instruction::MovRR {
+                    src: src.clone(),
+                    dest: target,
+                });
+            }
+
+            src
+        } else {
+            if target.is_none() && stack_offset.is_none() {
+                continue;
+            }
+
+            // The Arguments on the Stack are right above the saved PR and our own Stack-Frame
+            let offset = ctx.stack_size + 4 + (index - function_call::ARGUMENT_REGISTERS) * 4;
+            let dest = target.unwrap_or_else(|| sh4a::GeneralPurposeRegister::new(1));
+
+            instructions.extend(stack_access(offset.try_into().unwrap(), |base| {
+                sh4a::Instruction::MovLR0PRR {
+                    base,
+                    target: dest.clone(),
+                }
+            }));
+
+            dest
+        };
+
+        if let Some(offset) = stack_offset {
+            instructions.extend(stack_access(offset.try_into().unwrap(), |base| {
+                sh4a::Instruction::MovLRR0PR {
+                    base,
+                    src: src.clone(),
+                }
+            }));
+        }
+    }
+
+    let remaining = statement_iter.collect();
+    func.block.set_statements(remaining);
+
+    instructions
+}
+
+/// Generates the Instructions to access the Stack at the given Offset from the Stack-Pointer,
+/// using R0 for the Offset and saving it while doing so
+fn stack_access<F>(offset: u32, access: F) -> Vec<sh4a::Instruction>
+where
+    F: FnOnce(sh4a::GeneralPurposeRegister) -> sh4a::Instruction,
+{
+    let mut instructions = vec![sh4a::Instruction::PushL {
+        reg: sh4a::GeneralPurposeRegister::new(0),
+    }];
+    // Pushing R0 moved the Stack-Pointer by 4 Bytes
+    instructions.extend(constants::store_u32(
+        sh4a::GeneralPurposeRegister::new(0),
+        offset + 4,
+    ));
+    instructions.push(access(sh4a::GeneralPurposeRegister::stack_reg()));
+    instructions.push(sh4a::Instruction::PopL {
+        reg: sh4a::GeneralPurposeRegister::new(0),
+    });
+
+    instructions
+}
+
 pub fn block_to_asm(block: ir::BasicBlock, ctx: &Context) -> sh4a::Block {
     let statements = block.get_statements();
 
@@ -54,20 +166,11 @@ pub fn block_to_asm(block: ir::BasicBlock, ctx: &Context) -> sh4a::Block {
             } => {
                 let target_reg = ctx.registers.get(&target).unwrap().clone();
 
-                match (target_reg, con) {
-                    (sh4a::Register::GeneralPurpose(gp), ir::Constant::I32(val)) => {
-                        if (-128..127).contains(&val) {
-                            instructions.push(sh4a::Instruction::MovIR {
-                                dest: gp,
-                                immediate: val as i8,
-                            });
-
-                            continue;
-                        }
-
-                        todo!("Constant I32")
+                match target_reg {
+                    sh4a::Register::GeneralPurpose(gp) => {
+                        instructions.extend(constants::store_constant(gp, &con));
                     }
-                    (reg, con) => {
+                    reg => {
                         dbg!(&reg, &con);
 
                         todo!()
@@ -158,6 +261,109 @@ pub fn block_to_asm(block: ir::BasicBlock, ctx: &Context) -> sh4a::Block {
                     reg: sh4a::GeneralPurposeRegister::new(0),
                 });
             }
+            ir::Statement::SaveGlobalVariable { name, value } => {
+                let address = *ctx.globals.get(&name).unwrap();
+
+                let src = match (ctx.registers.get(&value).unwrap().clone(), &value.ty) {
+                    (sh4a::Register::GeneralPurpose(gp), ir::Type::I32)
+                    | (sh4a::Register::GeneralPurpose(gp), ir::Type::U32)
+                    | (sh4a::Register::GeneralPurpose(gp), ir::Type::Pointer(_)) => gp,
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
+
+                // R1 holds the Address of the Global and R0 the Offset of 0, so both are saved
+                instructions.push(sh4a::Instruction::PushL {
+                    reg: sh4a::GeneralPurposeRegister::new(0),
+                });
+                instructions.push(sh4a::Instruction::PushL {
+                    reg: sh4a::GeneralPurposeRegister::new(1),
+                });
+                instructions.extend(constants::store_u32(
+                    sh4a::GeneralPurposeRegister::new(1),
+                    address,
+                ));
+                instructions.push(sh4a::Instruction::MovIR {
+                    dest: sh4a::GeneralPurposeRegister::new(0),
+                    immediate: 0,
+                });
+                instructions.push(sh4a::Instruction::MovLRR0PR {
+                    base: sh4a::GeneralPurposeRegister::new(1),
+                    src,
+                });
+                instructions.push(sh4a::Instruction::PopL {
+                    reg: sh4a::GeneralPurposeRegister::new(1),
+                });
+                instructions.push(sh4a::Instruction::PopL {
+                    reg: sh4a::GeneralPurposeRegister::new(0),
+                });
+            }
+            ir::Statement::WriteMemory { target, value, .. } => {
+                let base = match target {
+                    ir::Operand::Variable(var) => match ctx.registers.get(&var).unwrap().clone() {
+                        sh4a::Register::GeneralPurpose(gp) => gp,
+                        other => {
+                            dbg!(&other);
+                            panic!("Addresses should never be stored in a Floating Point Register")
+                        }
+                    },
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
+
+                // Constants are first loaded into R1, which is saved like R0
+                let (src, uses_r1) = match value {
+                    ir::Operand::Variable(var) => {
+                        match (ctx.registers.get(&var).unwrap().clone(), &var.ty) {
+                            (sh4a::Register::GeneralPurpose(gp), ir::Type::I32)
+                            | (sh4a::Register::GeneralPurpose(gp), ir::Type::U32)
+                            | (sh4a::Register::GeneralPurpose(gp), ir::Type::Pointer(_)) => {
+                                (gp, false)
+                            }
+                            other => {
+                                dbg!(&other);
+                                todo!()
+                            }
+                        }
+                    }
+                    ir::Operand::Constant(con @ (ir::Constant::I32(_) | ir::Constant::U32(_))) => {
+                        instructions.push(sh4a::Instruction::PushL {
+                            reg: sh4a::GeneralPurposeRegister::new(1),
+                        });
+                        instructions.extend(constants::store_constant(
+                            sh4a::GeneralPurposeRegister::new(1),
+                            &con,
+                        ));
+                        (sh4a::GeneralPurposeRegister::new(1), true)
+                    }
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
+
+                // R0 is used as the Offset of 0 for the Address, so it is saved around the Write
+                instructions.push(sh4a::Instruction::PushL {
+                    reg: sh4a::GeneralPurposeRegister::new(0),
+                });
+                instructions.push(sh4a::Instruction::MovIR {
+                    dest: sh4a::GeneralPurposeRegister::new(0),
+                    immediate: 0,
+                });
+                instructions.push(sh4a::Instruction::MovLRR0PR { base, src });
+                instructions.push(sh4a::Instruction::PopL {
+                    reg: sh4a::GeneralPurposeRegister::new(0),
+                });
+                if uses_r1 {
+                    instructions.push(sh4a::Instruction::PopL {
+                        reg: sh4a::GeneralPurposeRegister::new(1),
+                    });
+                }
+            }
             ir::Statement::Jump(target, _) => {
                 let target_name = block_name(&target);
 
@@ -195,8 +401,35 @@ pub fn block_to_asm(block: ir::BasicBlock, ctx: &Context) -> sh4a::Block {
                     };
                 }
 
+                instructions.extend(ctx.pre_ret_instr.iter().cloned());
                 instructions.push(sh4a::Instruction::Return);
             }
+            ir::Statement::Call {
+                name,
+                arguments,
+                variadic,
+            } => {
+                instructions.extend(function_call::call(
+                    function_call::CallTarget::Name(name),
+                    arguments,
+                    variadic,
+                    None,
+                    ctx,
+                ));
+            }
+            ir::Statement::IndirectCall {
+                target,
+                arguments,
+                variadic,
+            } => {
+                instructions.extend(function_call::call(
+                    function_call::CallTarget::Address(target),
+                    arguments,
+                    variadic,
+                    None,
+                    ctx,
+                ));
+            }
             ir::Statement::InlineAsm {
                 template,
                 inputs,
@@ -20,7 +20,39 @@ pub fn store_i64(register: sh4a::GeneralPurposeRegister, value: i64) -> Vec<sh4a
         }];
     }
 
-    todo!()
+    // Only the lower 32 Bits fit into a Register, which is all that is needed for any Integer of
+    // at most 32 Bits
+    store_u32(register, value as u32)
+}
+
+/// The Value of an integer Constant of any Width, sign or zero extended depending on its Type
+pub fn integer_value(con: &ir::Constant) -> Option<i64> {
+    match con {
+        ir::Constant::I8(v) => Some(*v as i64),
+        ir::Constant::I16(v) => Some(*v as i64),
+        ir::Constant::I32(v) => Some(*v as i64),
+        ir::Constant::I64(v) => Some(*v),
+        ir::Constant::U8(v) => Some(*v as i64),
+        ir::Constant::U16(v) => Some(*v as i64),
+        ir::Constant::U32(v) => Some(*v as i64),
+        ir::Constant::U64(v) => Some(*v as i64),
+        ir::Constant::F32(_) | ir::Constant::F64(_) => None,
+    }
+}
+
+/// Stores the Value of the integer Constant into the Register, independent of the Width of the
+/// Constant
+pub fn store_constant(
+    register: sh4a::GeneralPurposeRegister,
+    con: &ir::Constant,
+) -> Vec<sh4a::Instruction> {
+    match integer_value(con) {
+        Some(value) => store_i64(register, value),
+        None => {
+            dbg!(&con);
+            todo!("Floating Point Constants")
+        }
+    }
 }
 
 pub fn store_u32(register: sh4a::GeneralPurposeRegister, value: u32) -> Vec<sh4a::Instruction> {
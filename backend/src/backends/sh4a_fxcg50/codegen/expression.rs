@@ -1,4 +1,4 @@
-use super::Context;
+use super::{function_call, Context};
 use crate::backends::sh4a_fxcg50::codegen::constants;
 use isas::sh4a;
 
@@ -27,10 +27,8 @@ fn oper_to_reg(
             };
             dbg!(&free_reg);
 
-            let store_instr = match (con, free_reg.clone()) {
-                (ir::Constant::I64(val), sh4a::Register::GeneralPurpose(target)) => {
-                    constants::store_i64(target, val)
-                }
+            let store_instr = match free_reg.clone() {
+                sh4a::Register::GeneralPurpose(target) => constants::store_constant(target, &con),
                 other => {
                     dbg!(&other);
                     todo!()
@@ -42,8 +40,45 @@ fn oper_to_reg(
     }
 }
 
+/// Compares the two Registers and sets the T-Bit to the Result of the Comparison, except for
+/// NotEqual which sets it if they are Equal, so the T-Bit needs to be inverted afterwards
+fn compare(
+    op: ir::BinaryLogicOp,
+    signed: bool,
+    left: sh4a::GeneralPurposeRegister,
+    right: sh4a::GeneralPurposeRegister,
+) -> sh4a::Instruction {
+    // The SH4a only has greater (or equal) Comparisons, so the less (or equal) Comparisons are
+    // done by swapping the Operands
+    match (op, signed) {
+        (ir::BinaryLogicOp::Equal, _) | (ir::BinaryLogicOp::NotEqual, _) => {
+            sh4a::Instruction::CmpEq { left, right }
+        }
+        (ir::BinaryLogicOp::Greater, true) => sh4a::Instruction::CmpGt { left, right },
+        (ir::BinaryLogicOp::Greater, false) => sh4a::Instruction::CmpHi { left, right },
+        (ir::BinaryLogicOp::GreaterEq, true) => sh4a::Instruction::CmpGe { left, right },
+        (ir::BinaryLogicOp::GreaterEq, false) => sh4a::Instruction::CmpHs { left, right },
+        (ir::BinaryLogicOp::Less, true) => sh4a::Instruction::CmpGt {
+            left: right,
+            right: left,
+        },
+        (ir::BinaryLogicOp::Less, false) => sh4a::Instruction::CmpHi {
+            left: right,
+            right: left,
+        },
+        (ir::BinaryLogicOp::LessEq, true) => sh4a::Instruction::CmpGe {
+            left: right,
+            right: left,
+        },
+        (ir::BinaryLogicOp::LessEq, false) => sh4a::Instruction::CmpHs {
+            left: right,
+            right: left,
+        },
+    }
+}
+
 pub fn to_asm(
-    _target_var: &ir::Variable,
+    target_var: &ir::Variable,
     target: sh4a::Register,
     expression: ir::Expression,
     ctx: &Context,
@@ -51,12 +86,51 @@ pub fn to_asm(
     dbg!(&target, &expression);
 
     match expression {
+        // Adding a small Constant, like when advancing a Pointer, can be done directly using an
+        // Immediate without loading the Constant into a Register first
+        ir::Expression::BinaryOp {
+            op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
+            left: ir::Operand::Variable(left_var),
+            right: ir::Operand::Constant(right_con),
+        } if constants::integer_value(&right_con)
+            .map(|v| i8::try_from(v).is_ok())
+            .unwrap_or(false) =>
+        {
+            let value = constants::integer_value(&right_con).unwrap();
+
+            match (ctx.registers.get(&left_var).unwrap().clone(), target) {
+                (sh4a::Register::GeneralPurpose(left), sh4a::Register::GeneralPurpose(dest)) => {
+                    vec![
+                        sh4a::Instruction::MovRR {
+                            src: left,
+                            dest: dest.clone(),
+                        },
+                        sh4a::Instruction::AddImmediate {
+                            reg: dest,
+                            immediate: value as i8,
+                        },
+                    ]
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            }
+        }
         ir::Expression::BinaryOp { op, left, right } => {
             let mut result = Vec::new();
 
             let (left_reg, left_init, left_save) = oper_to_reg(left.clone(), None, ctx);
             if left_save {
-                todo!()
+                match left_reg.clone() {
+                    sh4a::Register::GeneralPurpose(gp) => {
+                        result.push(sh4a::Instruction::PushL { reg: gp });
+                    }
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
             }
             result.extend(left_init);
 
@@ -80,16 +154,21 @@ pub fn to_asm(
                     let left_ty = left.ty();
                     let signed = left_ty.signed();
 
-                    match (target, left_reg.clone(), right_reg.clone(), log_op) {
+                    match (target, left_reg.clone(), right_reg.clone()) {
                         (
                             sh4a::Register::GeneralPurpose(target),
                             sh4a::Register::GeneralPurpose(left),
                             sh4a::Register::GeneralPurpose(right),
-                            ir::BinaryLogicOp::Greater,
-                        ) if signed => vec![
-                            sh4a::Instruction::CmpGt { left, right },
-                            sh4a::Instruction::MovT { dest: target },
-                        ],
+                        ) => {
+                            let result_instr = match log_op {
+                                ir::BinaryLogicOp::NotEqual => {
+                                    sh4a::Instruction::MovRT { dest: target }
+                                }
+                                _ => sh4a::Instruction::MovT { dest: target },
+                            };
+
+                            vec![compare(log_op, signed, left, right), result_instr]
+                        }
                         other => {
                             dbg!(&other);
                             todo!()
@@ -100,6 +179,51 @@ pub fn to_asm(
                     let is_float = left.ty().is_float();
 
                     match (arith_op, left_reg.clone(), right_reg.clone(), target) {
+                        // Moving the Left side into the Target first would overwrite the Right
+                        // side, if they share the same Register
+                        (
+                            ir::BinaryArithmeticOp::Add,
+                            sh4a::Register::GeneralPurpose(left),
+                            sh4a::Register::GeneralPurpose(right),
+                            sh4a::Register::GeneralPurpose(target),
+                        ) if !is_float && target == right => vec![sh4a::Instruction::Add {
+                            dest: target,
+                            src2: left,
+                        }],
+                        (
+                            ir::BinaryArithmeticOp::Add,
+                            sh4a::Register::GeneralPurpose(left),
+                            sh4a::Register::GeneralPurpose(right),
+                            sh4a::Register::GeneralPurpose(target),
+                        ) if !is_float => {
+                            vec![
+                                sh4a::Instruction::MovRR {
+                                    src: left,
+                                    dest: target.clone(),
+                                },
+                                sh4a::Instruction::Add {
+                                    dest: target,
+                                    src2: right,
+                                },
+                            ]
+                        }
+                        (
+                            ir::BinaryArithmeticOp::Sub,
+                            sh4a::Register::GeneralPurpose(left),
+                            sh4a::Register::GeneralPurpose(right),
+                            sh4a::Register::GeneralPurpose(target),
+                        ) if !is_float && target == right => {
+                            vec![
+                                sh4a::Instruction::Neg {
+                                    dest: target.clone(),
+                                    src: right,
+                                },
+                                sh4a::Instruction::Add {
+                                    dest: target,
+                                    src2: left,
+                                },
+                            ]
+                        }
                         (
                             ir::BinaryArithmeticOp::Sub,
                             sh4a::Register::GeneralPurpose(left),
@@ -143,12 +267,57 @@ pub fn to_asm(
                 };
             }
             if left_save {
-                dbg!(&left_reg);
-                todo!()
+                match left_reg {
+                    sh4a::Register::GeneralPurpose(gp) => {
+                        result.push(sh4a::Instruction::PopL { reg: gp });
+                    }
+                    other => {
+                        dbg!(&other);
+                        todo!()
+                    }
+                };
             }
 
             result
         }
+        ir::Expression::UnaryOp {
+            op: ir::UnaryOp::Arith(arith_op),
+            base: ir::Operand::Variable(base_var),
+        } => match (ctx.registers.get(&base_var).unwrap().clone(), target) {
+            (sh4a::Register::GeneralPurpose(base), sh4a::Register::GeneralPurpose(dest))
+                if !base_var.ty.is_float() =>
+            {
+                match arith_op {
+                    ir::UnaryArithmeticOp::Increment => vec![
+                        sh4a::Instruction::MovRR {
+                            src: base,
+                            dest: dest.clone(),
+                        },
+                        sh4a::Instruction::AddImmediate {
+                            reg: dest,
+                            immediate: 1,
+                        },
+                    ],
+                    ir::UnaryArithmeticOp::Decrement => vec![
+                        sh4a::Instruction::MovRR {
+                            src: base,
+                            dest: dest.clone(),
+                        },
+                        sh4a::Instruction::AddImmediate {
+                            reg: dest,
+                            immediate: -1,
+                        },
+                    ],
+                    ir::UnaryArithmeticOp::Negate => {
+                        vec![sh4a::Instruction::Neg { dest, src: base }]
+                    }
+                }
+            }
+            other => {
+                dbg!(&other);
+                todo!()
+            }
+        },
         ir::Expression::Cast {
             base,
             target: target_ty,
@@ -209,6 +378,179 @@ pub fn to_asm(
 
             result
         }
+        ir::Expression::FunctionAddress { name } => {
+            let label = ctx.functions.get(&name).unwrap().clone();
+
+            match target {
+                sh4a::Register::GeneralPurpose(dest) => {
+                    vec![sh4a::Instruction::MovLabelAddress { label, dest }]
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            }
+        }
+        ir::Expression::ConstantAddress { name } => match target {
+            // The Constants are placed after the Code using their Name as the Label
+            sh4a::Register::GeneralPurpose(dest) => {
+                vec![sh4a::Instruction::MovLabelAddress { label: name, dest }]
+            }
+            other => {
+                dbg!(&other);
+                todo!()
+            }
+        },
+        ir::Expression::GlobalAddress { name } => {
+            let address = *ctx.globals.get(&name).unwrap();
+
+            match target {
+                sh4a::Register::GeneralPurpose(dest) => constants::store_u32(dest, address),
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            }
+        }
+        ir::Expression::FunctionCall {
+            name,
+            arguments,
+            variadic,
+            ..
+        } => function_call::call(
+            function_call::CallTarget::Name(name),
+            arguments,
+            variadic,
+            Some(target),
+            ctx,
+        ),
+        ir::Expression::IndirectFunctionCall {
+            target: call_target,
+            arguments,
+            variadic,
+            ..
+        } => function_call::call(
+            function_call::CallTarget::Address(call_target),
+            arguments,
+            variadic,
+            Some(target),
+            ctx,
+        ),
+        ir::Expression::VarArgsStart => {
+            // The variable Arguments start right after the named Arguments on the Stack, which
+            // are above the saved PR and our own Stack-Frame
+            let dest = match target {
+                sh4a::Register::GeneralPurpose(gp) => gp,
+                other => {
+                    dbg!(&other);
+                    panic!("Addresses should never be stored in a Floating Point Register")
+                }
+            };
+
+            let mut result = vec![sh4a::Instruction::MovRR {
+                src: sh4a::GeneralPurposeRegister::stack_reg(),
+                dest: dest.clone(),
+            }];
+
+            let mut space_left = ctx.stack_size + 4 + ctx.stack_arguments * 4;
+            while space_left > i8::MAX as usize {
+                result.push(sh4a::Instruction::AddImmediate {
+                    reg: dest.clone(),
+                    immediate: i8::MAX,
+                });
+                space_left -= i8::MAX as usize;
+            }
+            result.push(sh4a::Instruction::AddImmediate {
+                reg: dest,
+                immediate: space_left.try_into().unwrap(),
+            });
+
+            result
+        }
+        ir::Expression::ReadMemory {
+            address, read_ty, ..
+        } => {
+            let addr_reg = match oper_to_reg(address, None, ctx) {
+                (sh4a::Register::GeneralPurpose(gp), init, false) if init.is_empty() => gp,
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            };
+
+            match (target, read_ty) {
+                (sh4a::Register::GeneralPurpose(dest), ir::Type::I32)
+                | (sh4a::Register::GeneralPurpose(dest), ir::Type::U32)
+                | (sh4a::Register::GeneralPurpose(dest), ir::Type::Pointer(_)) => {
+                    // R0 is used as the Offset for the Address, so we save it while loading
+                    vec![
+                        sh4a::Instruction::PushL {
+                            reg: sh4a::GeneralPurposeRegister::new(0),
+                        },
+                        sh4a::Instruction::MovIR {
+                            dest: sh4a::GeneralPurposeRegister::new(0),
+                            immediate: 0,
+                        },
+                        sh4a::Instruction::MovLR0PRR {
+                            base: addr_reg,
+                            target: dest,
+                        },
+                        sh4a::Instruction::PopL {
+                            reg: sh4a::GeneralPurposeRegister::new(0),
+                        },
+                    ]
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            }
+        }
+        ir::Expression::ReadGlobalVariable { name } => {
+            let address = *ctx.globals.get(&name).unwrap();
+
+            match (target, target_var.ty.clone()) {
+                (sh4a::Register::GeneralPurpose(dest), ir::Type::I32)
+                | (sh4a::Register::GeneralPurpose(dest), ir::Type::U32)
+                | (sh4a::Register::GeneralPurpose(dest), ir::Type::Pointer(_)) => {
+                    // R1 holds the Address of the Global and R0 the Offset of 0, so both are saved
+                    let mut result = vec![
+                        sh4a::Instruction::PushL {
+                            reg: sh4a::GeneralPurposeRegister::new(0),
+                        },
+                        sh4a::Instruction::PushL {
+                            reg: sh4a::GeneralPurposeRegister::new(1),
+                        },
+                    ];
+                    result.extend(constants::store_u32(
+                        sh4a::GeneralPurposeRegister::new(1),
+                        address,
+                    ));
+                    result.extend([
+                        sh4a::Instruction::MovIR {
+                            dest: sh4a::GeneralPurposeRegister::new(0),
+                            immediate: 0,
+                        },
+                        sh4a::Instruction::MovLR0PRR {
+                            base: sh4a::GeneralPurposeRegister::new(1),
+                            target: dest,
+                        },
+                        sh4a::Instruction::PopL {
+                            reg: sh4a::GeneralPurposeRegister::new(1),
+                        },
+                        sh4a::Instruction::PopL {
+                            reg: sh4a::GeneralPurposeRegister::new(0),
+                        },
+                    ]);
+
+                    result
+                }
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            }
+        }
         ir::Expression::StackAlloc { size, alignment } => {
             dbg!(&target, &size, &alignment);
 
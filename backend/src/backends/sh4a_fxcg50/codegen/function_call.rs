@@ -0,0 +1,143 @@
+use isas::sh4a;
+
+use super::{constants, Context};
+
+/// The Number of Arguments that are passed in Registers
+pub const ARGUMENT_REGISTERS: usize = 4;
+
+/// The Register in which the Argument at the given Index is passed, which is only valid for the
+/// first [`ARGUMENT_REGISTERS`] Arguments
+pub fn argument_register(index: usize) -> sh4a::GeneralPurposeRegister {
+    debug_assert!(index < ARGUMENT_REGISTERS);
+
+    sh4a::GeneralPurposeRegister::new(4 + index as u8)
+}
+
+/// The Function that should be called
+pub enum CallTarget {
+    /// The Function with the given Name
+    Name(String),
+    /// The Function at the Address stored in the Operand
+    Address(ir::Operand),
+}
+
+fn operand_reg(var: &ir::Variable, ctx: &Context) -> sh4a::GeneralPurposeRegister {
+    match ctx.registers.get(var).unwrap().clone() {
+        sh4a::Register::GeneralPurpose(gp) => gp,
+        other => {
+            dbg!(&other);
+            todo!()
+        }
+    }
+}
+
+/// Loads the Value of the Operand into the given Register
+fn load_operand(
+    oper: ir::Operand,
+    dest: sh4a::GeneralPurposeRegister,
+    ctx: &Context,
+) -> Vec<sh4a::Instruction> {
+    match oper {
+        ir::Operand::Variable(var) => {
+            let src = operand_reg(&var, ctx);
+            vec![sh4a::Instruction::MovRR { src, dest }]
+        }
+        ir::Operand::Constant(con) => constants::store_constant(dest, &con),
+    }
+}
+
+/// Generates a Call to the given Function.
+///
+/// The first 4 Arguments are passed in R4-R7 and all the other ones are pushed onto the Stack
+/// in reverse Order, so that the 5th Argument is at the Stack-Pointer once the Function is
+/// entered. The variable Arguments of a variadic Function are always passed on the Stack, right
+/// after the named Arguments. The Address is loaded into R1, the Result of the Function is
+/// returned in R0 and the Arguments on the Stack are removed again by the Caller after the Call
+pub fn call(
+    call_target: CallTarget,
+    arguments: Vec<ir::Operand>,
+    variadic: Option<usize>,
+    result: Option<sh4a::Register>,
+    ctx: &Context,
+) -> Vec<sh4a::Instruction> {
+    let (mut arguments, var_arguments) = match variadic {
+        Some(fixed) => {
+            let mut arguments = arguments;
+            let var_arguments = arguments.split_off(fixed);
+            (arguments, var_arguments)
+        }
+        None => (arguments, Vec::new()),
+    };
+    let stack_arguments = arguments.split_off(arguments.len().min(ARGUMENT_REGISTERS));
+
+    let mut instructions = Vec::new();
+
+    // R0 is overwritten by the Result of the Call anyway, so it can be used for loading
+    // Constants without saving it first
+    let stack_space = (stack_arguments.len() + var_arguments.len()) * 4;
+    for arg in stack_arguments.into_iter().chain(var_arguments).rev() {
+        let reg = match arg {
+            ir::Operand::Variable(var) => operand_reg(&var, ctx),
+            ir::Operand::Constant(con) => {
+                let tmp = sh4a::GeneralPurposeRegister::new(0);
+                instructions.extend(constants::store_constant(tmp.clone(), &con));
+                tmp
+            }
+        };
+        instructions.push(sh4a::Instruction::PushL { reg });
+    }
+
+    for (index, arg) in arguments.into_iter().enumerate() {
+        let arg_reg = argument_register(index);
+
+        instructions.extend(load_operand(arg, arg_reg, ctx));
+    }
+
+    let jump_reg = sh4a::GeneralPurposeRegister::new(1);
+    match call_target {
+        CallTarget::Name(name) => {
+            let label = ctx.functions.get(&name).unwrap().clone();
+
+            instructions.push(sh4a::Instruction::MovLabelAddress {
+                label,
+                dest: jump_reg.clone(),
+            });
+        }
+        CallTarget::Address(target) => {
+            instructions.extend(load_operand(target, jump_reg.clone(), ctx));
+        }
+    };
+    instructions.push(sh4a::Instruction::JumpSubroutine { target: jump_reg });
+    instructions.push(sh4a::Instruction::Nop);
+
+    let mut space_left = stack_space;
+    while space_left > i8::MAX as usize {
+        instructions.push(sh4a::Instruction::AddImmediate {
+            reg: sh4a::GeneralPurposeRegister::stack_reg(),
+            immediate: i8::MAX,
+        });
+        space_left -= i8::MAX as usize;
+    }
+    if space_left > 0 {
+        instructions.push(sh4a::Instruction::AddImmediate {
+            reg: sh4a::GeneralPurposeRegister::stack_reg(),
+            immediate: space_left.try_into().unwrap(),
+        });
+    }
+
+    match result {
+        Some(sh4a::Register::GeneralPurpose(dest)) => {
+            instructions.push(sh4a::Instruction::MovRR {
+                src: sh4a::GeneralPurposeRegister::new(0),
+                dest,
+            });
+        }
+        Some(other) => {
+            dbg!(&other);
+            todo!()
+        }
+        None => {}
+    };
+
+    instructions
+}
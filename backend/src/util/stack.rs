@@ -68,9 +68,14 @@ where
 }
 
 /// Determine the number of Allocations needed
-fn allocations(
+fn allocations<TAS>(
+    func: &ir::FunctionDefinition,
     graph: &graphs::directed::DirectedGraph<ir::BasicBlock>,
-) -> BTreeMap<ir::Variable, (usize, usize)> {
+    size_align: TAS,
+) -> BTreeMap<ir::Variable, (usize, usize)>
+where
+    TAS: Fn(&ir::Type) -> (usize, usize),
+{
     // The Resulting Map of Allocations
     let mut result = BTreeMap::new();
 
@@ -79,13 +84,38 @@ fn allocations(
         let statements = block.get_statements();
 
         for stmnt in statements {
-            if let ir::Statement::Assignment {
-                target,
-                value: ir::Value::Expression(ir::Expression::StackAlloc { size, alignment }),
-            } = stmnt
-            {
-                result.insert(target, (alignment, size));
-            }
+            match stmnt {
+                ir::Statement::Assignment {
+                    target,
+                    value: ir::Value::Expression(ir::Expression::StackAlloc { size, alignment }),
+                } => {
+                    result.insert(target, (alignment, size));
+                }
+                // Structs that are passed to this Function might be passed in Registers, so
+                // they need Memory in the Frame to be stored in
+                ir::Statement::Assignment {
+                    target,
+                    value: ir::Value::Unknown,
+                } if matches!(target.ty, ir::Type::Struct { .. })
+                    && func.arguments.iter().any(|(name, _)| name == target.name()) =>
+                {
+                    let entry = size_align(&target.ty);
+                    result.insert(target, entry);
+                }
+                // The same applies to Structs that are returned from other Functions
+                ir::Statement::Assignment {
+                    target,
+                    value:
+                        ir::Value::Expression(
+                            ir::Expression::FunctionCall { .. }
+                            | ir::Expression::IndirectFunctionCall { .. },
+                        ),
+                } if matches!(target.ty, ir::Type::Struct { .. }) => {
+                    let entry = size_align(&target.ty);
+                    result.insert(target, entry);
+                }
+                _ => {}
+            };
         }
     }
 
@@ -102,8 +132,12 @@ pub struct StackAllocation<I> {
     pub pre_return_instr: Vec<I>,
     /// The Offsets for all the Variables by name
     pub var_offsets: HashMap<String, isize>,
-    /// The Offsets for certain Structures like arrays
+    /// The Offsets for certain Structures like arrays. Allocations with a greater Alignment than
+    /// the Stack-Alignment are only aligned to the Stack-Alignment, but have enough Space after
+    /// the Offset to align their Address to their own Alignment
     pub allocations: HashMap<ir::Variable, isize>,
+    /// The entire Space in Bytes that is allocated on the Stack
+    pub stack_size: usize,
 }
 
 /// The Configuration to use for the Stack-Allocation procedure
@@ -154,10 +188,23 @@ where
     let used_registers: HashSet<_> = reg_map.iter().map(|(_, r)| r).collect();
 
     // Determine the used Variables
-    let raw_vars = vars_used(&func_graph, conf.type_align_size);
+    let raw_vars = vars_used(&func_graph, &conf.type_align_size);
 
     // Determine the raw-number of allocations
-    let raw_allocations = allocations(&func_graph);
+    let raw_allocations = allocations(func, &func_graph, &conf.type_align_size);
+
+    // The Frame itself is only aligned to the Stack-Alignment, so Allocations with a greater
+    // Alignment get enough additional Space for the Backend to align their Address at runtime
+    let frame_entry = |alignment: usize, size: usize| {
+        if alignment > conf.stack_alignment {
+            (
+                conf.stack_alignment,
+                size + alignment - conf.stack_alignment,
+            )
+        } else {
+            (alignment, size)
+        }
+    };
 
     let alloc_iter = used_registers
         .iter()
@@ -170,7 +217,7 @@ where
         .chain(
             raw_allocations
                 .iter()
-                .map(|(_, (alignment, size))| (*alignment, *size)),
+                .map(|(_, (alignment, size))| frame_entry(*alignment, *size)),
         );
 
     // Determine the Space needed on the Stack for the provided Allocations
@@ -233,8 +280,9 @@ where
 
         let mut base = start_base;
         for (var, (alignment, size)) in raw_allocations.iter() {
-            let alignment = *alignment as i16;
-            let size = *size as i16;
+            let (alignment, size) = frame_entry(*alignment, *size);
+            let alignment = alignment as i16;
+            let size = size as i16;
 
             if base % alignment != 0 {
                 base += alignment - (base % alignment);
@@ -254,5 +302,6 @@ where
         pre_return_instr: pre_ret_instr,
         var_offsets,
         allocations,
+        stack_size: stack_space,
     }
 }
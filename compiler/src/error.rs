@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use ariadne::{Color, ColorGenerator, Fmt, Label, Report, ReportKind};
 use preprocessor::ProcessError;
-use semantic::SemanticError;
+use semantic::{SemanticError, SemanticWarning};
 use syntax::SyntaxError;
 
 mod cache;
@@ -13,6 +13,12 @@ pub enum Error<P> {
     Preprocessor(preprocessor::ProcessError<P>),
     Syntax(syntax::SyntaxError),
     Semantic(semantic::SemanticError),
+    /// The same Symbol was defined in more than one Translation-Unit
+    DuplicateSymbol {
+        name: String,
+        previous_definition: general::Span,
+        definition: general::Span,
+    },
 }
 
 impl<P> Error<P>
@@ -215,9 +221,34 @@ where
                             .unwrap();
                     }
                     SemanticError::MismatchedOperationTypes { left, right } => {
-                        dbg!(&left, &right);
+                        let sources = SourceCache::from([&left.span, &right.span]);
 
-                        todo!()
+                        let mut color_gen = ColorGenerator::new();
+
+                        let left_c = color_gen.next();
+                        let right_c = color_gen.next();
+
+                        let left_str = format!("{:?}", left.data).fg(left_c);
+                        let right_str = format!("{:?}", right.data).fg(right_c);
+
+                        Report::build(ReportKind::Error, &left.span, 0)
+                            .with_message(format!(
+                                "Operation between {} and {} is not supported",
+                                left_str, right_str
+                            ))
+                            .with_label(
+                                Label::new((&left.span, left.span.source_area().clone()))
+                                    .with_message(format!("This is {}", left_str))
+                                    .with_color(left_c),
+                            )
+                            .with_label(
+                                Label::new((&right.span, right.span.source_area().clone()))
+                                    .with_message(format!("This is {}", right_str))
+                                    .with_color(right_c),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
                     }
                     SemanticError::StructAccessOnNonStruct {
                         field_name,
@@ -321,12 +352,431 @@ where
                     SemanticError::MissingReturn {} => {
                         todo!()
                     }
+                    SemanticError::InvalidSwitchType { received } => {
+                        let sources = SourceCache::from([&received.span]);
+
+                        Report::build(ReportKind::Error, &received.span, 0)
+                            .with_message(format!("Switch on non Integer Type {:?}", received.data))
+                            .with_label(
+                                Label::new((&received.span, received.span.source_area().clone()))
+                                    .with_message("This is not an Integer"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidCaseValue { case } => {
+                        let sources = SourceCache::from([&case]);
+
+                        Report::build(ReportKind::Error, &case, 0)
+                            .with_message("Case Value is not an Integer Constant")
+                            .with_label(
+                                Label::new((&case, case.source_area().clone()))
+                                    .with_message("Case Value"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidVarArgs { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Invalid use of variable Arguments")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone())).with_message(
+                                    "Only allowed on a va_list in a variadic Function",
+                                ),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidEnumValue { value } => {
+                        let sources = SourceCache::from([&value]);
+
+                        Report::build(ReportKind::Error, &value, 0)
+                            .with_message("Enum Value is not an Integer Constant")
+                            .with_label(
+                                Label::new((&value, value.source_area().clone()))
+                                    .with_message("Enum Value"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidInitializer { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Invalid Initializer")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone()))
+                                    .with_message("Initializer does not match the Type"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::NonConstantInitializer { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Initializer is not constant")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone())).with_message(
+                                    "Can only use Variables with static Storage-Duration",
+                                ),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidBitField { name, width } => {
+                        let sources = SourceCache::from([&width]);
+
+                        Report::build(ReportKind::Error, &width, 0)
+                            .with_message(match name {
+                                Some(name) => format!("Invalid Bit-Field {:?}", name.0.data),
+                                None => "Invalid unnamed Bit-Field".to_string(),
+                            })
+                            .with_label(
+                                Label::new((&width, width.source_area().clone()))
+                                    .with_message("Width does not fit the Type of the Bit-Field"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidNumber { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Invalid Numeric Constant")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone()))
+                                    .with_message("Not a valid Number for its Radix and Suffix"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::NumberOverflow { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Numeric Constant is too large")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone()))
+                                    .with_message("Does not fit into any of the possible Types"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::ConstAssignment { target, ty } => {
+                        let sources = SourceCache::from([&target, &ty.span]);
+
+                        let mut color_gen = ColorGenerator::new();
+
+                        let target_c = color_gen.next();
+                        let ty_c = color_gen.next();
+
+                        Report::build(ReportKind::Error, &target, 0)
+                            .with_message("Write to a const Object")
+                            .with_label(
+                                Label::new((&target, target.source_area().clone()))
+                                    .with_message("Written here")
+                                    .with_color(target_c),
+                            )
+                            .with_label(
+                                Label::new((&ty.span, ty.span.source_area().clone()))
+                                    .with_message(format!(
+                                        "Declared with the const Type {}",
+                                        format!("{:?}", ty.data).fg(ty_c)
+                                    ))
+                                    .with_color(ty_c),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::DiscardedConst { base, target } => {
+                        let sources = SourceCache::from([&base.span, &target.span]);
+
+                        let mut color_gen = ColorGenerator::new();
+
+                        let base_c = color_gen.next();
+                        let target_c = color_gen.next();
+
+                        let base_str = format!("{:?}", base.data).fg(base_c);
+                        let target_str = format!("{:?}", target.data).fg(target_c);
+
+                        Report::build(ReportKind::Error, &base.span, 0)
+                            .with_message(format!(
+                                "Conversion from {} to {} discards the const Qualifier",
+                                base_str, target_str,
+                            ))
+                            .with_label(
+                                Label::new((&target.span, target.span.source_area().clone()))
+                                    .with_message(format!("Target Type {}", target_str))
+                                    .with_color(target_c),
+                            )
+                            .with_label(
+                                Label::new((&base.span, base.span.source_area().clone()))
+                                    .with_message(format!("Base Type {}", base_str))
+                                    .with_color(base_c),
+                            )
+                            .with_note(format!("If this was intentional, you can force this Conversion to happen by using an explicit Cast to {}", target_str))
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::DuplicateCase {
+                        value,
+                        previous_case,
+                    } => {
+                        let sources = SourceCache::from([&previous_case, &value.span]);
+
+                        Report::build(ReportKind::Error, &value.span, 0)
+                            .with_message(format!("Duplicate Case Value {}", value.data))
+                            .with_label(
+                                Label::new((&previous_case, previous_case.source_area().clone()))
+                                    .with_message("Previously used here"),
+                            )
+                            .with_label(
+                                Label::new((&value.span, value.span.source_area().clone()))
+                                    .with_message("Used again here"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::DuplicateDefault {
+                        default,
+                        previous_default,
+                    } => {
+                        let sources = SourceCache::from([&previous_default, &default]);
+
+                        Report::build(ReportKind::Error, &default, 0)
+                            .with_message("Multiple default Cases in one Switch")
+                            .with_label(
+                                Label::new((
+                                    &previous_default,
+                                    previous_default.source_area().clone(),
+                                ))
+                                .with_message("First default Case"),
+                            )
+                            .with_label(
+                                Label::new((&default, default.source_area().clone()))
+                                    .with_message("Second default Case"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::UnknownLabel { name } => {
+                        let sources = SourceCache::from([&name.0.span]);
+
+                        Report::build(ReportKind::Error, &name.0.span, 0)
+                            .with_message(format!("Unknown Label \"{}\"", name.0.data))
+                            .with_label(
+                                Label::new((&name.0.span, name.0.span.source_area().clone()))
+                                    .with_message(
+                                        "There is no Label with this Name in the Function",
+                                    ),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::DuplicateLabel {
+                        name,
+                        previous_definition,
+                    } => {
+                        let sources = SourceCache::from([&previous_definition, &name.0.span]);
+
+                        Report::build(ReportKind::Error, &name.0.span, 0)
+                            .with_message(format!("Label \"{}\" was defined again", name.0.data))
+                            .with_label(
+                                Label::new((
+                                    &previous_definition,
+                                    previous_definition.source_area().clone(),
+                                ))
+                                .with_message("Previously defined here"),
+                            )
+                            .with_label(
+                                Label::new((&name.0.span, name.0.span.source_area().clone()))
+                                    .with_message("Redefined here"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::FailedStaticAssertion { condition, message } => {
+                        let sources = SourceCache::from([&condition, &message.span]);
+
+                        Report::build(ReportKind::Error, &condition, 0)
+                            .with_message(format!("Static Assertion failed: {}", message.data))
+                            .with_label(
+                                Label::new((&condition, condition.source_area().clone()))
+                                    .with_message("Evaluated to false"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidStaticAssertion { condition } => {
+                        let sources = SourceCache::from([&condition]);
+
+                        Report::build(ReportKind::Error, &condition, 0)
+                            .with_message("Static Assertion is not an Integer Constant")
+                            .with_label(
+                                Label::new((&condition, condition.source_area().clone()))
+                                    .with_message("Condition of the Static Assertion"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidAlignment { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Invalid Alignment")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone())).with_message(
+                                    "Has to be an Integer Constant and a Power of two",
+                                ),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::InvalidArraySize { area } => {
+                        let sources = SourceCache::from([&area]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message("Invalid Array-Size")
+                            .with_label(
+                                Label::new((&area, area.source_area().clone())).with_message(
+                                    "Has to be a non-negative Integer, that is only variable for local Variables",
+                                ),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::IncompleteType { area, name } => {
+                        let sources = SourceCache::from([&area, &name.0.span]);
+
+                        Report::build(ReportKind::Error, &area, 0)
+                            .with_message(format!("Incomplete Type {:?}", name.0.data))
+                            .with_label(
+                                Label::new((&area, area.source_area().clone()))
+                                    .with_message("Requires the Definition of the Type"),
+                            )
+                            .with_label(
+                                Label::new((&name.0.span, name.0.span.source_area().clone()))
+                                    .with_message("Is not defined at this Point"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
+                    SemanticError::MismatchedTag {
+                        name,
+                        previous_definition,
+                    } => {
+                        let sources = SourceCache::from([&name.0.span, &previous_definition]);
+
+                        Report::build(ReportKind::Error, &name.0.span, 0)
+                            .with_message(format!(
+                                "{:?} is used as a different Kind of Type",
+                                name.0.data
+                            ))
+                            .with_label(
+                                Label::new((
+                                    &previous_definition,
+                                    previous_definition.source_area().clone(),
+                                ))
+                                .with_message("Previously defined here"),
+                            )
+                            .with_label(
+                                Label::new((&name.0.span, name.0.span.source_area().clone()))
+                                    .with_message("Used with a different Kind here"),
+                            )
+                            .finish()
+                            .print(sources)
+                            .unwrap();
+                    }
                     SemanticError::NotImplemented { ctx } => {
                         dbg!(&ctx);
                         todo!()
                     }
                 };
             }
+            Self::DuplicateSymbol {
+                name,
+                previous_definition,
+                definition,
+            } => {
+                let sources = SourceCache::from([&previous_definition, &definition]);
+
+                Report::build(ReportKind::Error, &definition, 0)
+                    .with_message(format!("{:?} is defined in multiple Files", name))
+                    .with_label(
+                        Label::new((
+                            &previous_definition,
+                            previous_definition.source_area().clone(),
+                        ))
+                        .with_message("Previously defined here"),
+                    )
+                    .with_label(
+                        Label::new((&definition, definition.source_area().clone()))
+                            .with_message("Defined again here"),
+                    )
+                    .finish()
+                    .print(sources)
+                    .unwrap();
+            }
         };
     }
 }
+
+/// Displays a Warning, which unlike an Error does not stop the Compilation
+pub fn display_warning(warning: &SemanticWarning) {
+    match warning {
+        SemanticWarning::SignChangingComparison { signed, unsigned } => {
+            let sources = SourceCache::from([&signed.span, &unsigned.span]);
+
+            let mut color_gen = ColorGenerator::new();
+
+            let signed_c = color_gen.next();
+            let unsigned_c = color_gen.next();
+
+            let signed_str = signed.data.to_string().fg(signed_c);
+            let unsigned_str = unsigned.data.to_string().fg(unsigned_c);
+
+            Report::build(ReportKind::Warning, &signed.span, 0)
+                .with_message(format!(
+                    "Comparison between {} and {} of different Signedness",
+                    signed_str, unsigned_str
+                ))
+                .with_label(
+                    Label::new((&signed.span, signed.span.source_area().clone()))
+                        .with_message(format!("This is converted to {}", unsigned_str))
+                        .with_color(signed_c),
+                )
+                .with_label(
+                    Label::new((&unsigned.span, unsigned.span.source_area().clone()))
+                        .with_message(format!("Because this is {}", unsigned_str))
+                        .with_color(unsigned_c),
+                )
+                .with_note("Negative Values will compare as very large unsigned Values")
+                .finish()
+                .print(sources)
+                .unwrap();
+        }
+    }
+}
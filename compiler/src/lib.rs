@@ -1,10 +1,11 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
+use general::Span;
 use optimizer::Optimization;
 use preprocessor::Loader;
 
 mod error;
-pub use error::Error;
+pub use error::{display_warning, Error};
 
 pub struct Config {
     pub target: general::arch::Target,
@@ -24,25 +25,51 @@ where
 
         let basic_ast = syntax::parse(preprocessed).map_err(Error::Syntax)?;
 
-        let aast = semantic::parse(basic_ast).map_err(Error::Semantic)?;
+        let aast = semantic::parse_for(basic_ast, &config.target.0).map_err(Error::Semantic)?;
+        for warning in aast.warnings.iter() {
+            display_warning(warning);
+        }
+        aast.check_static_assertions(&config.target.0)
+            .map_err(Error::Semantic)?;
 
-        let raw_ir = aast.convert_to_ir(config.target.0.clone());
+        let definitions = aast.defined_functions(&src_file);
+        let raw_ir = aast.convert_unit_to_ir(&src_file, config.target.0.clone());
 
-        Ok(raw_ir)
+        Ok((raw_ir, definitions))
     });
 
     let raw_ir = {
-        let mut tmp: ir::Program = irs_iter.next().unwrap()?;
+        let (mut tmp, definitions): (ir::Program, _) = irs_iter.next().unwrap()?;
+        let mut defined: HashMap<String, Span> = definitions.into_iter().collect();
 
         for o in irs_iter {
-            let other = o?;
+            let (other, definitions) = o?;
+
+            for (name, definition) in definitions {
+                if let Some(previous_definition) = defined.get(&name) {
+                    return Err(Error::DuplicateSymbol {
+                        name,
+                        previous_definition: previous_definition.clone(),
+                        definition,
+                    });
+                }
+                defined.insert(name, definition);
+            }
 
             let mut prev_global_statements = tmp.global.get_statements();
             let other_global_statements = other.global.get_statements();
             prev_global_statements.extend(other_global_statements);
             tmp.global.set_statements(prev_global_statements);
 
+            // The Functions of the other Program still start after its own Global-Block, which
+            // is dropped once its Statements are merged
+            for func in other.functions.values() {
+                func.block.remove_predecessor(other.global.weak_ptr());
+                func.block.add_predecessor(tmp.global.weak_ptr());
+            }
+
             tmp.functions.extend(other.functions);
+            tmp.constants.extend(other.constants);
         }
 
         tmp
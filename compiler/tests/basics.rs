@@ -1,21 +1,20 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use compiler::Config;
 use preprocessor::loader::files::FileLoader;
 
 macro_rules! compile_testing {
     ($name:ident, $path:expr, $compiles:expr, $ret_code:expr) => {
+        compile_testing!($name, $path, $compiles, $ret_code, 0);
+    };
+    ($name:ident, $path:expr, $compiles:expr, $ret_code:expr, $opt_level:expr) => {
         #[test]
         fn $name() {
             let base_path = Path::new("./tests/files/basics");
             let src_path = base_path.join($path);
-            let build_path = Path::new("./test-builds/basics").join(
-                PathBuf::from(($path).to_string())
-                    .file_stem()
-                    .unwrap()
-                    .to_str()
-                    .unwrap(),
-            );
+            // The same File can be compiled by multiple Tests, so every Test needs its own
+            // Build-Directory
+            let build_path = Path::new("./test-builds/basics").join(stringify!($name));
 
             dbg!(&src_path, &build_path);
 
@@ -25,7 +24,7 @@ macro_rules! compile_testing {
                 vec![src_path.to_str().unwrap().to_string()],
                 loader,
                 Config {
-                    opt_level: 0,
+                    opt_level: $opt_level,
                     target: general::arch::Target::default(),
                     target_file: Some(stringify!($name).to_string()),
                     build_dir: build_path.clone(),
@@ -65,10 +64,38 @@ compile_testing!(final_, "final.c", true, 0);
 compile_testing!(floats, "floats.c", true, 0);
 compile_testing!(function_call, "function_call.c", true, 0);
 
-// compile_testing!(globals, "globals.c", true, 0);
+compile_testing!(globals, "globals.c", true, 0);
+compile_testing!(global_updates, "global_updates.c", true, 0);
+compile_testing!(global_address, "global_address.c", true, 0);
+compile_testing!(global_arrays, "global_arrays.c", true, 0);
 compile_testing!(branching, "branching.c", true, 0);
 compile_testing!(for_loop, "for_loop.c", true, 0);
 compile_testing!(while_loop, "while_loop.c", true, 0);
+compile_testing!(do_while, "do_while.c", true, 0);
+compile_testing!(goto, "goto.c", true, 0);
+compile_testing!(union, "union.c", true, 0);
+compile_testing!(initializers, "initializers.c", true, 0);
+compile_testing!(bitfields, "bitfields.c", true, 0);
+compile_testing!(enums, "enum.c", true, 0);
+compile_testing!(struct_by_value, "struct_by_value.c", true, 0);
+compile_testing!(strings, "strings.c", true, 0);
+compile_testing!(string_constants, "string_constants.c", true, 0);
+compile_testing!(numbers, "numbers.c", true, 0);
+compile_testing!(bools, "bools.c", true, 0);
+compile_testing!(bools_optimized, "bools.c", true, 0, 1);
+compile_testing!(consts, "consts.c", true, 0);
+compile_testing!(volatile, "volatile.c", true, 0);
+compile_testing!(alignment, "alignment.c", true, 0);
+compile_testing!(arith_conversions, "arith_conversions.c", true, 0);
+compile_testing!(pointer_arith, "pointer_arith.c", true, 0);
+compile_testing!(multi_arrays, "multi_arrays.c", true, 0);
+compile_testing!(linked_list, "linked_list.c", true, 0);
+compile_testing!(short_circuit, "short_circuit.c", true, 0);
+compile_testing!(function_pointer, "function_pointer.c", true, 0);
+compile_testing!(varargs, "varargs.c", true, 0);
+compile_testing!(statics, "static.c", true, 0);
+compile_testing!(static_non_constant, "static_non_constant.c", false, 0);
+compile_testing!(switch, "switch.c", true, 0);
 compile_testing!(nested_loops, "nested_loops.c", true, 0);
 compile_testing!(missing_include, "missing_include.c", false, 0);
 compile_testing!(pointer, "pointer.c", true, 0);
@@ -76,3 +103,30 @@ compile_testing!(simple, "simple.c", true, 0);
 compile_testing!(spill_var, "spill_vars.c", true, 0);
 compile_testing!(factorial, "factorial.c", true, 6);
 compile_testing!(spilling, "spilling.c", true, 0);
+
+#[test]
+fn duplicate_symbol() {
+    let base_path = Path::new("./tests/files/basics/duplicate_symbol");
+    let build_path = Path::new("./test-builds/basics/duplicate_symbol");
+
+    let loader = FileLoader::new();
+
+    let comp_result = compiler::run(
+        vec![
+            base_path.join("first.c").to_str().unwrap().to_string(),
+            base_path.join("second.c").to_str().unwrap().to_string(),
+        ],
+        loader,
+        Config {
+            opt_level: 0,
+            target: general::arch::Target::default(),
+            target_file: Some("duplicate_symbol".to_string()),
+            build_dir: build_path.to_path_buf(),
+        },
+    );
+
+    match comp_result {
+        Err(compiler::Error::DuplicateSymbol { name, .. }) => assert_eq!("value", name),
+        other => panic!("Expected a duplicate Symbol, got {:?}", other),
+    };
+}
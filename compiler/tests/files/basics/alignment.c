@@ -0,0 +1,48 @@
+struct header {
+  char kind;
+  int length;
+};
+
+_Static_assert(sizeof(struct header) == 8, "header layout");
+_Static_assert(_Alignof(struct header) == 4, "header alignment");
+
+int main() {
+  _Static_assert(sizeof(int) == 4, "int size");
+
+  _Alignas(16) int values[2] = {1, 2};
+  if (values[1] != 2) {
+    return 1;
+  }
+
+  _Alignas(long) char buffer[4];
+  buffer[0] = 3;
+  if (buffer[0] != 3) {
+    return 2;
+  }
+
+  if (_Alignof(int) != 4) {
+    return 3;
+  }
+
+  // The Stack-Pointer is only 16-Byte aligned, so larger Alignments need to be established
+  // when taking the Address
+  char before = 1;
+  _Alignas(32) char wide[8];
+  wide[0] = before;
+  unsigned long wide_address = (unsigned long)wide;
+  if ((wide_address & 31) != 0) {
+    return 4;
+  }
+
+  _Alignas(64) int wider[3];
+  wider[2] = 5;
+  unsigned long wider_address = (unsigned long)wider;
+  if ((wider_address & 63) != 0) {
+    return 5;
+  }
+  if (wider[2] != 5 || wide[0] != 1) {
+    return 6;
+  }
+
+  return 0;
+}
@@ -0,0 +1,35 @@
+int main() {
+  char c = 100;
+  short s = 1000;
+  long l = 100000;
+  unsigned int u = 3;
+
+  int sum = c + c;
+  if (sum != 200) {
+    return 1;
+  }
+
+  long wide = s + l;
+  if (wide != 101000) {
+    return 2;
+  }
+
+  int negative = -7;
+  if (negative > l) {
+    return 3;
+  }
+
+  unsigned int product = u * s;
+  if (product != 3000) {
+    return 4;
+  }
+
+  // The Sum is an unsigned int, so it only becomes negative when converted back to an int
+  long mixed = u + negative;
+  int narrowed = u + negative;
+  if (mixed != 4294967292 || narrowed != -4) {
+    return 5;
+  }
+
+  return 0;
+}
@@ -0,0 +1,51 @@
+struct Flags {
+  unsigned ready : 1;
+  unsigned mode : 3;
+  int delta : 4;
+  unsigned wide : 20;
+  int after;
+};
+
+struct Padded {
+  unsigned low : 2;
+  unsigned : 3;
+  unsigned high : 3;
+  unsigned : 0;
+  unsigned next : 4;
+};
+
+int main() {
+  struct Flags f = { 1, 5, 3, .after = 2 };
+  if (sizeof(struct Flags) != 8) {
+    return 1;
+  }
+  if (f.ready != 1 || f.mode != 5 || f.delta != 3 || f.after != 2) {
+    return 2;
+  }
+
+  f.mode = 9;
+  if (f.mode != 1 || f.ready != 1) {
+    return 3;
+  }
+
+  int expected = -8;
+  f.delta = f.delta - 11;
+  if (f.delta != expected) {
+    return 4;
+  }
+
+  f.wide = 1000;
+  if (f.wide != 1000 || f.mode != 1 || f.after != 2) {
+    return 5;
+  }
+
+  struct Padded p = { 1, 6, 9 };
+  if (sizeof(struct Padded) != 8) {
+    return 6;
+  }
+  if (p.low != 1 || p.high != 6 || p.next != 9) {
+    return 7;
+  }
+
+  return 0;
+}
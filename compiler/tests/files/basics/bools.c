@@ -0,0 +1,49 @@
+#include <stdbool.h>
+
+bool is_set(int value) {
+  return value;
+}
+
+int main() {
+  bool big = 256;
+  if (big != 1) {
+    return 1;
+  }
+
+  if (is_set(512) != true) {
+    return 2;
+  }
+  if (is_set(0) != false) {
+    return 3;
+  }
+
+  int target = 0;
+  int* ptr = &target;
+  bool has_ptr = ptr;
+  if (has_ptr != true) {
+    return 4;
+  }
+
+  bool smaller = 3 < 5;
+  if (smaller != true) {
+    return 5;
+  }
+
+  bool incremented = true;
+  incremented++;
+  if (incremented != true) {
+    return 6;
+  }
+
+  bool decremented = false;
+  decremented--;
+  if (decremented != true) {
+    return 7;
+  }
+  decremented--;
+  if (decremented != false) {
+    return 8;
+  }
+
+  return 0;
+}
@@ -0,0 +1,48 @@
+const int offset = 3;
+
+struct point {
+  int x;
+  int y;
+};
+
+int sum(const int* values, int count) {
+  int result = 0;
+  for (int i = 0; i < count; i++) {
+    result = result + values[i];
+  }
+  return result;
+}
+
+int get_x(const struct point* p) {
+  return p->x;
+}
+
+int main() {
+  const int base = 4;
+  int doubled = base * 2;
+  if (doubled != 8) {
+    return 1;
+  }
+
+  if (offset != 3) {
+    return 2;
+  }
+
+  int values[3] = {1, 2, 3};
+  const int* view = values;
+  if (view[2] != 3) {
+    return 3;
+  }
+  if (sum(values, 3) != 6) {
+    return 4;
+  }
+
+  struct point p;
+  p.x = 5;
+  p.y = 6;
+  if (get_x(&p) != 5) {
+    return 5;
+  }
+
+  return 0;
+}
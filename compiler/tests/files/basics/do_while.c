@@ -0,0 +1,26 @@
+int main() {
+  int count = 0;
+  do {
+    count = count + 1;
+  } while (0);
+
+  if (count != 1) {
+    return 1;
+  }
+
+  int i = 0;
+  int sum = 0;
+  do {
+    i = i + 1;
+    if (i == 2) {
+      continue;
+    }
+    sum = sum + i;
+  } while (i < 4);
+
+  if (sum != 8) {
+    return 2;
+  }
+
+  return 0;
+}
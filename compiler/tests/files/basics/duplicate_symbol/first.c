@@ -0,0 +1,7 @@
+int value() {
+  return 1;
+}
+
+int main() {
+  return value() - 1;
+}
@@ -0,0 +1,3 @@
+int value() {
+  return 2;
+}
@@ -0,0 +1,38 @@
+enum state {
+  idle,
+  running = 4,
+  stopped
+};
+
+int next(enum state current) {
+  switch (current) {
+    case idle:
+      return running;
+    case running:
+      return stopped;
+    default:
+      return idle;
+  }
+  return idle;
+}
+
+int main() {
+  enum state current = idle;
+  current = next(current);
+  if (current != 4) {
+    return 1;
+  }
+
+  current = next(current);
+  if (current != stopped) {
+    return 2;
+  }
+
+  int values[stopped];
+  values[stopped - 1] = running;
+  if (values[4] != 4) {
+    return 3;
+  }
+
+  return 0;
+}
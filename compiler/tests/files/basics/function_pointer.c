@@ -0,0 +1,41 @@
+typedef int (*binary_op)(int a, int b);
+
+int add(int a, int b) {
+  return a + b;
+}
+
+int sub(int a, int b) {
+  return a - b;
+}
+
+int apply(binary_op op, int a, int b) {
+  return op(a, b);
+}
+
+int main() {
+  int (*fp)(int, int) = add;
+  if (fp(2, 3) != 5) {
+    return 1;
+  }
+
+  fp = &sub;
+  if ((*fp)(5, 3) != 2) {
+    return 2;
+  }
+
+  if (apply(add, 1, 1) != 2) {
+    return 3;
+  }
+
+  int (*ops[2])(int, int);
+  ops[0] = add;
+  ops[1] = sub;
+  if (ops[0](4, 3) != 7) {
+    return 4;
+  }
+  if (ops[1](4, 3) != 1) {
+    return 5;
+  }
+
+  return 0;
+}
@@ -0,0 +1,25 @@
+int g = 0;
+
+void set(int *target) {
+  *target = 42;
+}
+
+int *addr() {
+  static int count = 3;
+  return &count;
+}
+
+int main() {
+  set(&g);
+  if (g != 42) {
+    return 1;
+  }
+
+  int *count = addr();
+  *count = *count + 1;
+  if (*addr() != 4) {
+    return 2;
+  }
+
+  return 0;
+}
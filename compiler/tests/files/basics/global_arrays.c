@@ -0,0 +1,42 @@
+int values[8];
+
+struct pair {
+  int first;
+  int second;
+};
+
+struct pair pairs[2];
+int *cursor = (int *) 0;
+
+int main() {
+  for (int i = 0; i < 8; i++) {
+    values[i] = i * 2;
+  }
+
+  int sum = 0;
+  for (int i = 0; i < 8; i++) {
+    sum = sum + values[i];
+  }
+  if (sum != 56) {
+    return 1;
+  }
+
+  for (int i = 0; i < 2; i++) {
+    pairs[i].first = i;
+    pairs[i].second = values[i + 1];
+  }
+  if (pairs[1].first != 1 || pairs[1].second != 4) {
+    return 2;
+  }
+
+  cursor = values;
+  int total = 0;
+  for (int i = 0; i < 4; i++) {
+    total = total + cursor[i];
+  }
+  if (total != 12) {
+    return 3;
+  }
+
+  return 0;
+}
@@ -0,0 +1,17 @@
+int counter = 0;
+
+void bump() {
+	counter += 2;
+	counter++;
+	++counter;
+}
+
+int main() {
+	while (counter < 8) {
+		bump();
+	}
+
+	counter--;
+
+	return counter - 7;
+}
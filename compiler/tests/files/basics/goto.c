@@ -0,0 +1,45 @@
+int sum_until(int limit, int fail_at) {
+  int sum = 0;
+  int i = 0;
+  goto check;
+
+body:
+  if (i == fail_at) {
+    goto fail;
+  }
+  sum = sum + i;
+  i = i + 1;
+
+check:
+  if (i < limit) {
+    goto body;
+  }
+  return sum;
+
+fail:
+  return -1;
+}
+
+int skip_assignment() {
+  int x = 0;
+  goto mid;
+  x = 100;
+
+mid:
+  x += 1;
+  return x;
+}
+
+int main() {
+  if (sum_until(4, 10) != 6) {
+    return 1;
+  }
+  if (sum_until(4, 2) != -1) {
+    return 2;
+  }
+  if (skip_assignment() != 1) {
+    return 3;
+  }
+
+  return 0;
+}
@@ -0,0 +1,49 @@
+struct Point {
+  int x;
+  int y;
+};
+
+struct Line {
+  struct Point start;
+  struct Point end;
+};
+
+int table[4] = { 1, 2, [3] = 4 };
+struct Point origin = { .y = 7 };
+char greeting[10] = "hey";
+
+int main() {
+  int values[] = { 3, 4, 5 };
+  if (values[2] != 5) {
+    return 1;
+  }
+
+  struct Line line = { .end.y = 2, .start = { 1 } };
+  if (line.start.x != 1 || line.start.y != 0 || line.end.x != 0 || line.end.y != 2) {
+    return 2;
+  }
+
+  struct Point points[2] = { 1, 2, 3 };
+  if (points[1].x != 3 || points[1].y != 0) {
+    return 3;
+  }
+
+  struct Point p = (struct Point){ .y = 5 };
+  if (p.x != 0 || p.y != 5) {
+    return 4;
+  }
+
+  if (table[2] != 0 || table[3] != 4 || origin.y != 7) {
+    return 5;
+  }
+
+  char word[] = "ab";
+  if (sizeof(word) != 3 || word[1] != 'b' || word[2] != 0) {
+    return 6;
+  }
+  if (greeting[2] != 'y' || greeting[3] != 0 || greeting[9] != 0) {
+    return 7;
+  }
+
+  return 0;
+}
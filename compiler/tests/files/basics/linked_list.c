@@ -0,0 +1,55 @@
+struct list;
+
+typedef struct node node;
+
+struct node {
+  int value;
+  node *next;
+  struct list *owner;
+};
+
+struct list {
+  struct node *first;
+  int count;
+};
+
+int sum(node *current) {
+  int result = 0;
+  while (current != (node*) 0) {
+    result = result + current->value;
+    current = current->next;
+  }
+  return result;
+}
+
+int main() {
+  struct list values;
+  node first;
+  node second;
+  node third;
+
+  first.value = 1;
+  first.next = &second;
+  first.owner = &values;
+  second.value = 2;
+  second.next = &third;
+  third.value = 3;
+  third.next = (node*) 0;
+  values.first = &first;
+  values.count = 3;
+
+  if (sum(values.first) != 6) {
+    return 1;
+  }
+  if (first.next->next->value != 3) {
+    return 2;
+  }
+  if (first.owner->count != 3) {
+    return 3;
+  }
+  if (sizeof(struct node) != 24) {
+    return 4;
+  }
+
+  return 0;
+}
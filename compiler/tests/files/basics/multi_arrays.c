@@ -0,0 +1,96 @@
+int sum(int n) {
+  int values[n];
+  for (int i = 0; i < n; i++) {
+    values[i] = i;
+  }
+
+  int result = 0;
+  for (int i = 0; i < n; i++) {
+    result = result + values[i];
+  }
+  return result;
+}
+
+int in_loop(int n) {
+  int total = 0;
+  for (int i = 0; i < n; i++) {
+    int values[n];
+    values[i] = i;
+    if (i == 3) {
+      continue;
+    }
+    if (i == 5) {
+      break;
+    }
+    total = total + values[i];
+  }
+  return total;
+}
+
+// Without releasing the Arrays of every Iteration, this would need about 100MB of Stack
+int repeated(int n) {
+  int total = 0;
+  for (int i = 0; i < 100000; i++) {
+    char buffer[n];
+    buffer[0] = 1;
+    if (i < 50000) {
+      continue;
+    }
+    total = total + buffer[0];
+  }
+  return total;
+}
+
+int with_goto(int n) {
+  int total = 0;
+again:
+  total = total + 1;
+  if (total < 1000) {
+    int other[n];
+    other[0] = total;
+    total = other[0] + 50;
+    goto again;
+  }
+  return total;
+}
+
+int main() {
+  int grid[3][4];
+  for (int row = 0; row < 3; row++) {
+    for (int column = 0; column < 4; column++) {
+      grid[row][column] = row * 4 + column;
+    }
+  }
+
+  if (grid[2][1] != 9) {
+    return 1;
+  }
+  if (sizeof(grid) != 48) {
+    return 2;
+  }
+
+  int *flat = grid[1];
+  if (flat[3] != 7) {
+    return 3;
+  }
+
+  char buffer[sizeof(long) * 2];
+  if (sizeof(buffer) != 16) {
+    return 4;
+  }
+
+  if (sum(5) != 10) {
+    return 5;
+  }
+  if (in_loop(7) != 7) {
+    return 6;
+  }
+  if (repeated(1024) != 50000) {
+    return 7;
+  }
+  if (with_goto(3) != 1021) {
+    return 8;
+  }
+
+  return 0;
+}
@@ -0,0 +1,48 @@
+int main() {
+  unsigned int mask = 0xFFFF0000u;
+  if (mask != 4294901760u) {
+    return 1;
+  }
+
+  int octal = 017;
+  int binary = 0b1010;
+  if (octal != 15) {
+    return 2;
+  }
+  if (binary != 10) {
+    return 3;
+  }
+
+  long big = 2147483648;
+  if (big != 2147483648L) {
+    return 4;
+  }
+
+  float half = 0.5f;
+  float one = half + half;
+  int one_int = (int) one;
+  if (one_int != 1) {
+    return 5;
+  }
+
+  float f = 1.5f;
+  float g = f + 2.5f;
+  int four = (int) g;
+  if (four != 4) {
+    return 7;
+  }
+
+  double eight = 0x1p3;
+  int eight_int = (int) eight;
+  if (eight_int != 8) {
+    return 6;
+  }
+
+  double quarter = eight * 0.03125;
+  int quarter_scaled = (int) (quarter * 4.0);
+  if (quarter_scaled != 1) {
+    return 8;
+  }
+
+  return 0;
+}
@@ -0,0 +1,62 @@
+struct point {
+  int x;
+  int y;
+};
+
+int main() {
+  int values[4] = {1, 2, 3, 4};
+  int *start = values;
+  int *end = values + 4;
+
+  long count = end - start;
+  if (count != 4) {
+    return 1;
+  }
+
+  int *third = 2 + start;
+  int third_value = *third;
+  if (third_value != 3) {
+    return 2;
+  }
+
+  if (third >= end) {
+    return 3;
+  }
+
+  struct point points[2] = {{1, 2}, {3, 4}};
+  struct point *second = points + 1;
+  if (second->x != 3) {
+    return 4;
+  }
+
+  if (points[1].y != 4) {
+    return 5;
+  }
+
+  long point_count = second - points;
+  if (point_count != 1) {
+    return 6;
+  }
+
+  int *walk = values;
+  walk++;
+  if (*walk != 2) {
+    return 7;
+  }
+  ++walk;
+  if (*walk != 3) {
+    return 8;
+  }
+  int *before = walk--;
+  if (*before != 3 || *walk != 2) {
+    return 9;
+  }
+  if (*--walk != 1 || walk != start) {
+    return 10;
+  }
+  if (*walk++ != 1 || walk - start != 1) {
+    return 11;
+  }
+
+  return 0;
+}
@@ -0,0 +1,73 @@
+int calls = 0;
+
+int count(int value) {
+  calls = calls + 1;
+  return value;
+}
+
+int first_value(int *values) {
+  if (values != ((int*) 0) && values[0] > 2) {
+    return values[0];
+  }
+  return 0;
+}
+
+int is_three(int *value) {
+  // The right Side would dereference the Null-Pointer, if it was evaluated
+  if (value == ((int*) 0) || *value == 3) {
+    return 1;
+  }
+  return 0;
+}
+
+int value_or(int *value, int fallback) {
+  return value ? *value : fallback;
+}
+
+int count_calls() {
+  if (count(0) && count(1)) {
+    return 0;
+  }
+  if (count(1) || count(0)) {
+    return calls;
+  }
+  return 0;
+}
+
+int bounded(int *value) {
+  int i = 0;
+  while (i < 10 && *value != 0) {
+    i = i + 1;
+  }
+  return i;
+}
+
+int main() {
+  int *missing = (int*) 0;
+  int number = 5;
+  int *present = &number;
+
+  if (first_value(missing) != 0) {
+    return 1;
+  }
+  if (first_value(present) != 5) {
+    return 2;
+  }
+  if (is_three(missing) != 1) {
+    return 3;
+  }
+  if (value_or(missing, 7) != 7) {
+    return 4;
+  }
+  if (value_or(present, 7) != 5) {
+    return 5;
+  }
+  if (count_calls() != 2) {
+    return 6;
+  }
+  if (bounded(present) != 10) {
+    return 7;
+  }
+
+  return (number == 5 && calls == 2) ? 0 : 8;
+}
@@ -0,0 +1,38 @@
+static int helper(int value) {
+  return value + 1;
+}
+
+int counter() {
+  static int count = 0;
+  count = count + 1;
+  return count;
+}
+
+int base = 2;
+
+int chained() {
+  static int value = 1;
+  static int *ptr = &value;
+  static int step = base;
+  *ptr = *ptr + step;
+  return value;
+}
+
+int main() {
+  counter();
+  counter();
+  if (counter() != 3) {
+    return 1;
+  }
+
+  if (helper(1) != 2) {
+    return 2;
+  }
+
+  chained();
+  if (chained() != 5) {
+    return 3;
+  }
+
+  return 0;
+}
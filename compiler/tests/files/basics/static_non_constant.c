@@ -0,0 +1,8 @@
+int first(int a) {
+  static int x = a;
+  return x;
+}
+
+int main() {
+  return first(1);
+}
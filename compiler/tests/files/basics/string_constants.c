@@ -0,0 +1,21 @@
+char* greeting() {
+  return "hello";
+}
+
+int main() {
+  char* first = greeting();
+  char* second = "hello";
+  if (first[1] != 'e' || first[5] != 0) {
+    return 1;
+  }
+  if (second[4] != 'o') {
+    return 2;
+  }
+
+  int* wide = L"ab";
+  if (wide[1] != 'b' || wide[2] != 0) {
+    return 3;
+  }
+
+  return 0;
+}
@@ -0,0 +1,43 @@
+enum letters {
+  FIRST = 'a',
+  SECOND = 'b' + 1
+};
+
+int main() {
+  char* text = "tab\there\n";
+  if (text[3] != '\t') {
+    return 1;
+  }
+  if (text[8] != '\n' || text[9] != '\0') {
+    return 2;
+  }
+
+  char* joined = "ab" "cd";
+  if (joined[2] != 'c' || joined[4] != 0) {
+    return 3;
+  }
+
+  char* escapes = "\x41\102\"\\";
+  if (escapes[0] != 'A' || escapes[1] != 'B') {
+    return 4;
+  }
+  if (escapes[2] != '"' || escapes[3] != '\\') {
+    return 5;
+  }
+
+  char* utf8 = "ü";
+  if (utf8[0] != '\xc3' || utf8[1] != '\xbc' || utf8[2] != 0) {
+    return 6;
+  }
+
+  int* wide = L"grü\xdf";
+  if (wide[2] != 252 || wide[3] != 223 || wide[4] != 0) {
+    return 7;
+  }
+
+  if (SECOND - FIRST != 2) {
+    return 8;
+  }
+
+  return 0;
+}
@@ -0,0 +1,94 @@
+struct vec2 {
+  float x;
+  float y;
+};
+
+struct rect {
+  int x;
+  int y;
+  int w;
+  int h;
+};
+
+struct big {
+  int a;
+  int b;
+  int c;
+  int d;
+  int e;
+};
+
+struct vec2 twice(struct vec2 v) {
+  struct vec2 result;
+  result.x = v.x + v.x;
+  result.y = v.y + v.y;
+  return result;
+}
+
+int area(struct rect r) {
+  return r.w * r.h;
+}
+
+struct rect grow(struct rect r, int amount) {
+  r.w = r.w + amount;
+  r.h = r.h + amount;
+  return r;
+}
+
+struct big make_big(int base) {
+  struct big b;
+  b.a = base;
+  b.b = base + 1;
+  b.c = base + 2;
+  b.d = base + 3;
+  b.e = base + 4;
+  return b;
+}
+
+int sum_big(struct big b) {
+  b.a = b.a + b.b;
+  return b.a + b.c + b.d + b.e;
+}
+
+// The first eight Arguments use up all the Registers, so the Address of the Struct is passed
+// on the Stack
+long sum_after(long a, long b, long c, long d, long e, long f, long g, long h, struct big s) {
+  return a + b + c + d + e + f + g + h + s.a + s.e;
+}
+
+int main() {
+  struct rect r = {1, 2, 3, 4};
+  int amount = 2;
+  struct rect bigger = grow(r, amount);
+  if (area(bigger) != 30) {
+    return 1;
+  }
+  if (r.w != 3) {
+    return 2;
+  }
+
+  int base = 1;
+  struct big b = make_big(base);
+  if (sum_big(b) != 15) {
+    return 3;
+  }
+  if (b.a != 1) {
+    return 4;
+  }
+
+  if (sum_after(1, 2, 3, 4, 5, 6, 7, 8, b) != 42) {
+    return 6;
+  }
+  if (b.a != 1) {
+    return 7;
+  }
+
+  struct vec2 v = {1.5, 2.0};
+  struct vec2 scaled = twice(v);
+  int y = (int) scaled.y;
+  if (y != 4) {
+    return 5;
+  }
+
+  return 0;
+}
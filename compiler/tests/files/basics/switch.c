@@ -0,0 +1,114 @@
+int classify(int x) {
+  int result = 0;
+  switch (x) {
+    case 0:
+      result = 1;
+    case 1:
+      result = result + 2;
+      break;
+    case 2:
+      return 5;
+    default:
+      result = 10;
+  }
+
+  return result;
+}
+
+int nested(int kind, char c) {
+  switch (kind) {
+    case 1:
+      switch (c) {
+        case 'a':
+          return 10;
+        case 'b':
+          return 11;
+        default:
+          return 12;
+      }
+    case 2:
+      return 20;
+    default:
+      return 0;
+  }
+}
+
+int count_odd(int limit) {
+  int odd = 0;
+  for (int i = 0; i < limit; i++) {
+    switch (i & 1) {
+      case 0:
+        continue;
+      default:
+        odd++;
+    }
+  }
+  return odd;
+}
+
+int promoted(short s, char c) {
+  switch (s) {
+    case 70000:
+      return 1;
+    default:
+      break;
+  }
+  switch (c) {
+    case 255:
+      return 2;
+    case -1:
+      return 3;
+    default:
+      return 4;
+  }
+}
+
+int unlabeled(int x) {
+  int result = 1;
+  switch (x) {
+    int y;
+    result = 100;
+    case 1:
+      y = 2;
+      return result + y;
+    default:
+      return 0;
+  }
+}
+
+int main() {
+  if (classify(0) != 3) {
+    return 1;
+  }
+  if (classify(1) != 2) {
+    return 2;
+  }
+  if (classify(2) != 5) {
+    return 3;
+  }
+  if (classify(7) != 10) {
+    return 4;
+  }
+  if (nested(1, 'b') != 11) {
+    return 5;
+  }
+  if (nested(1, 'z') != 12) {
+    return 6;
+  }
+  if (nested(2, 'a') != 20) {
+    return 7;
+  }
+  if (count_odd(6) != 3) {
+    return 8;
+  }
+  if (promoted(4464, -1) != 3) {
+    return 9;
+  }
+  if (unlabeled(1) != 3 || unlabeled(5) != 0) {
+    return 10;
+  }
+
+  switch (count_odd(2)) {}
+
+  return 0;
+}
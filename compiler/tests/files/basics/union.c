@@ -0,0 +1,31 @@
+union value {
+  int number;
+  char letter;
+};
+
+struct tagged {
+  int tag;
+  union value inner;
+};
+
+int main() {
+  union value v;
+  v.number = 0;
+  v.letter = 3;
+
+  if (v.letter != 3) {
+    return 1;
+  }
+
+  union value* ptr = &v;
+  ptr->number = 5;
+  if (v.number != 5) {
+    return 2;
+  }
+
+  if (sizeof(union value) != 4) {
+    return 3;
+  }
+
+  return 0;
+}
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+
+int sum(int count, ...) {
+  va_list args;
+  va_start(args, count);
+
+  int result = 0;
+  for (int i = 0; i < count; i++) {
+    result = result + va_arg(args, int);
+  }
+
+  va_end(args);
+  return result;
+}
+
+int main() {
+  if (sum(0) != 0) {
+    return 1;
+  }
+
+  if (sum(3, 1, 2, 3) != 6) {
+    return 2;
+  }
+
+  return 0;
+}
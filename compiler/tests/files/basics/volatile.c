@@ -0,0 +1,36 @@
+struct registers {
+  int status;
+  int data;
+};
+
+int poll(volatile int* status, int count) {
+  int result = 0;
+  for (int i = 0; i < count; i++) {
+    int current = *status;
+    result = result + current;
+  }
+  return result;
+}
+
+int main() {
+  int value = 2;
+  volatile int* reg = &value;
+  *reg = 3;
+  if (*reg != 3) {
+    return 1;
+  }
+
+  if (poll(reg, 4) != 12) {
+    return 2;
+  }
+
+  struct registers regs;
+  volatile struct registers* mmio = &regs;
+  mmio->status = 1;
+  mmio->data = 7;
+  if (mmio->data != 7) {
+    return 3;
+  }
+
+  return 0;
+}
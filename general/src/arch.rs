@@ -6,13 +6,92 @@ pub enum Arch {
     SH4A,
 }
 
+/// The Sizes of the C-Types on a Target in Bytes, which also determine their Alignment.
+///
+/// `long long` is always 64-Bit, while `size_t` and `ptrdiff_t` are always `unsigned long` and
+/// `long`, which have the same Size as a Pointer in both supported Models
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataModel {
+    pub short: usize,
+    pub int: usize,
+    pub long: usize,
+    pub long_long: usize,
+    pub pointer: usize,
+    pub float: usize,
+    pub double: usize,
+    pub long_double: usize,
+    /// The largest Alignment of any of the Types, larger Types are only aligned to this
+    pub max_alignment: usize,
+}
+
+impl DataModel {
+    /// int, long and Pointers are 32-Bit and no Type is aligned to more than 4 Bytes
+    pub const ILP32: Self = Self {
+        short: 2,
+        int: 4,
+        long: 4,
+        long_long: 8,
+        pointer: 4,
+        float: 4,
+        double: 8,
+        long_double: 8,
+        max_alignment: 4,
+    };
+
+    /// int is 32-Bit, while long and Pointers are 64-Bit
+    pub const LP64: Self = Self {
+        short: 2,
+        int: 4,
+        long: 8,
+        long_long: 8,
+        pointer: 8,
+        float: 4,
+        double: 8,
+        long_double: 8,
+        max_alignment: 8,
+    };
+
+    /// The Alignment of a primitive Type with the given Size
+    pub fn alignment(&self, size: usize) -> usize {
+        size.min(self.max_alignment)
+    }
+
+    /// The IR-Type used for `size_t`
+    pub fn size_type(&self) -> ir::Type {
+        match self.long {
+            4 => ir::Type::U32,
+            _ => ir::Type::U64,
+        }
+    }
+
+    /// The IR-Type used for `ptrdiff_t`
+    pub fn ptrdiff_type(&self) -> ir::Type {
+        match self.long {
+            4 => ir::Type::I32,
+            _ => ir::Type::I64,
+        }
+    }
+}
+
 impl Arch {
+    /// The Data-Model used by the Architecture
+    pub fn data_model(&self) -> DataModel {
+        match self {
+            Self::X86 | Self::SH4A => DataModel::ILP32,
+            Self::X86_64 | Self::AArch64 => DataModel::LP64,
+        }
+    }
+
     pub fn ptr_size(&self) -> usize {
+        self.data_model().pointer
+    }
+
+    /// Whether or not the Architecture stores Values in Memory with the most significant Byte
+    /// first
+    pub fn big_endian(&self) -> bool {
         match self {
-            Self::X86 => 4,
-            Self::X86_64 => 8,
-            Self::AArch64 => 8,
-            Self::SH4A => 4,
+            Self::X86 | Self::X86_64 | Self::AArch64 => false,
+            Self::SH4A => true,
         }
     }
 
@@ -70,3 +149,15 @@ impl Default for Platform {
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Target(pub Arch, pub Platform);
+
+impl Target {
+    /// The Data-Model of the Target, all the supported Platforms use the Model of their
+    /// Architecture
+    pub fn data_model(&self) -> DataModel {
+        match self {
+            Self(arch, Platform::Linux | Platform::MacOs | Platform::CasioPrizm) => {
+                arch.data_model()
+            }
+        }
+    }
+}
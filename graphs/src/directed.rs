@@ -1125,4 +1125,203 @@ mod tests {
 
         // panic!("")
     }
+
+    #[test]
+    fn branch_joins_first_successor() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_node(MockNode {
+            id: 0,
+            successors: vec![1, 2],
+        });
+        // After Branch
+        graph.add_node(MockNode {
+            id: 1,
+            successors: vec![],
+        });
+        graph.add_node(MockNode {
+            id: 2,
+            successors: vec![3],
+        });
+        graph.add_node(MockNode {
+            id: 3,
+            successors: vec![1],
+        });
+
+        let result = succ_type(
+            graph.get_node(&0).unwrap(),
+            &graph,
+            successor::Context::None,
+        );
+        assert_eq!(
+            Some(SuccType::Branched {
+                sides: (2, None),
+                end: 1
+            }),
+            result
+        );
+
+        let ids: Vec<_> = graph.chain_iter().flatten().map(|n| n.id()).collect();
+        assert_eq!(vec![0, 2, 3, 1], ids);
+    }
+
+    #[test]
+    fn branch_without_join_follows_larger_side() {
+        let mut graph = DirectedGraph::new();
+
+        graph.add_node(MockNode {
+            id: 0,
+            successors: vec![1, 3],
+        });
+        // Returning Side
+        graph.add_node(MockNode {
+            id: 1,
+            successors: vec![2],
+        });
+        graph.add_node(MockNode {
+            id: 2,
+            successors: vec![],
+        });
+        // Rest of the Function
+        graph.add_node(MockNode {
+            id: 3,
+            successors: vec![4],
+        });
+        graph.add_node(MockNode {
+            id: 4,
+            successors: vec![5],
+        });
+        graph.add_node(MockNode {
+            id: 5,
+            successors: vec![],
+        });
+
+        let result = succ_type(
+            graph.get_node(&0).unwrap(),
+            &graph,
+            successor::Context::None,
+        );
+        assert_eq!(
+            Some(SuccType::Branched {
+                sides: (1, None),
+                end: 3
+            }),
+            result
+        );
+
+        // The Order of the Successors should not matter
+        graph.get_mut_node(&0).unwrap().successors = vec![3, 1];
+        let result = succ_type(
+            graph.get_node(&0).unwrap(),
+            &graph,
+            successor::Context::None,
+        );
+        assert_eq!(
+            Some(SuccType::Branched {
+                sides: (1, None),
+                end: 3
+            }),
+            result
+        );
+
+        let ids: Vec<_> = graph.chain_iter().flatten().map(|n| n.id()).collect();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], ids);
+    }
+
+    #[test]
+    fn branch_side_walk_bounded_by_cycle() {
+        let mut graph = DirectedGraph::new();
+
+        // Loop Head
+        graph.add_node(MockNode {
+            id: 0,
+            successors: vec![1],
+        });
+        // Branch Head
+        graph.add_node(MockNode {
+            id: 1,
+            successors: vec![2, 3],
+        });
+        // Side with Break
+        graph.add_node(MockNode {
+            id: 2,
+            successors: vec![10],
+        });
+        // Returning Side
+        graph.add_node(MockNode {
+            id: 3,
+            successors: vec![4],
+        });
+        graph.add_node(MockNode {
+            id: 4,
+            successors: vec![],
+        });
+        // After Loop, which is larger than both Sides
+        graph.add_node(MockNode {
+            id: 10,
+            successors: vec![11],
+        });
+        graph.add_node(MockNode {
+            id: 11,
+            successors: vec![12],
+        });
+        graph.add_node(MockNode {
+            id: 12,
+            successors: vec![],
+        });
+
+        let result = succ_type(
+            graph.get_node(&1).unwrap(),
+            &graph,
+            successor::Context::OuterGraph {
+                head: 0,
+                following: Some(10),
+            },
+        );
+        assert_eq!(
+            Some(SuccType::Branched {
+                sides: (2, None),
+                end: 3
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn branch_continue_in_cycle() {
+        let mut graph = DirectedGraph::new();
+
+        // Loop Head
+        graph.add_node(MockNode {
+            id: 0,
+            successors: vec![1, 10],
+        });
+        // Branch with Continue
+        graph.add_node(MockNode {
+            id: 1,
+            successors: vec![0, 2],
+        });
+        graph.add_node(MockNode {
+            id: 2,
+            successors: vec![0],
+        });
+        // After Loop
+        graph.add_node(MockNode {
+            id: 10,
+            successors: vec![],
+        });
+
+        let result = succ_type(
+            graph.get_node(&1).unwrap(),
+            &graph,
+            successor::Context::OuterGraph {
+                head: 0,
+                following: Some(10),
+            },
+        );
+        assert_eq!(Some(SuccType::Single(2)), result);
+
+        let ids: Vec<_> = graph.chain_iter().flatten().map(|n| n.id()).collect();
+        assert_eq!(vec![0, 1, 2, 10], ids);
+    }
 }
@@ -111,7 +111,16 @@ where
         match succ_type(next_node, self.graph, context) {
             None => Some(ChainEntry::Node(next_node)),
             Some(SuccType::Single(succ_id)) => {
-                if Some(succ_id) != self.end {
+                // A Side of a Branch inside of a Cycle might also leave the current Iteration,
+                // like for a Continue or Break, which ends the Chain as well
+                let leaves_cycle = match context {
+                    Context::None => false,
+                    Context::OuterGraph { head, following } => {
+                        succ_id == head || Some(succ_id) == following
+                    }
+                };
+
+                if Some(succ_id) != self.end && !leaves_cycle {
                     self.next = Some(succ_id);
                 }
 
@@ -120,7 +129,10 @@ where
                 Some(ChainEntry::Node(next_node))
             }
             Some(SuccType::Branched { end, sides }) => {
-                self.next = Some(end);
+                // A nested Branch might join up again at the End of the current Chain
+                if Some(end) != self.end {
+                    self.next = Some(end);
+                }
 
                 self.n_previous = Some(PreviousSucc::Branched { sides, end });
 
@@ -58,6 +58,35 @@ where
         _ => {}
     };
 
+    // Jumping back to the Head of the current Cycle, like for a Continue, is just another way
+    // of ending the current Iteration, so only the other Successor continues the Chain, unless
+    // the other Successor leads back to the Start, which makes the Start the Head of a nested
+    // Cycle that is left by jumping to the outer Head
+    if let Context::OuterGraph { head, following } = ctx {
+        let reaches_start = |side: N::Id| {
+            let mut visited = HashSet::new();
+            let mut remaining = vec![side];
+            while let Some(id) = remaining.pop() {
+                if id == start {
+                    return true;
+                }
+                if id == head || Some(id) == following || !visited.insert(id) {
+                    continue;
+                }
+
+                remaining.extend(graph.get_node(&id).unwrap().successors());
+            }
+            false
+        };
+
+        if first == head && !reaches_start(second) {
+            return Some(SuccType::Single(second));
+        }
+        if second == head && !reaches_start(first) {
+            return Some(SuccType::Single(first));
+        }
+    }
+
     /*
     match (is_end(&first), is_end(&second)) {
         (true, true) => return None,
@@ -92,6 +121,7 @@ where
         let mut visited = HashSet::new();
         visited.insert(first);
         let mut jumped_to_head = false;
+        let mut reaches_first = false;
         while let Some(id) = remaining.pop() {
             if first_ids.contains_key(&id) {
                 if id == start {
@@ -143,6 +173,9 @@ where
             visited.insert(id);
 
             jumped_to_head |= tmp.successors().any(|s| start == s);
+            // The first Node is marked as visited to skip the direct Edge from the Start, so the
+            // second Side joining up with it has to be detected here
+            reaches_first |= id != start && tmp.successors().any(|s| first == s);
 
             remaining.extend(tmp.successors().filter(|i| !visited.contains(i)).filter(
                 |i| match ctx {
@@ -176,6 +209,63 @@ where
             };
         }
 
+        if reaches_first {
+            return Some(SuccType::Branched {
+                sides: (second, None),
+                end: first,
+            });
+        }
+
+        // Neither Side joins up with the other, so the Side that continues the current Cycle or
+        // otherwise the one that continues further is the one following the Branch, like the
+        // Rest of the Function after a Side that returns.
+        // The Sides are only walked up until the Head or the Node following the current Cycle,
+        // because otherwise a Side leaving the Cycle would reach the Head again through any
+        // outer Cycle
+        let side_ids = |side: N::Id, other: N::Id| {
+            let mut ids = HashSet::new();
+            let mut remaining = vec![side];
+            while let Some(id) = remaining.pop() {
+                let blocked = id == start
+                    || id == other
+                    || match ctx {
+                        Context::None => false,
+                        Context::OuterGraph { head, following } => {
+                            id == head || Some(id) == following
+                        }
+                    };
+                if blocked || !ids.insert(id) {
+                    continue;
+                }
+
+                remaining.extend(graph.get_node(&id).unwrap().successors());
+            }
+            ids
+        };
+        let first_ids = side_ids(first, second);
+        let second_ids = side_ids(second, first);
+        let jumps_to_head = |id: &N::Id| match ctx {
+            Context::None => false,
+            Context::OuterGraph { head, .. } => graph
+                .get_node(id)
+                .map(|n| n.successors().any(|s| s == head))
+                .unwrap_or(false),
+        };
+        let second_follows = match (
+            first_ids.iter().any(jumps_to_head),
+            second_ids.iter().any(jumps_to_head),
+        ) {
+            (false, true) => true,
+            (true, false) => false,
+            _ => second_ids.len() > first_ids.len(),
+        };
+        if second_follows {
+            return Some(SuccType::Branched {
+                sides: (first, None),
+                end: second,
+            });
+        }
+
         if start_node.successors().any(|s| s == second) {
             Some(SuccType::Branched {
                 sides: (second, None),
@@ -291,7 +291,7 @@ impl BasicBlock {
         let tmp_var =
             Variable::new(name.to_string(), Type::Void).set_meta(VariableMetadata::Temporary);
         let phi_stmnt = Statement::Assignment {
-            target: tmp_var,
+            target: tmp_var.clone(),
             value: Value::Phi { sources: vec![] },
         };
 
@@ -327,7 +327,14 @@ impl BasicBlock {
             tmp.pop();
         }
 
-        let var = sources.get(0).unwrap().var.clone();
+        // The Placeholder is only found through a Loop back into this Block, so the new
+        // Variable needs to be based on one of the actual Definitions
+        let var = sources
+            .iter()
+            .map(|s| &s.var)
+            .find(|v| **v != tmp_var)
+            .unwrap_or(&sources[0].var)
+            .clone();
         if sources.iter().all(|s| s.var == var) && sources.len() > 1 {
             debug_assert!(!var.global());
             return Some(var);
@@ -0,0 +1,41 @@
+/// A Blob of read-only Data that is stored in the Program itself, like the Content of a
+/// String-Literal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstantData {
+    /// The raw Bytes of the Data, already in the Byte-Order of the Target
+    pub content: Vec<u8>,
+    /// The Alignment of the Data in Bytes
+    pub alignment: usize,
+}
+
+impl ConstantData {
+    /// Creates a new Blob with the given Content and Alignment
+    pub fn new(content: Vec<u8>, alignment: usize) -> Self {
+        Self { content, alignment }
+    }
+
+    /// Whether or not the Data is a C-String made up of single Bytes, which ends with the only 0
+    /// Byte in it
+    pub fn is_c_string(&self) -> bool {
+        match self.content.split_last() {
+            Some((0, rest)) => self.alignment == 1 && !rest.contains(&0),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_string() {
+        assert!(ConstantData::new(b"test\0".to_vec(), 1).is_c_string());
+        assert!(ConstantData::new(b"\0".to_vec(), 1).is_c_string());
+
+        assert!(!ConstantData::new(b"test".to_vec(), 1).is_c_string());
+        assert!(!ConstantData::new(b"te\0st\0".to_vec(), 1).is_c_string());
+        assert!(!ConstantData::new(vec![1, 0, 0, 0, 0, 0, 0, 0], 4).is_c_string());
+        assert!(!ConstantData::new(vec![], 1).is_c_string());
+    }
+}
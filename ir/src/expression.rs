@@ -70,6 +70,9 @@ pub enum Expression {
         address: Operand,
         /// The Type to read
         read_ty: Type,
+        /// Whether or not the Read is volatile, like for memory-mapped Registers, in which case
+        /// it must never be removed, merged or reordered
+        volatile: bool,
     },
     /// Reads the Value from the Global Variable
     ReadGlobalVariable {
@@ -84,6 +87,39 @@ pub enum Expression {
         arguments: Vec<Operand>,
         /// The returned Type
         return_ty: Type,
+        /// If the called Function is variadic, this contains the Number of fixed Arguments and
+        /// all the Arguments after them are passed as variable Arguments
+        variadic: Option<usize>,
+    },
+    /// A Call to the Function at the Address stored in the Target
+    IndirectFunctionCall {
+        /// The Address of the Function to call
+        target: Operand,
+        /// The given Arguments for this Call
+        arguments: Vec<Operand>,
+        /// The returned Type
+        return_ty: Type,
+        /// If the called Function is variadic, this contains the Number of fixed Arguments and
+        /// all the Arguments after them are passed as variable Arguments
+        variadic: Option<usize>,
+    },
+    /// Evaluates to the Address of the first variable Argument passed to the current Function
+    VarArgsStart,
+    /// Obtains the Address of the Function with the given Name
+    FunctionAddress {
+        /// The Name of the Function
+        name: String,
+    },
+    /// Obtains the Address of the read-only Data with the given Name, which is stored in the
+    /// Constants of the Program
+    ConstantAddress {
+        /// The Name of the Constant-Data
+        name: String,
+    },
+    /// Obtains the Address at which the Global-Variable with the given Name is stored
+    GlobalAddress {
+        /// The unique Name of the Global-Variable
+        name: String,
     },
     /// Allocates size bytes on the Stack and evalutes to the start Address of this Block, which
     /// should be used for reserving the Space for Arrays or Structs
@@ -93,6 +129,31 @@ pub enum Expression {
         /// The Alignment of the Bytes to allocate
         alignment: usize,
     },
+    /// Allocates a Number of Bytes on the Stack, that is only known at Runtime, and evaluates to
+    /// the start Address of this Block, which is used for variable length Arrays.
+    ///
+    /// The Memory is released again by restoring a Stack-Pointer saved before the Allocation
+    /// or once the Function returns
+    DynamicStackAlloc {
+        /// The Size to allocate in Bytes
+        size: Operand,
+        /// The Alignment of the Bytes to allocate
+        alignment: usize,
+    },
+    /// Evaluates to the current Stack-Pointer, so that the Memory allocated by following
+    /// DynamicStackAllocs can be released again using a StackRestore
+    StackSave,
+    /// Reserves size bytes in the static Data of the Program and evaluates to the start Address
+    /// of this Block, which is used instead of a StackAlloc for Arrays or Structs that are stored
+    /// in Global-Variables
+    StaticAlloc {
+        /// The Name of the static Data, which is unique in the entire Program
+        name: String,
+        /// The Size to reserve in Bytes
+        size: usize,
+        /// The Alignment of the Bytes to reserve
+        alignment: usize,
+    },
 }
 
 impl Expression {
@@ -115,7 +176,25 @@ impl Expression {
 
                 UsedVariableIter::VarLength(Box::new(owned.into_iter().flat_map(|a| a.used_vars())))
             }
+            Self::IndirectFunctionCall {
+                target, arguments, ..
+            } => {
+                let owned = arguments.clone();
+
+                UsedVariableIter::VarLength(Box::new(
+                    target
+                        .used_vars()
+                        .chain(owned.into_iter().flat_map(|a| a.used_vars())),
+                ))
+            }
+            Self::FunctionAddress { .. } => UsedVariableIter::Empty,
+            Self::ConstantAddress { .. } => UsedVariableIter::Empty,
+            Self::GlobalAddress { .. } => UsedVariableIter::Empty,
             Self::StackAlloc { .. } => UsedVariableIter::Empty,
+            Self::DynamicStackAlloc { size, .. } => size.used_vars(),
+            Self::StackSave => UsedVariableIter::Empty,
+            Self::StaticAlloc { .. } => UsedVariableIter::Empty,
+            Self::VarArgsStart => UsedVariableIter::Empty,
         }
     }
 
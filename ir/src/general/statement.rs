@@ -19,6 +19,9 @@ pub enum Statement<B, WB> {
         target: Operand,
         /// The Value
         value: Operand,
+        /// Whether or not the Write is volatile, like for memory-mapped Registers, in which case
+        /// it must never be removed, merged or reordered
+        volatile: bool,
     },
     /// A single Function-Call
     Call {
@@ -26,6 +29,19 @@ pub enum Statement<B, WB> {
         name: String,
         /// The Arguments for the Function
         arguments: Vec<Operand>,
+        /// If the called Function is variadic, this contains the Number of fixed Arguments and
+        /// all the Arguments after them are passed as variable Arguments
+        variadic: Option<usize>,
+    },
+    /// A single Call to the Function at the Address stored in the Target
+    IndirectCall {
+        /// The Address of the Function to call
+        target: Operand,
+        /// The Arguments for the Function
+        arguments: Vec<Operand>,
+        /// If the called Function is variadic, this contains the Number of fixed Arguments and
+        /// all the Arguments after them are passed as variable Arguments
+        variadic: Option<usize>,
     },
     /// This indicates that the Variable should be saved, usually on the Stack
     SaveVariable {
@@ -48,6 +64,12 @@ pub enum Statement<B, WB> {
         /// The Variable passed as an output
         output: Option<Variable>,
     },
+    /// Resets the Stack-Pointer to the Value previously obtained from a StackSave, which releases
+    /// all the Memory allocated by DynamicStackAllocs since then
+    StackRestore {
+        /// The Variable containing the saved Stack-Pointer
+        value: Variable,
+    },
     /// Returns the given Variable from the Function
     Return(Option<Variable>),
     /// Jumps to the given Block unconditionally
@@ -82,22 +104,38 @@ where
                 Self::WriteMemory {
                     target: s_target,
                     value: s_value,
+                    volatile: s_volatile,
                 },
                 Self::WriteMemory {
                     target: o_target,
                     value: o_value,
+                    volatile: o_volatile,
                 },
-            ) => s_target == o_target && s_value == o_value,
+            ) => s_target == o_target && s_value == o_value && s_volatile == o_volatile,
             (
                 Self::Call {
                     name: s_name,
                     arguments: s_arguments,
+                    variadic: s_variadic,
                 },
                 Self::Call {
                     name: o_name,
                     arguments: o_arguments,
+                    variadic: o_variadic,
+                },
+            ) => s_name == o_name && s_arguments == o_arguments && s_variadic == o_variadic,
+            (
+                Self::IndirectCall {
+                    target: s_target,
+                    arguments: s_arguments,
+                    variadic: s_variadic,
+                },
+                Self::IndirectCall {
+                    target: o_target,
+                    arguments: o_arguments,
+                    variadic: o_variadic,
                 },
-            ) => s_name == o_name && s_arguments == o_arguments,
+            ) => s_target == o_target && s_arguments == o_arguments && s_variadic == o_variadic,
             (Self::SaveVariable { var: s_var }, Self::SaveVariable { var: o_var }) => {
                 s_var == o_var
             }
@@ -113,6 +151,9 @@ where
                     output: o_out,
                 },
             ) => s_temp == o_temp && s_in == o_in && s_out == o_out,
+            (Self::StackRestore { value: s_value }, Self::StackRestore { value: o_value }) => {
+                s_value == o_value
+            }
             (Self::Return(s_var), Self::Return(o_var)) => s_var == o_var,
             (Self::Jump(s_next, _), Self::Jump(o_next, _)) => {
                 s_next.compare(o_next, blocks, current_block)
@@ -153,15 +194,35 @@ where
                 .field("name", name)
                 .field("value", value)
                 .finish(),
-            Self::WriteMemory { target, value } => f
+            Self::WriteMemory {
+                target,
+                value,
+                volatile,
+            } => f
                 .debug_struct("WriteMemory")
                 .field("target", target)
                 .field("value", value)
+                .field("volatile", volatile)
                 .finish(),
-            Self::Call { name, arguments } => f
+            Self::Call {
+                name,
+                arguments,
+                variadic,
+            } => f
                 .debug_struct("Call")
                 .field("name", name)
                 .field("arguments", arguments)
+                .field("variadic", variadic)
+                .finish(),
+            Self::IndirectCall {
+                target,
+                arguments,
+                variadic,
+            } => f
+                .debug_struct("IndirectCall")
+                .field("target", target)
+                .field("arguments", arguments)
+                .field("variadic", variadic)
                 .finish(),
             Self::InlineAsm {
                 template,
@@ -173,6 +234,10 @@ where
                 .field("output", output)
                 .field("inputs", inputs)
                 .finish(),
+            Self::StackRestore { value } => f
+                .debug_struct("StackRestore")
+                .field("value", value)
+                .finish(),
             Self::Return(var) => f.debug_tuple("Return").field(var).finish(),
             Self::Jump(target, _) => f.debug_tuple("Jump").field(&(print_block(target))).finish(),
             Self::JumpTrue(var, target, _) => f
@@ -197,7 +262,7 @@ where
             Self::Assignment { value, .. } => value.used_vars(),
             Self::SaveVariable { var } => var.clone().into(),
             Self::SaveGlobalVariable { value, .. } => value.clone().into(),
-            Self::WriteMemory { target, value } => {
+            Self::WriteMemory { target, value, .. } => {
                 let target_iter = target.used_vars();
                 let value_iter = value.used_vars();
 
@@ -208,12 +273,24 @@ where
 
                 UsedVariableIter::VarLength(Box::new(owned.into_iter().flat_map(|a| a.used_vars())))
             }
+            Self::IndirectCall {
+                target, arguments, ..
+            } => {
+                let owned = arguments.clone();
+
+                UsedVariableIter::VarLength(Box::new(
+                    target
+                        .used_vars()
+                        .chain(owned.into_iter().flat_map(|a| a.used_vars())),
+                ))
+            }
             Self::InlineAsm { inputs, output, .. } => {
                 let inputs_iter = inputs.clone().into_iter();
                 let output_iter = output.clone().into_iter();
 
                 UsedVariableIter::VarLength(Box::new(inputs_iter.chain(output_iter)))
             }
+            Self::StackRestore { value } => value.clone().into(),
             Self::Return(None) => UsedVariableIter::Empty,
             Self::Return(Some(var)) => var.clone().into(),
             Self::Jump(_, _) => UsedVariableIter::Empty,
@@ -23,6 +23,9 @@ pub use expression::*;
 mod block;
 pub use block::*;
 
+mod data;
+pub use data::ConstantData;
+
 mod function;
 pub use function::FunctionDefinition;
 
@@ -52,6 +55,11 @@ pub struct Program {
     pub global: BasicBlock,
     /// The various Function Definitions in the Program
     pub functions: HashMap<String, FunctionDefinition>,
+    /// The read-only Data of the Program, like String-Literals, by their unique Name
+    pub constants: HashMap<String, ConstantData>,
+    /// The explicit Alignments of Global Variables, which are stricter than the natural
+    /// Alignment of their Type, by the Name of the Global
+    pub global_alignments: HashMap<String, usize>,
 }
 
 impl Program {
@@ -83,6 +91,8 @@ impl Debug for Program {
 
         f_struct.field("global", &self.global);
         f_struct.field("functions", &self.functions);
+        f_struct.field("constants", &self.constants);
+        f_struct.field("global_alignments", &self.global_alignments);
 
         Ok(())
     }
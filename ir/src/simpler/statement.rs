@@ -21,11 +21,20 @@ impl Statement {
                     value: n_value,
                 }
             }
-            crate::Statement::WriteMemory { target, value } => Self::WriteMemory { target, value },
+            crate::Statement::WriteMemory {
+                target,
+                value,
+                volatile,
+            } => Self::WriteMemory {
+                target,
+                value,
+                volatile,
+            },
             crate::Statement::SaveVariable { var } => Self::SaveVariable { var },
             crate::Statement::SaveGlobalVariable { name, value } => {
                 Self::SaveGlobalVariable { name, value }
             }
+            crate::Statement::StackRestore { value } => Self::StackRestore { value },
             crate::Statement::InlineAsm {
                 template,
                 inputs,
@@ -35,7 +44,24 @@ impl Statement {
                 inputs,
                 output,
             },
-            crate::Statement::Call { name, arguments } => Self::Call { name, arguments },
+            crate::Statement::Call {
+                name,
+                arguments,
+                variadic,
+            } => Self::Call {
+                name,
+                arguments,
+                variadic,
+            },
+            crate::Statement::IndirectCall {
+                target,
+                arguments,
+                variadic,
+            } => Self::IndirectCall {
+                target,
+                arguments,
+                variadic,
+            },
             crate::Statement::Jump(target, meta) => {
                 let n_target = block_map.get(&target.as_ptr()).unwrap().clone();
                 Self::Jump(n_target, meta)
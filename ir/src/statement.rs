@@ -73,15 +73,23 @@ impl ToDot for Statement {
 
                 lines.add_edge(graphviz::Edge::new(src, &name));
             }
-            Self::WriteMemory { target, value } => {
-                let content = format!("WriteMemory {:?} = {:?}", target, value);
+            Self::WriteMemory {
+                target,
+                value,
+                volatile,
+            } => {
+                let content = if *volatile {
+                    format!("WriteMemory volatile {:?} = {:?}", target, value)
+                } else {
+                    format!("WriteMemory {:?} = {:?}", target, value)
+                };
                 lines.add_node(
                     graphviz::Node::new(&name).add_label("label", content.replace('"', "\\\"")),
                 );
 
                 lines.add_edge(graphviz::Edge::new(src, &name));
             }
-            Self::Call { .. } => {
+            Self::Call { .. } | Self::IndirectCall { .. } => {
                 let content = format!("{:?}", self);
                 lines.add_node(
                     graphviz::Node::new(&name).add_label("label", content.replace('"', "\\\"")),
@@ -101,6 +109,14 @@ impl ToDot for Statement {
 
                 lines.add_edge(graphviz::Edge::new(src, &name));
             }
+            Self::StackRestore { value } => {
+                let content = format!("StackRestore {:?}", value);
+                lines.add_node(
+                    graphviz::Node::new(&name).add_label("label", content.replace('"', "\\\"")),
+                );
+
+                lines.add_edge(graphviz::Edge::new(src, &name));
+            }
             Self::Return(val) => {
                 let content = format!("return {:?}", val);
                 lines.add_node(
@@ -97,6 +97,11 @@ fn expression_content(exp: &Expression) -> String {
             op,
             operand_content(right)
         ),
+        Expression::DynamicStackAlloc { size, alignment } => format!(
+            "DynamicStackAlloc {{ size: {}, alignment: {} }}",
+            operand_content(size),
+            alignment
+        ),
         other => format!("{:?}", other),
     }
 }
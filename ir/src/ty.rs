@@ -29,10 +29,16 @@ pub enum Type {
     Pointer(Box<Self>),
     /// An Array of some Type with a given Size
     Array(Box<Self>, usize),
-    /// A Struct with the given members
+    /// A Struct with the given members, which is used for passing Structs to and returning them
+    /// from Functions. A Variable of this Type holds the Address of the Memory containing the
+    /// Struct, while the Backends move its Content according to their Calling-Convention
     Struct {
         /// The Members of the Struct
         members: Vec<(String, Type)>,
+        /// The Size of the entire Struct in Bytes, including all of its Padding
+        size: usize,
+        /// The Alignment of the Struct in Bytes
+        alignment: usize,
     },
 }
 
@@ -51,6 +51,20 @@ pub enum Instruction {
         shift: u8,
         immediate: u16,
     },
+    /// Moves the unsigned immediate into the given Register at the Shift, while keeping the
+    /// other Bits of the Register
+    Movk {
+        dest: GPRegister,
+        shift: u8,
+        immediate: u16,
+    },
+    /// Moves the Bits of the General-Purpose Register into the Floating-Point Register of the
+    /// same Size
+    /// FMOV (general) C7.2.131
+    FMovGeneral {
+        dest: FPRegister,
+        src: GPRegister,
+    },
     /// Page: 1824
     FMovImmediate {
         dest: FPRegister,
@@ -116,6 +130,40 @@ pub enum Instruction {
         shift: Shift,
         amount: u8,
     },
+    /// AND (shifted register)
+    BitwiseAndRegisterShifted {
+        dest: GPRegister,
+        src1: GPRegister,
+        src2: GPRegister,
+        shift: Shift,
+        amount: u8,
+    },
+    /// EOR (shifted register)
+    BitwiseXorRegisterShifted {
+        dest: GPRegister,
+        src1: GPRegister,
+        src2: GPRegister,
+        shift: Shift,
+        amount: u8,
+    },
+    /// LSLV, shifts src1 left by the Amount in src2
+    ShiftLeftRegister {
+        dest: GPRegister,
+        src1: GPRegister,
+        src2: GPRegister,
+    },
+    /// LSRV, shifts src1 right by the Amount in src2 and fills the upper Bits with zeros
+    LogicalShiftRightRegister {
+        dest: GPRegister,
+        src1: GPRegister,
+        src2: GPRegister,
+    },
+    /// ASRV, shifts src1 right by the Amount in src2 and fills the upper Bits with the Sign-Bit
+    ArithmeticShiftRightRegister {
+        dest: GPRegister,
+        src1: GPRegister,
+        src2: GPRegister,
+    },
     /// Page: 1917
     FPSub {
         dest: FPRegister,
@@ -140,6 +188,18 @@ pub enum Instruction {
         base: GpOrSpRegister,
         offset: Imm9Signed,
     },
+    /// STURH C6.2.352
+    StoreHalfWordUnscaled {
+        reg: GPRegister,
+        base: GpOrSpRegister,
+        offset: Imm9Signed,
+    },
+    /// STURB C6.2.351
+    StoreByteUnscaled {
+        reg: GPRegister,
+        base: GpOrSpRegister,
+        offset: Imm9Signed,
+    },
     /// Page: 1874
     /// STR (register) C6.2.323
     StoreRegisterRegisterOffset {
@@ -241,6 +301,11 @@ pub enum Instruction {
         src: FPRegister,
         dest: GPRegister,
     },
+    /// FCVT C7.2.69
+    FloatingPointConvert {
+        src: FPRegister,
+        dest: FPRegister,
+    },
     JumpLabel {
         target: String,
     },
@@ -274,6 +339,10 @@ pub enum Instruction {
     BranchLinkLabel {
         target: String,
     },
+    /// Branches to the Address stored in the Register and stores the Return-Address in X30
+    BranchLinkRegister {
+        target: GPRegister,
+    },
     Call {
         target: String,
     },
@@ -287,9 +356,31 @@ impl Display for Instruction {
             Self::StoreRegisterUnscaled { reg, base, offset } => {
                 write!(f, "stur {}, [{}, #{}]", reg, base, offset)
             }
+            Self::StoreHalfWordUnscaled { reg, base, offset } => {
+                write!(f, "sturh {}, [{}, #{}]", reg, base, offset)
+            }
+            Self::StoreByteUnscaled { reg, base, offset } => {
+                write!(f, "sturb {}, [{}, #{}]", reg, base, offset)
+            }
             Self::StoreFPUnscaled { reg, base, offset } => {
                 write!(f, "str {}, [{}, #{}]", reg, base, offset)
             }
+            Self::StoreRegisterRegisterOffset { reg, base, offset }
+            | Self::LoadRegisterRegisterOffset { reg, base, offset } => {
+                let op = match self {
+                    Self::StoreRegisterRegisterOffset { .. } => "str",
+                    _ => "ldr",
+                };
+                write!(f, "{} {}, [{}, {}]", op, reg, base, offset)
+            }
+            Self::StoreFPRegisterOffset { reg, base, offset } => {
+                write!(f, "str {}, [{}, {}]", reg, base, offset)
+            }
+            Self::LoadSignedWordRegisterOffset {
+                reg,
+                base,
+                offset_reg,
+            } => write!(f, "ldrsw {}, [{}, {}]", reg, base, offset_reg),
             Self::StpPreIndex {
                 first,
                 second,
@@ -304,6 +395,18 @@ impl Display for Instruction {
             Self::LoadSignedWordUnscaled { reg, base, offset } => {
                 write!(f, "ldursw {}, [{}, #{}]", reg, base, offset)
             }
+            Self::LoadHalfWordUnscaled { reg, base, offset } => {
+                write!(f, "ldurh {}, [{}, #{}]", reg, base, offset)
+            }
+            Self::LoadSignedHalfWordUnscaled { reg, base, offset } => {
+                write!(f, "ldursh {}, [{}, #{}]", reg, base, offset)
+            }
+            Self::LoadByteUnscaled { reg, base, offset } => {
+                write!(f, "ldurb {}, [{}, #{}]", reg, base, offset)
+            }
+            Self::LoadSignedByteUnscaled { reg, base, offset } => {
+                write!(f, "ldursb {}, [{}, #{}]", reg, base, offset)
+            }
             Self::LoadFPUnscaled { reg, base, offset } => {
                 write!(f, "ldur {}, [{}, #{}]", reg, base, offset)
             }
@@ -325,6 +428,13 @@ impl Display for Instruction {
             } => {
                 write!(f, "movz {}, #{}, LSL #{}", dest, immediate, shift)
             }
+            Self::Movk {
+                dest,
+                immediate,
+                shift,
+            } => {
+                write!(f, "movk {}, #{}, LSL #{}", dest, immediate, shift)
+            }
             Self::MovI64 { dest, value } => {
                 write!(f, "mov {}, #{}", dest, value)
             }
@@ -337,6 +447,9 @@ impl Display for Instruction {
             Self::FMovRegister { dest, src } => {
                 write!(f, "fmov {}, {}", dest, src)
             }
+            Self::FMovGeneral { dest, src } => {
+                write!(f, "fmov {}, {}", dest, src)
+            }
             Self::AddImmediate {
                 dest,
                 src,
@@ -397,6 +510,9 @@ impl Display for Instruction {
             Self::FloatingPointToSignedIntegerMinusInf { dest, src } => {
                 write!(f, "fcvtms {}, {}", dest, src)
             }
+            Self::FloatingPointConvert { dest, src } => {
+                write!(f, "fcvt {}, {}", dest, src)
+            }
             Self::CmpImmediate {
                 reg,
                 immediate,
@@ -424,6 +540,9 @@ impl Display for Instruction {
             Self::BranchLinkLabel { target } => {
                 write!(f, "bl {}", target)
             }
+            Self::BranchLinkRegister { target } => {
+                write!(f, "blr {}", target)
+            }
             Self::Return => {
                 write!(f, "ret")
             }
@@ -436,6 +555,33 @@ impl Display for Instruction {
             } => {
                 write!(f, "orr {}, {}, {}, {} #{}", dest, src1, src2, shift, amount)
             }
+            Self::BitwiseAndRegisterShifted {
+                dest,
+                src1,
+                src2,
+                shift,
+                amount,
+            } => {
+                write!(f, "and {}, {}, {}, {} #{}", dest, src1, src2, shift, amount)
+            }
+            Self::BitwiseXorRegisterShifted {
+                dest,
+                src1,
+                src2,
+                shift,
+                amount,
+            } => {
+                write!(f, "eor {}, {}, {}, {} #{}", dest, src1, src2, shift, amount)
+            }
+            Self::ShiftLeftRegister { dest, src1, src2 } => {
+                write!(f, "lsl {}, {}, {}", dest, src1, src2)
+            }
+            Self::LogicalShiftRightRegister { dest, src1, src2 } => {
+                write!(f, "lsr {}, {}, {}", dest, src1, src2)
+            }
+            Self::ArithmeticShiftRightRegister { dest, src1, src2 } => {
+                write!(f, "asr {}, {}, {}", dest, src1, src2)
+            }
             other => {
                 dbg!(&other);
                 todo!()
@@ -1,10 +1,13 @@
 //! The ISA and general "Spec" for the SH4a Instruction-Set and some conventions used
 //!
 //! # Register
-//! * R0-R3: Used in the Function-Call ABI
-//! * R4: This Register is used for storing Jump/Branch Target Addresses
-//! * R5-14: These are General Purpose Registers and can be used for whatever Purposes needed,
-//! but need to be callee saved
+//! * R0: Used for the Return-Value of a Function and as a Scratch Register for Address
+//! calculations
+//! * R1: Scratch Register that also holds the Address of the Function for a Call
+//! * R4-R7: Used for the first 4 Arguments of a Function-Call, R4 is also used for storing
+//! Jump/Branch Target Addresses
+//! * R2-R3, R8-14: These are General Purpose Registers and can be used for whatever Purposes
+//! needed, but need to be callee saved
 //! * R15: The Stack-Pointer Register
 //!
 //! # General
@@ -75,6 +78,16 @@ pub enum Instruction {
         /// The Target Register to store the Value into
         dest: GeneralPurposeRegister,
     },
+    /// Stores the absolute Address of the given Label into the Register
+    ///
+    /// This does not directly translate to any one instruction in the Architecture but rather
+    /// expands to the same set of instructions as the MovImmR Instruction
+    MovLabelAddress {
+        /// The Label whose Address should be stored
+        label: String,
+        /// The Target Register to store the Address into
+        dest: GeneralPurposeRegister,
+    },
     /// Calculates an Address by adding R0 and the Base-Register together, then loads a 32-Bit
     /// Value from that new Address and stores it into the Target Register
     ///
@@ -139,6 +152,16 @@ pub enum Instruction {
         /// The Immediate Value to add
         immediate: i8,
     },
+    /// Adds the src2 Register to the destination Register and stores the Result back into the
+    /// Destination Register
+    ///
+    /// Underlying Instruction: add Rm,Rn
+    Add {
+        /// The First Part and Destination Register
+        dest: GeneralPurposeRegister,
+        /// The Second Part
+        src2: GeneralPurposeRegister,
+    },
     /// Subtracts the src2 Register from the destination Register and stores the Result back into
     /// the Destination Register
     ///
@@ -149,6 +172,24 @@ pub enum Instruction {
         /// The Second Part
         src2: GeneralPurposeRegister,
     },
+    /// Negates the Value of the src Register and stores the Result into the destination Register
+    ///
+    /// Underlying Instruction: neg Rm,Rn
+    Neg {
+        /// The Destination Register
+        dest: GeneralPurposeRegister,
+        /// The Register whose Value should be negated
+        src: GeneralPurposeRegister,
+    },
+    /// Checks if left == right and sets the T-Bit to 1 if true and 0 if false
+    ///
+    /// Underlying Instruction: cmp/eq Rm,Rn
+    CmpEq {
+        /// The Left side of the Comparison
+        left: GeneralPurposeRegister,
+        /// The Right side of the Comparison
+        right: GeneralPurposeRegister,
+    },
     /// Checks if left > right for signed Values and sets the T-Bit to 1 if true and 0 if false
     ///
     /// Underlying Instruction: cmp/gt Rm,Rn
@@ -158,6 +199,33 @@ pub enum Instruction {
         /// The Right side of the Comparison
         right: GeneralPurposeRegister,
     },
+    /// Checks if left >= right for signed Values and sets the T-Bit to 1 if true and 0 if false
+    ///
+    /// Underlying Instruction: cmp/ge Rm,Rn
+    CmpGe {
+        /// The Left side of the Comparison
+        left: GeneralPurposeRegister,
+        /// The Right side of the Comparison
+        right: GeneralPurposeRegister,
+    },
+    /// Checks if left > right for unsigned Values and sets the T-Bit to 1 if true and 0 if false
+    ///
+    /// Underlying Instruction: cmp/hi Rm,Rn
+    CmpHi {
+        /// The Left side of the Comparison
+        left: GeneralPurposeRegister,
+        /// The Right side of the Comparison
+        right: GeneralPurposeRegister,
+    },
+    /// Checks if left >= right for unsigned Values and sets the T-Bit to 1 if true and 0 if false
+    ///
+    /// Underlying Instruction: cmp/hs Rm,Rn
+    CmpHs {
+        /// The Left side of the Comparison
+        left: GeneralPurposeRegister,
+        /// The Right side of the Comparison
+        right: GeneralPurposeRegister,
+    },
     /// Checks if src > 0 for signed values and sets the T-Bit to 1 if true and 0 if false
     ///
     /// Underlying Instruction: cmp/pl Rn
@@ -187,6 +255,13 @@ pub enum Instruction {
         /// The Destination Register
         dest: GeneralPurposeRegister,
     },
+    /// Moves the inverted Value of the T-Bit into the given Destination Register
+    ///
+    /// Underlying Instruction: movrt Rn
+    MovRT {
+        /// The Destination Register
+        dest: GeneralPurposeRegister,
+    },
     /// Jumps to some Label in the Assembly
     JumpLabel {
         /// The Label to jump to
@@ -56,6 +56,8 @@ pub fn optimize(ir: Program, config: Config) -> Program {
     let mut result = Program {
         global: ir.global,
         functions: HashMap::new(),
+        constants: ir.constants,
+        global_alignments: ir.global_alignments,
     };
 
     for (name, def) in ir.functions.into_iter() {
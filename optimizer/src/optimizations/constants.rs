@@ -158,7 +158,10 @@ impl OptimizationPass for ConstantProp {
 #[cfg(test)]
 mod tests {
 
-    use ir::{BasicBlock, BinaryArithmeticOp, BinaryOp, Expression, Operand, Type};
+    use ir::{
+        BasicBlock, BinaryArithmeticOp, BinaryOp, Expression, Operand, Type, UnaryArithmeticOp,
+        UnaryOp,
+    };
 
     use super::*;
 
@@ -330,4 +333,38 @@ mod tests {
 
         assert_eq!(expected_func, result);
     }
+
+    #[test]
+    fn decrement_wraps() {
+        let block = BasicBlock::new(
+            vec![],
+            vec![Statement::Assignment {
+                target: Variable::tmp(1, Type::U8),
+                value: Value::Expression(Expression::UnaryOp {
+                    op: UnaryOp::Arith(UnaryArithmeticOp::Decrement),
+                    base: Operand::Constant(Constant::U8(0)),
+                }),
+            }],
+        );
+        let ir_func = ir::FunctionDefinition {
+            name: "test".to_string(),
+            arguments: vec![],
+            return_ty: Type::Void,
+            block,
+        };
+
+        let expected_block = BasicBlock::new(
+            vec![],
+            vec![Statement::Assignment {
+                target: Variable::tmp(1, Type::U8),
+                value: Value::Constant(Constant::U8(255)),
+            }],
+        );
+
+        let pass = ConstantProp::new();
+        let result = pass.pass_function(ir_func);
+        dbg!(&result);
+
+        assert_eq!(expected_block, result.block);
+    }
 }
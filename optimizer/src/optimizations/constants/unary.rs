@@ -7,28 +7,28 @@ pub fn arith_con(op: UnaryArithmeticOp, con: Constant) -> Value {
             base: Operand::Constant(con),
         }),
         UnaryArithmeticOp::Increment => match con {
-            Constant::I8(v) => Value::Constant(Constant::I8(v + 1)),
-            Constant::I16(v) => Value::Constant(Constant::I16(v + 1)),
-            Constant::I32(v) => Value::Constant(Constant::I32(v + 1)),
-            Constant::I64(v) => Value::Constant(Constant::I64(v + 1)),
-            Constant::U8(v) => Value::Constant(Constant::U8(v + 1)),
-            Constant::U16(v) => Value::Constant(Constant::U16(v + 1)),
-            Constant::U32(v) => Value::Constant(Constant::U32(v + 1)),
-            Constant::U64(v) => Value::Constant(Constant::U64(v + 1)),
+            Constant::I8(v) => Value::Constant(Constant::I8(v.wrapping_add(1))),
+            Constant::I16(v) => Value::Constant(Constant::I16(v.wrapping_add(1))),
+            Constant::I32(v) => Value::Constant(Constant::I32(v.wrapping_add(1))),
+            Constant::I64(v) => Value::Constant(Constant::I64(v.wrapping_add(1))),
+            Constant::U8(v) => Value::Constant(Constant::U8(v.wrapping_add(1))),
+            Constant::U16(v) => Value::Constant(Constant::U16(v.wrapping_add(1))),
+            Constant::U32(v) => Value::Constant(Constant::U32(v.wrapping_add(1))),
+            Constant::U64(v) => Value::Constant(Constant::U64(v.wrapping_add(1))),
             other => Value::Expression(Expression::UnaryOp {
                 op: UnaryOp::Arith(UnaryArithmeticOp::Increment),
                 base: Operand::Constant(other),
             }),
         },
         UnaryArithmeticOp::Decrement => match con {
-            Constant::I8(v) => Value::Constant(Constant::I8(v - 1)),
-            Constant::I16(v) => Value::Constant(Constant::I16(v - 1)),
-            Constant::I32(v) => Value::Constant(Constant::I32(v - 1)),
-            Constant::I64(v) => Value::Constant(Constant::I64(v - 1)),
-            Constant::U8(v) => Value::Constant(Constant::U8(v - 1)),
-            Constant::U16(v) => Value::Constant(Constant::U16(v - 1)),
-            Constant::U32(v) => Value::Constant(Constant::U32(v - 1)),
-            Constant::U64(v) => Value::Constant(Constant::U64(v - 1)),
+            Constant::I8(v) => Value::Constant(Constant::I8(v.wrapping_sub(1))),
+            Constant::I16(v) => Value::Constant(Constant::I16(v.wrapping_sub(1))),
+            Constant::I32(v) => Value::Constant(Constant::I32(v.wrapping_sub(1))),
+            Constant::I64(v) => Value::Constant(Constant::I64(v.wrapping_sub(1))),
+            Constant::U8(v) => Value::Constant(Constant::U8(v.wrapping_sub(1))),
+            Constant::U16(v) => Value::Constant(Constant::U16(v.wrapping_sub(1))),
+            Constant::U32(v) => Value::Constant(Constant::U32(v.wrapping_sub(1))),
+            Constant::U64(v) => Value::Constant(Constant::U64(v.wrapping_sub(1))),
             other => Value::Expression(Expression::UnaryOp {
                 op: UnaryOp::Arith(UnaryArithmeticOp::Decrement),
                 base: Operand::Constant(other),
@@ -69,6 +69,11 @@ impl DeadCode {
 
         for stmnt in stmnt_iter {
             match &stmnt {
+                // Volatile Reads have to be performed, even if their Result is never used
+                ir::Statement::Assignment {
+                    value: ir::Value::Expression(ir::Expression::ReadMemory { volatile: true, .. }),
+                    ..
+                } => result.push(stmnt),
                 ir::Statement::Assignment { target, .. } if !used_vars.contains(target) => {
                     let used = stmnt.used_vars();
 
@@ -232,4 +237,68 @@ mod tests {
 
         assert_eq!(expected_def, result_def);
     }
+
+    #[test]
+    fn unused_volatile_read() {
+        let ptr_var = Variable::new("ptr", ir::Type::Pointer(Box::new(ir::Type::I32)));
+        let x_var = Variable::new("x", ir::Type::I32);
+        let y_var = Variable::new("y", ir::Type::I32);
+
+        let ir_block = BasicBlock::new(
+            vec![],
+            vec![
+                Statement::Assignment {
+                    target: x_var.clone(),
+                    value: ir::Value::Expression(ir::Expression::ReadMemory {
+                        address: ir::Operand::Variable(ptr_var.clone()),
+                        read_ty: ir::Type::I32,
+                        volatile: true,
+                    }),
+                },
+                Statement::Assignment {
+                    target: y_var,
+                    value: ir::Value::Expression(ir::Expression::ReadMemory {
+                        address: ir::Operand::Variable(ptr_var.clone()),
+                        read_ty: ir::Type::I32,
+                        volatile: false,
+                    }),
+                },
+                Statement::Return(None),
+            ],
+        );
+
+        let func_def = ir::FunctionDefinition {
+            name: "test".to_string(),
+            arguments: vec![(ptr_var.name().to_string(), ptr_var.ty.clone())],
+            return_ty: ir::Type::Void,
+            block: ir_block,
+        };
+
+        let expected_block = BasicBlock::new(
+            vec![],
+            vec![
+                Statement::Assignment {
+                    target: x_var,
+                    value: ir::Value::Expression(ir::Expression::ReadMemory {
+                        address: ir::Operand::Variable(ptr_var.clone()),
+                        read_ty: ir::Type::I32,
+                        volatile: true,
+                    }),
+                },
+                Statement::Return(None),
+            ],
+        );
+        let expected_def = ir::FunctionDefinition {
+            name: "test".to_string(),
+            arguments: vec![(ptr_var.name().to_string(), ptr_var.ty.clone())],
+            return_ty: ir::Type::Void,
+            block: expected_block,
+        };
+
+        let op_pass = DeadCode::new();
+
+        let result_def = op_pass.pass_function(func_def);
+
+        assert_eq!(expected_def, result_def);
+    }
 }
@@ -9,6 +9,9 @@ use crate::OptimizationPass;
 /// the number of Jump instructions to connect the different Blocks
 ///
 /// This is similiar to function inlining, just at the block level and only per function by default
+///
+/// The Statements of the merged Blocks keep their original order, so volatile Memory-Accesses are
+/// never reordered by this
 pub struct Merger {}
 
 impl Merger {
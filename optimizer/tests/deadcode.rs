@@ -66,6 +66,7 @@ void test() {
                 value: Value::Constant(Constant::I64(1)),
             },
             Statement::SaveVariable { var: x1_var },
+            Statement::Return(None),
         ],
     );
     expected_func_first.add_statement(Statement::Jump(
@@ -0,0 +1,86 @@
+use general::{Source, Span};
+use optimizer::Optimization;
+
+fn optimized_ir(content: &str, name: &str) -> String {
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+    let semantic_ast = semantic::parse(syntax_ast).unwrap();
+    let raw_ir = semantic_ast.convert_to_ir(general::arch::Arch::X86_64);
+
+    // The same Passes that are used for -O1
+    let mut config = optimizer::Config::new();
+    config.add_pass(optimizer::optimizations::Merger::new());
+    config.add_pass(
+        optimizer::optimizations::ConstantProp::new()
+            .chain(optimizer::optimizations::DeadCode::new())
+            .repeat(25),
+    );
+
+    let func = raw_ir.functions.get(name).unwrap().clone();
+    let result = optimizer::optimize_func(func, &config);
+
+    let text = ir::text_rep::generate_text_rep(&result);
+    println!("{}", text);
+    text
+}
+
+#[test]
+fn volatile_load_in_loop() {
+    let text = optimized_ir(
+        "
+void test(volatile int* status) {
+    int count = 0;
+    while (count < 10) {
+        int value = *status;
+        count = count + 1;
+    }
+}
+        ",
+        "test",
+    );
+
+    assert_eq!(1, text.matches("ReadMemory").count());
+    assert!(text.contains("volatile: true"));
+}
+
+#[test]
+fn volatile_loads_are_not_merged() {
+    let text = optimized_ir(
+        "
+int test(volatile int* status) {
+    int result = 0;
+    while (result < 10) {
+        int first = *status;
+        int second = *status;
+        result = result + 1;
+    }
+    return result;
+}
+        ",
+        "test",
+    );
+
+    assert_eq!(2, text.matches("volatile: true").count());
+}
+
+#[test]
+fn volatile_store_in_loop() {
+    let text = optimized_ir(
+        "
+void test(volatile int* reg) {
+    int count = 0;
+    while (count < 10) {
+        *reg = 1;
+        *reg = 0;
+        count = count + 1;
+    }
+}
+        ",
+        "test",
+    );
+
+    assert_eq!(2, text.matches("WriteMemory").count());
+    assert_eq!(2, text.matches("volatile: true").count());
+}
@@ -37,7 +37,10 @@ pub trait Loader {
 
 pub mod files {
 
-    use std::{fmt::Display, path::PathBuf};
+    use std::{
+        fmt::Display,
+        path::{Path, PathBuf},
+    };
 
     use general::{Source, Span};
 
@@ -45,6 +48,18 @@ pub mod files {
 
     use super::LoadDirective;
 
+    /// Returns the Content of the Header with the given Path, if it is one of the Headers that
+    /// are shipped with the Compiler itself, like "stdarg.h", because they depend on Builtins of
+    /// the Compiler
+    fn builtin_header(path: &Path) -> Option<&'static str> {
+        match path.to_str()? {
+            "stdalign.h" => Some(include_str!("../include/stdalign.h")),
+            "stdarg.h" => Some(include_str!("../include/stdarg.h")),
+            "stdbool.h" => Some(include_str!("../include/stdbool.h")),
+            _ => None,
+        }
+    }
+
     pub struct FileLoader {
         lib_roots: Vec<PathBuf>,
     }
@@ -112,6 +127,12 @@ pub mod files {
                 return Ok(res_span);
             }
 
+            if let Some(content) = builtin_header(&path.relative_path) {
+                let path_str = path.relative_path.to_str().unwrap();
+                let res_source = Source::new(path_str, content);
+                return Ok(res_source.into());
+            }
+
             Err(FileLoadError { target: path })
         }
     }
@@ -79,7 +79,11 @@ where
                                     );
 
                                     drop(state);
-                                    return self.tmp.next();
+                                    // The Define might expand to nothing
+                                    if let Some(next) = self.tmp.next() {
+                                        return Some(next);
+                                    }
+                                    continue;
                                 }
                                 None => return Some(Ok(PIR::Token(tok))),
                             };
@@ -123,7 +127,11 @@ where
 
                             drop(state);
 
-                            return self.tmp.next();
+                            // A Header might only consist of Directives, like Defines
+                            if let Some(next) = self.tmp.next() {
+                                return Some(next);
+                            }
+                            continue;
                         }
                         Directive::DefineBlock { name, body } => {
                             let tokenized = tokenizer::tokenize(body).collect();
@@ -155,7 +163,11 @@ where
                                 Err(e) => return Some(Err(e)),
                             };
                             self.tmp = Box::new(tmp.into_iter().map(Ok));
-                            return self.tmp.next();
+                            // The Conditional might not contain any Tokens
+                            if let Some(next) = self.tmp.next() {
+                                return Some(next);
+                            }
+                            continue;
                         }
                         Directive::Pragma { content } => {
                             let span_content = content.content();
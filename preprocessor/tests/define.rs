@@ -18,6 +18,7 @@ fn nested_defines() {
             span: Span::new_arc_source_og(arced_source.clone(), 136..139, Span::new_arc_source_og(arced_source.clone(), 136..139, Span::new_source(define_source.clone(), 47..57))),
             data: TokenData::StringLiteral {
                 content: "HI THERE".to_owned(),
+                prefix: None,
             },
         },
         Token {
@@ -45,6 +46,7 @@ fn nested_defines() {
             span: Span::new_arc_source_og(arced_source.clone(), 332..335, Span::new_arc_source_og(arced_source, 332..335, Span::new_source(define_source.clone(), 47..57))),
             data: TokenData::StringLiteral {
                 content: "HI THERE".to_owned(),
+                prefix: None,
             },
         },
         Token {
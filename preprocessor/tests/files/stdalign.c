@@ -0,0 +1,7 @@
+#include <stdalign.h>
+
+alignas(16) int buffer[4];
+
+long alignment() {
+	return alignof(long);
+}
@@ -0,0 +1,9 @@
+#include <stdarg.h>
+
+int first(int count, ...) {
+	va_list args;
+	va_start(args, count);
+	int res = va_arg(args, int);
+	va_end(args);
+	return res;
+}
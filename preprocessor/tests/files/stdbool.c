@@ -0,0 +1,8 @@
+#include <stdbool.h>
+
+bool negate(bool value) {
+	if (value == true) {
+		return false;
+	}
+	return true;
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use preprocessor::loader::files::FileLoader;
+use tokenizer::{Keyword, TokenData};
+
+#[test]
+fn builtin_stdalign_header() {
+    let loader = FileLoader::new();
+
+    let result = preprocessor::preprocess(Arc::new(loader), "./tests/files/stdalign.c").unwrap();
+    dbg!(&result);
+
+    let align_as = result
+        .iter()
+        .filter(|t| t.data == TokenData::Keyword(Keyword::AlignAs))
+        .count();
+    assert_eq!(1, align_as);
+
+    let align_of = result
+        .iter()
+        .filter(|t| t.data == TokenData::Keyword(Keyword::AlignOf))
+        .count();
+    assert_eq!(1, align_of);
+
+    let literals: Vec<_> = result
+        .iter()
+        .filter_map(|t| match &t.data {
+            TokenData::Literal { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert!(!literals.contains(&"alignas"));
+    assert!(!literals.contains(&"alignof"));
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use preprocessor::loader::files::FileLoader;
+use tokenizer::TokenData;
+
+#[test]
+fn builtin_stdarg_header() {
+    let loader = FileLoader::new();
+
+    let result = preprocessor::preprocess(Arc::new(loader), "./tests/files/stdarg.c").unwrap();
+    dbg!(&result);
+
+    let literals: Vec<_> = result
+        .iter()
+        .filter_map(|t| match &t.data {
+            TokenData::Literal { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert!(literals.contains(&"__builtin_va_list"));
+    assert!(literals.contains(&"__builtin_va_start"));
+    assert!(literals.contains(&"__builtin_va_arg"));
+    assert!(literals.contains(&"__builtin_va_end"));
+    assert!(!literals.contains(&"va_arg"));
+    assert!(!literals.contains(&"va_start"));
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use preprocessor::loader::files::FileLoader;
+use tokenizer::{DataType, Keyword, TokenData};
+
+#[test]
+fn builtin_stdbool_header() {
+    let loader = FileLoader::new();
+
+    let result = preprocessor::preprocess(Arc::new(loader), "./tests/files/stdbool.c").unwrap();
+    dbg!(&result);
+
+    let bools = result
+        .iter()
+        .filter(|t| t.data == TokenData::Keyword(Keyword::DataType(DataType::Bool)))
+        .count();
+    assert_eq!(2, bools);
+
+    let literals: Vec<_> = result
+        .iter()
+        .filter_map(|t| match &t.data {
+            TokenData::Literal { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert!(literals.contains(&"1"));
+    assert!(literals.contains(&"0"));
+    assert!(!literals.contains(&"bool"));
+    assert!(!literals.contains(&"true"));
+    assert!(!literals.contains(&"false"));
+}
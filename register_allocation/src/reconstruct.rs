@@ -110,6 +110,28 @@ fn reconstruct_chain<R>(
                     None => last_def.clone(),
                 };
 
+                // A Side that never reaches the Node following the Branch, like a Case of a
+                // Switch that ends in a Continue or Return, does not contribute any Definitions
+                // to the Rest of the Chain
+                if let Some(ChainEntry::Node(following)) = peekable.peek() {
+                    let reaches_following =
+                        |side: &DirectedChain<'_, BasicBlock>| match side.duplicate().last() {
+                            Some(ChainEntry::Node(n)) => {
+                                n.successors().contains_key(&following.as_ptr())
+                            }
+                            _ => true,
+                        };
+
+                    if !reaches_following(&left) {
+                        *last_def = right_defs;
+                        continue;
+                    }
+                    if !right_opt.as_ref().map(reaches_following).unwrap_or(true) {
+                        *last_def = left_defs;
+                        continue;
+                    }
+                }
+
                 // Filters out all the Groups that are only in one of the Sides, this should only
                 // include temporary/local variables that "will" never escape their scope
                 // and therefore dont need to be considered for the rest
@@ -141,9 +163,15 @@ fn reconstruct_chain<R>(
 
                 let different_defs = left_defs.joined_iter(&right_defs);
 
-                let raw_peeked = peekable
-                    .peek_mut()
-                    .expect("There should be a block after a Branch");
+                // A nested Branch at the End of the Chain joins up in the Block following the
+                // outer Branch, which will then handle the Join
+                let raw_peeked = match peekable.peek_mut() {
+                    Some(p) => p,
+                    None => {
+                        *last_def = junction;
+                        continue;
+                    }
+                };
                 let peeked = match raw_peeked {
                     ChainEntry::Node(n) => n,
                     other => {
@@ -299,7 +327,7 @@ fn replace(stmnt: &mut Statement, defs: &LastDefinition) {
             };
             *value = n_var.clone();
         }
-        Statement::WriteMemory { target, value } => {
+        Statement::WriteMemory { target, value, .. } => {
             replace_oper(target, defs);
             replace_oper(value, defs);
         }
@@ -308,6 +336,25 @@ fn replace(stmnt: &mut Statement, defs: &LastDefinition) {
                 replace_oper(arg, defs);
             }
         }
+        Statement::IndirectCall {
+            target, arguments, ..
+        } => {
+            replace_oper(target, defs);
+            for arg in arguments.iter_mut() {
+                replace_oper(arg, defs);
+            }
+        }
+        Statement::StackRestore { value } => match defs.get_last(value) {
+            Some(LastDef::Single(d, _)) => {
+                *value = d.clone();
+            }
+            Some(LastDef::Two(_, _)) => {
+                todo!()
+            }
+            // The Definitions of the two Sides of a Branch, which are not joined up yet, are
+            // dropped, so the Variable is kept like for an unknown Phi-Source
+            None => {}
+        },
         Statement::Jump(_, _) => {}
         Statement::JumpTrue(var, _, _) => {
             let group: VariableGroup = var.clone().into();
@@ -421,8 +468,25 @@ fn replace_exp(exp: &mut Expression, defs: &LastDefinition) {
                 replace_oper(arg, defs);
             }
         }
+        Expression::IndirectFunctionCall {
+            target, arguments, ..
+        } => {
+            replace_oper(target, defs);
+            for arg in arguments.iter_mut() {
+                replace_oper(arg, defs);
+            }
+        }
         Expression::StackAlloc { .. } => {}
+        Expression::DynamicStackAlloc { size, .. } => {
+            replace_oper(size, defs);
+        }
+        Expression::StackSave => {}
+        Expression::StaticAlloc { .. } => {}
         Expression::ReadGlobalVariable { .. } => {}
+        Expression::FunctionAddress { .. } => {}
+        Expression::ConstantAddress { .. } => {}
+        Expression::GlobalAddress { .. } => {}
+        Expression::VarArgsStart => {}
         Expression::ReadMemory { address, .. } => {
             replace_oper(address, defs);
         }
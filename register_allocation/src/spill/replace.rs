@@ -25,9 +25,14 @@ pub fn expression_replace(
                 .collect(),
             return_ty,
         },
-        ir::Expression::ReadMemory { address, read_ty } => ir::Expression::ReadMemory {
+        ir::Expression::ReadMemory {
+            address,
+            read_ty,
+            volatile,
+        } => ir::Expression::ReadMemory {
             address: operand_replace(address, to_replace, replacement),
             read_ty,
+            volatile,
         },
         ir::Expression::ReadGlobalVariable { name } => ir::Expression::ReadGlobalVariable { name },
         ir::Expression::StackAlloc { size, alignment } => {
@@ -100,9 +105,14 @@ pub fn statement(
             target,
             value: value_replace(value, to_replace, replacement, replacement_block),
         },
-        ir::Statement::WriteMemory { target, value } => ir::Statement::WriteMemory {
+        ir::Statement::WriteMemory {
+            target,
+            value,
+            volatile,
+        } => ir::Statement::WriteMemory {
             target: operand_replace(target, to_replace, replacement),
             value: value_replace(value, to_replace, replacement, replacement_block),
+            volatile,
         },
         ir::Statement::Call { name, arguments } => {
             let n_args: Vec<_> = arguments
@@ -25,11 +25,28 @@ fn replace_expression(exp: &mut ir::Expression, previous: &ir::Variable, n_var:
                 replace_operand(arg, previous, n_var);
             }
         }
+        ir::Expression::IndirectFunctionCall {
+            target, arguments, ..
+        } => {
+            replace_operand(target, previous, n_var);
+            for arg in arguments {
+                replace_operand(arg, previous, n_var);
+            }
+        }
         ir::Expression::UnaryOp { base, .. } => {
             replace_operand(base, previous, n_var);
         }
         ir::Expression::ReadGlobalVariable { .. } => {}
+        ir::Expression::FunctionAddress { .. } => {}
+        ir::Expression::ConstantAddress { .. } => {}
+        ir::Expression::GlobalAddress { .. } => {}
+        ir::Expression::VarArgsStart => {}
         ir::Expression::StackAlloc { .. } => {}
+        ir::Expression::DynamicStackAlloc { size, .. } => {
+            replace_operand(size, previous, n_var);
+        }
+        ir::Expression::StackSave => {}
+        ir::Expression::StaticAlloc { .. } => {}
         ir::Expression::ReadMemory { address, .. } => {
             replace_operand(address, previous, n_var);
         }
@@ -86,7 +103,11 @@ pub fn replace_used_variables(
             *value = n_var.clone();
         }
         ir::Statement::SaveGlobalVariable { .. } => {}
-        ir::Statement::WriteMemory { target, value } => {
+        ir::Statement::StackRestore { value } if value == previous => {
+            *value = n_var.clone();
+        }
+        ir::Statement::StackRestore { .. } => {}
+        ir::Statement::WriteMemory { target, value, .. } => {
             replace_operand(target, previous, n_var);
             replace_operand(value, previous, n_var);
         }
@@ -95,6 +116,14 @@ pub fn replace_used_variables(
                 replace_operand(arg, previous, n_var);
             }
         }
+        ir::Statement::IndirectCall {
+            target, arguments, ..
+        } => {
+            replace_operand(target, previous, n_var);
+            for arg in arguments.iter_mut() {
+                replace_operand(arg, previous, n_var);
+            }
+        }
         ir::Statement::InlineAsm { .. } => {
             todo!("Replace inlined asm")
         }
@@ -399,3 +399,49 @@ int main() {
     // TODO
     // assert_eq!(expected_ir_func, main_func);
 }
+
+#[test]
+fn continue_in_switch() {
+    let test_program = "
+int main() {
+    int odd = 0;
+    for (int i = 0; i < 6; i++) {
+        switch (i & 1) {
+            case 0:
+                continue;
+            default:
+                odd++;
+        }
+    }
+    return odd;
+}
+        ";
+    let test_source = general::Source::new("test", test_program);
+    let test_span: general::Span = test_source.into();
+    let tokens = tokenizer::tokenize(test_span);
+    let ast = syntax::parse(tokens).unwrap();
+    let aast = semantic::parse(ast).unwrap();
+    let ir = aast.convert_to_ir(general::arch::Arch::X86_64);
+
+    let main_func: ir::FunctionDefinition = ir.functions.get("main").unwrap().clone();
+
+    // The Case with the Continue never reaches the Rest of the Loop-Body, so its Definitions
+    // must not be joined with the ones of the other Cases
+    let result_allocation = RegisterMapping::allocate(
+        &main_func,
+        &[
+            TestRegister::GeneralPurpose(0),
+            TestRegister::GeneralPurpose(1),
+            TestRegister::GeneralPurpose(2),
+            TestRegister::GeneralPurpose(3),
+            TestRegister::GeneralPurpose(4),
+            TestRegister::GeneralPurpose(5),
+            TestRegister::GeneralPurpose(6),
+            TestRegister::GeneralPurpose(7),
+        ],
+        AllocationCtx { build_path: None },
+    );
+    dbg!(&result_allocation);
+
+    main_func.verify();
+}
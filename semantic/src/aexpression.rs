@@ -1,8 +1,11 @@
 use std::collections::BTreeSet;
 
-use general::{Span, SpanData};
+use general::{
+    arch::{Arch, DataModel},
+    Span, SpanData,
+};
 use ir::{BasicBlock, Value};
-use syntax::{Expression, Identifier, SingleOperation};
+use syntax::{Expression, Identifier, SingleOperation, TypeToken};
 
 use crate::{
     atype, conversion::ConvertContext, AAssignTarget, APrimitive, AType, ArrayAccessTarget,
@@ -24,6 +27,17 @@ pub use functioncall::*;
 mod structaccess;
 pub use structaccess::*;
 
+mod varargs;
+pub use varargs::*;
+
+mod initializer;
+pub use initializer::*;
+
+mod pointer_arith;
+pub use pointer_arith::*;
+
+mod conditional;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum AExpression {
     Literal(Literal),
@@ -41,6 +55,11 @@ pub enum AExpression {
         ty: AType,
         area: Span,
     },
+    AlignOf {
+        /// The Type of which we want to know the Alignment
+        ty: AType,
+        area: Span,
+    },
     ArrayAccess {
         base: Box<Self>,
         index: Box<Self>,
@@ -48,6 +67,13 @@ pub enum AExpression {
     },
     StructAccess(StructAccess),
     FunctionCall(FunctionCall),
+    IndirectCall(IndirectCall),
+    /// The Address of a Function, which is used when the Name of a Function appears anywhere
+    /// other than directly in a Call
+    FunctionAddress {
+        name: Identifier,
+        ty: AType,
+    },
     Cast {
         base: Box<Self>,
         target: AType,
@@ -57,6 +83,7 @@ pub enum AExpression {
         right: Box<Self>,
         op: AOperator,
     },
+    PointerArithmetic(PointerArithmetic),
     UnaryOperator {
         base: Box<Self>,
         op: UnaryOperator,
@@ -66,6 +93,15 @@ pub enum AExpression {
         left: Box<Self>,
         right: Box<Self>,
     },
+    /// The Address of the first variable Argument of the current Function, which is used to
+    /// initialize a va_list
+    VarArgsStart {
+        area: Span,
+    },
+    VaArg(VaArg),
+    /// An Initializer-List or Compound-Literal, which evaluates to the Address of the
+    /// initialized Object
+    InitializerList(InitializerList),
     InlineAssembly {
         template: SpanData<String>,
         input_vars: Vec<(Identifier, SpanData<AType>)>,
@@ -83,6 +119,12 @@ pub enum EvaluationValue {
 #[derive(Debug, PartialEq)]
 pub enum EvaluationError {
     Pointers,
+    /// The Expression can not be evaluated at compile-time
+    NotConstant,
+    DivideByZero,
+    /// The Value depends on the Target, like the Size of a Type, and can therefore only be
+    /// evaluated once the Target is known
+    TargetDependent,
 }
 
 impl AExpression {
@@ -95,79 +137,80 @@ impl AExpression {
         VC: VariableContainer,
     {
         match raw {
-            Expression::Literal { content } => {
-                if content.data.contains('.') {
-                    let value: f64 = match content.data.parse() {
-                        Ok(v) => v,
-                        Err(_) => {
-                            return Err(SemanticError::MismatchedTypes {
-                                expected: SpanData {
-                                    span: content.span.clone(),
-                                    data: AType::Primitve(APrimitive::Double),
-                                },
-                                received: SpanData {
-                                    span: content.span.clone(),
-                                    data: AType::Primitve(APrimitive::Void),
-                                },
-                            });
-                        }
-                    };
-
-                    let span_d = SpanData {
-                        span: content.span,
-                        data: value,
-                    };
-                    Ok(Self::Literal(Literal::FloatingPoint(span_d)))
-                } else {
-                    let value: i64 = match content.data.parse() {
-                        Ok(v) => v,
-                        Err(_) => {
-                            return Err(SemanticError::MismatchedTypes {
-                                expected: SpanData {
-                                    span: content.span.clone(),
-                                    data: AType::Primitve(APrimitive::LongInt),
-                                },
-                                received: SpanData {
-                                    span: content.span.clone(),
-                                    data: AType::Primitve(APrimitive::Void),
-                                },
-                            });
-                        }
-                    };
-
-                    let span_d = SpanData {
-                        span: content.span,
-                        data: value,
-                    };
-                    Ok(Self::Literal(Literal::Integer(span_d)))
-                }
+            Expression::Literal { content } => Literal::parse_number(content).map(Self::Literal),
+            Expression::StringLiteral { content, encoding } => {
+                Ok(Self::Literal(Literal::StringLiteral(content, encoding)))
             }
-            Expression::StringLiteral { content } => {
-                Ok(Self::Literal(Literal::StringLiteral(content)))
+            Expression::CharLiteral { content, encoding } => {
+                Ok(Self::Literal(Literal::CharLiteral(content, encoding)))
             }
-            Expression::CharLiteral { content } => Ok(Self::Literal(Literal::CharLiteral(content))),
             Expression::SizeOf { ty, area } => {
-                let a_ty = AType::parse(ty, ty_defs, vars)?;
+                // The Parser can not distinguish between a Variable and the Name of a TypeDef
+                let a_ty = match ty {
+                    TypeToken::TypeDefed { name } if ty_defs.get_definition(&name).is_none() => {
+                        match vars.get_var(&name) {
+                            Some(var) => var.ty.clone(),
+                            None => AType::parse(TypeToken::TypeDefed { name }, ty_defs, vars)?,
+                        }
+                    }
+                    ty => AType::parse(ty, ty_defs, vars)?,
+                }
+                .complete(ty_defs);
+                a_ty.ensure_complete(&area)?;
 
                 Ok(Self::SizeOf { ty: a_ty, area })
             }
+            Expression::AlignOf { ty, area } => {
+                let a_ty = AType::parse(ty, ty_defs, vars)?.complete(ty_defs);
+                a_ty.ensure_complete(&area)?;
+
+                Ok(Self::AlignOf { ty: a_ty, area })
+            }
+            Expression::VaArg { list, ty, area } => {
+                VaArg::parse(*list, ty, area, ty_defs, vars).map(Self::VaArg)
+            }
             Expression::Identifier { ident } => {
                 let var_dec = match vars.get_var(&ident) {
                     Some(tmp) => tmp,
                     None => {
-                        return Err(SemanticError::UnknownIdentifier { name: ident });
+                        if let Some(func) = vars.get_func(&ident) {
+                            let ty = AType::Pointer(Box::new(AType::Function {
+                                return_ty: Box::new(func.return_ty.clone()),
+                                arguments: func
+                                    .arguments
+                                    .iter()
+                                    .map(|arg| arg.data.ty.clone())
+                                    .collect(),
+                                var_args: func.var_args,
+                            }));
+
+                            return Ok(Self::FunctionAddress { name: ident, ty });
+                        }
+
+                        // Enum-Variants are simply replaced by their constant Value
+                        return match ty_defs.get_enum_constant(&ident) {
+                            Some(value) => Ok(Self::Literal(Literal::Integer(
+                                SpanData {
+                                    span: ident.0.span,
+                                    data: value,
+                                },
+                                APrimitive::Int,
+                            ))),
+                            None => Err(SemanticError::UnknownIdentifier { name: ident }),
+                        };
                     }
                 };
 
                 let var_name = &var_dec.internal_name;
-                let var_type = &var_dec.ty;
+                // The Variable might point to a Struct, that has only been defined after it
+                let var_type = var_dec.ty.clone().complete(ty_defs);
                 let var_span = &var_dec.declaration;
 
                 Ok(AExpression::Variable {
                     name: var_name.clone(),
                     src: ident,
                     ty: SpanData {
-                        data: var_type.clone(),
+                        data: var_type,
                         span: var_span.clone(),
                     },
                 })
@@ -180,23 +223,32 @@ impl AExpression {
                 let (struct_def, def_span) = match base_ty.get_struct_def() {
                     Some(s) => s,
                     None => {
+                        let struct_ty = match base_ty.into_ty() {
+                            AType::Pointer(inner) => inner.as_ref(),
+                            other => other,
+                        };
+                        struct_ty.ensure_complete(&field.0.span)?;
+
                         return Err(SemanticError::MismatchedTypes {
-                            expected: SpanData {
+                            expected: Box::new(SpanData {
                                 span: field.0.span.clone(),
                                 data: atype::AType::Struct {
                                     def: StructDef {
+                                        name: None,
                                         members: vec![StructMember {
-                                            name: field.clone(),
+                                            name: Some(field.clone()),
                                             ty: AType::Primitve(APrimitive::Void),
+                                            bit_width: None,
+                                            alignment: None,
                                         }],
                                     },
                                     area: field.0.span,
                                 },
-                            },
-                            received: SpanData {
+                            }),
+                            received: Box::new(SpanData {
                                 span: base_exp.entire_span(),
                                 data: base_ty,
-                            },
+                            }),
                         });
                     }
                 };
@@ -206,14 +258,21 @@ impl AExpression {
                     None => {
                         return Err(SemanticError::UnknownStructField {
                             field_name: field,
-                            struct_def: SpanData {
+                            struct_def: Box::new(SpanData {
                                 span: def_span.clone(),
                                 data: struct_def.clone(),
-                            },
+                            }),
                         });
                     }
                 };
 
+                // The Struct is either accessed directly or through a Pointer
+                let struct_ty = match base_ty.into_ty() {
+                    AType::Pointer(inner) => inner.as_ref().clone(),
+                    _ => base_exp.object_type(),
+                };
+                let field_ty = field_ty.qualify_member(&struct_ty).complete(ty_defs);
+
                 Ok(Self::StructAccess(StructAccess {
                     base: Box::new(base_exp),
                     field,
@@ -224,13 +283,14 @@ impl AExpression {
                 base,
                 operation: SingleOperation::FuntionCall(mut raw_args),
             } => {
+                // Only Identifiers that are not Variables refer to a Function directly, everything
+                // else has to evaluate to a Function-Pointer
                 let name = match *base {
-                    Expression::Identifier { ident } => ident,
+                    Expression::Identifier { ident } if vars.get_var(&ident).is_none() => ident,
                     other => {
-                        return Err(SemanticError::InvalidOperation {
-                            base: other.entire_span().unwrap(),
-                            operation: InvalidOperation::FunctionCall,
-                        })
+                        let target = Self::parse(other, ty_defs, vars)?;
+                        return IndirectCall::parse(target, raw_args, ty_defs, vars)
+                            .map(Self::IndirectCall);
                     }
                 };
 
@@ -243,7 +303,16 @@ impl AExpression {
                     let template_arg = AExpression::parse(raw_template_arg, ty_defs, vars)?;
 
                     let template = match template_arg {
-                        AExpression::Literal(Literal::StringLiteral(data)) => data,
+                        AExpression::Literal(Literal::StringLiteral(
+                            SpanData { span, data },
+                            _,
+                        )) => {
+                            let bytes: Vec<u8> = data.into_iter().map(|u| u as u8).collect();
+                            SpanData {
+                                span,
+                                data: String::from_utf8_lossy(&bytes).into_owned(),
+                            }
+                        }
                         _ => panic!("Expected String Literal"),
                     };
 
@@ -341,8 +410,11 @@ impl AExpression {
                     });
                 }
 
-                let arg_iter = func_dec.arguments.iter().zip(args.into_iter());
-                let arg_results: Vec<_> = arg_iter
+                let mut arg_iter = args.into_iter();
+                let arg_results: Vec<_> = func_dec
+                    .arguments
+                    .iter()
+                    .zip(arg_iter.by_ref())
                     .map(|(expected, recveived)| {
                         atype::assign_type::determine_type(
                             recveived,
@@ -355,12 +427,18 @@ impl AExpression {
                     return err.clone();
                 }
 
-                let args: Vec<_> = arg_results.into_iter().filter_map(|t| t.ok()).collect();
+                // All the remaining Arguments are variable Arguments
+                let args: Vec<_> = arg_results
+                    .into_iter()
+                    .filter_map(|t| t.ok())
+                    .chain(arg_iter.map(promote_var_arg))
+                    .collect();
 
                 Ok(Self::FunctionCall(FunctionCall {
                     name,
                     arguments: args,
-                    result_ty: func_dec.return_ty.clone(),
+                    result_ty: func_dec.return_ty.clone().complete(ty_defs),
+                    variadic: func_dec.var_args.then_some(func_dec.arguments.len()),
                 }))
             }
             Expression::SingleOperation {
@@ -371,7 +449,7 @@ impl AExpression {
 
                 let base_ty = base_exp.result_type();
 
-                let elem_ty = match base_ty {
+                let elem_ty = match base_ty.ty() {
                     AType::Array(arr) => arr.ty,
                     AType::Pointer(inner) => inner,
                     _ => {
@@ -403,6 +481,8 @@ impl AExpression {
                 match a_base {
                     AExpression::Variable { .. } => {}
                     AExpression::ArrayAccess { .. } => {}
+                    // The Name of a Function already evaluates to its Address
+                    AExpression::FunctionAddress { .. } => return Ok(a_base),
                     _ => {
                         return Err(SemanticError::InvalidOperation {
                             base: a_base.entire_span(),
@@ -411,7 +491,7 @@ impl AExpression {
                     }
                 };
 
-                let base_ty = a_base.result_type();
+                let base_ty = a_base.object_type();
                 let target_ty = AType::Pointer(Box::new(base_ty));
 
                 Ok(Self::AddressOf {
@@ -426,8 +506,13 @@ impl AExpression {
                 let a_base = Self::parse(*base, ty_defs, vars)?;
 
                 let base_ty = a_base.result_type();
-                match base_ty {
-                    AType::Pointer(_) => {}
+                match base_ty.into_ty() {
+                    // Dereferencing a Function-Pointer results in the Function itself, which is
+                    // then again treated as a Pointer to it
+                    AType::Pointer(inner) if matches!(inner.into_ty(), AType::Function { .. }) => {
+                        return Ok(a_base);
+                    }
+                    AType::Pointer(inner) => inner.ensure_complete(&a_base.entire_span())?,
                     _ => {
                         return Err(SemanticError::InvalidOperation {
                             base: a_base.entire_span(),
@@ -446,6 +531,28 @@ impl AExpression {
 
                 let a_op = UnaryOperator::from(operation);
 
+                let base_ty = a_base.object_type();
+                if a_op.modifies_base() && base_ty.is_const() {
+                    let ty_span = match &a_base {
+                        AExpression::Variable { ty, .. } => ty.span.clone(),
+                        other => other.entire_span(),
+                    };
+
+                    return Err(SemanticError::ConstAssignment {
+                        target: a_base.entire_span(),
+                        ty: Box::new(SpanData {
+                            span: ty_span,
+                            data: base_ty,
+                        }),
+                    });
+                }
+
+                let a_base = if a_op.promotes_base() {
+                    atype::promote(a_base)
+                } else {
+                    a_base
+                };
+
                 Ok(Self::UnaryOperator {
                     base: Box::new(a_base),
                     op: a_op,
@@ -461,24 +568,39 @@ impl AExpression {
                 let right_a = Self::parse(*right, ty_defs, vars)?;
 
                 let op_a = AOperator::from(operation);
+                let model = vars.data_model();
+
+                if let AOperator::Arithmetic(arith_op) = &op_a {
+                    if PointerArithmetic::applies(&left_a, &right_a) {
+                        return PointerArithmetic::parse(left_a, right_a, arith_op.clone())
+                            .map(Self::PointerArithmetic);
+                    }
+                }
 
                 let (left_exp, right_exp) = match &op_a {
                     AOperator::Comparison(_) => {
+                        if let Some(warning) =
+                            atype::sign_changing_comparison(&left_a, &right_a, &model)
+                        {
+                            vars.warn(warning);
+                        }
+
                         // TODO
-                        // Check for Type Compatibility when comparing
-                        (left_a, right_a)
+                        // Check for Type Compatibility when comparing non arithmetic Types
+                        atype::determine_common_types(left_a, right_a, &model)
                     }
                     AOperator::Combinator(_) => {
                         // TODO
                         // Check for Type Compatibility when combining logic
                         (left_a, right_a)
                     }
-                    AOperator::Arithmetic(_) => atype::determine_types(left_a, right_a)?,
-                    AOperator::Bitwise(_) => {
-                        // TODO
-                        // Check for Type Compatibility when performing bitwise operations
-                        (left_a, right_a)
+                    AOperator::Arithmetic(_) => atype::determine_types(left_a, right_a, &model)?,
+                    // The Operands of a Shift are promoted on their own, as the Type of the
+                    // Result only depends on the left Operand
+                    AOperator::Bitwise(BitwiseOp::ShiftLeft | BitwiseOp::ShiftRight) => {
+                        (atype::promote(left_a), atype::promote(right_a))
                     }
+                    AOperator::Bitwise(_) => atype::determine_types(left_a, right_a, &model)?,
                 };
 
                 Ok(Self::BinaryOperator {
@@ -497,11 +619,21 @@ impl AExpression {
                     base: Box::new(inner_exp),
                 })
             }
-            Expression::ArrayLiteral { .. } => {
-                // TODO
-                Err(SemanticError::NotImplemented {
-                    ctx: "Array Literals".to_string(),
-                })
+            Expression::ArrayLiteral { parts } => {
+                // Initializer-Lists are only allowed in the Initialization of a Variable, where
+                // their Type is already known
+                Err(SemanticError::InvalidInitializer { area: parts.span })
+            }
+            Expression::DesignatedInitializer { value, .. } => {
+                let area = value
+                    .entire_span()
+                    .expect("Designated Initializers always have a Value with a Span");
+                Err(SemanticError::InvalidInitializer { area })
+            }
+            Expression::CompoundLiteral { target_ty, parts } => {
+                let ty = AType::parse(target_ty, ty_defs, vars)?;
+
+                InitializerList::parse(ty, parts, ty_defs, vars)
             }
             Expression::Conditional {
                 condition,
@@ -511,19 +643,21 @@ impl AExpression {
                 let condition_exp = Self::parse(*condition, ty_defs, vars)?;
                 let left_exp = Self::parse(*first, ty_defs, vars)?;
                 let right_exp = Self::parse(*second, ty_defs, vars)?;
+                let (left_exp, right_exp) =
+                    atype::determine_common_types(left_exp, right_exp, &vars.data_model());
 
                 let left_ty = left_exp.result_type();
                 let right_ty = right_exp.result_type();
                 if left_ty != right_ty {
                     return Err(SemanticError::MismatchedTypes {
-                        expected: SpanData {
+                        expected: Box::new(SpanData {
                             span: left_exp.entire_span(),
                             data: left_ty,
-                        },
-                        received: SpanData {
+                        }),
+                        received: Box::new(SpanData {
                             span: right_exp.entire_span(),
                             data: right_ty,
-                        },
+                        }),
                     });
                 }
 
@@ -537,37 +671,239 @@ impl AExpression {
     }
 
     pub fn const_evaluate(&self) -> Result<EvaluationValue, EvaluationError> {
+        self.evaluate(None)
+    }
+
+    /// Evaluates the Expression at compile-time for the given Target, which also allows for
+    /// Values that depend on the Target, like the Size or Alignment of a Type
+    pub fn const_evaluate_for(&self, arch: &Arch) -> Result<EvaluationValue, EvaluationError> {
+        self.evaluate(Some(arch))
+    }
+
+    /// Converts the constant Value to the integer Type, which depends on the Width of the Type
+    /// and therefore might depend on the Target
+    fn wrap_constant(
+        value: i64,
+        target: &APrimitive,
+        arch: Option<&Arch>,
+    ) -> Result<i64, EvaluationError> {
+        match arch {
+            Some(arch) => Ok(target.wrap_integer(value, &arch.data_model())),
+            None => {
+                let narrow = target.wrap_integer(value, &DataModel::ILP32);
+                if narrow != target.wrap_integer(value, &DataModel::LP64) {
+                    return Err(EvaluationError::TargetDependent);
+                }
+                Ok(narrow)
+            }
+        }
+    }
+
+    /// Converts the constant Result of an Operation to the Type it was performed in, which has
+    /// no Effect for non integer Types
+    fn wrap_result(value: i64, ty: &AType, arch: Option<&Arch>) -> Result<i64, EvaluationError> {
+        match atype::arithmetic_primitive(ty) {
+            Some(prim) if prim.rank().is_some() => Self::wrap_constant(value, &prim, arch),
+            _ => Ok(value),
+        }
+    }
+
+    /// Evaluates a binary Operation on two integer Constants, that have both already been
+    /// converted to the given Type.
+    ///
+    /// The Values of unsigned Types are never negative, except for 64-Bit Types where they are
+    /// stored as their Bit-Pattern, so they are compared, divided and shifted as u64
+    fn evaluate_integer_operation(
+        op: &AOperator,
+        left: i64,
+        right: i64,
+        ty: &AType,
+    ) -> Result<i64, EvaluationError> {
+        let unsigned = atype::arithmetic_primitive(ty)
+            .map(|prim| prim.is_unsigned())
+            .unwrap_or(false);
+        let (left_u, right_u) = (left as u64, right as u64);
+
+        let result = match op {
+            AOperator::Arithmetic(ArithemticOp::Add) => left.wrapping_add(right),
+            AOperator::Arithmetic(ArithemticOp::Sub) => left.wrapping_sub(right),
+            AOperator::Arithmetic(ArithemticOp::Multiply) => left.wrapping_mul(right),
+            AOperator::Arithmetic(ArithemticOp::Divide | ArithemticOp::Modulo) if right == 0 => {
+                return Err(EvaluationError::DivideByZero)
+            }
+            AOperator::Arithmetic(ArithemticOp::Divide) if unsigned => (left_u / right_u) as i64,
+            AOperator::Arithmetic(ArithemticOp::Divide) => left.wrapping_div(right),
+            AOperator::Arithmetic(ArithemticOp::Modulo) if unsigned => (left_u % right_u) as i64,
+            AOperator::Arithmetic(ArithemticOp::Modulo) => left.wrapping_rem(right),
+            AOperator::Bitwise(BitwiseOp::And) => left & right,
+            AOperator::Bitwise(BitwiseOp::Or) => left | right,
+            AOperator::Bitwise(BitwiseOp::Xor) => left ^ right,
+            AOperator::Bitwise(BitwiseOp::ShiftLeft) => left.wrapping_shl(right as u32),
+            AOperator::Bitwise(BitwiseOp::ShiftRight) if unsigned => {
+                left_u.wrapping_shr(right as u32) as i64
+            }
+            AOperator::Bitwise(BitwiseOp::ShiftRight) => left.wrapping_shr(right as u32),
+            AOperator::Comparison(comp) => {
+                let ordering = if unsigned {
+                    left_u.cmp(&right_u)
+                } else {
+                    left.cmp(&right)
+                };
+                let res = match comp {
+                    AComparitor::Less => ordering.is_lt(),
+                    AComparitor::LessEqual => ordering.is_le(),
+                    AComparitor::Greater => ordering.is_gt(),
+                    AComparitor::GreaterEqual => ordering.is_ge(),
+                    AComparitor::Equal => ordering.is_eq(),
+                    AComparitor::NotEqual => ordering.is_ne(),
+                };
+                res as i64
+            }
+            AOperator::Combinator(LogicCombinator::And) => (left != 0 && right != 0) as i64,
+            AOperator::Combinator(LogicCombinator::Or) => (left != 0 || right != 0) as i64,
+        };
+
+        Ok(result)
+    }
+
+    fn evaluate(&self, arch: Option<&Arch>) -> Result<EvaluationValue, EvaluationError> {
         match self {
             Self::Literal(lit) => match lit {
-                Literal::Integer(SpanData { data, .. }) => {
-                    Ok(EvaluationValue::Integer(*data as i64))
-                }
-                Literal::FloatingPoint(SpanData { data, .. }) => {
+                Literal::Integer(SpanData { data, .. }, _) => Ok(EvaluationValue::Integer(*data)),
+                Literal::FloatingPoint(SpanData { data, .. }, _) => {
                     Ok(EvaluationValue::FloatingPoint(*data))
                 }
-                Literal::StringLiteral(SpanData { .. }) => Err(EvaluationError::Pointers),
-                Literal::CharLiteral(SpanData { .. }) => todo!("Allow Char const eval"),
+                Literal::StringLiteral(SpanData { .. }, _) => Err(EvaluationError::Pointers),
+                Literal::CharLiteral(SpanData { data, .. }, encoding) => Ok(
+                    EvaluationValue::Integer(Literal::char_value(*data, *encoding)),
+                ),
             },
-            unknown => panic!("Dont know how to evaluate {:?}", unknown),
+            Self::Cast { base, target } => {
+                let value = base.evaluate(arch)?;
+
+                match (value, target.into_ty()) {
+                    (EvaluationValue::Integer(v), AType::Primitve(APrimitive::Bool)) => {
+                        Ok(EvaluationValue::Integer((v != 0) as i64))
+                    }
+                    (EvaluationValue::FloatingPoint(v), AType::Primitve(APrimitive::Bool)) => {
+                        Ok(EvaluationValue::Integer((v != 0.0) as i64))
+                    }
+                    (EvaluationValue::Integer(v), AType::Primitve(APrimitive::Float))
+                    | (EvaluationValue::Integer(v), AType::Primitve(APrimitive::Double)) => {
+                        Ok(EvaluationValue::FloatingPoint(v as f64))
+                    }
+                    (EvaluationValue::FloatingPoint(v), AType::Primitve(prim))
+                        if prim.rank().is_some() =>
+                    {
+                        Self::wrap_constant(v as i64, prim, arch).map(EvaluationValue::Integer)
+                    }
+                    (EvaluationValue::Integer(v), AType::Primitve(prim))
+                        if prim.rank().is_some() =>
+                    {
+                        Self::wrap_constant(v, prim, arch).map(EvaluationValue::Integer)
+                    }
+                    (value, AType::Primitve(_)) => Ok(value),
+                    _ => Err(EvaluationError::Pointers),
+                }
+            }
+            Self::UnaryOperator { base, op } => {
+                let value = base.evaluate(arch)?;
+
+                match (op, value) {
+                    (UnaryOperator::Arithmetic(UnaryArithmeticOp::Positive), value) => Ok(value),
+                    (
+                        UnaryOperator::Arithmetic(UnaryArithmeticOp::Negate),
+                        EvaluationValue::Integer(v),
+                    ) => Self::wrap_result(v.wrapping_neg(), &base.result_type(), arch)
+                        .map(EvaluationValue::Integer),
+                    (
+                        UnaryOperator::Arithmetic(UnaryArithmeticOp::Negate),
+                        EvaluationValue::FloatingPoint(v),
+                    ) => Ok(EvaluationValue::FloatingPoint(-v)),
+                    (UnaryOperator::Bitwise(Bitwise::Not), EvaluationValue::Integer(v)) => {
+                        Self::wrap_result(!v, &base.result_type(), arch)
+                            .map(EvaluationValue::Integer)
+                    }
+                    (UnaryOperator::Logic(UnaryLogicOp::Not), EvaluationValue::Integer(v)) => {
+                        Ok(EvaluationValue::Integer((v == 0) as i64))
+                    }
+                    _ => Err(EvaluationError::NotConstant),
+                }
+            }
+            Self::BinaryOperator { left, right, op } => {
+                let (left_value, right_value) = match (left.evaluate(arch), right.evaluate(arch)) {
+                    (Ok(EvaluationValue::Integer(l)), Ok(EvaluationValue::Integer(r))) => (l, r),
+                    // An Operand that is not constant at all takes precedence over one that only
+                    // depends on the Target
+                    (Err(EvaluationError::TargetDependent), Err(e)) | (Err(e), _) | (_, Err(e)) => {
+                        return Err(e)
+                    }
+                    _ => return Err(EvaluationError::NotConstant),
+                };
+
+                // The Operands of a Comparison have been converted to a common Type, which
+                // is different from the Type of the Result
+                let operand_ty = left.result_type();
+                let result =
+                    Self::evaluate_integer_operation(op, left_value, right_value, &operand_ty)?;
+                let result = Self::wrap_result(result, &self.result_type(), arch)?;
+
+                Ok(EvaluationValue::Integer(result))
+            }
+            // Only the selected Operand is evaluated, so the other one does not need to be
+            // constant
+            Self::InlineConditional {
+                condition,
+                left,
+                right,
+            } => {
+                let selected = match condition.evaluate(arch)? {
+                    EvaluationValue::Integer(v) => v != 0,
+                    EvaluationValue::FloatingPoint(v) => v != 0.0,
+                };
+
+                if selected {
+                    left.evaluate(arch)
+                } else {
+                    right.evaluate(arch)
+                }
+            }
+            Self::SizeOf { ty, .. } if ty.variable_length().is_some() => {
+                Err(EvaluationError::NotConstant)
+            }
+            Self::SizeOf { ty, .. } => arch
+                .map(|arch| EvaluationValue::Integer(ty.byte_size(arch) as i64))
+                .ok_or(EvaluationError::TargetDependent),
+            Self::AlignOf { ty, .. } => arch
+                .map(|arch| EvaluationValue::Integer(ty.alignment(arch) as i64))
+                .ok_or(EvaluationError::TargetDependent),
+            _ => Err(EvaluationError::NotConstant),
         }
     }
 
     pub fn result_type(&self) -> AType {
         match self {
             Self::Literal(lit) => match lit {
-                Literal::Integer(_) => AType::Primitve(APrimitive::LongInt),
-                Literal::FloatingPoint(_) => AType::Primitve(APrimitive::Float),
-                Literal::StringLiteral(_) => {
-                    AType::Pointer(Box::new(AType::Primitve(APrimitive::Char)))
+                Literal::Integer(_, ty) | Literal::FloatingPoint(_, ty) => {
+                    AType::Primitve(ty.clone())
+                }
+                Literal::StringLiteral(_, encoding) => {
+                    AType::Pointer(Box::new(AType::Primitve(Literal::unit_type(*encoding))))
                 }
-                Literal::CharLiteral(_) => AType::Primitve(APrimitive::Char),
+                Literal::CharLiteral(_, encoding) => AType::Primitve(Literal::unit_type(*encoding)),
             },
-            Self::Variable { ty, .. } => ty.data.clone(),
+            // Reading the Value of an Object drops the Qualifiers of its Type
+            Self::Variable { .. } | Self::ArrayAccess { .. } | Self::StructAccess(_) => {
+                self.object_type().unqualified()
+            }
             Self::AddressOf { ty, .. } => ty.clone(),
-            Self::SizeOf { .. } => AType::Primitve(APrimitive::UnsignedInt),
-            Self::ArrayAccess { ty, .. } => ty.data.clone(),
-            Self::StructAccess(StructAccess { ty, .. }) => ty.clone(),
+            // size_t
+            Self::SizeOf { .. } | Self::AlignOf { .. } => {
+                AType::Primitve(APrimitive::UnsignedLongInt)
+            }
             Self::FunctionCall(FunctionCall { result_ty, .. }) => result_ty.clone(),
+            Self::IndirectCall(IndirectCall { result_ty, .. }) => result_ty.clone(),
+            Self::FunctionAddress { ty, .. } => ty.clone(),
             Self::Cast { target, .. } => target.clone(),
             Self::BinaryOperator { op, left, right } => match op {
                 AOperator::Comparison(_) => AType::Primitve(APrimitive::Int),
@@ -577,42 +913,71 @@ impl AExpression {
 
                     left.result_type()
                 }
-                AOperator::Bitwise(_) => AType::Primitve(APrimitive::Int),
+                AOperator::Bitwise(_) => left.result_type(),
             },
+            Self::PointerArithmetic(arith) => arith.result_type(),
+            // The Base of -, + and ~ has already been promoted and the Increments keep the Type
+            // of the modified Object
             Self::UnaryOperator { op, base } => match op {
-                UnaryOperator::Arithmetic(_) => AType::Primitve(APrimitive::Int),
+                UnaryOperator::Arithmetic(_) => base.result_type(),
                 UnaryOperator::Logic(_) => AType::Primitve(APrimitive::Int),
-                UnaryOperator::Bitwise(_) => AType::Primitve(APrimitive::Int),
-                UnaryOperator::Derference => match base.result_type() {
-                    AType::Pointer(inner) => *inner,
-                    other => {
-                        dbg!(&other);
-                        todo!()
-                    }
-                },
+                UnaryOperator::Bitwise(_) => base.result_type(),
+                UnaryOperator::Derference => self.object_type().unqualified(),
             },
             Self::InlineConditional { left, right, .. } => {
                 debug_assert_eq!(left.result_type(), right.result_type());
 
                 left.result_type()
             }
+            Self::VarArgsStart { .. } => AType::va_list(),
+            Self::VaArg(VaArg { ty, .. }) => ty.clone(),
+            Self::InitializerList(list) => list.ty.clone(),
             Self::InlineAssembly { .. } => AType::Primitve(APrimitive::Void),
         }
     }
+
+    /// The Type of the Object this Expression refers to, including its Qualifiers, which is
+    /// needed to check whether the Object can be modified or its Address taken.
+    ///
+    /// For Expressions that do not refer to an Object, this is the same as the
+    /// [`AExpression::result_type`]
+    pub fn object_type(&self) -> AType {
+        match self {
+            Self::Variable { ty, .. } | Self::ArrayAccess { ty, .. } => ty.data.clone(),
+            Self::StructAccess(StructAccess { ty, .. }) => ty.clone(),
+            Self::UnaryOperator {
+                op: UnaryOperator::Derference,
+                base,
+            } => match base.result_type().ty() {
+                AType::Pointer(inner) => *inner,
+                other => {
+                    dbg!(&other);
+                    todo!()
+                }
+            },
+            other => other.result_type(),
+        }
+    }
+
     pub fn entire_span(&self) -> Span {
         match &self {
             Self::Literal(lit) => match lit {
-                Literal::Integer(SpanData { span, .. }) => span.clone(),
-                Literal::FloatingPoint(SpanData { span, .. }) => span.clone(),
-                Literal::StringLiteral(SpanData { span, .. }) => span.clone(),
-                Literal::CharLiteral(SpanData { span, .. }) => span.clone(),
+                Literal::Integer(SpanData { span, .. }, _) => span.clone(),
+                Literal::FloatingPoint(SpanData { span, .. }, _) => span.clone(),
+                Literal::StringLiteral(SpanData { span, .. }, _) => span.clone(),
+                Literal::CharLiteral(SpanData { span, .. }, _) => span.clone(),
             },
             Self::Variable { src, .. } => src.0.span.clone(),
             Self::AddressOf { base, .. } => base.entire_span(),
-            Self::SizeOf { area, .. } => area.clone(),
+            Self::SizeOf { area, .. } | Self::AlignOf { area, .. } => area.clone(),
+            Self::VarArgsStart { area } => area.clone(),
+            Self::VaArg(VaArg { area, .. }) => area.clone(),
+            Self::InitializerList(list) => list.area.clone(),
             Self::ArrayAccess { base, .. } => base.entire_span(),
             Self::StructAccess(StructAccess { field, .. }) => field.0.span.clone(),
             Self::FunctionCall(FunctionCall { name, .. }) => name.0.span.clone(),
+            Self::IndirectCall(IndirectCall { target, .. }) => target.entire_span(),
+            Self::FunctionAddress { name, .. } => name.0.span.clone(),
             Self::Cast { base, .. } => base.entire_span(),
             Self::BinaryOperator { left, right, .. } => {
                 let left_span = left.entire_span();
@@ -625,6 +990,7 @@ impl AExpression {
 
                 Span::new_arc_source(source, start..end)
             }
+            Self::PointerArithmetic(arith) => arith.entire_span(),
             Self::UnaryOperator { base, .. } => base.entire_span(),
             Self::InlineConditional {
                 condition, right, ..
@@ -670,7 +1036,14 @@ impl AExpression {
                 tmp
             }
             Self::AddressOf { base, .. } => base.used_variables(),
-            Self::SizeOf { .. } => BTreeSet::new(),
+            Self::SizeOf { ty, .. } => match ty.variable_length() {
+                Some(length) => length.used_variables(),
+                None => BTreeSet::new(),
+            },
+            Self::AlignOf { .. } => BTreeSet::new(),
+            Self::VarArgsStart { .. } => BTreeSet::new(),
+            Self::VaArg(VaArg { list, .. }) => list.used_variables(),
+            Self::InitializerList(list) => list.used_variables(),
             Self::ArrayAccess { base, index, .. } => {
                 let mut tmp = BTreeSet::new();
 
@@ -689,6 +1062,16 @@ impl AExpression {
 
                 tmp
             }
+            Self::IndirectCall(call) => {
+                let mut tmp = call.target.used_variables();
+
+                for arg in call.arguments.iter() {
+                    tmp.extend(arg.used_variables());
+                }
+
+                tmp
+            }
+            Self::FunctionAddress { .. } => BTreeSet::new(),
             Self::Cast { base, .. } => base.used_variables(),
             Self::BinaryOperator { left, right, .. } => {
                 let mut tmp = BTreeSet::new();
@@ -696,6 +1079,7 @@ impl AExpression {
                 tmp.extend(right.used_variables());
                 tmp
             }
+            Self::PointerArithmetic(arith) => arith.used_variables(),
             Self::UnaryOperator { base, .. } => base.used_variables(),
             Self::InlineConditional {
                 condition,
@@ -720,6 +1104,129 @@ impl AExpression {
         }
     }
 
+    /// Whether or not the Expression contains Short-Circuit Operations, which are converted into
+    /// their own Control-Flow and therefore split the Block they are evaluated in
+    pub fn short_circuits(&self) -> bool {
+        fn entries_short_circuit(entries: &[Option<AInitializer>]) -> bool {
+            entries.iter().flatten().any(|entry| match entry {
+                AInitializer::Single(exp) => exp.short_circuits(),
+                AInitializer::List(inner) => entries_short_circuit(inner),
+            })
+        }
+
+        match self {
+            Self::BinaryOperator {
+                op: AOperator::Combinator(_),
+                ..
+            }
+            | Self::InlineConditional { .. } => true,
+            Self::Literal(_)
+            | Self::Variable { .. }
+            | Self::AlignOf { .. }
+            | Self::VarArgsStart { .. }
+            | Self::FunctionAddress { .. }
+            | Self::InlineAssembly { .. } => false,
+            Self::SizeOf { ty, .. } => ty
+                .variable_length()
+                .map(|length| length.short_circuits())
+                .unwrap_or(false),
+            Self::AddressOf { base, .. }
+            | Self::StructAccess(StructAccess { base, .. })
+            | Self::Cast { base, .. }
+            | Self::UnaryOperator { base, .. }
+            | Self::VaArg(VaArg { list: base, .. }) => base.short_circuits(),
+            Self::InitializerList(list) => entries_short_circuit(&list.entries),
+            Self::ArrayAccess { base, index, .. } => {
+                base.short_circuits() || index.short_circuits()
+            }
+            Self::FunctionCall(call) => call.arguments.iter().any(|arg| arg.short_circuits()),
+            Self::IndirectCall(call) => {
+                call.target.short_circuits() || call.arguments.iter().any(|a| a.short_circuits())
+            }
+            Self::BinaryOperator { left, right, .. } => {
+                left.short_circuits() || right.short_circuits()
+            }
+            Self::PointerArithmetic(
+                PointerArithmetic::Offset {
+                    base: left,
+                    offset: right,
+                    ..
+                }
+                | PointerArithmetic::Difference { left, right, .. },
+            ) => left.short_circuits() || right.short_circuits(),
+        }
+    }
+
+    /// Converts the Value of the Expression to a _Bool, which is 0 if the Value compares equal
+    /// to 0 and 1 otherwise
+    fn bool_value(self, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+        let bool_ty = APrimitive::Bool.to_ir(ctx.arch());
+
+        if let Self::Literal(_) = &self {
+            if let Ok(value) = self.const_evaluate() {
+                let truth = match value {
+                    EvaluationValue::Integer(v) => v != 0,
+                    EvaluationValue::FloatingPoint(v) => v != 0.0,
+                };
+                return Value::Constant(ir::Constant::U8(truth as u8));
+            }
+        }
+
+        // Comparisons and _Bools already result in either 0 or 1, so they only need to be
+        // truncated
+        let normalized = match &self {
+            Self::BinaryOperator {
+                op: AOperator::Comparison(_) | AOperator::Combinator(_),
+                ..
+            }
+            | Self::UnaryOperator {
+                op: UnaryOperator::Logic(UnaryLogicOp::Not),
+                ..
+            } => true,
+            other => *other.result_type().into_ty() == AType::Primitve(APrimitive::Bool),
+        };
+
+        let value = self.to_ir(block, ctx);
+        let operand = Self::val_to_operand(value, block, ctx);
+
+        let result_value = if normalized {
+            Value::Expression(ir::Expression::Cast {
+                target: bool_ty.clone(),
+                base: operand,
+            })
+        } else {
+            Value::Expression(ir::Expression::BinaryOp {
+                op: ir::BinaryOp::Logic(ir::BinaryLogicOp::NotEqual),
+                right: ir::Operand::Constant(zero_constant(&operand.ty())),
+                left: operand,
+            })
+        };
+
+        let result =
+            ir::Variable::tmp(ctx.next_tmp(), bool_ty).set_description("Value converted to _Bool");
+        block.add_statement(ir::Statement::Assignment {
+            target: result.clone(),
+            value: result_value,
+        });
+
+        Value::Variable(result)
+    }
+
+    /// Converts an Array-Index into a `ptrdiff_t` Operand, which has the Size of a Pointer, so
+    /// that it can be used in the Address-Calculation
+    pub(crate) fn index_operand(self, block: &mut BasicBlock, ctx: &ConvertContext) -> ir::Operand {
+        let index = match self.result_type().to_ir(ctx.arch()) {
+            ty if ty == ctx.arch().data_model().ptrdiff_type() => self,
+            _ => Self::Cast {
+                base: Box::new(self),
+                target: AType::Primitve(APrimitive::LongInt),
+            },
+        };
+
+        let index_value = index.to_ir(block, ctx);
+        Self::val_to_operand(index_value, block, ctx)
+    }
+
     pub fn val_to_operand(value: Value, block: &BasicBlock, ctx: &ConvertContext) -> ir::Operand {
         match value {
             Value::Unknown => {
@@ -782,20 +1289,36 @@ impl AExpression {
                     ir::Operand::Variable(tmp_var)
                 }
                 ir::Expression::AdressOf { base } => {
-                    let _tmp_var = ir::Variable::tmp(ctx.next_tmp(), base.ty())
-                        .set_description("Temp Variable for AdressOf");
+                    let tmp_var =
+                        ir::Variable::tmp(ctx.next_tmp(), ir::Type::Pointer(Box::new(base.ty())))
+                            .set_description("Temp Variable for AdressOf");
 
-                    todo!("Generate Operand for AdressOf Operation")
+                    let value = Value::Expression(exp);
+                    let tmp_meta = value.assign_meta(&tmp_var);
+                    let tmp_var = tmp_var.set_meta(tmp_meta);
 
-                    /*
                     let assign_statement = ir::Statement::Assignment {
                         target: tmp_var.clone(),
-                        value: Value::Expression(base),
+                        value,
                     };
                     block.add_statement(assign_statement);
 
                     ir::Operand::Variable(tmp_var)
-                    */
+                }
+                ir::Expression::GlobalAddress { name } => {
+                    let global_var = ctx.get_global(name).unwrap();
+                    let tmp_var = ir::Variable::tmp(
+                        ctx.next_tmp(),
+                        ir::Type::Pointer(Box::new(global_var.ty.clone())),
+                    )
+                    .set_description("Temp Variable for GlobalAddress");
+
+                    block.add_statement(ir::Statement::Assignment {
+                        target: tmp_var.clone(),
+                        value: Value::Expression(exp),
+                    });
+
+                    ir::Operand::Variable(tmp_var)
                 }
                 ir::Expression::ReadGlobalVariable { name } => {
                     let global_var = ctx.get_global(name).unwrap();
@@ -819,8 +1342,18 @@ impl AExpression {
         match self {
             AExpression::Literal(lit) => lit.to_value(block, ctx),
             AExpression::Variable { name, .. } => {
-                if ctx.get_global(&name).is_some() {
-                    return Value::Expression(ir::Expression::ReadGlobalVariable { name });
+                // Globals are read into a temporary Variable of their own Type, so the Read
+                // does not depend on the Type of the Variable it is assigned to
+                if let Some(global) = ctx.get_global(&name) {
+                    let tmp_var = ir::Variable::tmp(ctx.next_tmp(), global.ty.clone())
+                        .set_description("Read Global Variable");
+
+                    block.add_statement(ir::Statement::Assignment {
+                        target: tmp_var.clone(),
+                        value: Value::Expression(ir::Expression::ReadGlobalVariable { name }),
+                    });
+
+                    return Value::Variable(tmp_var);
                 }
 
                 let var = block.definition(&name, &|| ctx.next_tmp(), None).unwrap();
@@ -828,6 +1361,11 @@ impl AExpression {
 
                 Value::Variable(var)
             }
+            AExpression::BinaryOperator {
+                op: AOperator::Combinator(comb),
+                left,
+                right,
+            } => conditional::combinator_to_ir(comb, *left, *right, block, ctx),
             AExpression::BinaryOperator { op, left, right } => {
                 let ir_op = op.to_ir();
 
@@ -844,7 +1382,26 @@ impl AExpression {
                 })
             }
             AExpression::Cast { base, target } => {
-                let target_ty = target.to_ir();
+                if *target.into_ty() == AType::Primitve(APrimitive::Bool) {
+                    return base.bool_value(block, ctx);
+                }
+
+                let target_ty = target.to_ir(ctx.arch());
+
+                // Converting between Pointers that only differ in the Qualifiers of the pointed
+                // to Type, like from "int*" to "const int*", does not change the Value
+                let base_ty = base.result_type();
+                if matches!(base_ty.into_ty(), AType::Pointer(_))
+                    && base_ty.to_ir(ctx.arch()) == target_ty
+                {
+                    return base.to_ir(block, ctx);
+                }
+
+                if let AExpression::Literal(lit) = base.as_ref() {
+                    if let Some(constant) = lit.cast_constant(&target_ty) {
+                        return Value::Constant(constant);
+                    }
+                }
 
                 let value = base.to_ir(block, ctx);
                 let val_operand = Self::val_to_operand(value, block, ctx);
@@ -854,12 +1411,42 @@ impl AExpression {
                     base: val_operand,
                 })
             }
+            AExpression::PointerArithmetic(arith) => arith.to_ir(block, ctx),
+            AExpression::InlineConditional {
+                condition,
+                left,
+                right,
+            } => conditional::inline_conditional_to_ir(*condition, *left, *right, block, ctx),
             AExpression::UnaryOperator { base, op } => op.to_ir(base, block, ctx),
             AExpression::FunctionCall(call) => call.to_ir(block, ctx),
+            AExpression::IndirectCall(call) => call.to_ir(block, ctx),
+            AExpression::VarArgsStart { .. } => Value::Expression(ir::Expression::VarArgsStart),
+            AExpression::VaArg(va_arg) => va_arg.to_ir(block, ctx),
+            AExpression::InitializerList(list) => list.to_ir(block, ctx),
+            AExpression::FunctionAddress { name, ty } => {
+                let tmp_var = ir::Variable::tmp(ctx.next_tmp(), ty.to_ir(ctx.arch()))
+                    .set_description("Address of Function");
+
+                block.add_statement(ir::Statement::Assignment {
+                    target: tmp_var.clone(),
+                    value: Value::Expression(ir::Expression::FunctionAddress {
+                        name: ctx.function_name(&name.0.data),
+                    }),
+                });
+
+                Value::Variable(tmp_var)
+            }
             AExpression::AddressOf { base, .. } => {
+                // Variables for Arrays, Structs and Unions already store the Address of their
+                // Data, so that Address is also the Address of the Variable
+                let aggregate = matches!(
+                    base.result_type().ty(),
+                    AType::Array(_) | AType::Struct { .. } | AType::Union { .. }
+                );
                 let base_value = base.ir_address(block, ctx);
 
                 match &base_value {
+                    ir::Value::Variable(_) if aggregate => base_value,
                     ir::Value::Variable(_) => {
                         let base_oper = Self::val_to_operand(base_value, block, ctx);
 
@@ -873,18 +1460,17 @@ impl AExpression {
                 }
             }
             AExpression::ArrayAccess { base, ty, index } => {
-                let base_address_value = base.ir_address(block, ctx);
+                let base_address_value = base.elements_address(block, ctx);
                 let base_oper = Self::val_to_operand(base_address_value, block, ctx);
 
-                let index_value = index.to_ir(block, ctx);
-                let index_oper = Self::val_to_operand(index_value, block, ctx);
+                let index_oper = index.index_operand(block, ctx);
 
                 let element_size = ty.data.byte_size(ctx.arch());
 
                 let offset_value = ir::Value::Expression(ir::Expression::BinaryOp {
                     op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
                     left: index_oper,
-                    right: ir::Operand::Constant(ir::Constant::I64(element_size as i64)),
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(element_size as i64)),
                 });
                 let offset_oper = Self::val_to_operand(offset_value, block, ctx);
 
@@ -894,9 +1480,14 @@ impl AExpression {
                     right: offset_oper,
                 };
 
+                let volatile = ty.data.is_volatile();
                 let target_ty = ty.data.ty();
                 match &target_ty {
-                    AType::Primitve(_) => {
+                    // Elements that are Aggregates themselves are represented by their Address
+                    AType::Array(_) | AType::Struct { .. } | AType::Union { .. } => {
+                        ir::Value::Expression(target_addr_exp)
+                    }
+                    _ => {
                         let target_addr_oper = Self::val_to_operand(
                             ir::Value::Expression(target_addr_exp),
                             block,
@@ -905,48 +1496,70 @@ impl AExpression {
 
                         Value::Expression(ir::Expression::ReadMemory {
                             address: target_addr_oper,
-                            read_ty: target_ty.to_ir(),
+                            read_ty: target_ty.to_ir(ctx.arch()),
+                            volatile,
                         })
                     }
-                    AType::Array(_) | AType::Struct { .. } => {
-                        ir::Value::Expression(target_addr_exp)
-                    }
-                    other => {
-                        dbg!(&other);
-
-                        todo!("Array Access for non Primitive Type");
-                    }
                 }
             }
-            AExpression::StructAccess(StructAccess { base, field, .. }) => {
+            AExpression::StructAccess(StructAccess { base, field, ty }) => {
                 let base_ty = base.result_type().ty();
                 let (s_def, _) = base_ty.get_struct_def().unwrap();
+                let offset = base_ty.member_offset(&field.0.data, ctx.arch()).unwrap();
 
-                let base_addr_value = base.ir_address(block, ctx);
+                // For "->" the Pointer itself needs to be loaded to get the Address of the Struct
+                let base_addr_value = base.elements_address(block, ctx);
                 let base_oper = Self::val_to_operand(base_addr_value, block, ctx);
 
                 let raw_field_ty = s_def.find_member(&field).unwrap().data;
-                let field_ty = raw_field_ty.to_ir();
-
-                let offset = s_def.member_offset(&field.0.data, ctx.arch()).unwrap();
+                let field_ty = raw_field_ty.clone().to_ir(ctx.arch());
 
                 let offset_value = Value::Expression(ir::Expression::BinaryOp {
                     op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
                     left: base_oper,
-                    right: ir::Operand::Constant(ir::Constant::I64(offset as i64)),
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(offset as i64)),
                 });
 
                 let offset_oper = Self::val_to_operand(offset_value, block, ctx);
 
+                if let Some(bit_field) = base_ty.bit_field(&field.0.data, ctx.arch()) {
+                    return read_bit_field(&bit_field, offset_oper, &ty, block, ctx);
+                }
+
                 Value::Expression(ir::Expression::ReadMemory {
                     address: offset_oper,
                     read_ty: field_ty,
+                    volatile: ty.is_volatile(),
                 })
             }
             AExpression::SizeOf { ty, .. } => {
+                let size_ty = ctx.arch().data_model().size_type();
+
+                // The Size of a variable length Array is only known at Runtime
+                if let (Some(length), AType::Array(arr)) = (ty.variable_length(), ty.into_ty()) {
+                    let length_exp = AExpression::Cast {
+                        base: Box::new(length.clone()),
+                        target: AType::Primitve(APrimitive::UnsignedLongInt),
+                    };
+                    let length_oper =
+                        Self::val_to_operand(length_exp.to_ir(block, ctx), block, ctx);
+
+                    let element_size = arr.ty.byte_size(ctx.arch());
+                    return Value::Expression(ir::Expression::BinaryOp {
+                        op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
+                        left: length_oper,
+                        right: ir::Operand::Constant(unit_constant(element_size, &size_ty)),
+                    });
+                }
+
                 let size = ty.byte_size(ctx.arch());
+                ir::Value::Constant(unit_constant(size, &size_ty))
+            }
+            AExpression::AlignOf { ty, .. } => {
+                let alignment = ty.alignment(ctx.arch());
+                let size_ty = ctx.arch().data_model().size_type();
 
-                ir::Value::Constant(ir::Constant::I64(size as i64))
+                ir::Value::Constant(unit_constant(alignment, &size_ty))
             }
             other => {
                 dbg!(&other);
@@ -956,6 +1569,15 @@ impl AExpression {
         }
     }
 
+    /// The Address of the first Element of an Array or the Address stored in a Pointer, which
+    /// is the Base for Array-Accesses and Pointer-Arithmetic
+    pub(crate) fn elements_address(self, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+        match self.result_type().ty() {
+            AType::Pointer(_) => self.to_ir(block, ctx),
+            _ => self.ir_address(block, ctx),
+        }
+    }
+
     /// This is used to convert the Expression into a Value that contains the Target Address for
     /// some other access.
     /// The resulting address will then be used in things like "ReadMemory" or "WriteMemory" or to
@@ -965,44 +1587,60 @@ impl AExpression {
             Self::Variable {
                 ref name, ref ty, ..
             } => {
-                let var = match ctx.get_global(name) {
-                    Some(g) => g.clone(),
-                    None => block.definition(name, &|| ctx.next_tmp(), None).unwrap(),
+                let (var, global) = match ctx.get_global(name) {
+                    Some(g) => (g.clone(), true),
+                    None => (
+                        block.definition(name, &|| ctx.next_tmp(), None).unwrap(),
+                        false,
+                    ),
                 };
 
                 match ty.data.clone().ty() {
-                    AType::Pointer(_) | AType::Array(_) | AType::Struct { .. } => {
-                        if var.global() {
-                            let next_var = var.next_gen();
+                    AType::Pointer(_)
+                    | AType::Array(_)
+                    | AType::Struct { .. }
+                    | AType::Union { .. } => {
+                        // The Value is read into a temporary Variable, like for any other Read
+                        // of a Global, which can then be treated like any other local Variable
+                        if global {
+                            let tmp_var = ir::Variable::tmp(ctx.next_tmp(), var.ty.clone())
+                                .set_description("Read Global Variable");
                             block.add_statement(ir::Statement::Assignment {
-                                target: next_var.clone(),
+                                target: tmp_var.clone(),
                                 value: ir::Value::Expression(ir::Expression::ReadGlobalVariable {
                                     name: var.name().to_string(),
                                 }),
                             });
 
-                            return Value::Variable(next_var);
+                            return Value::Variable(tmp_var);
                         }
 
                         ir::Value::Variable(var)
                     }
+                    // Other Globals are only stored in Memory, so their Address is the one of
+                    // their static Data. While converting the Global-Block the Globals are not
+                    // yet known to the Context, but every Variable in it is a Global
+                    _ if global || ctx.global() => {
+                        ir::Value::Expression(ir::Expression::GlobalAddress {
+                            name: var.name().to_string(),
+                        })
+                    }
                     _ => ir::Value::Expression(ir::Expression::AdressOf {
                         base: ir::Operand::Variable(var),
                     }),
                 }
             }
             Self::ArrayAccess { base, ty, index } => {
-                let base_address = base.ir_address(block, ctx);
+                let base_address = base.elements_address(block, ctx);
 
-                let index_value = index.to_ir(block, ctx);
-                let index_oper = Self::val_to_operand(index_value, block, ctx);
+                let index_oper = index.index_operand(block, ctx);
 
                 let ty_size = ty.data.byte_size(ctx.arch());
 
                 let offset_value = ir::Value::Expression(ir::Expression::BinaryOp {
                     op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
                     left: index_oper,
-                    right: ir::Operand::Constant(ir::Constant::I64(ty_size as i64)),
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(ty_size as i64)),
                 });
 
                 let base_oper = Self::val_to_operand(base_address, block, ctx);
@@ -1018,17 +1656,15 @@ impl AExpression {
             }
             Self::StructAccess(StructAccess { base, field, .. }) => {
                 let base_ty = base.result_type();
-                let (struct_def, _) = base_ty.get_struct_def().unwrap();
+                let field_offset = base_ty.member_offset(&field.0.data, ctx.arch()).unwrap();
 
-                let base_address = base.ir_address(block, ctx);
+                let base_address = base.elements_address(block, ctx);
                 let base_address_oper = Self::val_to_operand(base_address, block, ctx);
 
-                let field_offset = struct_def.member_offset(&field.0.data, ctx.arch()).unwrap();
-
                 let target_addr_exp = ir::Expression::BinaryOp {
                     op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
                     left: base_address_oper,
-                    right: ir::Operand::Constant(ir::Constant::I64(field_offset as i64)),
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(field_offset as i64)),
                 };
 
                 ir::Value::Expression(target_addr_exp)
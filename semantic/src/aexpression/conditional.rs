@@ -0,0 +1,177 @@
+use ir::{BasicBlock, BlockBuilder, Value};
+
+use crate::{conversion::ConvertContext, unit_constant, AExpression, APrimitive, LogicCombinator};
+
+/// Converts "&&" and "||" into Control-Flow, so that the right Side is only evaluated if the
+/// left Side does not already determine the Result.
+///
+/// Afterwards the given Block is replaced by the Block in which both Paths join again
+pub(crate) fn combinator_to_ir(
+    op: LogicCombinator,
+    left: AExpression,
+    right: AExpression,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> Value {
+    let result_ty = APrimitive::Int.to_ir(ctx.arch());
+
+    let left_value = left.bool_value(block, ctx);
+    let left_var = condition_var(left_value, block, ctx);
+
+    // The Result, if the right Side is skipped
+    let skipped_result = match op {
+        LogicCombinator::And => 0,
+        LogicCombinator::Or => 1,
+    };
+    let result_var = ir::Variable::tmp(ctx.next_tmp(), result_ty.clone())
+        .set_description("Result of Short-Circuit Operation");
+    block.add_statement(ir::Statement::Assignment {
+        target: result_var.clone(),
+        value: Value::Constant(unit_constant(skipped_result, &result_ty)),
+    });
+
+    let right_block = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+        .description("Short-Circuit Right Side")
+        .build();
+    let end_block = BlockBuilder::new(vec![], vec![])
+        .description("Short-Circuit End")
+        .build();
+
+    match op {
+        LogicCombinator::And => {
+            block.add_statement(ir::Statement::JumpTrue(
+                left_var,
+                right_block.clone(),
+                ir::JumpMetadata::Branch,
+            ));
+            block.add_statement(ir::Statement::Jump(
+                end_block.clone(),
+                ir::JumpMetadata::Branch,
+            ));
+        }
+        LogicCombinator::Or => {
+            block.add_statement(ir::Statement::JumpTrue(
+                left_var,
+                end_block.clone(),
+                ir::JumpMetadata::Branch,
+            ));
+            block.add_statement(ir::Statement::Jump(
+                right_block.clone(),
+                ir::JumpMetadata::Branch,
+            ));
+        }
+    };
+    end_block.add_predecessor(block.weak_ptr());
+
+    let mut right_end = right_block;
+    let right_value = right.bool_value(&mut right_end, ctx);
+    let right_oper = AExpression::val_to_operand(right_value, &right_end, ctx);
+    right_end.add_statement(ir::Statement::Assignment {
+        target: result_var.next_gen(),
+        value: Value::Expression(ir::Expression::Cast {
+            target: result_ty,
+            base: right_oper,
+        }),
+    });
+
+    join(result_var, right_end, end_block, block, ctx)
+}
+
+/// Converts the Conditional-Operator into Control-Flow, so that only the selected Side is
+/// evaluated.
+///
+/// Afterwards the given Block is replaced by the Block in which both Paths join again
+pub(crate) fn inline_conditional_to_ir(
+    condition: AExpression,
+    left: AExpression,
+    right: AExpression,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> Value {
+    let result_ty = left.result_type().to_ir(ctx.arch());
+
+    let cond_value = condition.bool_value(block, ctx);
+    let cond_var = condition_var(cond_value, block, ctx);
+
+    let true_block = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+        .description("Conditional-Operator True Side")
+        .build();
+    let false_block = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+        .description("Conditional-Operator False Side")
+        .build();
+    let end_block = BlockBuilder::new(vec![], vec![])
+        .description("Conditional-Operator End")
+        .build();
+
+    block.add_statement(ir::Statement::JumpTrue(
+        cond_var,
+        true_block.clone(),
+        ir::JumpMetadata::Branch,
+    ));
+    block.add_statement(ir::Statement::Jump(
+        false_block.clone(),
+        ir::JumpMetadata::Branch,
+    ));
+
+    let result_var = ir::Variable::tmp(ctx.next_tmp(), result_ty)
+        .set_description("Result of Conditional-Operator");
+
+    let mut true_end = true_block;
+    let true_value = left.to_ir(&mut true_end, ctx);
+    true_end.add_statement(ir::Statement::Assignment {
+        target: result_var.clone(),
+        value: true_value,
+    });
+    true_end.add_statement(ir::Statement::Jump(
+        end_block.clone(),
+        ir::JumpMetadata::Branch,
+    ));
+    end_block.add_predecessor(true_end.weak_ptr());
+
+    let mut false_end = false_block;
+    let false_value = right.to_ir(&mut false_end, ctx);
+    false_end.add_statement(ir::Statement::Assignment {
+        target: result_var.next_gen(),
+        value: false_value,
+    });
+
+    join(result_var, false_end, end_block, block, ctx)
+}
+
+/// Stores the Value in a Variable, that can be used to conditionally jump
+fn condition_var(value: Value, block: &BasicBlock, ctx: &ConvertContext) -> ir::Variable {
+    match value {
+        Value::Variable(var) => var,
+        other => {
+            let cond_var = ir::Variable::tmp(ctx.next_tmp(), APrimitive::Bool.to_ir(ctx.arch()));
+            block.add_statement(ir::Statement::Assignment {
+                target: cond_var.clone(),
+                value: other,
+            });
+            cond_var
+        }
+    }
+}
+
+/// Jumps from the last Path into the End-Block and combines the Definitions of the Result from
+/// all the Paths using a Phi-Node
+fn join(
+    result_var: ir::Variable,
+    last_path: BasicBlock,
+    end_block: BasicBlock,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> Value {
+    last_path.add_statement(ir::Statement::Jump(
+        end_block.clone(),
+        ir::JumpMetadata::Branch,
+    ));
+    end_block.add_predecessor(last_path.weak_ptr());
+
+    let result = end_block
+        .definition(result_var.name(), &|| ctx.next_tmp(), None)
+        .expect("The Result is defined on every Path");
+
+    *block = end_block;
+    Value::Variable(result)
+}
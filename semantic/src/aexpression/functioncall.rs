@@ -1,13 +1,43 @@
+use general::SpanData;
 use ir::{BasicBlock, Operand, Value};
-use syntax::Identifier;
+use syntax::{Expression, Identifier};
 
-use crate::{conversion::ConvertContext, AExpression, AType};
+use super::initializer::copy_object;
+use crate::{
+    atype, conversion::ConvertContext, AExpression, APrimitive, AType, InvalidOperation, Literal,
+    SemanticError, TypeDefinitions, VariableContainer,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionCall {
     pub name: Identifier,
     pub arguments: Vec<AExpression>,
     pub result_ty: AType,
+    /// The Number of fixed Arguments, if the called Function is variadic
+    pub variadic: Option<usize>,
+}
+
+/// Applies the default Argument Promotions to a variable Argument, because there is no declared
+/// Type to convert it to. Integers smaller than an int are passed as an int and floats as a
+/// double
+pub(crate) fn promote_var_arg(arg: AExpression) -> AExpression {
+    let target = match (&arg, arg.result_type().into_ty()) {
+        (AExpression::Literal(Literal::CharLiteral(..)), _) => APrimitive::Int,
+        (_, AType::Primitve(prim)) => match prim {
+            APrimitive::Char
+            | APrimitive::UnsignedChar
+            | APrimitive::Short
+            | APrimitive::UnsignedShort => APrimitive::Int,
+            APrimitive::Float => APrimitive::Double,
+            _ => return arg,
+        },
+        _ => return arg,
+    };
+
+    AExpression::Cast {
+        base: Box::new(arg),
+        target: AType::Primitve(target),
+    }
 }
 
 impl FunctionCall {
@@ -18,16 +48,72 @@ impl FunctionCall {
     ) -> Vec<ir::Operand> {
         let mut args = Vec::new();
         for tmp_arg in arguments {
+            let arg_ty = tmp_arg.result_type();
             let arg_value = tmp_arg.to_ir(block, ctx);
 
             let arg_oper = AExpression::val_to_operand(arg_value, block, ctx);
 
+            let arg_oper = match arg_ty.into_ty() {
+                AType::Struct { .. } => Self::struct_argument(&arg_ty, arg_oper, block, ctx),
+                _ => arg_oper,
+            };
+
             args.push(arg_oper);
         }
 
         args
     }
 
+    /// Structs are passed by Value, so the Callee receives the Address of its own Copy of the
+    /// Struct, which it is free to modify
+    fn struct_argument(
+        ty: &AType,
+        src: ir::Operand,
+        block: &mut BasicBlock,
+        ctx: &ConvertContext,
+    ) -> ir::Operand {
+        let src_var = match src {
+            ir::Operand::Variable(var) => var,
+            ir::Operand::Constant(_) => unreachable!("Structs are always stored in Memory"),
+        };
+
+        let size = ty.byte_size(ctx.arch()) as usize;
+        let alignment = ty.alignment(ctx.arch()) as usize;
+
+        let copy_var =
+            ir::Variable::tmp(ctx.next_tmp(), ir::Type::Pointer(Box::new(ir::Type::Void)));
+        block.add_statement(ir::Statement::Assignment {
+            target: copy_var.clone(),
+            value: Value::Expression(ctx.reserve_memory(size, alignment)),
+        });
+        copy_object(ty, &src_var, &copy_var, 0, block, ctx);
+
+        let arg_var = ir::Variable::tmp(ctx.next_tmp(), ty.clone().to_value_ir(ctx.arch()));
+        block.add_statement(ir::Statement::Assignment {
+            target: arg_var.clone(),
+            value: Value::Variable(copy_var),
+        });
+
+        Operand::Variable(arg_var)
+    }
+
+    /// A returned Struct is received as its Address, which is then used like the Address of any
+    /// other Struct
+    fn result_value(result: ir::Variable, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+        if !matches!(result.ty, ir::Type::Struct { .. }) {
+            return Value::Variable(result);
+        }
+
+        let address =
+            ir::Variable::tmp(ctx.next_tmp(), ir::Type::Pointer(Box::new(ir::Type::Void)));
+        block.add_statement(ir::Statement::Assignment {
+            target: address.clone(),
+            value: Value::Variable(result),
+        });
+
+        Value::Variable(address)
+    }
+
     fn cleanup_ir(args: &[Operand]) -> Vec<ir::Statement> {
         args.iter()
             .filter_map(|arg| match arg {
@@ -41,9 +127,9 @@ impl FunctionCall {
     }
 
     pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) -> ir::Value {
-        let name = self.name.0.data;
+        let name = ctx.function_name(&self.name.0.data);
         let args = Self::argument_ir(self.arguments, block, ctx);
-        let ty = self.result_ty.to_ir();
+        let ty = self.result_ty.to_value_ir(ctx.arch());
 
         let tmp_var = ir::Variable::tmp(ctx.next_tmp(), ty.clone());
 
@@ -55,6 +141,7 @@ impl FunctionCall {
                 name,
                 arguments: args,
                 return_ty: ty,
+                variadic: self.variadic,
             }),
         };
         block.add_statement(func_statement);
@@ -63,17 +150,24 @@ impl FunctionCall {
             block.add_statement(tmp);
         }
 
-        Value::Variable(tmp_var)
+        Self::result_value(tmp_var, block, ctx)
     }
 
     pub fn to_standalone_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) {
-        let name = self.name.0.data;
+        // A returned Struct still needs Memory to be stored in, even if it is never used
+        if matches!(self.result_ty.into_ty(), AType::Struct { .. }) {
+            self.to_ir(block, ctx);
+            return;
+        }
+
+        let name = ctx.function_name(&self.name.0.data);
         let args = Self::argument_ir(self.arguments, block, ctx);
         let cleanup_statements = Self::cleanup_ir(&args);
 
         let func_statemnet = ir::Statement::Call {
             name,
             arguments: args,
+            variadic: self.variadic,
         };
 
         block.add_statement(func_statemnet);
@@ -82,3 +176,137 @@ impl FunctionCall {
         }
     }
 }
+
+/// A Call to a Function through a Function-Pointer
+#[derive(Debug, PartialEq, Clone)]
+pub struct IndirectCall {
+    pub target: Box<AExpression>,
+    pub arguments: Vec<AExpression>,
+    pub result_ty: AType,
+    /// The Number of fixed Arguments, if the called Function is variadic
+    pub variadic: Option<usize>,
+}
+
+impl IndirectCall {
+    /// Parses a Call of the given Target, which has to be a Pointer to a Function, and checks
+    /// the Arguments against the Signature of that Function
+    pub fn parse<VC>(
+        target: AExpression,
+        raw_args: Vec<Expression>,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<Self, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        let target_span = target.entire_span();
+        let target_ty = target.result_type();
+        let (return_ty, arg_types, var_args) = match target_ty.into_ty() {
+            AType::Pointer(inner) => match inner.into_ty() {
+                AType::Function {
+                    return_ty,
+                    arguments,
+                    var_args,
+                } => (return_ty.as_ref().clone(), arguments.clone(), *var_args),
+                _ => {
+                    return Err(SemanticError::InvalidOperation {
+                        base: target_span,
+                        operation: InvalidOperation::FunctionCall,
+                    })
+                }
+            },
+            _ => {
+                return Err(SemanticError::InvalidOperation {
+                    base: target_span,
+                    operation: InvalidOperation::FunctionCall,
+                })
+            }
+        };
+
+        if (raw_args.len() != arg_types.len() && !var_args)
+            || (var_args && raw_args.len() < arg_types.len())
+        {
+            return Err(SemanticError::MismatchedFunctionArgsCount {
+                expected: SpanData {
+                    span: target_span.clone(),
+                    data: arg_types.len(),
+                },
+                received: SpanData {
+                    span: target_span,
+                    data: raw_args.len(),
+                },
+            });
+        }
+
+        let mut arguments = Vec::with_capacity(raw_args.len());
+        for (index, raw_arg) in raw_args.into_iter().enumerate() {
+            let arg = AExpression::parse(raw_arg, ty_defs, vars)?;
+
+            let arg = match arg_types.get(index) {
+                Some(expected) => {
+                    atype::assign_type::determine_type(arg, (expected, &target_span))?
+                }
+                None => promote_var_arg(arg),
+            };
+            arguments.push(arg);
+        }
+
+        Ok(Self {
+            target: Box::new(target),
+            arguments,
+            result_ty: return_ty,
+            variadic: var_args.then_some(arg_types.len()),
+        })
+    }
+
+    fn target_ir(target: AExpression, block: &mut BasicBlock, ctx: &ConvertContext) -> Operand {
+        let target_value = target.to_ir(block, ctx);
+        AExpression::val_to_operand(target_value, block, ctx)
+    }
+
+    pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) -> ir::Value {
+        let target = Self::target_ir(*self.target, block, ctx);
+        let args = FunctionCall::argument_ir(self.arguments, block, ctx);
+        let ty = self.result_ty.to_value_ir(ctx.arch());
+
+        let tmp_var = ir::Variable::tmp(ctx.next_tmp(), ty.clone());
+
+        let cleanup_statements = FunctionCall::cleanup_ir(&args);
+
+        block.add_statement(ir::Statement::Assignment {
+            target: tmp_var.clone(),
+            value: Value::Expression(ir::Expression::IndirectFunctionCall {
+                target,
+                arguments: args,
+                return_ty: ty,
+                variadic: self.variadic,
+            }),
+        });
+
+        for tmp in cleanup_statements {
+            block.add_statement(tmp);
+        }
+
+        FunctionCall::result_value(tmp_var, block, ctx)
+    }
+
+    pub fn to_standalone_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) {
+        if matches!(self.result_ty.into_ty(), AType::Struct { .. }) {
+            self.to_ir(block, ctx);
+            return;
+        }
+
+        let target = Self::target_ir(*self.target, block, ctx);
+        let args = FunctionCall::argument_ir(self.arguments, block, ctx);
+        let cleanup_statements = FunctionCall::cleanup_ir(&args);
+
+        block.add_statement(ir::Statement::IndirectCall {
+            target,
+            arguments: args,
+            variadic: self.variadic,
+        });
+        for c_stmnt in cleanup_statements {
+            block.add_statement(c_stmnt);
+        }
+    }
+}
@@ -0,0 +1,718 @@
+use std::collections::BTreeSet;
+
+use general::{arch::Arch, Span, SpanData};
+use ir::{BasicBlock, Value};
+use syntax::{Designator, Expression};
+
+use crate::{
+    atype, conversion::ConvertContext, object_alignment, write_bit_field, AAlignment, AExpression,
+    AType, Array, BitField, EvaluationValue, Literal, SemanticError, TypeDefinitions,
+    VariableContainer,
+};
+
+/// The Initializer for a single Object inside of an Initializer-List
+#[derive(Debug, PartialEq, Clone)]
+pub enum AInitializer {
+    /// A single Value, which is either used for a scalar Object or copied into an entire
+    /// Aggregate, like another Struct of the same Type
+    Single(AExpression),
+    /// The Initializers for the Elements of an Array or the Members of a Struct or Union, where
+    /// every Entry that was not explicitly initialized is None and gets filled with zero
+    List(Vec<Option<AInitializer>>),
+}
+
+/// An Initializer-List, like "{1, 2}" or "{ .x = 1, [3] = 5 }", for an Array, Struct or Union.
+/// This is used for the Initialization of Variables as well as for Compound-Literals
+#[derive(Debug, PartialEq, Clone)]
+pub struct InitializerList {
+    /// The Type of the initialized Object, where the Size of an Array is already determined
+    pub ty: AType,
+    /// The Initializers for all the Elements or Members of the Object
+    pub entries: Vec<Option<AInitializer>>,
+    pub area: Span,
+    /// The explicit Alignment of the initialized Variable, if it has one
+    pub alignment: Option<AAlignment>,
+}
+
+/// The Items of an Initializer-List that still need to be processed, stored in reverse Order so
+/// that the next Item can simply be popped and an Item can be put back again
+type Items = Vec<Expression>;
+
+impl InitializerList {
+    /// Parses the Initializer-List for an Object of the given Type. Scalar Types can also be
+    /// initialized using Braces, like "int x = {5};", in which case this simply returns the
+    /// Value itself
+    pub fn parse<VC>(
+        ty: AType,
+        parts: SpanData<Vec<Expression>>,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<AExpression, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        let area = parts.span.clone();
+        let parser = InitParser { ty_defs, vars };
+        let entries = match parser.parse_braced(&ty, parts)? {
+            AInitializer::Single(exp) => return Ok(exp),
+            AInitializer::List(entries) => entries,
+        };
+
+        // An Array without an explicit Size gets its Size from the initialized Elements
+        let ty = match ty {
+            AType::Array(Array {
+                size: None,
+                length: None,
+                ty,
+            }) => AType::Array(Array {
+                size: Some(entries.len()),
+                length: None,
+                ty,
+            }),
+            other => other,
+        };
+
+        Ok(AExpression::InitializerList(Self {
+            ty,
+            entries,
+            area,
+            alignment: None,
+        }))
+    }
+
+    pub fn used_variables(&self) -> BTreeSet<String> {
+        fn entries_used(entries: &[Option<AInitializer>], result: &mut BTreeSet<String>) {
+            for entry in entries.iter().flatten() {
+                match entry {
+                    AInitializer::Single(exp) => result.extend(exp.used_variables()),
+                    AInitializer::List(inner) => entries_used(inner, result),
+                };
+            }
+        }
+
+        let mut result = BTreeSet::new();
+        entries_used(&self.entries, &mut result);
+        result
+    }
+
+    /// Reserves the Memory for the Object, either on the Stack or in the static Data for
+    /// Globals, writes all the Values into it and returns the Address of the Object
+    pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+        let size = self.ty.byte_size(ctx.arch()) as usize;
+        let alignment = object_alignment(
+            self.ty.alignment(ctx.arch()) as usize,
+            self.alignment.as_ref(),
+            ctx.arch(),
+        );
+
+        let alloc_exp = ctx.reserve_memory(size, alignment);
+
+        let base_var = ir::Variable::tmp(ctx.next_tmp(), self.ty.clone().to_ir(ctx.arch()))
+            .set_description("Initializer-List");
+        block.add_statement(ir::Statement::Assignment {
+            target: base_var.clone(),
+            value: Value::Expression(alloc_exp),
+        });
+        block.add_statement(ir::Statement::SaveVariable {
+            var: base_var.clone(),
+        });
+
+        let init = AInitializer::List(self.entries);
+        write_object(&self.ty, Some(&init), &base_var, 0, block, ctx);
+
+        Value::Variable(base_var)
+    }
+}
+
+/// Returns the Array, Struct or Union Type itself, if the given Type is an Aggregate
+fn aggregate(ty: &AType) -> Option<&AType> {
+    match ty {
+        AType::TypeDef { ty, .. } | AType::Const(ty) | AType::Volatile(ty) => aggregate(ty),
+        AType::Array(_) | AType::Struct { .. } | AType::Union { .. } => Some(ty),
+        _ => None,
+    }
+}
+
+/// The Number of Sub-Objects of the Aggregate, which is None for Arrays without a Size
+fn sub_object_count(agg: &AType) -> Option<usize> {
+    match agg {
+        AType::Array(arr) => arr.size,
+        AType::Struct { def, .. } | AType::Union { def, .. } => Some(def.named_members().count()),
+        _ => unreachable!("Only called with Aggregates"),
+    }
+}
+
+fn sub_object_type(agg: &AType, index: usize) -> AType {
+    match agg {
+        AType::Array(arr) => *arr.ty.clone(),
+        AType::Struct { def, .. } | AType::Union { def, .. } => {
+            let (_, member) = def.named_members().nth(index).unwrap();
+            member.ty.clone()
+        }
+        _ => unreachable!("Only called with Aggregates"),
+    }
+}
+
+fn empty_entries(agg: &AType) -> Vec<Option<AInitializer>> {
+    vec![None; sub_object_count(agg).unwrap_or(0)]
+}
+
+fn set_entry(
+    agg: &AType,
+    entries: &mut Vec<Option<AInitializer>>,
+    index: usize,
+    init: AInitializer,
+) {
+    if entries.len() <= index {
+        entries.resize(index + 1, None);
+    }
+
+    // Only a single Member of a Union can be initialized
+    if let AType::Union { .. } = agg {
+        entries.iter_mut().for_each(|e| *e = None);
+    }
+
+    entries[index] = Some(init);
+}
+
+/// Checks if the Value is a String-Literal that initializes the given Aggregate, which needs to
+/// be an Array of the Character-Type matching the Encoding of the Literal
+fn is_string_init(agg: &AType, value: &Expression) -> bool {
+    let (arr, encoding) = match (agg, value) {
+        (AType::Array(arr), Expression::StringLiteral { encoding, .. }) => (arr, encoding),
+        _ => return false,
+    };
+
+    match arr.ty.into_ty() {
+        AType::Primitve(prim) => {
+            prim.rank().is_some() && prim.rank() == Literal::unit_type(*encoding).rank()
+        }
+        _ => false,
+    }
+}
+
+/// Resolves the Items of an Initializer-List to the Sub-Objects they initialize
+struct InitParser<'a, VC> {
+    ty_defs: &'a TypeDefinitions,
+    vars: &'a VC,
+}
+
+impl<'a, VC> InitParser<'a, VC>
+where
+    VC: VariableContainer,
+{
+    fn parse_braced(
+        &self,
+        ty: &AType,
+        parts: SpanData<Vec<Expression>>,
+    ) -> Result<AInitializer, SemanticError> {
+        let area = parts.span;
+        let mut items: Items = parts.data.into_iter().rev().collect();
+
+        let result = match aggregate(ty) {
+            // The String-Literal for a Character-Array can also be enclosed in Braces
+            Some(agg) if items.len() == 1 && is_string_init(agg, &items[0]) => {
+                let value = items.pop().expect("There is exactly one Item");
+                self.parse_string(agg, value)?
+            }
+            Some(agg) => {
+                let mut entries = empty_entries(agg);
+                self.fill(agg, &mut entries, &mut items, true, &area)?;
+                AInitializer::List(entries)
+            }
+            None => match items.pop() {
+                Some(item) => self.parse_value(ty, item, &mut items, &area)?,
+                None => return Err(SemanticError::InvalidInitializer { area }),
+            },
+        };
+
+        match items.pop() {
+            Some(excess) => Err(SemanticError::InvalidInitializer {
+                area: excess.entire_span().unwrap_or(area),
+            }),
+            None => Ok(result),
+        }
+    }
+
+    /// Initializes the Sub-Objects of the Aggregate with the next Items. If the Aggregate has no
+    /// Braces of its own, it only takes as many Items as it has Sub-Objects and stops at the next
+    /// Designator, as these belong to the enclosing Initializer-List
+    fn fill(
+        &self,
+        agg: &AType,
+        entries: &mut Vec<Option<AInitializer>>,
+        items: &mut Items,
+        braced: bool,
+        area: &Span,
+    ) -> Result<(), SemanticError> {
+        // Without a Designator only the first Member of a Union can be initialized
+        let limit = match agg {
+            AType::Union { .. } => Some(1),
+            other => sub_object_count(other),
+        };
+
+        let mut position = 0;
+        while let Some(item) = items.pop() {
+            let is_designated = matches!(item, Expression::DesignatedInitializer { .. });
+            let is_full = limit.map(|l| position >= l).unwrap_or(false);
+
+            if !braced && (is_designated || is_full) {
+                items.push(item);
+                break;
+            }
+
+            if let Expression::DesignatedInitializer { designators, value } = item {
+                position = self.designate(agg, entries, designators, *value, items, area)? + 1;
+                continue;
+            }
+
+            if is_full {
+                return Err(SemanticError::InvalidInitializer {
+                    area: item.entire_span().unwrap_or_else(|| area.clone()),
+                });
+            }
+
+            let sub_ty = sub_object_type(agg, position);
+            let init = self.parse_value(&sub_ty, item, items, area)?;
+            set_entry(agg, entries, position, init);
+            position += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Initializes the Sub-Object selected by the Designators with the given Value and returns
+    /// the Index of the Sub-Object selected by the first Designator
+    fn designate(
+        &self,
+        agg: &AType,
+        entries: &mut Vec<Option<AInitializer>>,
+        designators: Vec<Designator>,
+        value: Expression,
+        items: &mut Items,
+        area: &Span,
+    ) -> Result<usize, SemanticError> {
+        let mut designators = designators.into_iter();
+        let first = designators
+            .next()
+            .expect("A designated Initializer always has at least one Designator");
+        let index = self.designator_index(agg, first, area)?;
+        let sub_ty = sub_object_type(agg, index);
+
+        let rest: Vec<_> = designators.collect();
+        if rest.is_empty() {
+            let init = self.parse_value(&sub_ty, value, items, area)?;
+            set_entry(agg, entries, index, init);
+            return Ok(index);
+        }
+
+        let sub_agg = aggregate(&sub_ty).ok_or_else(|| SemanticError::InvalidInitializer {
+            area: value.entire_span().unwrap_or_else(|| area.clone()),
+        })?;
+
+        // Keep the already initialized Parts of the Sub-Object
+        let mut sub_entries = match entries.get_mut(index).and_then(Option::take) {
+            Some(AInitializer::List(e)) => e,
+            _ => empty_entries(sub_agg),
+        };
+        self.designate(sub_agg, &mut sub_entries, rest, value, items, area)?;
+        set_entry(agg, entries, index, AInitializer::List(sub_entries));
+
+        Ok(index)
+    }
+
+    fn designator_index(
+        &self,
+        agg: &AType,
+        designator: Designator,
+        area: &Span,
+    ) -> Result<usize, SemanticError> {
+        match (agg, designator) {
+            (
+                AType::Struct {
+                    def,
+                    area: def_area,
+                }
+                | AType::Union {
+                    def,
+                    area: def_area,
+                },
+                Designator::Member(name),
+            ) => match def
+                .named_members()
+                .position(|(memb_name, _)| memb_name.0.data == name.0.data)
+            {
+                Some(index) => Ok(index),
+                None => Err(SemanticError::UnknownStructField {
+                    field_name: name,
+                    struct_def: Box::new(SpanData {
+                        span: def_area.clone(),
+                        data: def.clone(),
+                    }),
+                }),
+            },
+            (AType::Array(arr), Designator::Index(raw_index)) => {
+                let index = AExpression::parse(*raw_index, self.ty_defs, self.vars)?;
+
+                match index.const_evaluate() {
+                    Ok(EvaluationValue::Integer(v))
+                        if v >= 0 && arr.size.map(|s| (v as usize) < s).unwrap_or(true) =>
+                    {
+                        Ok(v as usize)
+                    }
+                    _ => Err(SemanticError::InvalidInitializer {
+                        area: index.entire_span(),
+                    }),
+                }
+            }
+            (_, Designator::Member(name)) => {
+                Err(SemanticError::InvalidInitializer { area: name.0.span })
+            }
+            (_, Designator::Index(raw_index)) => Err(SemanticError::InvalidInitializer {
+                area: raw_index.entire_span().unwrap_or_else(|| area.clone()),
+            }),
+        }
+    }
+
+    /// Initializes the Elements of a Character-Array with the Code-Units of the String-Literal,
+    /// including the terminating 0 if the Array has enough Space for it
+    fn parse_string(&self, agg: &AType, value: Expression) -> Result<AInitializer, SemanticError> {
+        let (arr, content, encoding) = match (agg, value) {
+            (AType::Array(arr), Expression::StringLiteral { content, encoding }) => {
+                (arr, content, encoding)
+            }
+            _ => unreachable!("Only called for String-Literals initializing an Array"),
+        };
+        let elem_prim = match arr.ty.into_ty() {
+            AType::Primitve(prim) => prim.clone(),
+            _ => unreachable!("Only called for Arrays of a Character-Type"),
+        };
+
+        let mut units = content.data;
+        match arr.size {
+            Some(size) if units.len() > size => {
+                return Err(SemanticError::InvalidInitializer { area: content.span });
+            }
+            Some(size) if units.len() == size => {}
+            _ => units.push(0),
+        };
+
+        let mut entries = empty_entries(agg);
+        for (index, unit) in units.into_iter().enumerate() {
+            let data = SpanData {
+                span: content.span.clone(),
+                data: Literal::char_value(unit, encoding),
+            };
+            let exp = AExpression::Literal(Literal::Integer(data, elem_prim.clone()));
+            set_entry(agg, &mut entries, index, AInitializer::Single(exp));
+        }
+
+        Ok(AInitializer::List(entries))
+    }
+
+    /// Parses the Value for a single Sub-Object of the given Type
+    fn parse_value(
+        &self,
+        ty: &AType,
+        value: Expression,
+        items: &mut Items,
+        area: &Span,
+    ) -> Result<AInitializer, SemanticError> {
+        let raw = match value {
+            Expression::ArrayLiteral { parts } => return self.parse_braced(ty, parts),
+            other => other,
+        };
+
+        let agg = match aggregate(ty) {
+            Some(a) => a,
+            None => {
+                let exp = AExpression::parse(raw, self.ty_defs, self.vars)?;
+                let span = exp.entire_span();
+                let exp = atype::assign_type::determine_type(exp, (ty, &span))?;
+
+                let exp_ty = exp.result_type();
+                if exp_ty.into_ty() != ty.into_ty() {
+                    return Err(SemanticError::MismatchedTypes {
+                        expected: Box::new(SpanData {
+                            span: span.clone(),
+                            data: ty.clone(),
+                        }),
+                        received: Box::new(SpanData { span, data: exp_ty }),
+                    });
+                }
+
+                return Ok(AInitializer::Single(exp));
+            }
+        };
+
+        if is_string_init(agg, &raw) {
+            return self.parse_string(agg, raw);
+        }
+
+        // An Aggregate can also be initialized by another Object of the same Type
+        if let Ok(exp) = AExpression::parse(raw.clone(), self.ty_defs, self.vars) {
+            if exp.result_type().into_ty() == agg {
+                return Ok(AInitializer::Single(exp));
+            }
+        }
+
+        // Otherwise the Braces around this Sub-Aggregate were omitted and its Sub-Objects are
+        // taken from the enclosing Initializer-List
+        if sub_object_count(agg).is_none() {
+            return Err(SemanticError::InvalidInitializer { area: area.clone() });
+        }
+
+        items.push(raw);
+        let mut entries = empty_entries(agg);
+        self.fill(agg, &mut entries, items, false, area)?;
+
+        Ok(AInitializer::List(entries))
+    }
+}
+
+/// Collects all the scalar Parts of an Object of the given Type, together with their Offset
+fn scalar_parts(ty: &AType, offset: usize, arch: &Arch, result: &mut Vec<(usize, AType)>) {
+    match aggregate(ty) {
+        Some(AType::Array(arr)) => {
+            let elem_size = arr.ty.byte_size(arch) as usize;
+            for index in 0..arr.length_for(arch).unwrap_or(0) {
+                scalar_parts(&arr.ty, offset + index * elem_size, arch, result);
+            }
+        }
+        Some(AType::Struct { def, .. }) => {
+            for (name, member) in def.named_members() {
+                let member_offset = def.member_offset(&name.0.data, arch).unwrap();
+                scalar_parts(&member.ty, offset + member_offset, arch, result);
+            }
+        }
+        Some(AType::Union { def, .. }) => {
+            // The largest Member covers the entire Union
+            if let Some(largest) = def.sized_members().max_by_key(|m| m.ty.byte_size(arch)) {
+                scalar_parts(&largest.ty, offset, arch, result);
+            }
+        }
+        _ => result.push((offset, ty.clone())),
+    }
+}
+
+pub(crate) fn zero_constant(ty: &ir::Type) -> ir::Constant {
+    match ty {
+        ir::Type::I8 => ir::Constant::I8(0),
+        ir::Type::I16 => ir::Constant::I16(0),
+        ir::Type::I32 => ir::Constant::I32(0),
+        ir::Type::U8 => ir::Constant::U8(0),
+        ir::Type::U16 => ir::Constant::U16(0),
+        ir::Type::U32 => ir::Constant::U32(0),
+        ir::Type::U64 => ir::Constant::U64(0),
+        ir::Type::Float => ir::Constant::F32(0.0),
+        ir::Type::Double => ir::Constant::F64(0.0),
+        _ => ir::Constant::I64(0),
+    }
+}
+
+fn offset_address(
+    base: &ir::Variable,
+    offset: usize,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> ir::Operand {
+    if offset == 0 {
+        return ir::Operand::Variable(base.clone());
+    }
+
+    let address = Value::Expression(ir::Expression::BinaryOp {
+        op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
+        left: ir::Operand::Variable(base.clone()),
+        right: ir::Operand::Constant(ctx.arch().ptr_const(offset as i64)),
+    });
+    AExpression::val_to_operand(address, block, ctx)
+}
+
+/// Copies all the Parts of an Object of the given Type from the Memory at the Source-Address into
+/// the Memory at the Offset from the Base-Address
+pub(crate) fn copy_object(
+    ty: &AType,
+    src: &ir::Variable,
+    base: &ir::Variable,
+    offset: usize,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let mut parts = Vec::new();
+    scalar_parts(ty, 0, ctx.arch(), &mut parts);
+    for (part_offset, part_ty) in parts {
+        let volatile = part_ty.is_volatile();
+        let read_ty = part_ty.to_ir(ctx.arch());
+        let src_address = offset_address(src, part_offset, block, ctx);
+
+        let part_var = ir::Variable::tmp(ctx.next_tmp(), read_ty.clone());
+        block.add_statement(ir::Statement::Assignment {
+            target: part_var.clone(),
+            value: Value::Expression(ir::Expression::ReadMemory {
+                address: src_address,
+                read_ty,
+                volatile,
+            }),
+        });
+
+        let target = offset_address(base, offset + part_offset, block, ctx);
+        block.add_statement(ir::Statement::WriteMemory {
+            target,
+            value: ir::Operand::Variable(part_var),
+            volatile,
+        });
+    }
+}
+
+/// Writes the Initializer for an Object of the given Type into the Memory at the Offset from
+/// the Base-Address, where all the Parts without an Initializer are filled with zero
+fn write_object(
+    ty: &AType,
+    init: Option<&AInitializer>,
+    base: &ir::Variable,
+    offset: usize,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let agg = match (aggregate(ty), init) {
+        (None, Some(AInitializer::Single(exp))) => {
+            let value = exp.clone().to_ir(block, ctx);
+
+            let value_var = ir::Variable::tmp(ctx.next_tmp(), ty.clone().to_ir(ctx.arch()));
+            block.add_statement(ir::Statement::Assignment {
+                target: value_var.clone(),
+                value,
+            });
+
+            let target = offset_address(base, offset, block, ctx);
+            block.add_statement(ir::Statement::WriteMemory {
+                target,
+                value: ir::Operand::Variable(value_var),
+                volatile: ty.is_volatile(),
+            });
+            return;
+        }
+        (None, _) => {
+            let target = offset_address(base, offset, block, ctx);
+            block.add_statement(ir::Statement::WriteMemory {
+                target,
+                value: ir::Operand::Constant(zero_constant(&ty.clone().to_ir(ctx.arch()))),
+                volatile: ty.is_volatile(),
+            });
+            return;
+        }
+        (Some(agg), Some(AInitializer::Single(exp))) => {
+            // Copy all the Parts from the other Object
+            let src_value = exp.clone().to_ir(block, ctx);
+            let src_oper = AExpression::val_to_operand(src_value, block, ctx);
+            let src_var = match src_oper {
+                ir::Operand::Variable(v) => v,
+                ir::Operand::Constant(_) => unreachable!("Aggregates are always stored in Memory"),
+            };
+
+            copy_object(agg, &src_var, base, offset, block, ctx);
+            return;
+        }
+        (Some(agg), _) => agg,
+    };
+
+    let entries: &[Option<AInitializer>] = match init {
+        Some(AInitializer::List(entries)) => entries,
+        _ => &[],
+    };
+    let entry = |index: usize| entries.get(index).and_then(Option::as_ref);
+
+    match agg {
+        AType::Array(arr) => {
+            let elem_size = arr.ty.byte_size(ctx.arch()) as usize;
+            for index in 0..arr.length_for(ctx.arch()).unwrap_or(entries.len()) {
+                write_object(
+                    &arr.ty,
+                    entry(index),
+                    base,
+                    offset + index * elem_size,
+                    block,
+                    ctx,
+                );
+            }
+        }
+        AType::Struct { def, .. } => {
+            for (index, (name, member)) in def.named_members().enumerate() {
+                let member_offset = def.member_offset(&name.0.data, ctx.arch()).unwrap();
+                if let Some(field) = def.bit_field(&name.0.data, ctx.arch()) {
+                    write_bit_field_object(
+                        &member.ty,
+                        &field,
+                        entry(index),
+                        base,
+                        offset + member_offset,
+                        block,
+                        ctx,
+                    );
+                    continue;
+                }
+
+                write_object(
+                    &member.ty,
+                    entry(index),
+                    base,
+                    offset + member_offset,
+                    block,
+                    ctx,
+                );
+            }
+        }
+        AType::Union { def, .. } => {
+            // Only the initialized Member is written, which is the first one by default
+            let index = entries.iter().position(Option::is_some).unwrap_or(0);
+            if let Some((_, member)) = def.named_members().nth(index) {
+                match member.union_bit_field(ctx.arch()) {
+                    Some(field) => write_bit_field_object(
+                        &member.ty,
+                        &field,
+                        entry(index),
+                        base,
+                        offset,
+                        block,
+                        ctx,
+                    ),
+                    None => write_object(&member.ty, entry(index), base, offset, block, ctx),
+                };
+            }
+        }
+        _ => unreachable!("Only called with Aggregates"),
+    };
+}
+
+/// Writes the Initializer of a Bit-Field into its Storage-Unit at the Offset from the
+/// Base-Address, without changing the other Members in the same Unit
+fn write_bit_field_object(
+    ty: &AType,
+    field: &BitField,
+    init: Option<&AInitializer>,
+    base: &ir::Variable,
+    offset: usize,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let value = match init {
+        Some(AInitializer::Single(exp)) => exp.clone().to_ir(block, ctx),
+        _ => Value::Constant(zero_constant(&ty.clone().to_ir(ctx.arch()))),
+    };
+
+    let value_var = ir::Variable::tmp(ctx.next_tmp(), ty.clone().to_ir(ctx.arch()));
+    block.add_statement(ir::Statement::Assignment {
+        target: value_var.clone(),
+        value,
+    });
+
+    let target = offset_address(base, offset, block, ctx);
+    write_bit_field(
+        field,
+        target,
+        ty,
+        ir::Operand::Variable(value_var),
+        block,
+        ctx,
+    );
+}
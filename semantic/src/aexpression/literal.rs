@@ -1,76 +1,217 @@
 use general::SpanData;
-use ir::{BasicBlock, Constant, Expression, Operand, Statement, Type, Value, Variable};
+use ir::{BasicBlock, ConstantData, Expression, Statement, Type, Value, Variable};
+use syntax::{FloatKind, IntegerLength, LiteralEncoding, NumberError, NumberLiteral};
 
-use crate::conversion::ConvertContext;
+use crate::{conversion::ConvertContext, APrimitive, AType, SemanticError};
+
+use super::structaccess::unit_constant;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Literal {
-    Integer(SpanData<i64>),
-    FloatingPoint(SpanData<f64>),
-    StringLiteral(SpanData<String>),
-    CharLiteral(SpanData<char>),
+    /// An Integer-Constant with the Type determined by its Value, Radix and Suffix
+    Integer(SpanData<i64>, APrimitive),
+    /// A Floating-Point-Constant with the Type determined by its Suffix
+    FloatingPoint(SpanData<f64>, APrimitive),
+    /// A String-Literal with the Code-Units in its Encoding, without the terminating 0
+    StringLiteral(SpanData<Vec<u32>>, LiteralEncoding),
+    /// A Character-Constant with the Value of its single Code-Unit
+    CharLiteral(SpanData<u32>, LiteralEncoding),
 }
 
 impl Literal {
+    /// Parses the raw Text of a Numeric Constant into either an Integer- or a
+    /// Floating-Point-Constant with the matching Type
+    pub fn parse_number(content: SpanData<String>) -> Result<Self, SemanticError> {
+        let number = syntax::parse_number(&content.data).map_err(|err| match err {
+            NumberError::Malformed => SemanticError::InvalidNumber {
+                area: content.span.clone(),
+            },
+            NumberError::Overflow => SemanticError::NumberOverflow {
+                area: content.span.clone(),
+            },
+        })?;
+
+        match number {
+            NumberLiteral::Integer {
+                value,
+                decimal,
+                unsigned,
+                length,
+            } => {
+                let ty = Self::integer_type(value, decimal, unsigned, length).ok_or(
+                    SemanticError::NumberOverflow {
+                        area: content.span.clone(),
+                    },
+                )?;
+
+                let data = SpanData {
+                    span: content.span,
+                    data: value as i64,
+                };
+                Ok(Self::Integer(data, ty))
+            }
+            NumberLiteral::Float { value, kind } => {
+                let ty = match kind {
+                    FloatKind::Float => APrimitive::Float,
+                    FloatKind::Double => APrimitive::Double,
+                    FloatKind::LongDouble => APrimitive::LongDouble,
+                };
+
+                let data = SpanData {
+                    span: content.span,
+                    data: value,
+                };
+                Ok(Self::FloatingPoint(data, ty))
+            }
+        }
+    }
+
+    /// The Type of an Integer-Constant, which is the first Type that can represent the Value out
+    /// of the possible Types for its Suffix and Radix
+    fn integer_type(
+        value: u64,
+        decimal: bool,
+        unsigned: bool,
+        length: IntegerLength,
+    ) -> Option<APrimitive> {
+        use APrimitive::*;
+
+        let candidates: &[APrimitive] = match (length, unsigned, decimal) {
+            (IntegerLength::Int, false, true) => &[Int, LongInt, LongLongInt],
+            (IntegerLength::Int, false, false) => &[
+                Int,
+                UnsignedInt,
+                LongInt,
+                UnsignedLongInt,
+                LongLongInt,
+                UnsignedLongLongInt,
+            ],
+            (IntegerLength::Int, true, _) => &[UnsignedInt, UnsignedLongInt, UnsignedLongLongInt],
+            (IntegerLength::Long, false, true) => &[LongInt, LongLongInt],
+            (IntegerLength::Long, false, false) => {
+                &[LongInt, UnsignedLongInt, LongLongInt, UnsignedLongLongInt]
+            }
+            (IntegerLength::Long, true, _) => &[UnsignedLongInt, UnsignedLongLongInt],
+            (IntegerLength::LongLong, false, true) => &[LongLongInt],
+            (IntegerLength::LongLong, false, false) => &[LongLongInt, UnsignedLongLongInt],
+            (IntegerLength::LongLong, true, _) => &[UnsignedLongLongInt],
+        };
+
+        candidates
+            .iter()
+            .find(|ty| value <= Self::max_value(ty))
+            .cloned()
+    }
+
+    /// The largest Value that can be stored in the given Integer-Type
+    fn max_value(ty: &APrimitive) -> u64 {
+        match ty {
+            APrimitive::Int => i32::MAX as u64,
+            APrimitive::UnsignedInt => u32::MAX as u64,
+            APrimitive::LongInt | APrimitive::LongLongInt => i64::MAX as u64,
+            _ => u64::MAX,
+        }
+    }
+
+    /// The Type of a single Code-Unit in the given Encoding, which is the Type of a
+    /// Character-Constant as well as the Element-Type of a String-Literal
+    pub fn unit_type(encoding: LiteralEncoding) -> APrimitive {
+        match encoding {
+            LiteralEncoding::Plain | LiteralEncoding::Utf8 => APrimitive::Char,
+            // wchar_t
+            LiteralEncoding::Wide => APrimitive::Int,
+            // char16_t
+            LiteralEncoding::Utf16 => APrimitive::UnsignedShort,
+            // char32_t
+            LiteralEncoding::Utf32 => APrimitive::UnsignedInt,
+        }
+    }
+
+    /// The Value of a Character-Constant, interpreted as its Type
+    pub fn char_value(unit: u32, encoding: LiteralEncoding) -> i64 {
+        match Self::unit_type(encoding) {
+            APrimitive::Char => unit as u8 as i8 as i64,
+            APrimitive::Int => unit as i32 as i64,
+            _ => unit as i64,
+        }
+    }
+
+    /// The Value of a numeric Constant, already converted to the given Type, which avoids an
+    /// explicit Cast of a Constant in the IR
+    pub fn cast_constant(&self, target: &Type) -> Option<ir::Constant> {
+        let value = match self {
+            Self::Integer(SpanData { data, .. }, _) => *data,
+            Self::CharLiteral(SpanData { data, .. }, encoding) => {
+                Self::char_value(*data, *encoding)
+            }
+            Self::FloatingPoint(SpanData { data, .. }, _) => {
+                return match target {
+                    Type::Float => Some(ir::Constant::F32(*data as f32)),
+                    Type::Double => Some(ir::Constant::F64(*data)),
+                    _ => None,
+                };
+            }
+            Self::StringLiteral(..) => return None,
+        };
+
+        match target {
+            Type::I8 | Type::I16 | Type::I32 | Type::I64 => {
+                Some(unit_constant(value as u64, target))
+            }
+            Type::U8 | Type::U16 | Type::U32 | Type::U64 => {
+                Some(unit_constant(value as u64, target))
+            }
+            Type::Float => Some(ir::Constant::F32(value as f32)),
+            Type::Double => Some(ir::Constant::F64(value as f64)),
+            _ => None,
+        }
+    }
+
     pub fn to_value(self, block: &BasicBlock, ctx: &ConvertContext) -> Value {
         match self {
-            Self::Integer(SpanData { data, .. }) => Value::Constant(Constant::I64(data)),
-            Self::StringLiteral(SpanData { data, .. }) => {
-                dbg!(&data);
+            Self::Integer(SpanData { data, .. }, ty) => Value::Constant(unit_constant(
+                data as u64,
+                &AType::Primitve(ty).to_ir(ctx.arch()),
+            )),
+            Self::StringLiteral(SpanData { data, .. }, encoding) => {
+                let unit_prim = Self::unit_type(encoding);
+                let unit_size = unit_prim.byte_size(ctx.arch()) as usize;
+                let unit_ty = AType::Primitve(unit_prim).to_ir(ctx.arch());
 
-                if !data.is_ascii() {
-                    panic!("Currently only supports ASCII-Strings");
-                }
+                // The Literal always ends with a 0 Code-Unit
+                let big_endian = ctx.arch().big_endian();
+                let content = data
+                    .into_iter()
+                    .chain(std::iter::once(0))
+                    .flat_map(|unit| {
+                        if big_endian {
+                            unit.to_be_bytes()[4 - unit_size..].to_vec()
+                        } else {
+                            unit.to_le_bytes()[..unit_size].to_vec()
+                        }
+                    })
+                    .collect();
 
-                let data_len = data.len();
-                let data_bytes = data.bytes();
+                let name = ctx.add_constant(ConstantData::new(content, unit_size));
 
-                let arr_tmp_name = ctx.next_tmp();
-                let arr_tmp = Variable::tmp(arr_tmp_name, Type::Pointer(Box::new(Type::U8)));
-                let arr_decl = Statement::Assignment {
+                let arr_tmp = Variable::tmp(ctx.next_tmp(), Type::Pointer(Box::new(unit_ty)))
+                    .set_description("Address of String-Literal");
+                block.add_statement(Statement::Assignment {
                     target: arr_tmp.clone(),
-                    value: Value::Expression(Expression::StackAlloc {
-                        size: data_len,
-                        alignment: 1,
-                    }),
-                };
-
-                block.add_statement(arr_decl);
-                block.add_statement(Statement::SaveVariable {
-                    var: arr_tmp.clone(),
+                    value: Value::Expression(Expression::ConstantAddress { name }),
                 });
 
-                for (index, tmp_byte) in data_bytes.enumerate() {
-                    let offset_exp = Expression::BinaryOp {
-                        op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
-                        left: Operand::Variable(arr_tmp.clone()),
-                        right: Operand::Constant(Constant::I64(index as i64)),
-                    };
-                    let offset_var = Variable::tmp(ctx.next_tmp(), Type::I64);
-
-                    let offset_statement = Statement::Assignment {
-                        target: offset_var.clone(),
-                        value: Value::Expression(offset_exp),
-                    };
-                    block.add_statement(offset_statement);
-
-                    let assign_statement = Statement::WriteMemory {
-                        target: Operand::Variable(offset_var),
-                        value: Operand::Constant(Constant::U8(tmp_byte)),
-                    };
-                    block.add_statement(assign_statement);
-                }
-
                 Value::Variable(arr_tmp)
             }
-            Self::CharLiteral(SpanData { data, .. }) => {
-                assert!(data.is_ascii());
+            Self::CharLiteral(SpanData { data, .. }, encoding) => {
+                let unit_ty = AType::Primitve(Self::unit_type(encoding)).to_ir(ctx.arch());
 
-                let result = data as u8;
-
-                ir::Value::Constant(ir::Constant::U8(result))
+                ir::Value::Constant(unit_constant(data as u64, &unit_ty))
+            }
+            Self::FloatingPoint(SpanData { data, .. }, APrimitive::Float) => {
+                ir::Value::Constant(ir::Constant::F32(data as f32))
             }
-            Self::FloatingPoint(SpanData { data, .. }) => {
+            Self::FloatingPoint(SpanData { data, .. }, _) => {
                 ir::Value::Constant(ir::Constant::F64(data))
             }
         }
@@ -0,0 +1,230 @@
+use std::collections::BTreeSet;
+
+use general::{Span, SpanData};
+use ir::{BasicBlock, Value};
+
+use crate::{
+    conversion::ConvertContext, AExpression, APrimitive, AType, ArithemticOp, SemanticError,
+};
+
+/// Arithmetic involving Pointers, which always works in Elements of the pointed to Type instead
+/// of Bytes
+#[derive(Debug, PartialEq, Clone)]
+pub enum PointerArithmetic {
+    /// Moves the Pointer forwards or backwards by the given Number of Elements
+    Offset {
+        base: Box<AExpression>,
+        offset: Box<AExpression>,
+        /// Either Add or Sub
+        op: ArithemticOp,
+        element: AType,
+        area: Span,
+    },
+    /// The Number of Elements between two Pointers to the same Type, as a ptrdiff_t
+    Difference {
+        left: Box<AExpression>,
+        right: Box<AExpression>,
+        element: AType,
+        area: Span,
+    },
+}
+
+impl PointerArithmetic {
+    /// Checks if either of the Operands is a Pointer or an Array, which decays to a Pointer
+    pub fn applies(left: &AExpression, right: &AExpression) -> bool {
+        pointed_to(&left.result_type()).is_some() || pointed_to(&right.result_type()).is_some()
+    }
+
+    pub fn parse(
+        left: AExpression,
+        right: AExpression,
+        op: ArithemticOp,
+    ) -> Result<Self, SemanticError> {
+        let left_ty = left.result_type();
+        let right_ty = right.result_type();
+
+        let left_span = left.entire_span();
+        let right_span = right.entire_span();
+        let area = Span::new_arc_source(
+            left_span.source().clone(),
+            left_span.source_area().start..right_span.source_area().end,
+        );
+
+        match (pointed_to(&left_ty), pointed_to(&right_ty), &op) {
+            (Some(element), None, ArithemticOp::Add | ArithemticOp::Sub)
+                if right_ty.is_integer() && has_size(&element) =>
+            {
+                Ok(Self::Offset {
+                    base: Box::new(left),
+                    offset: Box::new(right),
+                    op,
+                    element,
+                    area,
+                })
+            }
+            (None, Some(element), ArithemticOp::Add)
+                if left_ty.is_integer() && has_size(&element) =>
+            {
+                Ok(Self::Offset {
+                    base: Box::new(right),
+                    offset: Box::new(left),
+                    op,
+                    element,
+                    area,
+                })
+            }
+            (Some(left_elem), Some(right_elem), ArithemticOp::Sub)
+                if has_size(&left_elem)
+                    && left_elem.clone().unqualified() == right_elem.clone().unqualified() =>
+            {
+                Ok(Self::Difference {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    element: left_elem,
+                    area,
+                })
+            }
+            _ => Err(SemanticError::MismatchedOperationTypes {
+                left: Box::new(SpanData {
+                    span: left_span,
+                    data: left_ty,
+                }),
+                right: Box::new(SpanData {
+                    span: right_span,
+                    data: right_ty,
+                }),
+            }),
+        }
+    }
+
+    pub fn result_type(&self) -> AType {
+        match self {
+            Self::Offset { element, .. } => AType::Pointer(Box::new(element.clone())),
+            Self::Difference { .. } => AType::Primitve(APrimitive::LongInt),
+        }
+    }
+
+    pub fn entire_span(&self) -> Span {
+        match self {
+            Self::Offset { area, .. } | Self::Difference { area, .. } => area.clone(),
+        }
+    }
+
+    pub fn used_variables(&self) -> BTreeSet<String> {
+        let (first, second) = match self {
+            Self::Offset { base, offset, .. } => (base, offset),
+            Self::Difference { left, right, .. } => (left, right),
+        };
+
+        let mut tmp = first.used_variables();
+        tmp.extend(second.used_variables());
+        tmp
+    }
+
+    pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+        match self {
+            Self::Offset {
+                base,
+                offset,
+                op,
+                element,
+                ..
+            } => {
+                let base_value = base.elements_address(block, ctx);
+                let base_oper = AExpression::val_to_operand(base_value, block, ctx);
+
+                let offset_oper = offset.index_operand(block, ctx);
+                let element_size = element.byte_size(ctx.arch());
+                let scaled_value = Value::Expression(ir::Expression::BinaryOp {
+                    op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
+                    left: offset_oper,
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(element_size as i64)),
+                });
+                let scaled_oper = AExpression::val_to_operand(scaled_value, block, ctx);
+
+                let ir_op = match op {
+                    ArithemticOp::Sub => ir::BinaryArithmeticOp::Sub,
+                    _ => ir::BinaryArithmeticOp::Add,
+                };
+                Value::Expression(ir::Expression::BinaryOp {
+                    op: ir::BinaryOp::Arith(ir_op),
+                    left: base_oper,
+                    right: scaled_oper,
+                })
+            }
+            Self::Difference {
+                left,
+                right,
+                element,
+                ..
+            } => {
+                let ptrdiff_ty = ctx.arch().data_model().ptrdiff_type();
+
+                let left_oper = Self::address_difference_operand(*left, &ptrdiff_ty, block, ctx);
+                let right_oper = Self::address_difference_operand(*right, &ptrdiff_ty, block, ctx);
+
+                let bytes_value = Value::Expression(ir::Expression::BinaryOp {
+                    op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Sub),
+                    left: left_oper,
+                    right: right_oper,
+                });
+                let bytes_oper = AExpression::val_to_operand(bytes_value, block, ctx);
+
+                // The Difference is always a Multiple of the Element-Size, so dividing by a
+                // Power of two is the same as an arithmetic Shift
+                let element_size = element.byte_size(ctx.arch());
+                let (op, divisor) = if element_size.is_power_of_two() {
+                    (
+                        ir::BinaryOp::Bitwise(ir::BinaryBitwiseOp::ShiftRight),
+                        element_size.trailing_zeros() as i64,
+                    )
+                } else {
+                    (
+                        ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Divide),
+                        element_size as i64,
+                    )
+                };
+
+                Value::Expression(ir::Expression::BinaryOp {
+                    op,
+                    left: bytes_oper,
+                    right: ir::Operand::Constant(ctx.arch().ptr_const(divisor)),
+                })
+            }
+        }
+    }
+
+    fn address_difference_operand(
+        exp: AExpression,
+        ptrdiff_ty: &ir::Type,
+        block: &mut BasicBlock,
+        ctx: &ConvertContext,
+    ) -> ir::Operand {
+        let address_value = exp.elements_address(block, ctx);
+        let address_oper = AExpression::val_to_operand(address_value, block, ctx);
+
+        let cast_value = Value::Expression(ir::Expression::Cast {
+            target: ptrdiff_ty.clone(),
+            base: address_oper,
+        });
+        AExpression::val_to_operand(cast_value, block, ctx)
+    }
+}
+
+/// The Type of the Elements that the Pointer or Array refers to
+fn pointed_to(ty: &AType) -> Option<AType> {
+    match ty.into_ty() {
+        AType::Pointer(inner) => Some(inner.as_ref().clone()),
+        AType::Array(arr) => Some(arr.ty.as_ref().clone()),
+        _ => None,
+    }
+}
+
+/// Only Pointers to Objects can be used in Arithmetic, as the Size of void, Functions and
+/// incomplete Types is unknown
+fn has_size(element: &AType) -> bool {
+    !matches!(
+        element.into_ty(),
+        AType::Primitve(APrimitive::Void) | AType::Function { .. } | AType::Incomplete { .. }
+    )
+}
@@ -1,6 +1,7 @@
+use ir::BasicBlock;
 use syntax::Identifier;
 
-use crate::{AExpression, AType};
+use crate::{conversion::ConvertContext, AExpression, AType, BitField};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct StructAccess {
@@ -8,3 +9,164 @@ pub struct StructAccess {
     pub field: Identifier,
     pub ty: AType,
 }
+
+/// Creates a Constant of the given integer Type, truncating the Value to its Size
+pub(crate) fn unit_constant(value: u64, ty: &ir::Type) -> ir::Constant {
+    match ty {
+        ir::Type::I8 => ir::Constant::I8(value as i8),
+        ir::Type::I16 => ir::Constant::I16(value as i16),
+        ir::Type::I32 => ir::Constant::I32(value as i32),
+        ir::Type::I64 => ir::Constant::I64(value as i64),
+        ir::Type::U8 => ir::Constant::U8(value as u8),
+        ir::Type::U16 => ir::Constant::U16(value as u16),
+        ir::Type::U32 => ir::Constant::U32(value as u32),
+        ir::Type::U64 => ir::Constant::U64(value),
+        other => unreachable!("Expected an integer Type, got {:?}", other),
+    }
+}
+
+fn bitwise(
+    op: ir::BinaryBitwiseOp,
+    left: ir::Operand,
+    right: ir::Operand,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> ir::Operand {
+    let value = ir::Value::Expression(ir::Expression::BinaryOp {
+        op: ir::BinaryOp::Bitwise(op),
+        left,
+        right,
+    });
+
+    AExpression::val_to_operand(value, block, ctx)
+}
+
+/// Loads the Storage-Unit at the given Address and extracts the Value of the Bit-Field from it.
+///
+/// Signed Fields are first shifted to the top of the Unit, so that shifting them back down also
+/// extends their Sign
+pub(crate) fn read_bit_field(
+    field: &BitField,
+    address: ir::Operand,
+    ty: &AType,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) -> ir::Value {
+    let unit_ty = ty.clone().to_ir(ctx.arch());
+    let unit_bits = ty.byte_size(ctx.arch()) as usize * 8;
+
+    let unit = AExpression::val_to_operand(
+        ir::Value::Expression(ir::Expression::ReadMemory {
+            address,
+            read_ty: unit_ty.clone(),
+            volatile: ty.is_volatile(),
+        }),
+        block,
+        ctx,
+    );
+
+    let (shifted, op, amount) = if unit_ty.signed() {
+        let upper = unit_bits - field.offset - field.width;
+        let shifted = match upper {
+            0 => unit,
+            n => bitwise(
+                ir::BinaryBitwiseOp::ShiftLeft,
+                unit,
+                ir::Operand::Constant(unit_constant(n as u64, &unit_ty)),
+                block,
+                ctx,
+            ),
+        };
+
+        (
+            shifted,
+            ir::BinaryBitwiseOp::ShiftRight,
+            (unit_bits - field.width) as u64,
+        )
+    } else {
+        let shifted = match field.offset {
+            0 => unit,
+            n => bitwise(
+                ir::BinaryBitwiseOp::ShiftRight,
+                unit,
+                ir::Operand::Constant(unit_constant(n as u64, &unit_ty)),
+                block,
+                ctx,
+            ),
+        };
+
+        (shifted, ir::BinaryBitwiseOp::And, field.mask())
+    };
+
+    if field.width == unit_bits {
+        return match shifted {
+            ir::Operand::Variable(var) => ir::Value::Variable(var),
+            ir::Operand::Constant(con) => ir::Value::Constant(con),
+        };
+    }
+
+    ir::Value::Expression(ir::Expression::BinaryOp {
+        op: ir::BinaryOp::Bitwise(op),
+        left: shifted,
+        right: ir::Operand::Constant(unit_constant(amount, &unit_ty)),
+    })
+}
+
+/// Stores the Value into the Bit-Field by loading its Storage-Unit from the given Address,
+/// replacing the Bits of the Field and then writing the entire Unit back, which keeps all the
+/// other Members in the same Unit intact
+pub(crate) fn write_bit_field(
+    field: &BitField,
+    address: ir::Operand,
+    ty: &AType,
+    value: ir::Operand,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let unit_ty = ty.clone().to_ir(ctx.arch());
+
+    let unit = AExpression::val_to_operand(
+        ir::Value::Expression(ir::Expression::ReadMemory {
+            address: address.clone(),
+            read_ty: unit_ty.clone(),
+            volatile: ty.is_volatile(),
+        }),
+        block,
+        ctx,
+    );
+
+    let field_mask = field.mask() << field.offset;
+    let cleared = bitwise(
+        ir::BinaryBitwiseOp::And,
+        unit,
+        ir::Operand::Constant(unit_constant(!field_mask, &unit_ty)),
+        block,
+        ctx,
+    );
+
+    let masked = bitwise(
+        ir::BinaryBitwiseOp::And,
+        value,
+        ir::Operand::Constant(unit_constant(field.mask(), &unit_ty)),
+        block,
+        ctx,
+    );
+    let shifted = match field.offset {
+        0 => masked,
+        n => bitwise(
+            ir::BinaryBitwiseOp::ShiftLeft,
+            masked,
+            ir::Operand::Constant(unit_constant(n as u64, &unit_ty)),
+            block,
+            ctx,
+        ),
+    };
+
+    let combined = bitwise(ir::BinaryBitwiseOp::Or, cleared, shifted, block, ctx);
+
+    block.add_statement(ir::Statement::WriteMemory {
+        target: address,
+        value: combined,
+        volatile: ty.is_volatile(),
+    });
+}
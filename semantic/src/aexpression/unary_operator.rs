@@ -1,12 +1,18 @@
 use ir::{BasicBlock, Value};
 use syntax::SingleOperation;
 
-use crate::{conversion::ConvertContext, AExpression, AStatement};
+use crate::{
+    conversion::ConvertContext, zero_constant, AExpression, APrimitive, AStatement, AType,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum UnaryArithmeticOp {
     SuffixIncrement,
     SuffixDecrement,
+    /// Increments the Variable and returns the updated Value
+    PrefixIncrement,
+    /// Decrements the Variable and returns the updated Value
+    PrefixDecrement,
     Positive,
     Negate,
     /// Simply increments the base Value and returns the Result
@@ -45,8 +51,12 @@ impl From<SingleOperation> for UnaryOperator {
             SingleOperation::Negative => Self::Arithmetic(UnaryArithmeticOp::Negate),
             SingleOperation::LogicalNot => Self::Logic(UnaryLogicOp::Not),
             SingleOperation::Dereference => Self::Derference,
-            SingleOperation::PrefixIncrement => Self::Arithmetic(UnaryArithmeticOp::Increment),
-            SingleOperation::PrefixDecrement => Self::Arithmetic(UnaryArithmeticOp::Decrement),
+            SingleOperation::PrefixIncrement => {
+                Self::Arithmetic(UnaryArithmeticOp::PrefixIncrement)
+            }
+            SingleOperation::PrefixDecrement => {
+                Self::Arithmetic(UnaryArithmeticOp::PrefixDecrement)
+            }
             SingleOperation::Positive => Self::Arithmetic(UnaryArithmeticOp::Positive),
             SingleOperation::BitwiseNot => Self::Bitwise(Bitwise::Not),
             unknown => todo!("Parse SingleOP: {:?}", unknown),
@@ -55,6 +65,29 @@ impl From<SingleOperation> for UnaryOperator {
 }
 
 impl UnaryOperator {
+    /// Whether or not the Operator writes the updated Value back to its Base
+    pub fn modifies_base(&self) -> bool {
+        matches!(
+            self,
+            Self::Arithmetic(
+                UnaryArithmeticOp::SuffixIncrement
+                    | UnaryArithmeticOp::SuffixDecrement
+                    | UnaryArithmeticOp::PrefixIncrement
+                    | UnaryArithmeticOp::PrefixDecrement
+            )
+        )
+    }
+
+    /// Whether or not the Integer-Promotions are performed on the Base before applying the
+    /// Operator, which then also determines the Type of the Result
+    pub fn promotes_base(&self) -> bool {
+        matches!(
+            self,
+            Self::Arithmetic(UnaryArithmeticOp::Positive | UnaryArithmeticOp::Negate)
+                | Self::Bitwise(Bitwise::Not)
+        )
+    }
+
     /// Converts the Operator with the given Base into their corresponding IR
     pub fn to_ir(
         self,
@@ -68,8 +101,9 @@ impl UnaryOperator {
             Self::Arithmetic(UnaryArithmeticOp::SuffixDecrement) => {
                 let base_target = base.clone().assign_target();
 
-                let result_var = ir::Variable::tmp(ctx.next_tmp(), base.result_type().to_ir())
-                    .set_description("Temp Variable holding Value before Decrementing");
+                let result_var =
+                    ir::Variable::tmp(ctx.next_tmp(), base.result_type().to_ir(ctx.arch()))
+                        .set_description("Temp Variable holding Value before Decrementing");
                 let result_assign = ir::Statement::Assignment {
                     target: result_var.clone(),
                     value: base_value,
@@ -90,8 +124,9 @@ impl UnaryOperator {
             Self::Arithmetic(UnaryArithmeticOp::SuffixIncrement) => {
                 let base_target = base.clone().assign_target();
 
-                let result_var = ir::Variable::tmp(ctx.next_tmp(), base.result_type().to_ir())
-                    .set_description("Temp Variable holding Value before Incrementing");
+                let result_var =
+                    ir::Variable::tmp(ctx.next_tmp(), base.result_type().to_ir(ctx.arch()))
+                        .set_description("Temp Variable holding Value before Incrementing");
 
                 let result_assign = ir::Statement::Assignment {
                     target: result_var.clone(),
@@ -110,8 +145,49 @@ impl UnaryOperator {
 
                 ir::Value::Variable(result_var)
             }
+            Self::Arithmetic(UnaryArithmeticOp::PrefixIncrement) => {
+                let update_assign = AStatement::Assignment {
+                    target: base.clone().assign_target(),
+                    value: AExpression::UnaryOperator {
+                        base: base.clone(),
+                        op: UnaryOperator::Arithmetic(UnaryArithmeticOp::Increment),
+                    },
+                };
+                update_assign.to_ir(block, ctx);
+
+                base.to_ir(block, ctx)
+            }
+            Self::Arithmetic(UnaryArithmeticOp::PrefixDecrement) => {
+                let update_assign = AStatement::Assignment {
+                    target: base.clone().assign_target(),
+                    value: AExpression::UnaryOperator {
+                        base: base.clone(),
+                        op: UnaryOperator::Arithmetic(UnaryArithmeticOp::Decrement),
+                    },
+                };
+                update_assign.to_ir(block, ctx);
+
+                base.to_ir(block, ctx)
+            }
             Self::Arithmetic(UnaryArithmeticOp::Positive) => base.to_ir(block, ctx),
             Self::Arithmetic(UnaryArithmeticOp::Negate) => {
+                // Negative Literals, like -1, are folded directly into a negative Constant
+                if let Value::Constant(con) = &base_value {
+                    let negated = match con {
+                        ir::Constant::I8(v) => ir::Constant::I8(v.wrapping_neg()),
+                        ir::Constant::I16(v) => ir::Constant::I16(v.wrapping_neg()),
+                        ir::Constant::I32(v) => ir::Constant::I32(v.wrapping_neg()),
+                        ir::Constant::I64(v) => ir::Constant::I64(v.wrapping_neg()),
+                        ir::Constant::U8(v) => ir::Constant::U8(v.wrapping_neg()),
+                        ir::Constant::U16(v) => ir::Constant::U16(v.wrapping_neg()),
+                        ir::Constant::U32(v) => ir::Constant::U32(v.wrapping_neg()),
+                        ir::Constant::U64(v) => ir::Constant::U64(v.wrapping_neg()),
+                        ir::Constant::F32(v) => ir::Constant::F32(-v),
+                        ir::Constant::F64(v) => ir::Constant::F64(-v),
+                    };
+                    return Value::Constant(negated);
+                }
+
                 let base_operand = AExpression::val_to_operand(base_value, block, ctx);
                 Value::Expression(ir::Expression::UnaryOp {
                     op: ir::UnaryOp::Arith(ir::UnaryArithmeticOp::Negate),
@@ -120,17 +196,41 @@ impl UnaryOperator {
             }
             Self::Arithmetic(UnaryArithmeticOp::Increment) => {
                 let base_operand = AExpression::val_to_operand(base_value, block, ctx);
-                Value::Expression(ir::Expression::UnaryOp {
+                if let AType::Pointer(element) = base.result_type().into_ty() {
+                    return pointer_step(base_operand, ir::BinaryArithmeticOp::Add, element, ctx);
+                }
+
+                let value = Value::Expression(ir::Expression::UnaryOp {
                     op: ir::UnaryOp::Arith(ir::UnaryArithmeticOp::Increment),
                     base: base_operand,
-                })
+                });
+
+                match base.result_type().into_ty() {
+                    AType::Primitve(APrimitive::Bool) => bool_result(value, block, ctx),
+                    _ => value,
+                }
             }
             Self::Arithmetic(UnaryArithmeticOp::Decrement) => {
                 let base_operand = AExpression::val_to_operand(base_value, block, ctx);
-                Value::Expression(ir::Expression::UnaryOp {
-                    op: ir::UnaryOp::Arith(ir::UnaryArithmeticOp::Decrement),
-                    base: base_operand,
-                })
+
+                match base.result_type().into_ty() {
+                    AType::Pointer(element) => {
+                        pointer_step(base_operand, ir::BinaryArithmeticOp::Sub, element, ctx)
+                    }
+                    // Decrementing a _Bool results in 1 for 0 and 0 - 1 for 1, so it simply
+                    // inverts the Value
+                    AType::Primitve(APrimitive::Bool) => {
+                        Value::Expression(ir::Expression::BinaryOp {
+                            op: ir::BinaryOp::Logic(ir::BinaryLogicOp::Equal),
+                            right: ir::Operand::Constant(zero_constant(&base_operand.ty())),
+                            left: base_operand,
+                        })
+                    }
+                    _ => Value::Expression(ir::Expression::UnaryOp {
+                        op: ir::UnaryOp::Arith(ir::UnaryArithmeticOp::Decrement),
+                        base: base_operand,
+                    }),
+                }
             }
             Self::Logic(UnaryLogicOp::Not) => {
                 let base_operand = AExpression::val_to_operand(base_value, block, ctx);
@@ -149,14 +249,60 @@ impl UnaryOperator {
                 })
             }
             Self::Derference => {
-                let read_ty = base.result_type();
+                // The Memory is read using the Type that the Pointer points to
+                let read_ty = match base.result_type().ty() {
+                    AType::Pointer(inner) => *inner,
+                    other => unreachable!("Dereferencing non Pointer {:?}", other),
+                };
+                let volatile = read_ty.is_volatile();
                 let base_operand = AExpression::val_to_operand(base_value, block, ctx);
 
                 ir::Value::Expression(ir::Expression::ReadMemory {
                     address: base_operand,
-                    read_ty: read_ty.to_ir(),
+                    read_ty: read_ty.unqualified().to_ir(ctx.arch()),
+                    volatile,
                 })
             }
         }
     }
 }
+
+/// Moves the Pointer by a single Element, like "p + 1" or "p - 1" would, instead of only by a
+/// single Byte
+fn pointer_step(
+    base: ir::Operand,
+    op: ir::BinaryArithmeticOp,
+    element: &AType,
+    ctx: &ConvertContext,
+) -> Value {
+    let element_size = element.byte_size(ctx.arch());
+
+    Value::Expression(ir::Expression::BinaryOp {
+        op: ir::BinaryOp::Arith(op),
+        left: base,
+        right: ir::Operand::Constant(ctx.arch().ptr_const(element_size as i64)),
+    })
+}
+
+/// Converts the Result of incrementing a _Bool back to either 0 or 1, like for any other
+/// Conversion to a _Bool.
+///
+/// The Arithmetic itself is performed on the 8-Bit Value, where 1 + 1 is still not 0, so it
+/// correctly results in 1
+fn bool_result(value: Value, block: &mut BasicBlock, ctx: &ConvertContext) -> Value {
+    let bool_ty = APrimitive::Bool.to_ir(ctx.arch());
+
+    let operand = AExpression::val_to_operand(value, block, ctx);
+    let result =
+        ir::Variable::tmp(ctx.next_tmp(), bool_ty).set_description("Value converted to _Bool");
+    block.add_statement(ir::Statement::Assignment {
+        target: result.clone(),
+        value: Value::Expression(ir::Expression::BinaryOp {
+            op: ir::BinaryOp::Logic(ir::BinaryLogicOp::NotEqual),
+            right: ir::Operand::Constant(zero_constant(&operand.ty())),
+            left: operand,
+        }),
+    });
+
+    Value::Variable(result)
+}
@@ -0,0 +1,120 @@
+use general::Span;
+use ir::{BasicBlock, Value};
+use syntax::{Expression, TypeToken};
+
+use crate::{
+    conversion::ConvertContext, AExpression, APrimitive, AType, SemanticError, TypeDefinitions,
+    VariableContainer,
+};
+
+/// Loads the next variable Argument from a va_list and advances the List to the Argument after
+/// it
+#[derive(Debug, PartialEq, Clone)]
+pub struct VaArg {
+    pub list: Box<AExpression>,
+    pub ty: AType,
+    pub area: Span,
+}
+
+impl VaArg {
+    pub fn parse<VC>(
+        list: Expression,
+        ty: TypeToken,
+        area: Span,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<Self, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        let list = AExpression::parse(list, ty_defs, vars)?;
+
+        // We need to update the List itself afterwards, so it has to be a Variable
+        match &list {
+            AExpression::Variable { ty, .. } if ty.data.into_ty() == &AType::va_list() => {}
+            _ => return Err(SemanticError::InvalidVarArgs { area }),
+        };
+
+        // Void can not be passed as an Argument and Arrays are passed as a Pointer to their
+        // first Element
+        let ty = AType::parse(ty, ty_defs, vars)?;
+        match ty.into_ty() {
+            AType::Primitve(APrimitive::Void) | AType::Array(_) => {
+                return Err(SemanticError::InvalidVarArgs { area })
+            }
+            _ => {}
+        };
+
+        Ok(Self {
+            list: Box::new(list),
+            ty,
+            area,
+        })
+    }
+
+    /// The Size of the Slot used for a single variable Argument of the given Type, because
+    /// every Argument takes up at least the Size of a Pointer on the Stack.
+    ///
+    /// Structs are passed as the Address of the Caller's Copy, so they are read like a Pointer
+    /// and the Struct is then used through that Address
+    fn slot_size(ty: &ir::Type, ctx: &ConvertContext) -> usize {
+        let ptr_size = ctx.pointer_size();
+
+        let size = match ty {
+            ir::Type::I8 | ir::Type::U8 => 1,
+            ir::Type::I16 | ir::Type::U16 => 2,
+            ir::Type::I32 | ir::Type::U32 | ir::Type::Float => 4,
+            ir::Type::I64 | ir::Type::U64 | ir::Type::Double => 8,
+            ir::Type::Pointer(_) | ir::Type::Struct { .. } => ptr_size,
+            ir::Type::LongDouble => 16,
+            ir::Type::Void | ir::Type::Array(..) => {
+                unreachable!("Void and Arrays are rejected when parsing the Variable Argument")
+            }
+        };
+
+        if size % ptr_size == 0 {
+            size
+        } else {
+            size + (ptr_size - size % ptr_size)
+        }
+    }
+
+    pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) -> ir::Value {
+        let name = match *self.list {
+            AExpression::Variable { name, .. } => name,
+            _ => unreachable!("The List is always a Variable"),
+        };
+
+        let read_ty = self.ty.to_ir(ctx.arch());
+        let slot_size = Self::slot_size(&read_ty, ctx);
+
+        let list_var = block.definition(&name, &|| ctx.next_tmp(), None).unwrap();
+
+        let result_var =
+            ir::Variable::tmp(ctx.next_tmp(), read_ty.clone()).set_description("Variable Argument");
+        block.add_statement(ir::Statement::Assignment {
+            target: result_var.clone(),
+            value: Value::Expression(ir::Expression::ReadMemory {
+                address: ir::Operand::Variable(list_var.clone()),
+                read_ty,
+                volatile: false,
+            }),
+        });
+
+        let next_value = Value::Expression(ir::Expression::BinaryOp {
+            op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
+            left: ir::Operand::Variable(list_var.clone()),
+            right: ir::Operand::Constant(ctx.arch().ptr_const(slot_size as i64)),
+        });
+        let next_var = list_var.next_gen();
+        let next_meta = next_value.assign_meta(&next_var);
+        let next_var = next_var.set_meta(next_meta);
+        block.add_statement(ir::Statement::Assignment {
+            target: next_var.clone(),
+            value: next_value,
+        });
+        block.add_statement(ir::Statement::SaveVariable { var: next_var });
+
+        Value::Variable(result_var)
+    }
+}
@@ -1,23 +1,45 @@
-use general::SpanData;
+use general::{Span, SpanData};
 use ir::{BasicBlock, BlockBuilder};
-use syntax::{AssignTarget, FunctionHead, Identifier, Statement};
+use syntax::{
+    AssignTarget, EnumVariants, Expression, FunctionHead, Identifier, Statement, TypeDefType,
+};
 
 use crate::{
-    atype, conversion::ConvertContext, AExpression, AFunctionArg, APrimitive, AScope, AType,
-    FunctionDeclaration, InvalidOperation, ParseState, SemanticError, VariableContainer,
+    atype, conversion::ConvertContext, write_bit_field, AExpression, AFunctionArg, APrimitive,
+    AScope, AType, FunctionDeclaration, InitializerList, InvalidOperation, Linkage, ParseState,
+    SemanticError, VariableContainer,
 };
 
 mod for_to_while;
 
+mod labels;
+
+mod switch;
+pub use switch::{ACaseLabel, ASwitchCase};
+
 mod target;
 pub use target::*;
 
+mod varargs;
+
+mod alignment;
+pub(crate) use alignment::object_alignment;
+pub use alignment::AAlignment;
+
+pub(crate) mod static_assert;
+
+pub(crate) mod variable_array;
+use variable_array::length_name as variable_length_name;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum AStatement {
     DeclareVar {
         name: String,
         src: Identifier,
         ty: AType,
+        /// The explicit Alignment of the Variable, which only affects Variables that are stored
+        /// in Memory, like Arrays and Structs
+        alignment: Option<AAlignment>,
     },
     Assignment {
         target: AAssignTarget,
@@ -28,6 +50,10 @@ pub enum AStatement {
         condition: AExpression,
         body: AScope,
     },
+    DoWhileLoop {
+        condition: AExpression,
+        body: AScope,
+    },
     ForLoop {
         condition: AExpression,
         updates: Vec<Self>,
@@ -35,17 +61,40 @@ pub enum AStatement {
     },
     Break,
     Continue,
+    /// A Label, which is visible in the entire Function it is defined in
+    Label {
+        name: Identifier,
+    },
+    Goto {
+        target: Identifier,
+    },
     If {
         condition: AExpression,
         body: AScope,
         else_: Option<AScope>,
     },
+    Switch {
+        value: AExpression,
+        cases: Vec<ASwitchCase>,
+    },
     Return {
         value: Option<AExpression>,
     },
     SubScope {
         inner: AScope,
     },
+    /// A Variable with static Storage-Duration declared inside of a Function. The contained
+    /// Declaration is stored as a Global-Variable and is therefore converted together with
+    /// the Global-Block instead of the Function itself
+    StaticVariable {
+        inner: Box<Self>,
+    },
+    /// A Static-Assertion, whose Condition depends on the Target and can therefore only be
+    /// checked once the Target is known
+    StaticAssert {
+        condition: AExpression,
+        message: SpanData<String>,
+    },
 }
 
 impl AStatement {
@@ -54,10 +103,47 @@ impl AStatement {
         parse_state: &mut ParseState,
     ) -> Result<Option<Self>, SemanticError> {
         match raw {
+            Statement::TypeDef {
+                name,
+                base_type:
+                    TypeDefType::EnumDef {
+                        name: enum_name,
+                        keyword,
+                        variants,
+                    },
+            } => {
+                Self::define_enum(enum_name, keyword, variants, parse_state)?;
+
+                // Enums are simply treated as ints everywhere
+                parse_state
+                    .mut_type_defs()
+                    .add_definition(name, AType::Primitve(APrimitive::Int));
+
+                Ok(None)
+            }
             Statement::TypeDef { name, base_type } => {
+                // A named Struct, that is defined as part of the TypeDef, can also be used on its
+                // own afterwards
+                let struct_name = match &base_type {
+                    TypeDefType::StructdDef {
+                        name: Some(struct_name),
+                        entire_span,
+                        ..
+                    } => Some(Identifier(SpanData {
+                        span: entire_span.clone(),
+                        data: struct_name.clone(),
+                    })),
+                    _ => None,
+                };
+
                 let target_ty =
                     AType::parse_typedef(base_type, parse_state.type_defs(), parse_state)?;
 
+                if let Some(struct_name) = struct_name {
+                    parse_state
+                        .mut_type_defs()
+                        .add_tag(struct_name, target_ty.clone());
+                }
                 parse_state.mut_type_defs().add_definition(name, target_ty);
 
                 Ok(None)
@@ -67,23 +153,56 @@ impl AStatement {
                 members,
                 definition,
             } => {
-                let ty =
-                    AType::parse_struct(members, definition, parse_state.type_defs(), parse_state)?;
+                let ty = AType::parse_struct(
+                    Some(name.0.data.clone()),
+                    members,
+                    definition,
+                    parse_state.type_defs(),
+                    parse_state,
+                )?;
 
-                parse_state.mut_type_defs().add_definition(name, ty);
+                parse_state.mut_type_defs().add_tag(name, ty);
                 Ok(None)
             }
-            Statement::EnumDefinition { name, variants } => {
-                let ty = AType::parse_enum(
-                    variants,
-                    name.0.span.clone(),
+            Statement::UnionDefinition {
+                name,
+                members,
+                definition,
+            } => {
+                let ty = AType::parse_union(
+                    Some(name.0.data.clone()),
+                    members,
+                    definition,
                     parse_state.type_defs(),
                     parse_state,
                 )?;
 
-                parse_state.mut_type_defs().add_definition(name, ty);
+                parse_state.mut_type_defs().add_tag(name, ty);
+                Ok(None)
+            }
+            // Declaring a Struct, that is already defined, does not change it
+            Statement::StructDeclaration { name } | Statement::UnionDeclaration { name } => {
+                if parse_state.type_defs().get_tag(&name).is_none() {
+                    parse_state
+                        .mut_type_defs()
+                        .add_tag(name.clone(), AType::Incomplete { name });
+                }
+
                 Ok(None)
             }
+            Statement::EnumDefinition {
+                name,
+                keyword,
+                variants,
+                declaration,
+            } => {
+                Self::define_enum(name, keyword, variants, parse_state)?;
+
+                match declaration {
+                    Some(declaration) => Self::parse(*declaration, parse_state),
+                    None => Ok(None),
+                }
+            }
             Statement::FunctionDeclaration(FunctionHead {
                 name,
                 r_type,
@@ -119,7 +238,14 @@ impl AStatement {
                 };
 
                 let declaration = name.0.span.clone();
-                parse_state.add_function_declaration(name, declaration, arguments, var_args, r_ty);
+                parse_state.add_function_declaration(
+                    name,
+                    declaration,
+                    arguments,
+                    var_args,
+                    r_ty,
+                    Linkage::External,
+                );
 
                 Ok(None)
             }
@@ -164,6 +290,13 @@ impl AStatement {
                     tmp
                 };
 
+                // A Function keeps the Linkage of a previous Declaration, like for
+                // "static int f(); int f() {}"
+                let linkage = parse_state
+                    .get_func(&name)
+                    .map(|prev| prev.linkage)
+                    .unwrap_or(Linkage::External);
+
                 if !parse_state.is_declared(&name) {
                     let declaration = name.0.span.clone();
                     parse_state.add_function_declaration(
@@ -172,6 +305,7 @@ impl AStatement {
                         arguments.clone(),
                         var_args,
                         r_ty.clone(),
+                        linkage,
                     );
                 }
 
@@ -189,9 +323,12 @@ impl AStatement {
                     span: name.0.span.clone(),
                     data: r_ty.clone(),
                 });
+                function_scope.set_variadic(var_args);
 
                 let inner_scope = AScope::parse(&function_scope, body)?;
 
+                labels::verify(&inner_scope)?;
+
                 // Check for correct return Statements
                 let (expected_r_val_ty, trailing_ret) = match &r_ty {
                     // If the function is of return type Void, there needs to be no return
@@ -211,26 +348,32 @@ impl AStatement {
                         None => unreachable!("If we expect a trailing Return Statement there also has to be a type set for it"),
                     };
 
-                    let (ret_ty, ret_exp) = match last {
-                        AStatement::Return { value: Some(val) } => (val.result_type(), val),
+                    match last {
+                        AStatement::Return {
+                            value: Some(ret_exp),
+                        } => {
+                            let ret_ty = ret_exp.result_type();
+                            if ret_ty != *expected_ty {
+                                return Err(SemanticError::MismatchedTypes {
+                                    expected: Box::new(SpanData {
+                                        span: name.0.span,
+                                        data: expected_ty.clone(),
+                                    }),
+                                    received: Box::new(SpanData {
+                                        span: ret_exp.entire_span(),
+                                        data: ret_ty,
+                                    }),
+                                });
+                            }
+                        }
                         AStatement::Return { value: None } => {
                             return Err(SemanticError::InvalidReturn {});
                         }
+                        // The Values of the nested Returns have already been converted to the
+                        // Return-Type when parsing them
+                        other if other.always_returns() => {}
                         _ => return Err(SemanticError::MissingReturn {}),
                     };
-
-                    if ret_ty != expected_ty {
-                        return Err(SemanticError::MismatchedTypes {
-                            expected: SpanData {
-                                span: name.0.span,
-                                data: expected_ty.clone(),
-                            },
-                            received: SpanData {
-                                span: ret_exp.entire_span(),
-                                data: ret_ty,
-                            },
-                        });
-                    }
                 }
 
                 let declaration = name.0.span.clone();
@@ -241,14 +384,89 @@ impl AStatement {
                         declaration,
                         return_ty: r_ty,
                         var_args,
+                        linkage,
                     },
                     inner_scope,
                 );
 
                 Ok(None)
             }
+            Statement::Static { inner, .. } => {
+                let func_name = match inner.as_ref() {
+                    Statement::FunctionDeclaration(head)
+                    | Statement::FunctionDefinition { head, .. } => Some(head.name.clone()),
+                    _ => None,
+                };
+
+                match func_name {
+                    Some(name) => {
+                        let result = Self::parse(*inner, parse_state)?;
+                        parse_state.set_internal_linkage(&name);
+                        Ok(result)
+                    }
+                    // Global Variables already get a Name that is unique to their Declaration,
+                    // so they can not clash with the Globals of other Translation-Units
+                    None if parse_state.return_ty().is_none() => Self::parse(*inner, parse_state),
+                    None => {
+                        let result = Self::parse(*inner, parse_state)?;
+
+                        // Static Variables are only allocated once, so their Size has to be known
+                        // at compile-time
+                        if let Some(Self::DeclareVar { ty, .. }) = &result {
+                            if let Some(length) = ty.variable_length() {
+                                return Err(SemanticError::InvalidArraySize {
+                                    area: length.entire_span(),
+                                });
+                            }
+                        }
+
+                        // Static Variables are initialized once before the Program starts, so
+                        // their Initializer can not depend on any local Variables
+                        let static_name = match &result {
+                            Some(Self::DeclareVar { name, .. }) => Some(name.clone()),
+                            Some(Self::Assignment {
+                                target: AAssignTarget::Variable { name, .. },
+                                value,
+                            }) => {
+                                if !value
+                                    .used_variables()
+                                    .iter()
+                                    .all(|var| parse_state.has_static_storage(var))
+                                {
+                                    return Err(SemanticError::NonConstantInitializer {
+                                        area: value.entire_span(),
+                                    });
+                                }
+
+                                Some(name.clone())
+                            }
+                            _ => None,
+                        };
+                        if let Some(name) = static_name {
+                            parse_state.mark_static(name);
+                        }
+
+                        Ok(result.map(|inner| Self::StaticVariable {
+                            inner: Box::new(inner),
+                        }))
+                    }
+                }
+            }
+            Statement::AlignAs {
+                alignment, inner, ..
+            } => {
+                let alignment = AAlignment::parse(alignment, parse_state.type_defs(), parse_state)?;
+
+                let result = Self::parse(*inner, parse_state)?;
+                Ok(result.map(|declaration| declaration.with_alignment(alignment)))
+            }
+            Statement::StaticAssert {
+                condition, message, ..
+            } => static_assert::parse(condition, message, parse_state),
             Statement::VariableDeclaration { ty, name } => {
-                let ty = AType::parse(ty, parse_state.type_defs(), parse_state)?;
+                let ty = AType::parse(ty, parse_state.type_defs(), parse_state)?
+                    .complete(parse_state.type_defs());
+                ty.ensure_complete(&name.0.span)?;
 
                 if parse_state.is_locally_declared(&name) {
                     let prev_dec = parse_state.get_declaration(&name).unwrap();
@@ -259,19 +477,45 @@ impl AStatement {
                 }
 
                 let declaration = name.0.span.clone();
+
+                // The Length of a variable length Array is determined once, when it is declared,
+                // and all later Uses of the Array refer to that Length
+                let var_ty = match ty.variable_length() {
+                    Some(length) if parse_state.return_ty().is_none() => {
+                        return Err(SemanticError::InvalidArraySize {
+                            area: length.entire_span(),
+                        });
+                    }
+                    Some(length) => {
+                        let int_name = ParseState::unique_var_name(&name, &declaration);
+                        ty.clone().with_variable_length(AExpression::Variable {
+                            name: variable_length_name(&int_name),
+                            src: name.clone(),
+                            ty: SpanData {
+                                span: length.entire_span(),
+                                data: AType::Primitve(APrimitive::UnsignedLongInt),
+                            },
+                        })
+                    }
+                    None => ty.clone(),
+                };
+
                 let int_name =
-                    parse_state.add_variable_declaration(name.clone(), declaration, ty.clone());
+                    parse_state.add_variable_declaration(name.clone(), declaration, var_ty);
 
                 let result = AStatement::DeclareVar {
                     name: int_name,
                     src: name,
                     ty,
+                    alignment: None,
                 };
 
                 Ok(Some(result))
             }
             Statement::VariableDeclarationAssignment { ty, name, value } => {
-                let ty = AType::parse(ty, parse_state.type_defs(), parse_state)?;
+                let ty = AType::parse(ty, parse_state.type_defs(), parse_state)?
+                    .complete(parse_state.type_defs());
+                ty.ensure_complete(&name.0.span)?;
 
                 if parse_state.is_locally_declared(&name) {
                     let prev_dec = parse_state.get_declaration(&name).unwrap();
@@ -281,45 +525,71 @@ impl AStatement {
                     });
                 }
 
+                // Variable length Arrays can not be initialized
+                if ty.variable_length().is_some() {
+                    return Err(SemanticError::InvalidInitializer {
+                        area: value.entire_span().unwrap(),
+                    });
+                }
+
+                // Initializer-Lists depend on the Type of the Variable and can also determine
+                // the Size of an Array Variable, which also applies to a String-Literal that
+                // initializes a Character-Array
+                let value = match value {
+                    Expression::StringLiteral { .. } if matches!(ty.into_ty(), AType::Array(_)) => {
+                        Expression::ArrayLiteral {
+                            parts: SpanData {
+                                span: value.entire_span().unwrap(),
+                                data: vec![value],
+                            },
+                        }
+                    }
+                    other => other,
+                };
+                if let Expression::ArrayLiteral { parts } = value {
+                    let init_exp =
+                        InitializerList::parse(ty, parts, parse_state.type_defs(), parse_state)?;
+                    let ty = init_exp.result_type();
+
+                    let declaration = name.0.span.clone();
+                    parse_state.add_variable_declaration(name.clone(), declaration, ty);
+
+                    let target = AAssignTarget::parse(
+                        AssignTarget::Variable(name),
+                        parse_state.type_defs(),
+                        parse_state,
+                    )?;
+
+                    return Ok(Some(Self::Assignment {
+                        target,
+                        value: init_exp,
+                    }));
+                }
+
                 let declaration = name.0.span.clone();
                 parse_state.add_variable_declaration(name.clone(), declaration, ty);
 
-                // Handle the assign Part of this
-                let assign_statement = Statement::VariableAssignment {
-                    target: AssignTarget::Variable(name),
-                    value,
-                };
+                // Handle the assign Part of this, which is the Initialization of the Variable and
+                // therefore also allowed for const Variables
+                let base_value_exp =
+                    AExpression::parse(value, parse_state.type_defs(), parse_state)?;
+
+                let a_target = AAssignTarget::parse(
+                    AssignTarget::Variable(name),
+                    parse_state.type_defs(),
+                    parse_state,
+                )?;
 
-                AStatement::parse(assign_statement, parse_state)
+                Self::parse_assignment(a_target, base_value_exp).map(Some)
             }
             Statement::VariableAssignment { target, value } => {
                 let base_value_exp =
                     AExpression::parse(value, parse_state.type_defs(), parse_state)?;
 
                 let a_target = AAssignTarget::parse(target, parse_state.type_defs(), parse_state)?;
-                let (var_type, var_span) = a_target.get_expected_type();
-
-                let value_exp =
-                    atype::assign_type::determine_type(base_value_exp, (&var_type, &var_span))?;
-
-                let exp_type = value_exp.result_type();
-                if var_type != exp_type {
-                    return Err(SemanticError::MismatchedTypes {
-                        expected: SpanData {
-                            span: var_span,
-                            data: var_type,
-                        },
-                        received: SpanData {
-                            span: value_exp.entire_span(),
-                            data: exp_type,
-                        },
-                    });
-                }
+                a_target.ensure_writable()?;
 
-                Ok(Some(Self::Assignment {
-                    target: a_target,
-                    value: value_exp,
-                }))
+                Self::parse_assignment(a_target, base_value_exp).map(Some)
             }
             Statement::VariableDerefAssignment { target, value } => {
                 let target_exp = AExpression::parse(target, parse_state.type_defs(), parse_state)?;
@@ -345,6 +615,7 @@ impl AStatement {
                     exp: target_exp,
                     ty_info,
                 };
+                target.ensure_writable()?;
 
                 let base_value_exp =
                     AExpression::parse(value, parse_state.type_defs(), parse_state)?;
@@ -361,6 +632,10 @@ impl AStatement {
                 }))
             }
             Statement::SingleExpression(raw_exp) => {
+                if varargs::is_builtin(&raw_exp) {
+                    return varargs::parse(raw_exp, parse_state);
+                }
+
                 let exp = AExpression::parse(raw_exp, parse_state.type_defs(), parse_state)?;
 
                 Ok(Some(Self::Expression(exp)))
@@ -375,6 +650,16 @@ impl AStatement {
                     body: inner_scope,
                 }))
             }
+            Statement::DoWhileLoop { condition, scope } => {
+                let inner_scope = AScope::parse(parse_state, scope)?;
+
+                let cond = AExpression::parse(condition, parse_state.type_defs(), parse_state)?;
+
+                Ok(Some(Self::DoWhileLoop {
+                    condition: cond,
+                    body: inner_scope,
+                }))
+            }
             Statement::ForLoop {
                 setup,
                 condition,
@@ -417,6 +702,8 @@ impl AStatement {
             }
             Statement::Break => Ok(Some(Self::Break)),
             Statement::Continue => Ok(Some(Self::Continue)),
+            Statement::Label { name } => Ok(Some(Self::Label { name })),
+            Statement::Goto { target } => Ok(Some(Self::Goto { target })),
             Statement::If {
                 condition,
                 scope,
@@ -440,6 +727,28 @@ impl AStatement {
                     else_: else_block,
                 }))
             }
+            Statement::Switch { value, cases } => {
+                let value_exp = AExpression::parse(value, parse_state.type_defs(), parse_state)?;
+
+                let value_ty = value_exp.result_type();
+                if !switch::valid_switch_type(&value_ty) {
+                    return Err(SemanticError::InvalidSwitchType {
+                        received: Box::new(SpanData {
+                            span: value_exp.entire_span(),
+                            data: value_ty,
+                        }),
+                    });
+                }
+
+                let value_exp = atype::promote(value_exp);
+                let a_cases =
+                    ASwitchCase::parse_cases(cases, &value_exp.result_type(), parse_state)?;
+
+                Ok(Some(Self::Switch {
+                    value: value_exp,
+                    cases: a_cases,
+                }))
+            }
             Statement::Return(raw_val) => {
                 let r_value = match raw_val {
                     Some(raw) => {
@@ -451,11 +760,11 @@ impl AStatement {
                         };
                         if let AType::Primitve(APrimitive::Void) = expected_r_ty.data {
                             return Err(SemanticError::MismatchedTypes {
-                                expected: expected_r_ty.clone(),
-                                received: SpanData {
+                                expected: Box::new(expected_r_ty.clone()),
+                                received: Box::new(SpanData {
                                     span: value.entire_span(),
                                     data: value.result_type(),
-                                },
+                                }),
                             });
                         }
 
@@ -475,6 +784,128 @@ impl AStatement {
         }
     }
 
+    /// Checks if every Path through the Statement ends in a Return, which is the Case for a
+    /// Switch with a default Case, where the last Case returns and no Case breaks out of it,
+    /// because every Case eventually falls through into the last one
+    fn always_returns(&self) -> bool {
+        match self {
+            Self::Return { .. } => true,
+            Self::SubScope { inner } => inner.statements.last().is_some_and(Self::always_returns),
+            Self::Switch { cases, .. } => {
+                let has_default = cases
+                    .iter()
+                    .any(|c| matches!(c.label, switch::ACaseLabel::Default(_)));
+                let breaks = cases
+                    .iter()
+                    .any(|c| c.statements.iter().any(Self::breaks_out));
+                let last_returns = cases
+                    .last()
+                    .and_then(|c| c.statements.last())
+                    .is_some_and(Self::always_returns);
+
+                has_default && !breaks && last_returns
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if the Statement contains a Break, that leaves the surrounding Loop or Switch
+    fn breaks_out(&self) -> bool {
+        match self {
+            Self::Break => true,
+            Self::If { body, else_, .. } => {
+                body.statements.iter().any(Self::breaks_out)
+                    || else_
+                        .as_ref()
+                        .is_some_and(|e| e.statements.iter().any(Self::breaks_out))
+            }
+            Self::SubScope { inner } => inner.statements.iter().any(Self::breaks_out),
+            _ => false,
+        }
+    }
+
+    /// Applies the explicit Alignment to the Declaration, which only has an Effect on
+    /// Declarations that reserve Memory for the Variable
+    fn with_alignment(self, alignment: AAlignment) -> Self {
+        match self {
+            Self::DeclareVar { name, src, ty, .. } => Self::DeclareVar {
+                name,
+                src,
+                ty,
+                alignment: Some(alignment),
+            },
+            Self::Assignment {
+                target,
+                value: AExpression::InitializerList(mut list),
+            } => {
+                list.alignment = Some(alignment);
+                Self::Assignment {
+                    target,
+                    value: AExpression::InitializerList(list),
+                }
+            }
+            Self::StaticVariable { inner } => Self::StaticVariable {
+                inner: Box::new(inner.with_alignment(alignment)),
+            },
+            other => other,
+        }
+    }
+
+    /// Parses the Variants of an Enum and makes them available as Constants, a named Enum can
+    /// afterwards also be referred to by its Tag
+    fn define_enum(
+        name: Option<Identifier>,
+        keyword: Span,
+        variants: EnumVariants,
+        parse_state: &mut ParseState,
+    ) -> Result<(), SemanticError> {
+        let area = name.as_ref().map(|n| n.0.span.clone()).unwrap_or(keyword);
+        let ty = AType::parse_enum(variants, area, parse_state.type_defs(), parse_state)?;
+
+        if let AType::Enum { def, .. } = &ty {
+            for (variant, value) in def.variants.iter() {
+                parse_state
+                    .mut_type_defs()
+                    .add_enum_constant(variant.clone(), *value);
+            }
+        }
+
+        if let Some(name) = name {
+            parse_state.mut_type_defs().add_tag(name, ty);
+        }
+        Ok(())
+    }
+
+    /// Converts the Value to the Type of the Target and creates the Assignment of it
+    fn parse_assignment(
+        target: AAssignTarget,
+        base_value: AExpression,
+    ) -> Result<Self, SemanticError> {
+        let (var_type, var_span) = target.get_expected_type();
+
+        let value_exp = atype::assign_type::determine_type(base_value, (&var_type, &var_span))?;
+
+        // The Value that is stored has no Qualifiers, those only apply to the Target itself
+        let exp_type = value_exp.result_type();
+        if var_type.clone().unqualified() != exp_type {
+            return Err(SemanticError::MismatchedTypes {
+                expected: Box::new(SpanData {
+                    span: var_span,
+                    data: var_type,
+                }),
+                received: Box::new(SpanData {
+                    span: value_exp.entire_span(),
+                    data: exp_type,
+                }),
+            });
+        }
+
+        Ok(Self::Assignment {
+            target,
+            value: value_exp,
+        })
+    }
+
     pub fn to_ir(self, block: &mut BasicBlock, ctx: &ConvertContext) {
         match self {
             Self::SubScope { inner } => {
@@ -495,20 +926,43 @@ impl AStatement {
 
                 *block = following_block;
             }
+            AStatement::StaticVariable { inner } => {
+                // Inside of the Function itself there is nothing to do, as the Variable is
+                // initialized once as part of the Global-Block
+                if ctx.global() {
+                    inner.to_ir(block, ctx);
+                }
+            }
+            // Static-Assertions are only checked at compile-time and have no Effect at runtime
+            AStatement::StaticAssert { .. } => {}
             AStatement::DeclareVar {
-                name, ty: raw_ty, ..
+                name,
+                ty: raw_ty,
+                alignment: explicit_alignment,
+                ..
             } => {
                 let ty = raw_ty.ty();
 
                 let target_name = name;
 
                 match ty {
+                    AType::Array(arr) if arr.is_variable() => variable_array::declare(
+                        target_name,
+                        arr,
+                        explicit_alignment.as_ref(),
+                        block,
+                        ctx,
+                    ),
                     AType::Array(arr) => {
-                        let arr_length = arr.size.unwrap();
-                        let alignment = arr.ty.alignment(ctx.arch()) as usize;
+                        let arr_length = arr.length_for(ctx.arch()).unwrap();
+                        let alignment = object_alignment(
+                            arr.ty.alignment(ctx.arch()) as usize,
+                            explicit_alignment.as_ref(),
+                            ctx.arch(),
+                        );
                         let size = arr_length * arr.ty.byte_size(ctx.arch()) as usize;
 
-                        let ir_ty = arr.ty.to_ir();
+                        let ir_ty = arr.ty.to_ir(ctx.arch());
                         let target_var =
                             ir::Variable::new(target_name, ir::Type::Pointer(Box::new(ir_ty)))
                                 .set_description("Declare Array Variable")
@@ -516,7 +970,9 @@ impl AStatement {
 
                         assert!(alignment != 0);
 
-                        let reserve_exp = ir::Expression::StackAlloc { size, alignment };
+                        let reserve_exp = ctx
+                            .with_static_symbol(target_var.name())
+                            .reserve_memory(size, alignment);
 
                         block.add_statement(ir::Statement::Assignment {
                             target: target_var.clone(),
@@ -524,20 +980,29 @@ impl AStatement {
                         });
                         block.add_statement(ir::Statement::SaveVariable { var: target_var });
                     }
-                    AType::Struct {
-                        def: struct_def, ..
-                    } => {
-                        let size = struct_def.entire_size(ctx.arch());
-                        let alignment = struct_def.alignment(ctx.arch());
+                    AType::Struct { ref def, .. } | AType::Union { ref def, .. } => {
+                        let (size, description) = match &ty {
+                            AType::Union { .. } => {
+                                (def.union_size(ctx.arch()), "Declare Union Variable")
+                            }
+                            _ => (def.entire_size(ctx.arch()), "Declare Struct Variable"),
+                        };
+                        let alignment = object_alignment(
+                            def.alignment(ctx.arch()),
+                            explicit_alignment.as_ref(),
+                            ctx.arch(),
+                        );
 
                         let ir_ty = ir::Type::Pointer(Box::new(ir::Type::Void));
                         let target_var = ir::Variable::new(target_name, ir_ty)
-                            .set_description("Declare Struct Variable")
+                            .set_description(description)
                             .set_global(ctx.global());
 
                         assert!(alignment != 0);
 
-                        let reserve_exp = ir::Expression::StackAlloc { size, alignment };
+                        let reserve_exp = ctx
+                            .with_static_symbol(target_var.name())
+                            .reserve_memory(size, alignment);
 
                         block.add_statement(ir::Statement::Assignment {
                             target: target_var.clone(),
@@ -546,7 +1011,13 @@ impl AStatement {
                         block.add_statement(ir::Statement::SaveVariable { var: target_var });
                     }
                     AType::Primitve(_) => {
-                        let ir_type = ty.to_ir();
+                        declare_global_alignment(
+                            &target_name,
+                            &ty,
+                            explicit_alignment.as_ref(),
+                            ctx,
+                        );
+                        let ir_type = ty.to_ir(ctx.arch());
 
                         let var = ir::Variable::new(target_name, ir_type)
                             .set_description("Declare Primitive Variable")
@@ -557,7 +1028,13 @@ impl AStatement {
                         });
                     }
                     AType::Pointer(_) => {
-                        let ir_type = ty.to_ir();
+                        declare_global_alignment(
+                            &target_name,
+                            &ty,
+                            explicit_alignment.as_ref(),
+                            ctx,
+                        );
+                        let ir_type = ty.to_ir(ctx.arch());
 
                         let var = ir::Variable::new(target_name, ir_type)
                             .set_description("Declare Pointer Variable")
@@ -576,12 +1053,19 @@ impl AStatement {
             }
             AStatement::Assignment { target, value } => {
                 let value_ty = value.result_type();
-                let value_exp = value.to_ir(block, ctx);
+                let value_exp = match &target {
+                    // The static Data for the Value of a Global is named after the Global
+                    AAssignTarget::Variable { name, .. } if ctx.global() => {
+                        value.to_ir(block, &ctx.with_static_symbol(name))
+                    }
+                    _ => value.to_ir(block, ctx),
+                };
+                let volatile = target.get_expected_type().0.is_volatile();
 
                 match target {
                     AAssignTarget::Variable { name, ty_info, .. } => {
                         if ctx.global() {
-                            let target_ty = ty_info.data.to_ir();
+                            let target_ty = ty_info.data.to_ir(ctx.arch());
                             let target = ir::Variable::new(name.clone(), target_ty);
 
                             block.add_statement(ir::Statement::Assignment {
@@ -597,14 +1081,24 @@ impl AStatement {
                         }
 
                         if let Some(global) = ctx.get_global(&name) {
-                            dbg!(&global);
-                            todo!("Assign to Global");
+                            let target = ir::Variable::tmp(ctx.next_tmp(), global.ty.clone());
+
+                            block.add_statement(ir::Statement::Assignment {
+                                target: target.clone(),
+                                value: value_exp,
+                            });
+                            block.add_statement(ir::Statement::SaveGlobalVariable {
+                                name,
+                                value: target,
+                            });
+
+                            return;
                         }
 
                         let next_var = match block.definition(&name, &|| ctx.next_tmp(), None) {
                             Some(var) => var.next_gen(),
                             None => {
-                                let target_ty = ty_info.data.to_ir();
+                                let target_ty = ty_info.data.to_ir(ctx.arch());
 
                                 debug_assert_ne!(target_ty, ir::Type::Void);
 
@@ -630,7 +1124,8 @@ impl AStatement {
 
                         let target_oper = AExpression::val_to_operand(address_value, block, ctx);
 
-                        let tmp_value_var = ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir());
+                        let tmp_value_var =
+                            ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir(ctx.arch()));
                         block.add_statement(ir::Statement::Assignment {
                             target: tmp_value_var.clone(),
                             value: value_exp.clone(),
@@ -638,6 +1133,7 @@ impl AStatement {
                         block.add_statement(ir::Statement::WriteMemory {
                             target: target_oper.clone(),
                             value: ir::Operand::Variable(tmp_value_var),
+                            volatile,
                         });
 
                         if let ir::Operand::Variable(target_var) = &target_oper {
@@ -671,7 +1167,8 @@ impl AStatement {
                         let target_value = ir::Value::Expression(target_exp);
                         let target_oper = AExpression::val_to_operand(target_value, block, ctx);
 
-                        let tmp_value_var = ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir());
+                        let tmp_value_var =
+                            ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir(ctx.arch()));
                         block.add_statement(ir::Statement::Assignment {
                             target: tmp_value_var.clone(),
                             value: value_exp,
@@ -679,15 +1176,42 @@ impl AStatement {
                         block.add_statement(ir::Statement::WriteMemory {
                             target: target_oper,
                             value: ir::Operand::Variable(tmp_value_var),
+                            volatile,
                         });
                     }
                     AAssignTarget::StructField(target) => {
+                        let (base_ty, _) = target.target.get_expected_type();
+                        let bit_field = base_ty.bit_field(&target.field.0.data, ctx.arch());
+                        let field_ty = target.ty_info.data.clone();
+
                         let target_exp = target.to_exp(block, ctx);
 
                         let target_value = ir::Value::Expression(target_exp);
                         let target_oper = AExpression::val_to_operand(target_value, block, ctx);
 
-                        let tmp_value_var = ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir());
+                        if let Some(bit_field) = bit_field {
+                            let tmp_value_var = ir::Variable::tmp(
+                                ctx.next_tmp(),
+                                field_ty.clone().to_ir(ctx.arch()),
+                            );
+                            block.add_statement(ir::Statement::Assignment {
+                                target: tmp_value_var.clone(),
+                                value: value_exp,
+                            });
+
+                            write_bit_field(
+                                &bit_field,
+                                target_oper,
+                                &field_ty,
+                                ir::Operand::Variable(tmp_value_var),
+                                block,
+                                ctx,
+                            );
+                            return;
+                        }
+
+                        let tmp_value_var =
+                            ir::Variable::tmp(ctx.next_tmp(), value_ty.to_ir(ctx.arch()));
                         block.add_statement(ir::Statement::Assignment {
                             target: tmp_value_var.clone(),
                             value: value_exp,
@@ -695,6 +1219,7 @@ impl AStatement {
                         block.add_statement(ir::Statement::WriteMemory {
                             target: target_oper,
                             value: ir::Operand::Variable(tmp_value_var),
+                            volatile,
                         });
                     }
                 };
@@ -704,6 +1229,9 @@ impl AStatement {
                     AExpression::FunctionCall(call) => {
                         call.to_standalone_ir(block, ctx);
                     }
+                    AExpression::IndirectCall(call) => {
+                        call.to_standalone_ir(block, ctx);
+                    }
                     AExpression::UnaryOperator { base, op } => {
                         op.to_ir(base, block, ctx);
                     }
@@ -751,7 +1279,7 @@ impl AStatement {
                 let ret_value = match value {
                     Some(raw_ret) => {
                         let raw_ty = raw_ret.result_type();
-                        let target_ty = raw_ty.to_ir();
+                        let target_ty = raw_ty.to_value_ir(ctx.arch());
 
                         let ret_exp = raw_ret.to_ir(block, ctx);
 
@@ -774,13 +1302,7 @@ impl AStatement {
                 else_,
             } => {
                 let cond_value = condition.to_ir(block, ctx);
-                let cond_var = ir::Variable::tmp(ctx.next_tmp(), ir::Type::I64);
-
-                let cond_statement = ir::Statement::Assignment {
-                    target: cond_var.clone(),
-                    value: cond_value,
-                };
-                block.add_statement(cond_statement);
+                let cond_var = condition_var(cond_value, block, ctx);
 
                 // The final resulting Block we reach after the If-Statement is complete
                 let end_block = BlockBuilder::new(vec![], vec![])
@@ -792,11 +1314,6 @@ impl AStatement {
                     .description("Conditional True Block")
                     .build();
                 let end_true_body = body.to_ir(&true_block, ctx);
-                end_true_body.add_statement(ir::Statement::Jump(
-                    end_block.clone(),
-                    ir::JumpMetadata::Branch,
-                ));
-                end_block.add_predecessor(end_true_body.weak_ptr());
 
                 block.add_statement(ir::Statement::JumpTrue(
                     cond_var,
@@ -809,16 +1326,37 @@ impl AStatement {
                         .description("Conditional False Block")
                         .build();
                     let end_false_block = else_.to_ir(&false_block, ctx);
-                    end_false_block.add_statement(ir::Statement::Jump(
-                        end_block.clone(),
-                        ir::JumpMetadata::Branch,
-                    ));
-                    block.add_statement(ir::Statement::Jump(
-                        end_false_block.clone(),
-                        ir::JumpMetadata::Branch,
-                    ));
-                    end_block.add_predecessor(end_false_block.weak_ptr());
+
+                    // A Side that already jumps forward does not also fall through to the End, as
+                    // long as the End can still be reached
+                    let true_jumps = ends_in_forward_jump(&end_true_body, ctx);
+                    let false_jumps = ends_in_forward_jump(&end_false_block, ctx);
+                    for (side, jumps) in [
+                        (&end_true_body, true_jumps),
+                        (&end_false_block, false_jumps),
+                    ] {
+                        if jumps && !(true_jumps && false_jumps) {
+                            continue;
+                        }
+
+                        side.add_statement(ir::Statement::Jump(
+                            end_block.clone(),
+                            ir::JumpMetadata::Branch,
+                        ));
+                        end_block.add_predecessor(side.weak_ptr());
+                    }
+
+                    block.add_statement(ir::Statement::Jump(false_block, ir::JumpMetadata::Branch));
                 } else {
+                    // A Body that already jumps forward does not also fall through to the End
+                    if !ends_in_forward_jump(&end_true_body, ctx) {
+                        end_true_body.add_statement(ir::Statement::Jump(
+                            end_block.clone(),
+                            ir::JumpMetadata::Branch,
+                        ));
+                        end_block.add_predecessor(end_true_body.weak_ptr());
+                    }
+
                     // Jump to the end Block directly
                     block.add_statement(ir::Statement::Jump(
                         end_block.clone(),
@@ -836,11 +1374,11 @@ impl AStatement {
                 start_block.add_predecessor(inner_block.weak_ptr());
 
                 for var in condition.used_variables() {
-                    if let Some(global) = ctx.get_global(&var) {
-                        todo!("Global Variable in Condition");
+                    // Globals are always read again from Memory, so they dont need a Phi-Node
+                    if ctx.get_global(&var).is_some() {
+                        continue;
                     }
 
-                    debug_assert!(ctx.get_global(&var).is_none());
                     let definition: ir::Variable = start_block
                         .definition(&var, &|| ctx.next_tmp(), None)
                         .unwrap();
@@ -855,25 +1393,33 @@ impl AStatement {
 
                 // Generate the first iteration of the start Block
                 {
-                    let mut cond_start = start_block.clone();
-                    let cond_value = condition.to_ir(&mut cond_start, ctx);
-                    let cond_var = ir::Variable::tmp(ctx.next_tmp(), ir::Type::I64);
-
-                    let cond_statement = ir::Statement::Assignment {
-                        target: cond_var.clone(),
-                        value: cond_value,
+                    let mut cond_start = if condition.short_circuits() {
+                        let cond_block = BlockBuilder::new(vec![start_block.weak_ptr()], vec![])
+                            .description("While Condition")
+                            .build();
+                        enter_loop(&start_block, &cond_block, &end_block, ctx);
+                        cond_block
+                    } else {
+                        start_block.clone()
                     };
-                    start_block.add_statement(cond_statement);
+                    let cond_value = condition.to_ir(&mut cond_start, ctx);
+                    let cond_var = condition_var(cond_value, &cond_start, ctx);
 
-                    start_block.add_statement(ir::Statement::JumpTrue(
+                    cond_start.add_statement(ir::Statement::JumpTrue(
                         cond_var,
                         inner_block.clone(),
                         ir::JumpMetadata::Linear,
                     ));
-                    start_block.add_statement(ir::Statement::Jump(
+                    cond_start.add_statement(ir::Statement::Jump(
                         end_block.clone(),
                         ir::JumpMetadata::LoopBreak,
                     ));
+
+                    if cond_start.as_ptr() != start_block.as_ptr() {
+                        inner_block.remove_predecessor(start_block.weak_ptr());
+                        inner_block.add_predecessor(cond_start.weak_ptr());
+                        end_block.add_predecessor(cond_start.weak_ptr());
+                    }
                 }
                 start_block.remove_predecessor(inner_block.weak_ptr());
 
@@ -897,6 +1443,75 @@ impl AStatement {
                 block.add_statement(ir::Statement::Jump(start_block, ir::JumpMetadata::Linear));
                 *block = end_block;
             }
+            AStatement::DoWhileLoop { condition, body } => {
+                let inner_block = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+                    .description("Do-While Body")
+                    .build();
+                let cond_block = BlockBuilder::new(vec![], vec![])
+                    .description("Do-While Condition")
+                    .build();
+                let end_block = BasicBlock::new(vec![cond_block.weak_ptr()], vec![]);
+
+                // The Loop gets a separate Start, which always enters the Body, so that all the
+                // Phi-Nodes of the Loop are placed at the Start of a Block and the Loop has a
+                // single Head, which decides between the Loop and its End
+                let start_block = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+                    .description("Do-While Start")
+                    .build();
+                inner_block.remove_predecessor(block.weak_ptr());
+                inner_block.add_predecessor(start_block.weak_ptr());
+
+                block.add_statement(ir::Statement::Jump(
+                    start_block.clone(),
+                    ir::JumpMetadata::Linear,
+                ));
+
+                // While generating the Body we add the Start itself as a temporary predecessor,
+                // which makes sure that all the Variables used in it get Phi-Nodes that will
+                // later be filled in with the Back-Edge
+                start_block.add_predecessor(start_block.weak_ptr());
+
+                // Generate the inner Part of the Loop, where a continue jumps to the Condition
+                {
+                    let loop_ctx = ctx.with_do_while_loop(cond_block.clone(), end_block.clone());
+
+                    let inner_end_block = body.to_ir(&inner_block, &loop_ctx);
+                    inner_end_block.add_statement(ir::Statement::Jump(
+                        cond_block.clone(),
+                        ir::JumpMetadata::Linear,
+                    ));
+                    cond_block.add_predecessor(inner_end_block.weak_ptr());
+                }
+
+                // Generate the Condition at the End of the Loop
+                {
+                    let mut cond_end = cond_block.clone();
+                    let cond_value = condition.to_ir(&mut cond_end, ctx);
+                    let cond_var = condition_var(cond_value, &cond_end, ctx);
+                    cond_end.add_statement(ir::Statement::JumpTrue(
+                        cond_var,
+                        start_block.clone(),
+                        ir::JumpMetadata::Loop,
+                    ));
+                    cond_end.add_statement(ir::Statement::Jump(
+                        end_block.clone(),
+                        ir::JumpMetadata::LoopBreak,
+                    ));
+
+                    start_block.remove_predecessor(start_block.weak_ptr());
+                    start_block.add_predecessor(cond_end.weak_ptr());
+
+                    end_block.remove_predecessor(cond_block.weak_ptr());
+                    end_block.add_predecessor(cond_end.weak_ptr());
+                }
+
+                // The Start is only entered once all the Phi-Nodes have been created, so that
+                // they are placed before any other Statement
+                enter_loop(&start_block, &inner_block, &end_block, ctx);
+                start_block.refresh_phis();
+
+                *block = end_block;
+            }
             AStatement::ForLoop {
                 condition,
                 body,
@@ -905,30 +1520,128 @@ impl AStatement {
                 let while_statement = for_to_while::convert(condition, body, updates);
                 while_statement.to_ir(block, ctx);
             }
+            AStatement::Switch { value, cases } => {
+                switch::to_ir(value, cases, block, ctx);
+            }
             AStatement::Break => {
+                if let Some(switch_end) = ctx.get_switch_end() {
+                    variable_array::restore_stack(ctx.switch_stack_depth(), block, ctx);
+                    switch_end.add_predecessor(block.weak_ptr());
+                    block.add_statement(ir::Statement::Jump(
+                        switch_end.clone(),
+                        ir::JumpMetadata::Branch,
+                    ));
+                    return;
+                }
+
                 let loop_end_block = match ctx.get_loop_end() {
                     Some(b) => b,
                     None => panic!("Break outside of Loop"),
                 };
 
+                variable_array::restore_stack(ctx.loop_stack_depth(), block, ctx);
                 loop_end_block.add_predecessor(block.weak_ptr());
                 block.add_statement(ir::Statement::Jump(
                     loop_end_block.clone(),
                     ir::JumpMetadata::LoopBreak,
                 ));
             }
+            AStatement::Label { name } => {
+                labels::label_to_ir(name, block, ctx);
+            }
+            AStatement::Goto { target } => {
+                labels::goto_to_ir(target, block, ctx);
+            }
             AStatement::Continue => {
                 let loop_start_block = match ctx.get_loop_start() {
                     Some(b) => b,
                     None => panic!("Continue outside of loop"),
                 };
 
+                variable_array::restore_stack(ctx.loop_stack_depth(), block, ctx);
                 loop_start_block.add_predecessor(block.weak_ptr());
                 block.add_statement(ir::Statement::Jump(
                     loop_start_block.clone(),
-                    ir::JumpMetadata::Loop,
+                    ctx.continue_metadata(),
                 ));
             }
         };
     }
 }
+
+/// Starts a Do-While-Loop or a Loop, whose Condition contains Short-Circuit Operations, by always
+/// jumping into the given Block.
+///
+/// The Start only exists so that the Loop has a single Head, which decides between the Loop and
+/// its End, and the Jump to the End is never actually taken
+fn enter_loop(
+    start_block: &BasicBlock,
+    target: &BasicBlock,
+    end_block: &BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let always_var = condition_var(ir::Value::Constant(ir::Constant::I64(1)), start_block, ctx);
+    start_block.add_statement(ir::Statement::JumpTrue(
+        always_var,
+        target.clone(),
+        ir::JumpMetadata::Linear,
+    ));
+    start_block.add_statement(ir::Statement::Jump(
+        end_block.clone(),
+        ir::JumpMetadata::LoopBreak,
+    ));
+    end_block.add_predecessor(start_block.weak_ptr());
+}
+
+/// Checks if the Block ends in a Jump forward, like a Goto to a later Label or a Continue in a
+/// Do-While-Loop, in which case it can not also fall through to the following Block.
+///
+/// Breaks still get the Fall-Through, which is never taken but keeps the Branch nested inside of
+/// its Loop or Switch
+fn ends_in_forward_jump(block: &BasicBlock, ctx: &ConvertContext) -> bool {
+    if ctx.is_unreachable(block) {
+        return true;
+    }
+
+    match block.get_statements().last() {
+        Some(ir::Statement::Jump(target, ir::JumpMetadata::Branch)) => ctx
+            .get_switch_end()
+            .map(|end| end.as_ptr() != target.as_ptr())
+            .unwrap_or(true),
+        _ => false,
+    }
+}
+
+/// Stores the Value of a Condition in a Variable, that can then be used for a conditional Jump
+fn condition_var(value: ir::Value, block: &BasicBlock, ctx: &ConvertContext) -> ir::Variable {
+    match value {
+        // Short-Circuit Operations already result in a Variable, which can be used directly
+        ir::Value::Variable(var) => var,
+        other => {
+            let cond_var = ir::Variable::tmp(ctx.next_tmp(), ir::Type::I64);
+            block.add_statement(ir::Statement::Assignment {
+                target: cond_var.clone(),
+                value: other,
+            });
+            cond_var
+        }
+    }
+}
+
+/// Records the explicit Alignment of a Global, which is not already part of its Value, so that
+/// the Backends can place it accordingly
+fn declare_global_alignment(
+    name: &str,
+    ty: &AType,
+    explicit: Option<&AAlignment>,
+    ctx: &ConvertContext,
+) {
+    if let (true, Some(explicit)) = (ctx.global(), explicit) {
+        let alignment = object_alignment(
+            ty.alignment(ctx.arch()) as usize,
+            Some(explicit),
+            ctx.arch(),
+        );
+        ctx.add_global_alignment(name, alignment);
+    }
+}
@@ -0,0 +1,67 @@
+use general::arch::Arch;
+use syntax::{Alignment, Expression};
+
+use crate::{
+    AExpression, AType, EvaluationValue, SemanticError, TypeDefinitions, VariableContainer,
+};
+
+/// The explicit Alignment of an Object, given by an Alignment-Specifier like "_Alignas(16)"
+#[derive(Debug, PartialEq, Clone)]
+pub enum AAlignment {
+    /// The Object uses the Alignment of the given Type
+    Type(AType),
+    /// The Object is aligned to the given Number of Bytes, where 0 has no Effect
+    Bytes(usize),
+}
+
+impl AAlignment {
+    pub fn parse<VC>(
+        raw: Alignment,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<Self, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        let raw_exp = match raw {
+            Alignment::Type(ty) => return AType::parse(ty, ty_defs, vars).map(Self::Type),
+            // The Parser can not distinguish between a Variable and the Name of a TypeDef
+            Alignment::Expression(Expression::Identifier { ident }) => {
+                match ty_defs.get_definition(&ident) {
+                    Some(ty) => return Ok(Self::Type(ty.clone())),
+                    None => Expression::Identifier { ident },
+                }
+            }
+            Alignment::Expression(exp) => exp,
+        };
+
+        let exp = AExpression::parse(raw_exp, ty_defs, vars)?;
+        match exp.const_evaluate() {
+            Ok(EvaluationValue::Integer(value))
+                if value == 0 || (value > 0 && (value as u64).is_power_of_two()) =>
+            {
+                Ok(Self::Bytes(value as usize))
+            }
+            _ => Err(SemanticError::InvalidAlignment {
+                area: exp.entire_span(),
+            }),
+        }
+    }
+
+    /// The Alignment in Bytes on the given Target
+    pub fn bytes(&self, arch: &Arch) -> usize {
+        match self {
+            Self::Type(ty) => ty.alignment(arch) as usize,
+            Self::Bytes(bytes) => *bytes,
+        }
+    }
+}
+
+/// The Alignment of an Object, which is the natural Alignment of its Type unless the Object has
+/// a stricter explicit Alignment
+pub fn object_alignment(natural: usize, explicit: Option<&AAlignment>, arch: &Arch) -> usize {
+    match explicit {
+        Some(alignment) => natural.max(alignment.bytes(arch)),
+        None => natural,
+    }
+}
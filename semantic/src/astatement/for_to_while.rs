@@ -1,27 +1,41 @@
 use crate::{AExpression, AScope, AStatement};
 
-fn insert_updates(scope: &mut AScope, _updates: &[AStatement]) {
-    let start = 0;
-    loop {
-        let sub_set = &scope.statements[start..];
-
-        let raw_found = sub_set
-            .iter()
-            .enumerate()
-            .find(|(_, s)| matches!(s, AStatement::Continue))
-            .map(|(i, _)| i);
-
-        match raw_found {
-            Some(_) => {
-                todo!()
+/// Inserts the Updates of the Loop before every Continue, that belongs to the Loop itself,
+/// because a Continue in a For-Loop still performs the Updates before checking the Condition
+/// again.
+///
+/// Continues in nested Loops belong to those Loops and are therefore left alone
+fn insert_updates(statements: &mut Vec<AStatement>, updates: &[AStatement]) {
+    let mut index = 0;
+    while index < statements.len() {
+        match &mut statements[index] {
+            AStatement::Continue => {
+                statements.splice(index..index, updates.iter().cloned());
+                index += updates.len();
+            }
+            AStatement::If { body, else_, .. } => {
+                insert_updates(&mut body.statements, updates);
+                if let Some(else_) = else_ {
+                    insert_updates(&mut else_.statements, updates);
+                }
             }
-            None => break,
+            AStatement::SubScope { inner } => {
+                insert_updates(&mut inner.statements, updates);
+            }
+            AStatement::Switch { cases, .. } => {
+                for case in cases.iter_mut() {
+                    insert_updates(&mut case.statements, updates);
+                }
+            }
+            _ => {}
         };
+
+        index += 1;
     }
 }
 
 pub fn convert(condition: AExpression, mut body: AScope, updates: Vec<AStatement>) -> AStatement {
-    insert_updates(&mut body, &updates);
+    insert_updates(&mut body.statements, &updates);
 
     body.statements.extend(updates);
 
@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet};
+
+use general::Span;
+use ir::{BasicBlock, BlockBuilder};
+use syntax::Identifier;
+
+use crate::{conversion::ConvertContext, AScope, AStatement, SemanticError};
+
+use super::variable_array;
+
+/// Checks the Labels and Gotos of an entire Function Body, because Labels are visible in the
+/// entire Function, regardless of the Scope they are defined in.
+///
+/// Every Label can only be defined once and every Goto has to target an existing Label
+pub fn verify(body: &AScope) -> Result<(), SemanticError> {
+    let mut labels: HashMap<String, Span> = HashMap::new();
+    let mut gotos: Vec<Identifier> = Vec::new();
+
+    for statement in body.nested_statement_iter() {
+        match statement {
+            AStatement::Label { name } => {
+                if let Some(prev) = labels.get(&name.0.data) {
+                    return Err(SemanticError::DuplicateLabel {
+                        name,
+                        previous_definition: prev.clone(),
+                    });
+                }
+
+                labels.insert(name.0.data, name.0.span);
+            }
+            AStatement::Goto { target } => {
+                gotos.push(target);
+            }
+            _ => {}
+        };
+    }
+
+    match gotos
+        .into_iter()
+        .find(|target| !labels.contains_key(&target.0.data))
+    {
+        Some(unknown) => Err(SemanticError::UnknownLabel { name: unknown }),
+        None => Ok(()),
+    }
+}
+
+/// Converts a Label by ending the current Block with a Jump to the Block of the Label, which is
+/// then used for all the following Statements.
+///
+/// Not all the predecessors of the Label are known yet, because of Gotos further down in the
+/// Function, so every Variable that is visible at the Label gets an empty Phi-Node, which is
+/// filled in once the entire Function has been converted
+pub fn label_to_ir(name: Identifier, block: &mut BasicBlock, ctx: &ConvertContext) {
+    let label_block = ctx.get_label(&name.0.data);
+
+    // Code like "goto done; fail: ..." can never fall through into the Label, so the Variables
+    // visible at the Label come from the Gotos targeting it and the Definitions kept for the
+    // unreachable Block
+    let sources = if ctx.is_unreachable(block) {
+        label_block
+            .get_predecessors()
+            .into_iter()
+            .filter_map(|pred| pred.upgrade())
+            .chain(std::iter::once(block.clone()))
+            .collect()
+    } else {
+        let block_ends = matches!(
+            block.get_statements().last(),
+            Some(ir::Statement::Jump(..)) | Some(ir::Statement::Return(_))
+        );
+        if !block_ends {
+            block.add_statement(ir::Statement::Jump(
+                label_block.clone(),
+                ir::JumpMetadata::Linear,
+            ));
+            label_block.add_predecessor(block.weak_ptr());
+        }
+        vec![block.clone()]
+    };
+
+    let mut phi_names: Vec<String> = Vec::new();
+    for source in sources.iter() {
+        for var_name in visible_variables(source, ctx) {
+            if phi_names.contains(&var_name) {
+                continue;
+            }
+
+            let definition = match source.definition(&var_name, &|| ctx.next_tmp(), None) {
+                Some(d) => d,
+                None => continue,
+            };
+
+            label_block.add_statement(ir::Statement::Assignment {
+                target: definition.next_gen(),
+                value: ir::Value::Phi { sources: vec![] },
+            });
+            phi_names.push(var_name);
+        }
+    }
+
+    *block = label_block;
+}
+
+/// Collects the Names of all the local Variables, which are defined in the Block or any of its
+/// predecessors
+fn visible_variables(block: &BasicBlock, ctx: &ConvertContext) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut visited = HashSet::new();
+    let mut remaining = vec![block.clone()];
+
+    while let Some(current) = remaining.pop() {
+        if !visited.insert(current.as_ptr()) {
+            continue;
+        }
+
+        for statement in current.get_statements() {
+            let target = match statement {
+                ir::Statement::Assignment { target, .. } => target,
+                _ => continue,
+            };
+
+            // Globals are always read again from Memory, so they dont need a Phi-Node
+            if target.is_tmp() || target.global() || ctx.get_global(target.name()).is_some() {
+                continue;
+            }
+            if !names.iter().any(|n| n == target.name()) {
+                names.push(target.name().to_string());
+            }
+        }
+
+        remaining.extend(
+            current
+                .get_predecessors()
+                .into_iter()
+                .filter_map(|pred| pred.upgrade()),
+        );
+    }
+
+    names
+}
+
+/// Converts a Goto into a Jump to the Block of the targeted Label and continues in a new Block,
+/// because the following Statements can only be reached through a Label
+pub fn goto_to_ir(target: Identifier, block: &mut BasicBlock, ctx: &ConvertContext) {
+    let backward = ctx.is_backward_goto(&target.0.span);
+    let metadata = if backward {
+        ir::JumpMetadata::Loop
+    } else {
+        ir::JumpMetadata::Branch
+    };
+
+    // Jumping out of the Scope of variable length Arrays releases their Memory, like any other
+    // way of leaving the Scope
+    variable_array::restore_stack(ctx.label_stack_depth(&target.0.data), block, ctx);
+
+    // Resolving the Definitions before the Jump keeps the Phi-Nodes they need in front of it,
+    // because the Label looks them up again once the entire Function has been converted
+    let definitions: Vec<_> = visible_variables(block, ctx)
+        .into_iter()
+        .filter_map(|var_name| block.definition(&var_name, &|| ctx.next_tmp(), None))
+        .collect();
+
+    let label_block = ctx.get_label(&target.0.data);
+    label_block.add_predecessor(block.weak_ptr());
+
+    // A backward Goto always jumps back to its Label, like the Start of a Loop, and the Jump to
+    // the following Block is never taken, but still gives the Loop an Exit
+    if backward {
+        let always_var =
+            super::condition_var(ir::Value::Constant(ir::Constant::I64(1)), block, ctx);
+        block.add_statement(ir::Statement::JumpTrue(always_var, label_block, metadata));
+
+        let next = BlockBuilder::new(vec![block.weak_ptr()], vec![])
+            .description("Never reached after Goto")
+            .build();
+        block.add_statement(ir::Statement::Jump(next.clone(), ir::JumpMetadata::Linear));
+        *block = next;
+        return;
+    }
+
+    block.add_statement(ir::Statement::Jump(label_block, metadata));
+
+    // The Block already ends in the Jump, so it does not fall through into the following
+    // Statements and the unreachable Block still needs Definitions for the Variables used in it
+    let unreachable = BlockBuilder::new(vec![], vec![])
+        .description("Unreachable after Goto")
+        .build();
+    for definition in definitions {
+        unreachable.add_statement(ir::Statement::Assignment {
+            target: definition.next_gen(),
+            value: ir::Value::Unknown,
+        });
+    }
+
+    *block = unreachable;
+}
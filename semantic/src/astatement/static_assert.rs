@@ -0,0 +1,52 @@
+use general::{arch::Arch, SpanData};
+use syntax::Expression;
+
+use crate::{
+    AExpression, AScope, AStatement, EvaluationError, EvaluationValue, ParseState, SemanticError,
+};
+
+/// Parses a Static-Assertion and checks it right away, if its Condition does not depend on the
+/// Target. Otherwise the Assertion is kept, to be checked once the Target is known
+pub fn parse(
+    condition: Expression,
+    message: SpanData<String>,
+    parse_state: &mut ParseState,
+) -> Result<Option<AStatement>, SemanticError> {
+    let condition = AExpression::parse(condition, parse_state.type_defs(), parse_state)?;
+
+    match condition.const_evaluate() {
+        Err(EvaluationError::TargetDependent) => {
+            Ok(Some(AStatement::StaticAssert { condition, message }))
+        }
+        other => check(other, &condition, message).map(|_| None),
+    }
+}
+
+/// Checks all the Static-Assertions in the Scope, including the ones nested in other Statements,
+/// for the given Target
+pub fn verify(scope: &AScope, arch: &Arch) -> Result<(), SemanticError> {
+    for statement in scope.nested_statement_iter() {
+        if let AStatement::StaticAssert { condition, message } = statement {
+            check(condition.const_evaluate_for(arch), &condition, message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check(
+    value: Result<EvaluationValue, EvaluationError>,
+    condition: &AExpression,
+    message: SpanData<String>,
+) -> Result<(), SemanticError> {
+    match value {
+        Ok(EvaluationValue::Integer(0)) => Err(SemanticError::FailedStaticAssertion {
+            condition: condition.entire_span(),
+            message,
+        }),
+        Ok(EvaluationValue::Integer(_)) => Ok(()),
+        _ => Err(SemanticError::InvalidStaticAssertion {
+            condition: condition.entire_span(),
+        }),
+    }
+}
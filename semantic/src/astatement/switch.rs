@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use general::{Span, SpanData};
+use ir::{BasicBlock, BlockBuilder};
+use syntax::{CaseLabel, SwitchCase};
+
+use crate::{
+    conversion::ConvertContext, AExpression, AScope, AStatement, AType, EvaluationValue,
+    ParseState, SemanticError,
+};
+
+use super::variable_array;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ACaseLabel {
+    /// A Case with the already evaluated constant Value it matches
+    Value(SpanData<i64>),
+    /// The default Case
+    Default(Span),
+    /// The Statements before the first Label, which are never entered by the Switch itself
+    Unlabeled,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ASwitchCase {
+    pub label: ACaseLabel,
+    pub statements: Vec<AStatement>,
+}
+
+impl ASwitchCase {
+    /// Parses all the Cases of a single Switch-Statement, making sure that every Case-Value is
+    /// an integer constant and only used once and that there is at most one default Case.
+    ///
+    /// The Case-Values are converted to the already promoted Type of the Switch-Value, before
+    /// checking them for Duplicates. All the Cases share the same Scope, like they would in C
+    pub fn parse_cases(
+        raw_cases: Vec<SwitchCase>,
+        value_ty: &AType,
+        parse_state: &ParseState,
+    ) -> Result<Vec<Self>, SemanticError> {
+        let mut switch_state = ParseState::based(parse_state);
+
+        let mut previous_values: Vec<SpanData<i64>> = Vec::new();
+        let mut previous_default: Option<Span> = None;
+
+        let mut cases = Vec::with_capacity(raw_cases.len());
+        for raw_case in raw_cases {
+            let label = match raw_case.label {
+                CaseLabel::Case(raw_value) => {
+                    let value_exp =
+                        AExpression::parse(raw_value, switch_state.type_defs(), &switch_state)?;
+                    let value_span = value_exp.entire_span();
+
+                    let value = match value_exp.const_evaluate() {
+                        Ok(EvaluationValue::Integer(v)) => v,
+                        _ => return Err(SemanticError::InvalidCaseValue { case: value_span }),
+                    };
+
+                    // The Width of a long depends on the Target, so a Value that does not fit
+                    // into every long is only truncated once it is converted to the IR
+                    let converted = AExpression::Cast {
+                        base: Box::new(value_exp),
+                        target: value_ty.clone(),
+                    };
+                    let value = match converted.const_evaluate() {
+                        Ok(EvaluationValue::Integer(v)) => v,
+                        _ => value,
+                    };
+
+                    if let Some(prev) = previous_values.iter().find(|p| p.data == value) {
+                        return Err(SemanticError::DuplicateCase {
+                            value: SpanData {
+                                span: value_span,
+                                data: value,
+                            },
+                            previous_case: prev.span.clone(),
+                        });
+                    }
+
+                    let value = SpanData {
+                        span: value_span,
+                        data: value,
+                    };
+                    previous_values.push(value.clone());
+
+                    ACaseLabel::Value(value)
+                }
+                CaseLabel::Default(span) => {
+                    if let Some(prev) = previous_default {
+                        return Err(SemanticError::DuplicateDefault {
+                            default: span,
+                            previous_default: prev,
+                        });
+                    }
+                    previous_default = Some(span.clone());
+
+                    ACaseLabel::Default(span)
+                }
+                CaseLabel::Unlabeled => ACaseLabel::Unlabeled,
+            };
+
+            let mut statements = Vec::new();
+            for raw_statement in raw_case.statements {
+                if let Some(statement) = AStatement::parse(raw_statement, &mut switch_state)? {
+                    statements.push(statement);
+                }
+            }
+
+            // Without a Label among them, nothing can ever reach the Statements before the
+            // first Case, while jumping into them is not supported
+            if label == ACaseLabel::Unlabeled {
+                let unlabeled = AScope {
+                    statements: statements.clone(),
+                    function_definitions: HashMap::new(),
+                };
+                if unlabeled
+                    .nested_statement_iter()
+                    .any(|s| matches!(s, AStatement::Label { .. }))
+                {
+                    return Err(SemanticError::NotImplemented {
+                        ctx: "Labels before the first Case of a Switch".to_string(),
+                    });
+                }
+            }
+
+            cases.push(Self { label, statements });
+        }
+
+        Ok(cases)
+    }
+}
+
+/// Checks if the given Type is allowed as the Value of a Switch-Statement
+pub fn valid_switch_type(ty: &AType) -> bool {
+    match ty.into_ty() {
+        AType::Primitve(prim) => prim.rank().is_some(),
+        AType::Const(inner) | AType::Volatile(inner) => valid_switch_type(inner),
+        _ => false,
+    }
+}
+
+fn case_constant(value: i64, ty: &ir::Type) -> ir::Constant {
+    match ty {
+        ir::Type::I8 => ir::Constant::I8(value as i8),
+        ir::Type::I16 => ir::Constant::I16(value as i16),
+        ir::Type::I32 => ir::Constant::I32(value as i32),
+        ir::Type::I64 => ir::Constant::I64(value),
+        ir::Type::U8 => ir::Constant::U8(value as u8),
+        ir::Type::U16 => ir::Constant::U16(value as u16),
+        ir::Type::U32 => ir::Constant::U32(value as u32),
+        ir::Type::U64 => ir::Constant::U64(value as u64),
+        other => unreachable!("Switch Values are always integers, got {:?}", other),
+    }
+}
+
+/// Converts the Switch-Statement into a chain of Guards, where every Case-Block is guarded by
+/// its own Block, that compares the Value against the Case and then either enters the Case or
+/// continues with the Guard of the next Case.
+///
+/// A Case is entered, if the Value matches it or the Guard of the previous Case was set,
+/// because the previous Case then falls through into it. The default Case is entered if the
+/// Value matches none of the Cases. This makes sure that every Case-Block is only entered from
+/// its Guard.
+///
+/// Every Case-Block falls through into the Guard of the next Case, unless it ends in a Jump
+/// forward, like a Break or a Goto to a later Label, and a Break inside of any Case jumps to the
+/// Block following the Switch. Like for an If-Statement, a Case that returns still gets the
+/// Fall-Through, which is never taken but keeps the Case nested inside of the Switch
+pub fn to_ir(
+    value: AExpression,
+    cases: Vec<ASwitchCase>,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let value_ty = value.result_type().to_ir(ctx.arch());
+    let switch_value = value.to_ir(block, ctx);
+    let switch_var =
+        ir::Variable::tmp(ctx.next_tmp(), value_ty.clone()).set_description("Switch Value");
+    block.add_statement(ir::Statement::Assignment {
+        target: switch_var.clone(),
+        value: switch_value,
+    });
+
+    let case_values: Vec<i64> = cases
+        .iter()
+        .filter_map(|c| match &c.label {
+            ACaseLabel::Value(v) => Some(v.data),
+            ACaseLabel::Default(_) | ACaseLabel::Unlabeled => None,
+        })
+        .collect();
+
+    let end_block = BlockBuilder::new(vec![], vec![])
+        .description("Switch After Block")
+        .build();
+
+    // All the Cases share a single Scope, so the Stack-Pointer for their variable length Arrays is
+    // saved once before the Switch and restored at its End
+    let stack_ctx = variable_array::first_in_cases(&cases)
+        .map(|array| variable_array::save_stack(array, block, ctx));
+    let case_ctx = stack_ctx.as_ref().unwrap_or(ctx);
+    let switch_ctx = case_ctx.with_switch(end_block.clone());
+
+    let mut case_iter = cases.into_iter().peekable();
+    // The Statements before the first Label are never executed, but the Variables they declare
+    // can still be used by all the Cases
+    if let Some(ACaseLabel::Unlabeled) = case_iter.peek().map(|c| &c.label) {
+        let unlabeled = case_iter.next().expect("We just peeked it");
+        for declaration in unlabeled
+            .statements
+            .into_iter()
+            .filter(|s| matches!(s, AStatement::DeclareVar { .. }))
+        {
+            declaration.to_ir(block, case_ctx);
+        }
+    }
+
+    // Without any Cases the Value is only evaluated for its Side-Effects
+    if case_iter.peek().is_none() {
+        block.add_statement(ir::Statement::Jump(
+            end_block.clone(),
+            ir::JumpMetadata::Linear,
+        ));
+        end_block.add_predecessor(block.weak_ptr());
+    }
+
+    let mut guard_block = block.clone();
+    let mut previous_guard: Option<ir::Operand> = None;
+    while let Some(case) = case_iter.next() {
+        let matches = match &case.label {
+            ACaseLabel::Value(value) => compare(
+                ir::BinaryLogicOp::Equal,
+                &switch_var,
+                case_constant(value.data, &value_ty),
+                &guard_block,
+                ctx,
+            ),
+            ACaseLabel::Default(_) => unmatched(&case_values, &switch_var, &guard_block, ctx),
+            ACaseLabel::Unlabeled => unreachable!("Only the first Case can be unlabeled"),
+        };
+        let guard = match previous_guard {
+            Some(previous) => assign_tmp(
+                ir::Expression::BinaryOp {
+                    op: ir::BinaryOp::Bitwise(ir::BinaryBitwiseOp::Or),
+                    left: previous,
+                    right: matches,
+                },
+                &guard_block,
+                ctx,
+            ),
+            None => matches,
+        };
+        let guard_var = match &guard {
+            ir::Operand::Variable(var) => var.clone(),
+            ir::Operand::Constant(_) => unreachable!("Guards are always stored in a Variable"),
+        };
+        previous_guard = Some(guard);
+
+        let case_block = BlockBuilder::new(vec![guard_block.weak_ptr()], vec![])
+            .description("Switch Case Block")
+            .build();
+        let next_block = match case_iter.peek() {
+            Some(_) => BlockBuilder::new(vec![guard_block.weak_ptr()], vec![])
+                .description("Switch Guard Block")
+                .build(),
+            None => {
+                end_block.add_predecessor(guard_block.weak_ptr());
+                end_block.clone()
+            }
+        };
+
+        guard_block.add_statement(ir::Statement::JumpTrue(
+            guard_var,
+            case_block.clone(),
+            ir::JumpMetadata::Branch,
+        ));
+        guard_block.add_statement(ir::Statement::Jump(
+            next_block.clone(),
+            ir::JumpMetadata::Linear,
+        ));
+
+        let mut current = case_block;
+        for statement in case.statements {
+            statement.to_ir(&mut current, &switch_ctx);
+        }
+
+        if !leaves_case(&current, ctx) {
+            current.add_statement(ir::Statement::Jump(
+                next_block.clone(),
+                ir::JumpMetadata::Linear,
+            ));
+            next_block.add_predecessor(current.weak_ptr());
+        }
+
+        guard_block = next_block;
+    }
+
+    *block = end_block;
+    if let Some(stack_ctx) = stack_ctx.as_ref() {
+        variable_array::restore_stack(ctx.stack_saves().len(), block, stack_ctx);
+    }
+}
+
+/// Checks if the Block already leaves the Case, by jumping anywhere else, like for a Break,
+/// Continue or Goto, or by returning, in which case it must not also fall through to the next
+/// Guard, as that would add a Predecessor that is never actually taken
+fn leaves_case(block: &BasicBlock, ctx: &ConvertContext) -> bool {
+    if ctx.is_unreachable(block) {
+        return true;
+    }
+
+    matches!(
+        block.get_statements().last(),
+        Some(ir::Statement::Jump(_, _) | ir::Statement::Return(_))
+    )
+}
+
+fn assign_tmp(value: ir::Expression, block: &BasicBlock, ctx: &ConvertContext) -> ir::Operand {
+    let var = ir::Variable::tmp(ctx.next_tmp(), ir::Type::I64);
+    block.add_statement(ir::Statement::Assignment {
+        target: var.clone(),
+        value: ir::Value::Expression(value),
+    });
+    ir::Operand::Variable(var)
+}
+
+fn compare(
+    op: ir::BinaryLogicOp,
+    switch_var: &ir::Variable,
+    case_value: ir::Constant,
+    block: &BasicBlock,
+    ctx: &ConvertContext,
+) -> ir::Operand {
+    assign_tmp(
+        ir::Expression::BinaryOp {
+            op: ir::BinaryOp::Logic(op),
+            left: ir::Operand::Variable(switch_var.clone()),
+            right: ir::Operand::Constant(case_value),
+        },
+        block,
+        ctx,
+    )
+}
+
+/// Checks that the Value matches none of the Cases, which is the Guard for the default Case
+fn unmatched(
+    case_values: &[i64],
+    switch_var: &ir::Variable,
+    block: &BasicBlock,
+    ctx: &ConvertContext,
+) -> ir::Operand {
+    let value_ty = switch_var.ty.clone();
+
+    let mut result = match case_values.first() {
+        Some(first) => compare(
+            ir::BinaryLogicOp::NotEqual,
+            switch_var,
+            case_constant(*first, &value_ty),
+            block,
+            ctx,
+        ),
+        // Without any other Case the default Case is always entered
+        None => {
+            let var = ir::Variable::tmp(ctx.next_tmp(), ir::Type::I64);
+            block.add_statement(ir::Statement::Assignment {
+                target: var.clone(),
+                value: ir::Value::Constant(ir::Constant::I64(1)),
+            });
+            return ir::Operand::Variable(var);
+        }
+    };
+    for value in case_values.iter().skip(1) {
+        let differs = compare(
+            ir::BinaryLogicOp::NotEqual,
+            switch_var,
+            case_constant(*value, &value_ty),
+            block,
+            ctx,
+        );
+        result = assign_tmp(
+            ir::Expression::BinaryOp {
+                op: ir::BinaryOp::Bitwise(ir::BinaryBitwiseOp::And),
+                left: result,
+                right: differs,
+            },
+            block,
+            ctx,
+        );
+    }
+
+    result
+}
@@ -22,13 +22,12 @@ impl ArrayAccessTarget {
 
         let elem_size = self.ty_info.data.byte_size(ctx.arch());
 
-        let index_value = self.index.to_ir(block, ctx);
-        let index_oper = AExpression::val_to_operand(index_value, block, ctx);
+        let index_oper = self.index.index_operand(block, ctx);
 
         let offset_exp = ir::Expression::BinaryOp {
             op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
             left: index_oper,
-            right: ir::Operand::Constant(ir::Constant::I64(elem_size as i64)),
+            right: ir::Operand::Constant(ctx.arch().ptr_const(elem_size as i64)),
         };
         let offset_value = ir::Value::Expression(offset_exp);
         let offset_oper = AExpression::val_to_operand(offset_value, block, ctx);
@@ -55,27 +54,23 @@ pub struct StructFieldTarget {
 
 impl StructFieldTarget {
     pub fn to_exp(self, block: &mut BasicBlock, ctx: &ConvertContext) -> ir::Expression {
-        let (base_value, base_ty) = self.target.base_target_address(block, ctx);
+        let (base_value, base_ty) = self.target.struct_address(block, ctx);
 
-        let struct_def = match base_ty {
-            AType::Struct { def, .. } => def,
-            other => {
-                dbg!(&other);
+        let offset = match base_ty.member_offset(&self.field.0.data, ctx.arch()) {
+            Some(o) => o,
+            None => {
+                dbg!(&base_ty);
 
                 panic!("Expected Struct as Target Type")
             }
         };
 
-        let offset = struct_def
-            .member_offset(&self.field.0.data, ctx.arch())
-            .expect("Field does not exist");
-
         let base_oper = AExpression::val_to_operand(base_value, block, ctx);
 
         ir::Expression::BinaryOp {
             op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
             left: base_oper,
-            right: ir::Operand::Constant(ir::Constant::I64(offset as i64)),
+            right: ir::Operand::Constant(ctx.arch().ptr_const(offset as i64)),
         }
     }
 }
@@ -137,7 +132,7 @@ impl AAssignTarget {
                     src: ident,
                     ty_info: SpanData {
                         span: var_dec.declaration.clone(),
-                        data: var_dec.ty.clone(),
+                        data: var_dec.ty.clone().complete(ty_defs),
                     },
                 })
             }
@@ -158,7 +153,7 @@ impl AAssignTarget {
                 };
 
                 let (arr_ty, arr_span) = base_target.get_expected_type();
-                let elem_ty = match arr_ty {
+                let elem_ty = match arr_ty.ty() {
                     AType::Array(base) => base.ty,
                     AType::Pointer(base) => base,
                     other => {
@@ -190,14 +185,15 @@ impl AAssignTarget {
                 let (struct_def, def_span) = match base_ty.get_struct_def() {
                     Some(s) => s,
                     None => {
+                        base_ty.ensure_complete(&field.0.span)?;
                         let span = base_target.ty_span().clone();
 
                         return Err(SemanticError::StructAccessOnNonStruct {
                             field_name: field,
-                            received: SpanData {
+                            received: Box::new(SpanData {
                                 span,
                                 data: base_ty,
-                            },
+                            }),
                         });
                     }
                 };
@@ -207,13 +203,14 @@ impl AAssignTarget {
                     None => {
                         return Err(SemanticError::UnknownStructField {
                             field_name: field.clone(),
-                            struct_def: SpanData {
+                            struct_def: Box::new(SpanData {
                                 span: def_span.clone(),
                                 data: struct_def.clone(),
-                            },
+                            }),
                         });
                     }
                 };
+                let field_ty = field_ty.qualify_member(&base_ty).complete(ty_defs);
 
                 Ok(Self::StructField(StructFieldTarget {
                     target: Box::new(base_target),
@@ -227,10 +224,11 @@ impl AAssignTarget {
             AssignTarget::StructPtrAccess { base, field } => {
                 let base_target = Self::parse(*base, ty_defs, vars)?;
 
-                let (struct_def, def_span) = match base_target.base_ty() {
+                let (struct_def, def_span, struct_ty) = match base_target.base_ty().into_ty() {
                     AType::Pointer(inner) => match inner.get_struct_def() {
-                        Some(s) => s,
+                        Some((def, span)) => (def, span, inner.as_ref()),
                         None => {
+                            inner.ensure_complete(&field.0.span)?;
                             dbg!(&inner);
 
                             todo!("Expected a Struct-Pointer");
@@ -248,13 +246,14 @@ impl AAssignTarget {
                     None => {
                         return Err(SemanticError::UnknownStructField {
                             field_name: field,
-                            struct_def: SpanData {
+                            struct_def: Box::new(SpanData {
                                 span: def_span.clone(),
                                 data: struct_def.clone(),
-                            },
+                            }),
                         });
                     }
                 };
+                let field_ty = field_ty.qualify_member(struct_ty).complete(ty_defs);
 
                 Ok(Self::StructField(StructFieldTarget {
                     target: Box::new(base_target),
@@ -268,6 +267,33 @@ impl AAssignTarget {
         }
     }
 
+    /// The Span of the Target itself, like the Name of the Variable or the accessed Field
+    pub fn target_span(&self) -> Span {
+        match self {
+            Self::Variable { src, .. } => src.0.span.clone(),
+            Self::Deref { exp, .. } => exp.entire_span(),
+            Self::ArrayAccess(arr) => arr.target.target_span().join(arr.index.entire_span()),
+            Self::StructField(field) => field.field.0.span.clone(),
+        }
+    }
+
+    /// Checks that the Target can be written to, which is not the case if its Type is
+    /// const-qualified
+    pub fn ensure_writable(&self) -> Result<(), SemanticError> {
+        let (ty, ty_span) = self.get_expected_type();
+        if !ty.is_const() {
+            return Ok(());
+        }
+
+        Err(SemanticError::ConstAssignment {
+            target: self.target_span(),
+            ty: Box::new(SpanData {
+                span: ty_span,
+                data: ty,
+            }),
+        })
+    }
+
     pub fn get_expected_type(&self) -> (AType, Span) {
         match &self {
             Self::Variable { ty_info, .. } => (ty_info.data.clone(), ty_info.span.clone()),
@@ -291,20 +317,17 @@ impl AAssignTarget {
     ) -> (ir::Value, AType) {
         match self {
             Self::Variable { name, src, ty_info } => match ty_info.data.ty() {
-                AType::Struct { def, area } => {
+                ty @ (AType::Struct { .. } | AType::Union { .. }) => {
                     let var = AExpression::Variable {
                         name,
                         src,
                         ty: SpanData {
                             span: ty_info.span,
-                            data: AType::Struct {
-                                def: def.clone(),
-                                area: area.clone(),
-                            },
+                            data: ty.clone(),
                         },
                     };
 
-                    (var.to_ir(block, ctx), AType::Struct { def, area })
+                    (var.to_ir(block, ctx), ty)
                 }
                 AType::Array(arr) => {
                     let var = AExpression::Variable {
@@ -348,13 +371,13 @@ impl AAssignTarget {
                 field,
                 ty_info,
             }) => {
-                let (base_address_value, base_target_ty) = target.base_target_address(block, ctx);
+                let (base_address_value, base_target_ty) = target.struct_address(block, ctx);
                 let base_address_oper = AExpression::val_to_operand(base_address_value, block, ctx);
 
-                let (struct_def, _) = base_target_ty.get_struct_def().unwrap();
-
-                let raw_offset = struct_def.member_offset(&field.0.data, ctx.arch()).unwrap();
-                let offset_oper = ir::Operand::Constant(ir::Constant::I64(raw_offset as i64));
+                let raw_offset = base_target_ty
+                    .member_offset(&field.0.data, ctx.arch())
+                    .unwrap();
+                let offset_oper = ir::Operand::Constant(ctx.arch().ptr_const(raw_offset as i64));
 
                 let target_exp = ir::Expression::BinaryOp {
                     op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Add),
@@ -371,6 +394,28 @@ impl AAssignTarget {
         }
     }
 
+    /// Returns the Address of the Struct that the Target refers to, which for a Pointer stored in
+    /// a Field or Array means that the Pointer itself has to be loaded first, like for the
+    /// "first.next" in "first.next->value = 0"
+    fn struct_address(self, block: &mut BasicBlock, ctx: &ConvertContext) -> (ir::Value, AType) {
+        let stored = !matches!(self, Self::Variable { .. });
+        let (address, ty) = self.base_target_address(block, ctx);
+
+        match ty.into_ty() {
+            AType::Pointer(_) if stored => {
+                let address_oper = AExpression::val_to_operand(address, block, ctx);
+                let pointer = ir::Value::Expression(ir::Expression::ReadMemory {
+                    address: address_oper,
+                    read_ty: ty.clone().unqualified().to_ir(ctx.arch()),
+                    volatile: ty.is_volatile(),
+                });
+
+                (pointer, ty)
+            }
+            _ => (address, ty),
+        }
+    }
+
     pub fn used_vars(&self) -> BTreeSet<String> {
         let mut result = BTreeSet::new();
 
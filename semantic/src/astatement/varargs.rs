@@ -0,0 +1,119 @@
+use general::SpanData;
+use syntax::{AssignTarget, Expression, Identifier, SingleOperation};
+
+use crate::{atype, AAssignTarget, AExpression, AStatement, AType, ParseState, SemanticError};
+
+const VA_START: &str = "__builtin_va_start";
+const VA_END: &str = "__builtin_va_end";
+const VA_COPY: &str = "__builtin_va_copy";
+
+/// Checks if the Expression is a Call to one of the Builtins for variable Arguments, which are
+/// used as Statements, like "__builtin_va_start"
+pub fn is_builtin(raw: &Expression) -> bool {
+    match raw {
+        Expression::SingleOperation {
+            base,
+            operation: SingleOperation::FuntionCall(_),
+        } => matches!(
+            base.as_ref(),
+            Expression::Identifier { ident } if [VA_START, VA_END, VA_COPY].contains(&ident.0.data.as_str())
+        ),
+        _ => false,
+    }
+}
+
+/// Parses the given Argument as the va_list that should be modified by one of the Builtins
+fn list_target(
+    raw: Expression,
+    builtin: &Identifier,
+    parse_state: &ParseState,
+) -> Result<AAssignTarget, SemanticError> {
+    let ident = match raw {
+        Expression::Identifier { ident } => ident,
+        _ => {
+            return Err(SemanticError::InvalidVarArgs {
+                area: builtin.0.span.clone(),
+            })
+        }
+    };
+
+    let target = AAssignTarget::parse(
+        AssignTarget::Variable(ident),
+        parse_state.type_defs(),
+        parse_state,
+    )?;
+
+    let (list_ty, _) = target.get_expected_type();
+    if list_ty.into_ty() != &AType::va_list() {
+        return Err(SemanticError::InvalidVarArgs {
+            area: builtin.0.span.clone(),
+        });
+    }
+
+    Ok(target)
+}
+
+/// Parses a Call to one of the Builtins for variable Arguments.
+///
+/// "va_start" and "va_copy" simply become Assignments to the given va_list, while "va_end"
+/// does not need to do anything
+pub fn parse(
+    raw: Expression,
+    parse_state: &mut ParseState,
+) -> Result<Option<AStatement>, SemanticError> {
+    let (name, mut raw_args) = match raw {
+        Expression::SingleOperation {
+            base,
+            operation: SingleOperation::FuntionCall(args),
+        } => match *base {
+            Expression::Identifier { ident } => (ident, args),
+            _ => unreachable!("Only called for Builtins"),
+        },
+        _ => unreachable!("Only called for Builtins"),
+    };
+
+    let expected_args = match name.0.data.as_str() {
+        VA_END => 1,
+        _ => 2,
+    };
+    if raw_args.len() != expected_args {
+        return Err(SemanticError::MismatchedFunctionArgsCount {
+            expected: SpanData {
+                span: name.0.span.clone(),
+                data: expected_args,
+            },
+            received: SpanData {
+                span: name.0.span,
+                data: raw_args.len(),
+            },
+        });
+    }
+
+    let target = list_target(raw_args.remove(0), &name, parse_state)?;
+
+    match name.0.data.as_str() {
+        VA_START => {
+            if !parse_state.is_variadic() {
+                return Err(SemanticError::InvalidVarArgs { area: name.0.span });
+            }
+
+            // The second Argument only names the last fixed Argument, which we dont actually
+            // need to find the variable Arguments
+            AExpression::parse(raw_args.remove(0), parse_state.type_defs(), parse_state)?;
+
+            Ok(Some(AStatement::Assignment {
+                target,
+                value: AExpression::VarArgsStart { area: name.0.span },
+            }))
+        }
+        VA_COPY => {
+            let src = AExpression::parse(raw_args.remove(0), parse_state.type_defs(), parse_state)?;
+
+            let (expected_type, expected_span) = target.get_expected_type();
+            let value = atype::assign_type::determine_type(src, (&expected_type, &expected_span))?;
+
+            Ok(Some(AStatement::Assignment { target, value }))
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use ir::BasicBlock;
+
+use crate::{
+    conversion::ConvertContext, unit_constant, AAlignment, AExpression, APrimitive, AScope,
+    AStatement, ASwitchCase, AType, Array,
+};
+
+use super::object_alignment;
+
+/// The Name of the hidden Variable, that stores the Length of the variable length Array with the
+/// given internal Name
+pub(crate) fn length_name(array: &str) -> String {
+    format!("{}_length", array)
+}
+
+/// Evaluates the Length of the variable length Array, stores it for later Uses of the Array and
+/// then allocates the Memory for its Elements on the Stack
+pub(crate) fn declare(
+    name: String,
+    arr: Array,
+    explicit_alignment: Option<&AAlignment>,
+    block: &mut BasicBlock,
+    ctx: &ConvertContext,
+) {
+    let length = arr
+        .length
+        .expect("A variable length Array always has a Length-Expression");
+    let length_ty = AType::Primitve(APrimitive::UnsignedLongInt);
+
+    let length_exp = AExpression::Cast {
+        base: length,
+        target: length_ty.clone(),
+    };
+    let length_value = length_exp.to_ir(block, ctx);
+    let length_var = ir::Variable::new(length_name(&name), length_ty.to_ir(ctx.arch()))
+        .set_description("Length of variable length Array");
+    block.add_statement(ir::Statement::Assignment {
+        target: length_var.clone(),
+        value: length_value,
+    });
+
+    let element_size = arr.ty.byte_size(ctx.arch());
+    let element_size = unit_constant(element_size, &length_var.ty);
+    let size_var = ir::Variable::tmp(ctx.next_tmp(), length_var.ty.clone());
+    block.add_statement(ir::Statement::Assignment {
+        target: size_var.clone(),
+        value: ir::Value::Expression(ir::Expression::BinaryOp {
+            op: ir::BinaryOp::Arith(ir::BinaryArithmeticOp::Multiply),
+            left: ir::Operand::Variable(length_var),
+            right: ir::Operand::Constant(element_size),
+        }),
+    });
+
+    let alignment = object_alignment(
+        arr.ty.alignment(ctx.arch()) as usize,
+        explicit_alignment,
+        ctx.arch(),
+    );
+
+    let target_var = ir::Variable::new(name, ir::Type::Pointer(Box::new(arr.ty.to_ir(ctx.arch()))))
+        .set_description("Declare variable length Array");
+    block.add_statement(ir::Statement::Assignment {
+        target: target_var.clone(),
+        value: ir::Value::Expression(ir::Expression::DynamicStackAlloc {
+            size: ir::Operand::Variable(size_var),
+            alignment,
+        }),
+    });
+    block.add_statement(ir::Statement::SaveVariable { var: target_var });
+}
+
+/// The Name of the hidden Variable, that stores the Stack-Pointer from before the variable length
+/// Array with the given internal Name and the other Arrays of its Scope were allocated
+fn stack_save_name(array: &str) -> String {
+    format!("{}_stack", array)
+}
+
+/// Returns the internal Name of the variable length Array, if the Statement declares one, whose
+/// Memory is allocated on the Stack when the Declaration is reached
+pub(crate) fn declared_array(statement: &AStatement) -> Option<&str> {
+    match statement {
+        AStatement::DeclareVar { name, ty, .. } if ty.variable_length().is_some() => Some(name),
+        _ => None,
+    }
+}
+
+/// Saves the current Stack-Pointer, before the first variable length Array of a Scope is
+/// allocated, and returns the Context for the rest of the Scope.
+///
+/// Leaving the Scope again, by reaching its End or through a Break, Continue or Goto, then
+/// restores the saved Stack-Pointer, so that the Arrays of a Loop-Body dont pile up on the Stack
+/// with every Iteration
+pub(crate) fn save_stack(array: &str, block: &BasicBlock, ctx: &ConvertContext) -> ConvertContext {
+    let name = stack_save_name(array);
+    let saved = ir::Variable::new(name.clone(), ir::Type::Pointer(Box::new(ir::Type::Void)))
+        .set_description("Saved Stack-Pointer");
+    block.add_statement(ir::Statement::Assignment {
+        target: saved,
+        value: ir::Value::Expression(ir::Expression::StackSave),
+    });
+
+    ctx.with_stack_save(name)
+}
+
+/// Releases the Memory of all the variable length Arrays, that were allocated after the given
+/// Number of Stack-Pointers were saved, by restoring the next saved Stack-Pointer
+pub(crate) fn restore_stack(depth: usize, block: &BasicBlock, ctx: &ConvertContext) {
+    let name = match ctx.stack_saves().get(depth) {
+        Some(n) => n,
+        None => return,
+    };
+
+    let saved = block
+        .definition(name, &|| ctx.next_tmp(), None)
+        .expect("The Stack-Pointer is always saved before it can be restored");
+    block.add_statement(ir::Statement::StackRestore { value: saved });
+}
+
+/// Collects the Number of saved Stack-Pointers at every Label in the Function-Body, which
+/// matches the Stack-Pointers saved for the enclosing Scopes while converting them
+pub(crate) fn label_stack_depths(body: &AScope) -> HashMap<String, usize> {
+    let mut depths = HashMap::new();
+    collect_label_depths(&body.statements, 0, true, &mut depths);
+    depths
+}
+
+fn collect_label_depths(
+    statements: &[AStatement],
+    mut depth: usize,
+    mut saves: bool,
+    depths: &mut HashMap<String, usize>,
+) {
+    for statement in statements {
+        if saves && declared_array(statement).is_some() {
+            depth += 1;
+            saves = false;
+        }
+
+        match statement {
+            AStatement::Label { name } => {
+                depths.insert(name.0.data.clone(), depth);
+            }
+            AStatement::If { body, else_, .. } => {
+                collect_label_depths(&body.statements, depth, true, depths);
+                if let Some(else_) = else_ {
+                    collect_label_depths(&else_.statements, depth, true, depths);
+                }
+            }
+            AStatement::WhileLoop { body, .. }
+            | AStatement::DoWhileLoop { body, .. }
+            | AStatement::ForLoop { body, .. } => {
+                collect_label_depths(&body.statements, depth, true, depths);
+            }
+            AStatement::SubScope { inner } => {
+                collect_label_depths(&inner.statements, depth, true, depths);
+            }
+            AStatement::Switch { cases, .. } => {
+                // All the Cases share a single Scope, so the Stack-Pointer is saved once before
+                // the entire Switch
+                let case_depth = if first_in_cases(cases).is_some() {
+                    depth + 1
+                } else {
+                    depth
+                };
+                for case in cases {
+                    collect_label_depths(&case.statements, case_depth, false, depths);
+                }
+            }
+            _ => {}
+        };
+    }
+}
+
+/// The internal Name of the first variable length Array declared in any of the Cases of a Switch
+pub(crate) fn first_in_cases(cases: &[ASwitchCase]) -> Option<&str> {
+    cases
+        .iter()
+        .flat_map(|c| c.statements.iter())
+        .find_map(declared_array)
+}
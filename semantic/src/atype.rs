@@ -3,7 +3,10 @@ use std::borrow::Borrow;
 use general::{Span, SpanData};
 use syntax::{DataType, EnumVariants, Identifier, Modifier, StructMembers, TypeDefType, TypeToken};
 
-use crate::{AExpression, EvaluationValue, SemanticError, TypeDefinitions, VariableContainer};
+use crate::{
+    AExpression, EvaluationError, EvaluationValue, SemanticError, TypeDefinitions,
+    VariableContainer,
+};
 
 mod struct_def;
 pub use struct_def::*;
@@ -17,17 +20,49 @@ mod base;
 use base::BaseTypes;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct EnumDefinition {}
+pub struct EnumDefinition {
+    /// All the Variants of the Enum with their already evaluated constant Values
+    pub variants: Vec<(Identifier, i64)>,
+}
 
 #[derive(Debug, Clone)]
 pub enum AType {
     Primitve(APrimitive),
     Pointer(Box<Self>),
-    Struct { def: StructDef, area: Span },
-    Enum { def: EnumDefinition, area: Span },
+    Struct {
+        def: StructDef,
+        area: Span,
+    },
+    /// A Union shares the Definition with Structs, but all of its Members are stored at the
+    /// same Offset
+    Union {
+        def: StructDef,
+        area: Span,
+    },
+    Enum {
+        def: EnumDefinition,
+        area: Span,
+    },
+    /// A Struct or Union that has been declared, but not defined (yet), so its Size and Members
+    /// are unknown and it can only be used through Pointers
+    Incomplete {
+        name: Identifier,
+    },
     Array(Array),
     Const(Box<Self>),
-    TypeDef { name: Identifier, ty: Box<Self> },
+    /// Every Access to an Object of this Type has to actually be performed, like for
+    /// memory-mapped Registers
+    Volatile(Box<Self>),
+    TypeDef {
+        name: Identifier,
+        ty: Box<Self>,
+    },
+    /// The Signature of a Function, which is only ever used behind a Pointer
+    Function {
+        return_ty: Box<Self>,
+        arguments: Vec<Self>,
+        var_args: bool,
+    },
 }
 
 impl<O> PartialEq<O> for AType
@@ -48,6 +83,16 @@ where
                     area: o_area,
                 },
             ) => s_def.eq(o_def) && s_area.eq(o_area),
+            (
+                Self::Union {
+                    def: s_def,
+                    area: s_area,
+                },
+                Self::Union {
+                    def: o_def,
+                    area: o_area,
+                },
+            ) => s_def.eq(o_def) && s_area.eq(o_area),
             (
                 Self::Enum {
                     def: s_def,
@@ -58,9 +103,35 @@ where
                     area: o_area,
                 },
             ) => s_def.eq(o_def) && s_area.eq(o_area),
+            (Self::Incomplete { name: s_name }, Self::Incomplete { name: o_name }) => {
+                s_name.0.data == o_name.0.data
+            }
+            // An incomplete Type is the same as its Definition, which is needed for Pointers that
+            // were declared before the Type was completed
+            (Self::Incomplete { name }, Self::Struct { def, .. } | Self::Union { def, .. })
+            | (Self::Struct { def, .. } | Self::Union { def, .. }, Self::Incomplete { name }) => {
+                def.name.as_ref() == Some(&name.0.data)
+            }
             (Self::Array(s_arr), Self::Array(o_arr)) => s_arr.eq(o_arr),
             (Self::Const(s_c), Self::Const(o_c)) => s_c.eq(o_c),
+            (Self::Volatile(s_v), Self::Volatile(o_v)) => s_v.eq(o_v),
             (Self::TypeDef { ty: s_ty, .. }, Self::TypeDef { ty: o_ty, .. }) => s_ty.eq(o_ty),
+            // A TypeDef is only another Name for the same Type
+            (Self::TypeDef { ty, .. }, other) | (other, Self::TypeDef { ty, .. }) => {
+                ty.as_ref().eq(other)
+            }
+            (
+                Self::Function {
+                    return_ty: s_ret,
+                    arguments: s_args,
+                    var_args: s_var,
+                },
+                Self::Function {
+                    return_ty: o_ret,
+                    arguments: o_args,
+                    var_args: o_var,
+                },
+            ) => s_ret.eq(o_ret) && s_args.eq(o_args) && s_var == o_var,
             _ => false,
         }
     }
@@ -69,6 +140,8 @@ where
 #[derive(Debug, PartialEq, Clone)]
 pub enum APrimitive {
     Void,
+    /// `_Bool`, which only ever stores 0 or 1
+    Bool,
     Char,
     UnsignedChar,
     Short,
@@ -88,7 +161,8 @@ impl APrimitive {
     pub fn is_unsigned(&self) -> bool {
         matches!(
             self,
-            Self::UnsignedChar
+            Self::Bool
+                | Self::UnsignedChar
                 | Self::UnsignedShort
                 | Self::UnsignedInt
                 | Self::UnsignedLongInt
@@ -105,6 +179,7 @@ impl APrimitive {
 
     pub fn rank(&self) -> Option<usize> {
         match self {
+            Self::Bool => Some(0),
             Self::Char | Self::UnsignedChar => Some(1),
             Self::Short | Self::UnsignedShort => Some(2),
             Self::Int | Self::UnsignedInt => Some(3),
@@ -114,55 +189,356 @@ impl APrimitive {
         }
     }
 
-    pub fn byte_size(&self) -> u64 {
-        match self {
-            Self::Char | Self::UnsignedChar => 1,
-            Self::Short | Self::UnsignedShort => 2,
-            Self::Int | Self::UnsignedInt => 4,
-            _ => todo!("Size of {:?} in Bytes", self),
+    /// The Size of the Type in Bytes, as determined by the Data-Model of the Architecture
+    pub fn byte_size(&self, arch: &general::arch::Arch) -> u64 {
+        self.model_size(&arch.data_model())
+    }
+
+    /// The Size of the Type in Bytes for the given Data-Model
+    pub fn model_size(&self, model: &general::arch::DataModel) -> u64 {
+        let size = match self {
+            Self::Bool | Self::Char | Self::UnsignedChar => 1,
+            Self::Short | Self::UnsignedShort => model.short,
+            Self::Int | Self::UnsignedInt => model.int,
+            Self::LongInt | Self::UnsignedLongInt => model.long,
+            Self::LongLongInt | Self::UnsignedLongLongInt => model.long_long,
+            Self::Float => model.float,
+            Self::Double => model.double,
+            Self::LongDouble => model.long_double,
+            Self::Void => todo!("Size of {:?} in Bytes", self),
+        };
+
+        size as u64
+    }
+
+    /// Converts the integer Value to this integer Type, by truncating it to the Width of the
+    /// Type and then sign- or zero-extending it again
+    pub fn wrap_integer(&self, value: i64, model: &general::arch::DataModel) -> i64 {
+        if let Self::Bool = self {
+            return (value != 0) as i64;
+        }
+
+        let bits = self.model_size(model) * 8;
+        if bits >= 64 {
+            return value;
+        }
+
+        let shift = 64 - bits;
+        if self.is_unsigned() {
+            ((value as u64) << shift >> shift) as i64
+        } else {
+            (value << shift) >> shift
         }
     }
 
-    pub fn alignment(&self) -> u64 {
+    pub fn alignment(&self, arch: &general::arch::Arch) -> u64 {
+        let size = self.byte_size(arch);
+        arch.data_model().alignment(size as usize) as u64
+    }
+
+    pub fn to_ir(&self, arch: &general::arch::Arch) -> ir::Type {
         match self {
-            Self::Char | Self::UnsignedChar => 1,
-            Self::Short | Self::UnsignedShort => 2,
-            Self::Int | Self::UnsignedInt => 4,
-            _ => todo!("Size of {:?} in Bytes", self),
+            Self::Void => ir::Type::Void,
+            Self::Float => ir::Type::Float,
+            Self::Double => ir::Type::Double,
+            Self::LongDouble => match self.byte_size(arch) {
+                8 => ir::Type::Double,
+                size => todo!("{} Byte long double", size),
+            },
+            integer => match (integer.byte_size(arch), integer.is_unsigned()) {
+                (1, false) => ir::Type::I8,
+                (2, false) => ir::Type::I16,
+                (4, false) => ir::Type::I32,
+                (8, false) => ir::Type::I64,
+                (1, true) => ir::Type::U8,
+                (2, true) => ir::Type::U16,
+                (4, true) => ir::Type::U32,
+                (8, true) => ir::Type::U64,
+                (size, _) => unreachable!("Integer with {} Bytes", size),
+            },
         }
     }
 }
 
+/// Displays the Type the way it is spelled in C, like "unsigned int"
+impl std::fmt::Display for APrimitive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Void => "void",
+            Self::Bool => "_Bool",
+            Self::Char => "char",
+            Self::UnsignedChar => "unsigned char",
+            Self::Short => "short",
+            Self::UnsignedShort => "unsigned short",
+            Self::Int => "int",
+            Self::UnsignedInt => "unsigned int",
+            Self::LongInt => "long",
+            Self::UnsignedLongInt => "unsigned long",
+            Self::LongLongInt => "long long",
+            Self::UnsignedLongLongInt => "unsigned long long",
+            Self::Float => "float",
+            Self::Double => "double",
+            Self::LongDouble => "long double",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Array {
+    /// The Number of Elements, if it is an Integer Constant
     pub size: Option<usize>,
+    /// The Number of Elements, if it is not an Integer Constant, because it either depends on
+    /// the Target, like "sizeof(long)", or is only known at Runtime, like for a variable length
+    /// Array
+    pub length: Option<Box<AExpression>>,
     pub ty: Box<AType>,
 }
 
+impl Array {
+    /// The Number of Elements in the Array on the given Target, if it is known at compile-time
+    pub fn length_for(&self, arch: &general::arch::Arch) -> Option<usize> {
+        match &self.length {
+            Some(length) => match length.const_evaluate_for(arch) {
+                Ok(EvaluationValue::Integer(value)) => usize::try_from(value).ok(),
+                _ => None,
+            },
+            None => self.size,
+        }
+    }
+
+    /// Checks if the Number of Elements is only known at Runtime, which makes this a variable
+    /// length Array
+    pub fn is_variable(&self) -> bool {
+        match &self.length {
+            Some(length) => !matches!(
+                length.const_evaluate(),
+                Ok(_) | Err(EvaluationError::TargetDependent)
+            ),
+            None => false,
+        }
+    }
+}
+
 impl AType {
+    /// The underlying Type, without the Name of a TypeDef or any Qualifiers
     #[must_use]
     pub fn ty(self) -> Self {
         match self {
-            Self::TypeDef { ty, .. } => *ty,
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.ty(),
             other => other,
         }
     }
+    /// The Type used for "va_list", which simply points to the next variable Argument on the
+    /// Stack
+    pub fn va_list() -> Self {
+        Self::Pointer(Box::new(Self::Primitve(APrimitive::Char)))
+    }
+
+    /// Checks if Values of this Type are Integers, which includes Enums
+    pub fn is_integer(&self) -> bool {
+        match self.into_ty() {
+            Self::Primitve(prim) => prim.rank().is_some(),
+            Self::Enum { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The Expression for the Number of Elements, if this is a variable length Array
+    pub fn variable_length(&self) -> Option<&AExpression> {
+        match self.into_ty() {
+            Self::Array(arr) if arr.is_variable() => arr.length.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Replaces the Number of Elements of a variable length Array, which is used to refer to the
+    /// Length determined when the Array was declared, instead of evaluating the Expression again
+    #[must_use]
+    pub(crate) fn with_variable_length(self, length: AExpression) -> Self {
+        match self {
+            Self::Array(arr) if arr.is_variable() => Self::Array(Array {
+                size: None,
+                length: Some(Box::new(length)),
+                ty: arr.ty,
+            }),
+            Self::TypeDef { name, ty } => Self::TypeDef {
+                name,
+                ty: Box::new(ty.with_variable_length(length)),
+            },
+            Self::Const(ty) => Self::Const(Box::new(ty.with_variable_length(length))),
+            Self::Volatile(ty) => Self::Volatile(Box::new(ty.with_variable_length(length))),
+            other => other,
+        }
+    }
+
+    /// The Name of the Struct or Union that has not been defined, if the Size of Objects of this
+    /// Type is unknown because of it
+    pub fn incomplete_name(&self) -> Option<&Identifier> {
+        match self.into_ty() {
+            Self::Incomplete { name } => Some(name),
+            Self::Array(arr) => arr.ty.incomplete_name(),
+            _ => None,
+        }
+    }
+
+    /// The Area where the Struct, Union or Enum, that a Tag refers to, was defined or declared
+    pub(crate) fn tag_area(&self) -> Option<&Span> {
+        match self {
+            Self::Struct { area, .. } | Self::Union { area, .. } | Self::Enum { area, .. } => {
+                Some(area)
+            }
+            Self::Incomplete { name } => Some(&name.0.span),
+            _ => None,
+        }
+    }
+
+    /// The Error for a Tag that is used with a different Kind of Type than the one it refers to
+    fn mismatched_tag(name: Identifier, target: &Self) -> SemanticError {
+        let previous_definition = target
+            .tag_area()
+            .cloned()
+            .unwrap_or_else(|| name.0.span.clone());
+
+        SemanticError::MismatchedTag {
+            name,
+            previous_definition,
+        }
+    }
+
+    /// Ensures that the Size of Objects of this Type is known, where the Area is the Part of the
+    /// Code that requires it
+    pub(crate) fn ensure_complete(&self, area: &Span) -> Result<(), SemanticError> {
+        match self.incomplete_name() {
+            Some(name) => Err(SemanticError::IncompleteType {
+                area: area.clone(),
+                name: name.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces every incomplete Struct or Union, that is either this Type itself or the Type it
+    /// points to, with its Definition, if it has been defined in the meantime
+    #[must_use]
+    pub(crate) fn complete(self, ty_defs: &TypeDefinitions) -> Self {
+        match self {
+            Self::Incomplete { name } => match ty_defs.get_tag(&name) {
+                Some(ty @ (Self::Struct { .. } | Self::Union { .. })) => ty.clone(),
+                _ => Self::Incomplete { name },
+            },
+            Self::Pointer(inner) => Self::Pointer(Box::new(inner.complete(ty_defs))),
+            Self::Array(arr) => Self::Array(Array {
+                ty: Box::new(arr.ty.complete(ty_defs)),
+                ..arr
+            }),
+            Self::TypeDef { name, ty } => Self::TypeDef {
+                name,
+                ty: Box::new(ty.complete(ty_defs)),
+            },
+            Self::Const(ty) => Self::Const(Box::new(ty.complete(ty_defs))),
+            Self::Volatile(ty) => Self::Volatile(Box::new(ty.complete(ty_defs))),
+            other => other,
+        }
+    }
+
+    /// The underlying Type, without the Name of a TypeDef or any Qualifiers
     pub fn into_ty(&self) -> &Self {
         match self {
-            Self::TypeDef { ty, .. } => ty,
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.into_ty(),
+            other => other,
+        }
+    }
+
+    /// Checks if the Type itself is const-qualified, which means that Objects of this Type can
+    /// not be modified
+    pub fn is_const(&self) -> bool {
+        match self {
+            Self::Const(_) => true,
+            Self::TypeDef { ty, .. } | Self::Volatile(ty) => ty.is_const(),
+            _ => false,
+        }
+    }
+
+    /// Checks if the Type itself is volatile-qualified, which means that every Read and Write
+    /// of Objects of this Type has to be performed exactly as written
+    pub fn is_volatile(&self) -> bool {
+        match self {
+            Self::Volatile(_) => true,
+            Self::TypeDef { ty, .. } | Self::Const(ty) => ty.is_volatile(),
+            _ => false,
+        }
+    }
+
+    /// Removes the Qualifiers of the Type itself, which is the Type of the Value that is read
+    /// from an Object of this Type
+    #[must_use]
+    pub fn unqualified(self) -> Self {
+        match self {
+            Self::Const(inner) | Self::Volatile(inner) => inner.unqualified(),
+            Self::TypeDef { ty, .. } if ty.is_const() || ty.is_volatile() => ty.unqualified(),
             other => other,
         }
     }
 
+    /// Applies the Qualifiers of a Struct or Union to the Type of one of its Members, as the
+    /// Members of a const Struct are also const, even if they were not declared as such
+    #[must_use]
+    pub(crate) fn qualify_member(self, parent: &Self) -> Self {
+        let mut result = self;
+        if parent.is_volatile() && !result.is_volatile() {
+            result = Self::Volatile(Box::new(result));
+        }
+        if parent.is_const() && !result.is_const() {
+            result = Self::Const(Box::new(result));
+        }
+        result
+    }
+
+    /// Returns the Definition of the Struct or Union, which is either this Type itself or the
+    /// Type it points to
     pub fn get_struct_def(&self) -> Option<(&StructDef, &Span)> {
         match self {
             Self::Struct { def, area } => Some((def, area)),
-            Self::TypeDef { ty, .. } => ty.get_struct_def(),
+            Self::Union { def, area } => Some((def, area)),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.get_struct_def(),
             Self::Pointer(inner) => inner.get_struct_def(),
             _ => None,
         }
     }
 
+    /// Calculates the Offset of the given Member in the Struct or Union, that is either this Type
+    /// itself or the Type it points to
+    pub fn member_offset(&self, name: &str, arch: &general::arch::Arch) -> Option<usize> {
+        match self {
+            Self::Struct { def, .. } => def.member_offset(name, arch),
+            Self::Union { def, .. } => def.members.iter().find(|m| m.has_name(name)).map(|_| 0),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => {
+                ty.member_offset(name, arch)
+            }
+            Self::Pointer(inner) => inner.member_offset(name, arch),
+            _ => None,
+        }
+    }
+
+    /// Returns the Position of the given Member in its Storage-Unit, if it is a Bit-Field of the
+    /// Struct or Union, that is either this Type itself or the Type it points to
+    pub fn bit_field(&self, name: &str, arch: &general::arch::Arch) -> Option<BitField> {
+        match self {
+            Self::Struct { def, .. } => def.bit_field(name, arch),
+            Self::Union { def, .. } => def
+                .members
+                .iter()
+                .find(|m| m.has_name(name))
+                .and_then(|m| m.union_bit_field(arch)),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => {
+                ty.bit_field(name, arch)
+            }
+            Self::Pointer(inner) => inner.bit_field(name, arch),
+            _ => None,
+        }
+    }
+
     pub fn implicitly_castable(&self, target: &Self) -> bool {
         if self == target {
             return true;
@@ -170,12 +546,15 @@ impl AType {
 
         match (self, target) {
             (Self::Array(arr), Self::Pointer(inner)) => &arr.ty == inner,
-            (base, Self::Const(inner_target)) => base.implicitly_castable(inner_target),
+            (base, Self::Const(inner_target) | Self::Volatile(inner_target)) => {
+                base.implicitly_castable(inner_target)
+            }
             _ => false,
         }
     }
 
     pub fn parse_struct<VC>(
+        name: Option<String>,
         members: StructMembers,
         entire_span: Span,
         ty_defs: &TypeDefinitions,
@@ -184,19 +563,18 @@ impl AType {
     where
         VC: VariableContainer,
     {
-        let str_members_iter = members
-            .into_iter()
-            .map(|(raw_ty, ident)| (AType::parse(raw_ty, ty_defs, vars), ident));
-
         let str_members = {
             let mut tmp = Vec::new();
 
-            for (str_res, ident) in str_members_iter {
-                let str_ty = str_res?;
-                tmp.push(StructMember {
-                    ty: str_ty,
-                    name: ident,
-                });
+            for (raw_ty, ident, raw_width, raw_alignment) in members {
+                tmp.push(StructMember::parse(
+                    raw_ty,
+                    ident,
+                    raw_width,
+                    raw_alignment,
+                    ty_defs,
+                    vars,
+                )?);
             }
 
             tmp
@@ -204,34 +582,81 @@ impl AType {
 
         Ok(Self::Struct {
             def: StructDef {
+                name,
                 members: str_members,
             },
             area: entire_span,
         })
     }
 
+    pub fn parse_union<VC>(
+        name: Option<String>,
+        members: StructMembers,
+        entire_span: Span,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<Self, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        match Self::parse_struct(name, members, entire_span, ty_defs, vars)? {
+            Self::Struct { def, area } => Ok(Self::Union { def, area }),
+            _ => unreachable!("Parsing a Struct always returns a Struct"),
+        }
+    }
+
+    /// Parses the Definition of an Enum, where every Variant without an explicit Value gets
+    /// the Value of the previous Variant plus one, starting at 0.
+    ///
+    /// The Variants can already be used in the Values of the following Variants
     pub fn parse_enum<VC>(
         variants: EnumVariants,
         entire_span: Span,
-        _ty_defs: &TypeDefinitions,
-        _vars: &VC,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
     ) -> Result<Self, SemanticError>
     where
         VC: VariableContainer,
     {
-        let p_variants: Vec<_> = variants
-            .members
-            .into_iter()
-            .map(|v| {
-                dbg!(&v);
-                todo!()
-            })
-            .collect();
-        // TODO
-        let _ = p_variants;
+        let mut enum_defs = TypeDefinitions::based(ty_defs);
+
+        let mut p_variants: Vec<(Identifier, i64)> = Vec::with_capacity(variants.members.len());
+        let mut next_value = 0;
+        for variant in variants.members {
+            if let Some((prev, _)) = p_variants
+                .iter()
+                .find(|(n, _)| n.0.data == variant.name.0.data)
+            {
+                return Err(SemanticError::Redeclaration {
+                    name: variant.name,
+                    previous_declaration: prev.0.span.clone(),
+                });
+            }
+
+            let value = match variant.value {
+                Some(raw_value) => {
+                    let value_exp = AExpression::parse(raw_value, &enum_defs, vars)?;
+                    match value_exp.const_evaluate() {
+                        Ok(EvaluationValue::Integer(v)) => v,
+                        _ => {
+                            return Err(SemanticError::InvalidEnumValue {
+                                value: value_exp.entire_span(),
+                            })
+                        }
+                    }
+                }
+                None => next_value,
+            };
+            next_value = value + 1;
+
+            enum_defs.add_enum_constant(variant.name.clone(), value);
+            p_variants.push((variant.name, value));
+        }
 
         Ok(Self::Enum {
-            def: EnumDefinition {},
+            def: EnumDefinition {
+                variants: p_variants,
+            },
             area: entire_span,
         })
     }
@@ -279,6 +704,11 @@ impl AType {
 
                 Ok(Self::Const(Box::new(ty)))
             }
+            (raw_ty, Modifier::Volatile) => {
+                let ty = AType::parse(raw_ty, ty_defs, vars)?;
+
+                Ok(Self::Volatile(Box::new(ty)))
+            }
             (base, Modifier::Long) => {
                 let tmp_tok = TypeToken::Composition {
                     base: Box::new(base),
@@ -311,6 +741,7 @@ impl AType {
             TypeToken::Primitive(prim) => {
                 let prim_ty = match prim.data {
                     DataType::Void => APrimitive::Void,
+                    DataType::Bool => APrimitive::Bool,
                     DataType::Char => APrimitive::Char,
                     DataType::Short => APrimitive::Short,
                     DataType::Int => APrimitive::Int,
@@ -329,35 +760,48 @@ impl AType {
             TypeToken::ArrayType { size, base } => {
                 let base_ty = Self::parse(*base, ty_defs, vars)?;
 
-                let base_size = match size {
-                    Some(s) => {
-                        let a_exp = AExpression::parse(*s, ty_defs, vars)?;
-
-                        let size = match a_exp.const_evaluate() {
-                            Ok(v) => match v {
-                                EvaluationValue::Integer(s) => s,
-                                EvaluationValue::FloatingPoint(_) => {
-                                    panic!("Size cannot be floating point")
-                                }
-                            },
-                            Err(e) => {
-                                todo!("Evaluating Expression: {:?}", e);
-                            }
-                        };
-
-                        if size >= 0 {
-                            Some(size as usize)
-                        } else {
-                            panic!("Size cannot be negative");
-                        }
+                // Only the outermost Dimension may be variable, because the Elements are
+                // accessed using their constant Size
+                if base_ty.variable_length().is_some() {
+                    return Err(SemanticError::NotImplemented {
+                        ctx: "Arrays of variable length Arrays".to_string(),
+                    });
+                }
+
+                let size = match size {
+                    Some(s) => AExpression::parse(*s, ty_defs, vars)?,
+                    None => {
+                        return Ok(Self::Array(Array {
+                            size: None,
+                            length: None,
+                            ty: Box::new(base_ty),
+                        }))
                     }
-                    None => None,
                 };
 
-                Ok(Self::Array(Array {
-                    size: base_size,
-                    ty: Box::new(base_ty),
-                }))
+                match size.const_evaluate() {
+                    Ok(EvaluationValue::Integer(value)) if value >= 0 => Ok(Self::Array(Array {
+                        size: Some(value as usize),
+                        length: None,
+                        ty: Box::new(base_ty),
+                    })),
+                    // The Length is either evaluated once the Target is known or at Runtime
+                    Err(EvaluationError::TargetDependent | EvaluationError::NotConstant)
+                        if size.result_type().is_integer() =>
+                    {
+                        Ok(Self::Array(Array {
+                            size: None,
+                            length: Some(Box::new(size)),
+                            ty: Box::new(base_ty),
+                        }))
+                    }
+                    _ => Err(SemanticError::InvalidArraySize {
+                        area: size.entire_span(),
+                    }),
+                }
+            }
+            TypeToken::TypeDefed { name } if name.0.data == "__builtin_va_list" => {
+                Ok(Self::va_list())
             }
             TypeToken::TypeDefed { name } => match ty_defs.get_definition(&name) {
                 Some(ty) => Ok(Self::TypeDef {
@@ -370,43 +814,65 @@ impl AType {
                 Self::parse_composition(modifier, *base, ty_defs, vars)
             }
             TypeToken::StructType { name } => {
-                let target_ty = match ty_defs.get_definition(&name) {
+                // Using a Struct before its Definition implicitly declares it
+                let target_ty = match ty_defs.get_tag(&name) {
                     Some(t) => t,
-                    None => {
-                        return Err(SemanticError::UnknownType { name });
-                    }
+                    None => return Ok(Self::Incomplete { name }),
                 };
 
-                match &target_ty {
-                    AType::Struct { .. } => {}
-                    other => {
-                        dbg!(&other);
-
-                        panic!("Expected a Struct-Type");
-                    }
-                };
+                if !matches!(target_ty, AType::Struct { .. } | AType::Incomplete { .. }) {
+                    return Err(Self::mismatched_tag(name, target_ty));
+                }
 
                 Ok(target_ty.clone())
             }
             TypeToken::EnumType { name } => {
-                let target_ty = match ty_defs.get_definition(&name) {
+                let target_ty = match ty_defs.get_tag(&name) {
                     Some(t) => t,
                     None => return Err(SemanticError::UnknownType { name }),
                 };
 
-                dbg!(&target_ty);
+                if !matches!(target_ty, AType::Enum { .. }) {
+                    return Err(Self::mismatched_tag(name, target_ty));
+                }
 
-                todo!("Enum Type")
+                // Enums are simply treated as ints everywhere
+                Ok(AType::Primitve(APrimitive::Int))
+            }
+            TypeToken::FunctionPointer {
+                return_ty,
+                arguments,
+                var_args,
+            } => {
+                let return_ty = Self::parse(*return_ty, ty_defs, vars)?;
+                let arguments = arguments
+                    .into_iter()
+                    .map(|arg| Self::parse(arg, ty_defs, vars))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self::Pointer(Box::new(Self::Function {
+                    return_ty: Box::new(return_ty),
+                    arguments,
+                    var_args,
+                })))
+            }
+            TypeToken::AnonymousStruct { members, keyword } => {
+                Self::parse_struct(None, members, keyword, ty_defs, vars)
+            }
+            TypeToken::AnonymousUnion { members, keyword } => {
+                Self::parse_union(None, members, keyword, ty_defs, vars)
             }
             TypeToken::UnionType { name } => {
-                let target_ty = match ty_defs.get_definition(&name) {
+                let target_ty = match ty_defs.get_tag(&name) {
                     Some(t) => t,
-                    None => return Err(SemanticError::UnknownType { name }),
+                    None => return Ok(Self::Incomplete { name }),
                 };
 
-                dbg!(&target_ty);
+                if !matches!(target_ty, AType::Union { .. } | AType::Incomplete { .. }) {
+                    return Err(Self::mismatched_tag(name, target_ty));
+                }
 
-                todo!("Union Type")
+                Ok(target_ty.clone())
             }
         }
     }
@@ -424,31 +890,14 @@ impl AType {
                 name,
                 members,
                 entire_span,
+            } => Self::parse_struct(name, members, entire_span, ty_defs, vars),
+            TypeDefType::EnumDef {
+                name,
+                keyword,
+                variants,
             } => {
-                let str_members: Vec<_> = members
-                    .into_iter()
-                    .filter_map(
-                        |(raw_ty, ident)| match AType::parse(raw_ty, ty_defs, vars) {
-                            Ok(ty) => Some(StructMember { name: ident, ty }),
-                            _ => None,
-                        },
-                    )
-                    .collect();
-
-                let struct_def = StructDef {
-                    members: str_members,
-                };
-
-                match name {
-                    Some(n) => {
-                        dbg!(&n);
-                        todo!("Named Struct");
-                    }
-                    None => Ok(Self::Struct {
-                        def: struct_def,
-                        area: entire_span,
-                    }),
-                }
+                let area = name.map(|n| n.0.span).unwrap_or(keyword);
+                Self::parse_enum(variants, area, ty_defs, vars)
             }
             TypeDefType::Type(inner_type) => {
                 let ty = Self::parse(inner_type, ty_defs, vars)?;
@@ -463,62 +912,128 @@ impl AType {
         }
     }
 
-    pub fn to_ir(self) -> ir::Type {
+    pub fn to_ir(self, arch: &general::arch::Arch) -> ir::Type {
         match self {
-            Self::Primitve(prim) => match prim {
-                APrimitive::Void => ir::Type::Void,
-                APrimitive::Char => ir::Type::I8,
-                APrimitive::Short => ir::Type::I16,
-                APrimitive::Int => ir::Type::I32,
-                APrimitive::LongInt => ir::Type::I64,
-                APrimitive::LongLongInt => todo!("Unsupported 128 bit"),
-                APrimitive::Float => ir::Type::Float,
-                APrimitive::Double => ir::Type::Double,
-                APrimitive::UnsignedInt => ir::Type::U32,
-                other => {
-                    dbg!(&other);
-
-                    todo!("Unkown Primitve Conversion")
-                }
-            },
+            Self::Primitve(prim) => prim.to_ir(arch),
             Self::Pointer(raw_inner) => {
-                let inner = raw_inner.to_ir();
+                let inner = raw_inner.to_ir(arch);
                 ir::Type::Pointer(Box::new(inner))
             }
-            Self::Array(arr) => ir::Type::Pointer(Box::new(arr.ty.to_ir())),
+            Self::Array(arr) => ir::Type::Pointer(Box::new(arr.ty.to_ir(arch))),
             Self::Struct { .. } => ir::Type::Pointer(Box::new(ir::Type::Void)),
-            Self::TypeDef { ty, .. } => ty.to_ir(),
-            other => {
-                dbg!(&other);
+            Self::Union { .. } => ir::Type::Pointer(Box::new(ir::Type::Void)),
+            Self::Incomplete { .. } => ir::Type::Pointer(Box::new(ir::Type::Void)),
+            Self::Enum { .. } => APrimitive::Int.to_ir(arch),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.to_ir(arch),
+            // Functions have no Value representation of their own, a Pointer to them is simply
+            // an Address
+            Self::Function { .. } => ir::Type::Void,
+        }
+    }
+
+    /// The IR-Type of a Value of this Type, when it is passed to or returned from a Function.
+    /// Unlike [`AType::to_ir`], which only uses the Address of a Struct, this describes the
+    /// Layout of the entire Struct, so that the Backends can apply the Rules of their
+    /// Calling-Convention to it
+    pub fn to_value_ir(self, arch: &general::arch::Arch) -> ir::Type {
+        match self {
+            Self::Struct { def, .. } => def.to_ir(arch),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.to_value_ir(arch),
+            other => other.to_ir(arch),
+        }
+    }
+
+    /// The IR-Type of this Type when it is used as a Member in the Layout of a Struct
+    pub(crate) fn member_ir(&self, arch: &general::arch::Arch) -> ir::Type {
+        match self.into_ty() {
+            Self::Array(arr) => ir::Type::Array(
+                Box::new(arr.ty.member_ir(arch)),
+                arr.length_for(arch).unwrap_or(0),
+            ),
+            // The Members of a Union overlap, so it is only described by its Size and Alignment
+            Self::Union { def, .. } => {
+                let alignment = def.alignment(arch);
+                let unit = match alignment {
+                    1 => ir::Type::U8,
+                    2 => ir::Type::U16,
+                    4 => ir::Type::U32,
+                    _ => ir::Type::U64,
+                };
 
-                todo!("Unknown type conversion")
+                ir::Type::Array(Box::new(unit), def.union_size(arch) / alignment)
             }
+            other => other.clone().to_value_ir(arch),
         }
     }
 
     pub fn byte_size(&self, arch: &general::arch::Arch) -> u64 {
         match self {
-            Self::Primitve(prim) => prim.byte_size(),
+            Self::Primitve(prim) => prim.byte_size(arch),
             Self::Pointer(_) => arch.ptr_size() as u64,
-            Self::Array(arr) => arr.ty.byte_size(arch) * (arr.size.unwrap() as u64),
+            Self::Array(arr) => match arr.length_for(arch) {
+                Some(length) => arr.ty.byte_size(arch) * length as u64,
+                None => panic!("Array without a constant Length has no constant Size"),
+            },
             Self::Struct { def, .. } => def.entire_size(arch) as u64,
-            Self::TypeDef { ty, .. } => ty.byte_size(arch),
+            Self::Union { def, .. } => def.union_size(arch) as u64,
+            Self::Enum { .. } => APrimitive::Int.byte_size(arch),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.byte_size(arch),
             _ => todo!("Size of {:?} in Bytes", self),
         }
     }
 
     pub fn alignment(&self, arch: &general::arch::Arch) -> u64 {
         match self {
-            Self::Primitve(prim) => prim.alignment(),
+            Self::Primitve(prim) => prim.alignment(arch),
             Self::Pointer(_) => arch.ptr_size() as u64,
             Self::Array(arr) => arr.ty.alignment(arch),
             Self::Struct { def, .. } => def.alignment(arch) as u64,
-            Self::TypeDef { ty, .. } => ty.alignment(arch),
+            Self::Union { def, .. } => def.alignment(arch) as u64,
+            Self::Enum { .. } => APrimitive::Int.alignment(arch),
+            Self::TypeDef { ty, .. } | Self::Const(ty) | Self::Volatile(ty) => ty.alignment(arch),
             _ => todo!("Alignment of {:?} in Bytes", self),
         }
     }
 }
 
+/// Displays the Type the way it is spelled in C, like "const char*" or "struct point"
+impl std::fmt::Display for AType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primitve(prim) => write!(f, "{}", prim),
+            Self::Pointer(inner) => write!(f, "{}*", inner),
+            Self::Struct { def, .. } => match &def.name {
+                Some(name) => write!(f, "struct {}", name),
+                None => write!(f, "struct <anonymous>"),
+            },
+            Self::Union { def, .. } => match &def.name {
+                Some(name) => write!(f, "union {}", name),
+                None => write!(f, "union <anonymous>"),
+            },
+            Self::Enum { .. } => write!(f, "enum"),
+            Self::Incomplete { name } => write!(f, "{}", name.0.data),
+            Self::Array(arr) => match arr.size {
+                Some(size) => write!(f, "{}[{}]", arr.ty, size),
+                None => write!(f, "{}[]", arr.ty),
+            },
+            Self::Const(inner) => write!(f, "const {}", inner),
+            Self::Volatile(inner) => write!(f, "volatile {}", inner),
+            Self::TypeDef { name, .. } => write!(f, "{}", name.0.data),
+            Self::Function {
+                return_ty,
+                arguments,
+                var_args,
+            } => {
+                let mut arguments: Vec<String> = arguments.iter().map(|a| a.to_string()).collect();
+                if *var_args {
+                    arguments.push("...".to_string());
+                }
+                write!(f, "{}({})", return_ty, arguments.join(", "))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -533,6 +1048,7 @@ mod tests {
         let root = AType::Array(Array {
             ty: Box::new(AType::Primitve(APrimitive::Int)),
             size: None,
+            length: None,
         });
 
         let target = AType::Pointer(Box::new(AType::Primitve(APrimitive::Int)));
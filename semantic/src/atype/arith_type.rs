@@ -1,154 +1,218 @@
-use crate::{AExpression, APrimitive, AType, SemanticError};
+use general::{arch::DataModel, SpanData};
 
+use crate::{AExpression, APrimitive, AType, EvaluationValue, SemanticError, SemanticWarning};
+
+/// Converts both Operands of an arithmetic Operation to their common Type, using the usual
+/// arithmetic Conversions (C11 6.3.1.8)
 pub fn determine_types(
     left: AExpression,
     right: AExpression,
+    model: &DataModel,
 ) -> Result<(AExpression, AExpression), SemanticError> {
     let left_type = left.result_type();
     let right_type = right.result_type();
-    if left_type == right_type {
-        return Ok((left, right));
-    }
 
-    let left_prim = match left_type {
-        AType::Primitve(prim) => prim,
+    let (left_prim, right_prim) = match (
+        arithmetic_primitive(&left_type),
+        arithmetic_primitive(&right_type),
+    ) {
+        (Some(left_prim), Some(right_prim)) => (left_prim, right_prim),
+        _ if left_type == right_type => return Ok((left, right)),
         _ => {
             return Err(SemanticError::MismatchedOperationTypes {
-                left: general::SpanData {
+                left: Box::new(SpanData {
                     span: left.entire_span(),
                     data: left_type,
-                },
-                right: general::SpanData {
-                    span: right.entire_span(),
-                    data: right_type,
-                },
-            })
-        }
-    };
-    let right_prim = match right_type {
-        AType::Primitve(prim) => prim,
-        _ => {
-            return Err(SemanticError::MismatchedOperationTypes {
-                left: general::SpanData {
-                    span: left.entire_span(),
-                    data: AType::Primitve(left_prim),
-                },
-                right: general::SpanData {
+                }),
+                right: Box::new(SpanData {
                     span: right.entire_span(),
                     data: right_type,
-                },
+                }),
             })
         }
     };
 
-    match (left_prim, right_prim) {
-        (APrimitive::LongDouble, _) => {
-            let n_exp = AExpression::Cast {
-                base: Box::new(right),
-                target: AType::Primitve(APrimitive::LongDouble),
-            };
+    let target = common_type(promoted(left_prim), promoted(right_prim), model);
 
-            Ok((left, n_exp))
-        }
-        (_, APrimitive::LongDouble) => {
-            let n_exp = AExpression::Cast {
-                base: Box::new(left),
-                target: AType::Primitve(APrimitive::LongDouble),
-            };
+    Ok((convert(left, &target), convert(right, &target)))
+}
 
-            Ok((n_exp, right))
-        }
-        (APrimitive::Double, _) => {
-            let n_exp = AExpression::Cast {
-                base: Box::new(right),
-                target: AType::Primitve(APrimitive::Double),
-            };
+/// Converts both Operands to their common Type, if both of them are arithmetic. An Integer used
+/// together with a Pointer, like the Null-Pointer Constant 0, is converted to the Pointer-Type
+/// and all other Operands are left as they are, like for Comparisons between Pointers
+pub fn determine_common_types(
+    left: AExpression,
+    right: AExpression,
+    model: &DataModel,
+) -> (AExpression, AExpression) {
+    let left_type = left.result_type();
+    let right_type = right.result_type();
+
+    match (
+        arithmetic_primitive(&left_type),
+        arithmetic_primitive(&right_type),
+    ) {
+        (Some(left_prim), Some(right_prim)) => {
+            let target = common_type(promoted(left_prim), promoted(right_prim), model);
 
-            Ok((left, n_exp))
+            (convert(left, &target), convert(right, &target))
         }
-        (_, APrimitive::Double) => {
-            let n_exp = AExpression::Cast {
+        (Some(left_prim), None)
+            if left_prim.rank().is_some() && matches!(right_type.into_ty(), AType::Pointer(_)) =>
+        {
+            let n_left = AExpression::Cast {
                 base: Box::new(left),
-                target: AType::Primitve(APrimitive::Double),
+                target: right_type,
             };
 
-            Ok((n_exp, right))
+            (n_left, right)
         }
-        (APrimitive::Float, _) => {
-            let n_exp = AExpression::Cast {
+        (None, Some(right_prim))
+            if right_prim.rank().is_some() && matches!(left_type.into_ty(), AType::Pointer(_)) =>
+        {
+            let n_right = AExpression::Cast {
                 base: Box::new(right),
-                target: AType::Primitve(APrimitive::Float),
+                target: left_type,
             };
 
-            Ok((left, n_exp))
+            (left, n_right)
         }
-        (_, APrimitive::Float) => {
-            let n_exp = AExpression::Cast {
-                base: Box::new(left),
-                target: AType::Primitve(APrimitive::Float),
-            };
+        _ => (left, right),
+    }
+}
 
-            Ok((n_exp, right))
-        }
-        (left_prim, right_prim) if left_prim.is_unsigned() && right_prim.is_unsigned() => {
-            if left_prim.rank() > right_prim.rank() {
-                let n_exp = AExpression::Cast {
-                    target: AType::Primitve(left_prim),
-                    base: Box::new(right),
-                };
-
-                Ok((left, n_exp))
-            } else {
-                let n_exp = AExpression::Cast {
-                    target: AType::Primitve(right_prim),
-                    base: Box::new(left),
-                };
-
-                Ok((n_exp, right))
-            }
-        }
-        (left_prim, right_prim) if left_prim.is_signed() && right_prim.is_signed() => {
-            if left_prim.rank() > right_prim.rank() {
-                let n_exp = AExpression::Cast {
-                    target: AType::Primitve(left_prim),
-                    base: Box::new(right),
-                };
-
-                Ok((left, n_exp))
-            } else {
-                let n_exp = AExpression::Cast {
-                    target: AType::Primitve(right_prim),
-                    base: Box::new(left),
-                };
-
-                Ok((n_exp, right))
-            }
-        }
-        (left_prim, right_prim) => {
-            let left_rank = left_prim.rank().unwrap();
-            let right_rank = right_prim.rank().unwrap();
-
-            if left_rank >= right_rank && left_prim.is_unsigned() || left_rank > right_rank {
-                let n_right = AExpression::Cast {
-                    base: Box::new(right),
-                    target: AType::Primitve(left_prim),
-                };
-
-                return Ok((left, n_right));
-            } else if right_rank >= left_rank && right_prim.is_unsigned() || right_rank > left_rank
-            {
-                let n_left = AExpression::Cast {
-                    base: Box::new(left),
-                    target: AType::Primitve(right_prim),
-                };
-
-                return Ok((n_left, right));
-            }
-            dbg!(left_prim.rank(), right_prim.rank());
-
-            todo!("Different Primitives");
+/// Performs the Integer-Promotions (C11 6.3.1.1) on the Expression, which converts all Values of
+/// an Integer-Type with a lower Rank than int to int
+pub fn promote(exp: AExpression) -> AExpression {
+    match arithmetic_primitive(&exp.result_type()) {
+        Some(prim) => convert(exp, &promoted(prim)),
+        None => exp,
+    }
+}
+
+/// Checks if the Comparison of the two Operands converts a signed Operand to an unsigned Type.
+///
+/// Constants that are not negative keep their Value, so comparing against them is fine
+pub fn sign_changing_comparison(
+    left: &AExpression,
+    right: &AExpression,
+    model: &DataModel,
+) -> Option<SemanticWarning> {
+    let left_type = left.result_type();
+    let right_type = right.result_type();
+    let left_prim = promoted(arithmetic_primitive(&left_type)?);
+    let right_prim = promoted(arithmetic_primitive(&right_type)?);
+
+    if !common_type(left_prim.clone(), right_prim.clone(), model).is_unsigned() {
+        return None;
+    }
+
+    let (signed, signed_ty, unsigned, unsigned_ty) =
+        match (left_prim.is_signed(), right_prim.is_signed()) {
+            (true, false) => (left, left_type, right, right_type),
+            (false, true) => (right, right_type, left, left_type),
+            _ => return None,
+        };
+
+    if let Ok(EvaluationValue::Integer(value)) = signed.const_evaluate() {
+        if value >= 0 {
+            return None;
         }
     }
+
+    Some(SemanticWarning::SignChangingComparison {
+        signed: SpanData {
+            span: signed.entire_span(),
+            data: signed_ty,
+        },
+        unsigned: SpanData {
+            span: unsigned.entire_span(),
+            data: unsigned_ty,
+        },
+    })
+}
+
+/// The Primitive used for Values of the Type in arithmetic Operations, if it is an arithmetic
+/// Type at all. Enums are treated like their underlying int
+pub(crate) fn arithmetic_primitive(ty: &AType) -> Option<APrimitive> {
+    match ty.into_ty() {
+        AType::Primitve(APrimitive::Void) => None,
+        AType::Primitve(prim) => Some(prim.clone()),
+        AType::Enum { .. } => Some(APrimitive::Int),
+        _ => None,
+    }
+}
+
+/// The Type after the Integer-Promotions, int can represent all the Values of the smaller Types
+/// in every supported Data-Model, so they never get promoted to unsigned int
+fn promoted(prim: APrimitive) -> APrimitive {
+    match prim.rank() {
+        Some(rank) if rank < APrimitive::Int.rank().unwrap() => APrimitive::Int,
+        _ => prim,
+    }
+}
+
+/// The common Type of two already promoted Operands.
+///
+/// If the signed Operand has the greater Rank, it is only used when it can represent all the
+/// Values of the unsigned Operand in the Data-Model, otherwise both are converted to the unsigned
+/// Type corresponding to the signed one, like "long" and "unsigned int" on ILP32
+fn common_type(left: APrimitive, right: APrimitive, model: &DataModel) -> APrimitive {
+    let floats = [
+        APrimitive::LongDouble,
+        APrimitive::Double,
+        APrimitive::Float,
+    ];
+    if let Some(float) = floats.into_iter().find(|f| *f == left || *f == right) {
+        return float;
+    }
+
+    if left == right {
+        return left;
+    }
+
+    let left_rank = left.rank().unwrap();
+    let right_rank = right.rank().unwrap();
+    if left.is_unsigned() == right.is_unsigned() {
+        return if left_rank >= right_rank { left } else { right };
+    }
+
+    let (unsigned, signed) = if left.is_unsigned() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    if unsigned.rank() >= signed.rank() {
+        unsigned
+    } else if signed.model_size(model) > unsigned.model_size(model) {
+        signed
+    } else {
+        unsigned_counterpart(signed)
+    }
+}
+
+/// The unsigned Type with the same Rank as the promoted signed Type
+fn unsigned_counterpart(signed: APrimitive) -> APrimitive {
+    match signed {
+        APrimitive::Int => APrimitive::UnsignedInt,
+        APrimitive::LongInt => APrimitive::UnsignedLongInt,
+        APrimitive::LongLongInt => APrimitive::UnsignedLongLongInt,
+        other => other,
+    }
+}
+
+/// Casts the Expression to the Primitive, unless it already has that Type
+fn convert(exp: AExpression, target: &APrimitive) -> AExpression {
+    let target = AType::Primitve(target.clone());
+    if *exp.result_type().into_ty() == target {
+        return exp;
+    }
+
+    AExpression::Cast {
+        base: Box::new(exp),
+        target,
+    }
 }
 
 #[cfg(test)]
@@ -162,20 +226,26 @@ mod tests {
     #[test]
     fn same_types() {
         let in_source = Source::new("test", "123 + 234");
-        let left_in = AExpression::Literal(Literal::Integer(SpanData {
-            span: Span::new_source(in_source.clone(), 0..3),
-            data: 123,
-        }));
-        let right_in = AExpression::Literal(Literal::Integer(SpanData {
-            span: Span::new_source(in_source, 6..9),
-            data: 234,
-        }));
+        let left_in = AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(in_source.clone(), 0..3),
+                data: 123,
+            },
+            APrimitive::Int,
+        ));
+        let right_in = AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(in_source, 6..9),
+                data: 234,
+            },
+            APrimitive::Int,
+        ));
 
         let expected_left = left_in.clone();
         let expected_right = right_in.clone();
         let expected = Ok((expected_left, expected_right));
 
-        let result = determine_types(left_in, right_in);
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
         dbg!(&result);
 
         assert_eq!(expected, result);
@@ -184,23 +254,29 @@ mod tests {
     #[test]
     fn float_int() {
         let in_source = Source::new("test", "1.3 + 234");
-        let left_in = AExpression::Literal(Literal::FloatingPoint(SpanData {
-            span: Span::new_source(in_source.clone(), 0..3),
-            data: 1.3,
-        }));
-        let right_in = AExpression::Literal(Literal::Integer(SpanData {
-            span: Span::new_source(in_source, 6..9),
-            data: 234,
-        }));
+        let left_in = AExpression::Literal(Literal::FloatingPoint(
+            SpanData {
+                span: Span::new_source(in_source.clone(), 0..3),
+                data: 1.3,
+            },
+            APrimitive::Double,
+        ));
+        let right_in = AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(in_source, 6..9),
+                data: 234,
+            },
+            APrimitive::Int,
+        ));
 
         let expected_left = left_in.clone();
         let expected_right = AExpression::Cast {
             base: Box::new(right_in.clone()),
-            target: AType::Primitve(APrimitive::Float),
+            target: AType::Primitve(APrimitive::Double),
         };
         let expected = Ok((expected_left, expected_right));
 
-        let result = determine_types(left_in, right_in);
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
         dbg!(&result);
 
         assert_eq!(expected, result);
@@ -209,15 +285,21 @@ mod tests {
     #[test]
     fn uint_int() {
         let source = Source::new("test", "unsigned int");
-        let left_in = AExpression::Literal(Literal::Integer(SpanData {
-            span: Span::new_source(source.clone(), 9..12),
-            data: 1,
-        }));
+        let left_in = AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(source.clone(), 9..12),
+                data: 1,
+            },
+            APrimitive::Int,
+        ));
         let right_in = AExpression::Cast {
-            base: Box::new(AExpression::Literal(Literal::Integer(SpanData {
-                span: Span::new_source(source, 0..12),
-                data: 2,
-            }))),
+            base: Box::new(AExpression::Literal(Literal::Integer(
+                SpanData {
+                    span: Span::new_source(source, 0..12),
+                    data: 2,
+                },
+                APrimitive::Int,
+            ))),
             target: AType::Primitve(APrimitive::UnsignedLongInt),
         };
 
@@ -228,7 +310,7 @@ mod tests {
         let expected_right = right_in.clone();
         let expected = Ok((expected_left, expected_right));
 
-        let result = determine_types(left_in, right_in);
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
         dbg!(&result);
 
         assert_eq!(expected, result);
@@ -236,15 +318,21 @@ mod tests {
     #[test]
     fn int_uint() {
         let source = Source::new("test", "unsigned int");
-        let right_in = AExpression::Literal(Literal::Integer(SpanData {
-            span: Span::new_source(source.clone(), 9..12),
-            data: 1,
-        }));
+        let right_in = AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(source.clone(), 9..12),
+                data: 1,
+            },
+            APrimitive::Int,
+        ));
         let left_in = AExpression::Cast {
-            base: Box::new(AExpression::Literal(Literal::Integer(SpanData {
-                span: Span::new_source(source, 0..12),
-                data: 2,
-            }))),
+            base: Box::new(AExpression::Literal(Literal::Integer(
+                SpanData {
+                    span: Span::new_source(source, 0..12),
+                    data: 2,
+                },
+                APrimitive::Int,
+            ))),
             target: AType::Primitve(APrimitive::UnsignedLongInt),
         };
 
@@ -255,9 +343,136 @@ mod tests {
         let expected_left = left_in.clone();
         let expected = Ok((expected_left, expected_right));
 
-        let result = determine_types(left_in, right_in);
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
+        dbg!(&result);
+
+        assert_eq!(expected, result);
+    }
+
+    fn literal(source: &Source, range: std::ops::Range<usize>, prim: APrimitive) -> AExpression {
+        AExpression::Literal(Literal::Integer(
+            SpanData {
+                span: Span::new_source(source.clone(), range),
+                data: 1,
+            },
+            prim,
+        ))
+    }
+
+    #[test]
+    fn char_char() {
+        let source = Source::new("test", "a + b");
+        let left_in = literal(&source, 0..1, APrimitive::Char);
+        let right_in = literal(&source, 4..5, APrimitive::UnsignedChar);
+
+        let expected_left = AExpression::Cast {
+            base: Box::new(left_in.clone()),
+            target: AType::Primitve(APrimitive::Int),
+        };
+        let expected_right = AExpression::Cast {
+            base: Box::new(right_in.clone()),
+            target: AType::Primitve(APrimitive::Int),
+        };
+        let expected = Ok((expected_left, expected_right));
+
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
+        dbg!(&result);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn short_long() {
+        let source = Source::new("test", "a + b");
+        let left_in = literal(&source, 0..1, APrimitive::Short);
+        let right_in = literal(&source, 4..5, APrimitive::LongInt);
+
+        let expected_left = AExpression::Cast {
+            base: Box::new(left_in.clone()),
+            target: AType::Primitve(APrimitive::LongInt),
+        };
+        let expected_right = right_in.clone();
+        let expected = Ok((expected_left, expected_right));
+
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
+        dbg!(&result);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn uint_long() {
+        let source = Source::new("test", "a + b");
+        let left_in = literal(&source, 0..1, APrimitive::UnsignedInt);
+        let right_in = literal(&source, 4..5, APrimitive::LongInt);
+
+        let expected_left = AExpression::Cast {
+            base: Box::new(left_in.clone()),
+            target: AType::Primitve(APrimitive::LongInt),
+        };
+        let expected_right = right_in.clone();
+        let expected = Ok((expected_left, expected_right));
+
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
         dbg!(&result);
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn long_uint_ilp32() {
+        let source = Source::new("test", "a + b");
+        let left_in = literal(&source, 0..1, APrimitive::LongInt);
+        let right_in = literal(&source, 4..5, APrimitive::UnsignedInt);
+
+        let expected_left = AExpression::Cast {
+            base: Box::new(left_in.clone()),
+            target: AType::Primitve(APrimitive::UnsignedLongInt),
+        };
+        let expected_right = AExpression::Cast {
+            base: Box::new(right_in.clone()),
+            target: AType::Primitve(APrimitive::UnsignedLongInt),
+        };
+        let expected = Ok((expected_left, expected_right));
+
+        let result = determine_types(left_in, right_in, &DataModel::ILP32);
+        dbg!(&result);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn ulong_long_long_lp64() {
+        let source = Source::new("test", "a + b");
+        let left_in = literal(&source, 0..1, APrimitive::UnsignedLongInt);
+        let right_in = literal(&source, 4..5, APrimitive::LongLongInt);
+
+        let expected_left = AExpression::Cast {
+            base: Box::new(left_in.clone()),
+            target: AType::Primitve(APrimitive::UnsignedLongLongInt),
+        };
+        let expected_right = AExpression::Cast {
+            base: Box::new(right_in.clone()),
+            target: AType::Primitve(APrimitive::UnsignedLongLongInt),
+        };
+        let expected = Ok((expected_left, expected_right));
+
+        let result = determine_types(left_in, right_in, &DataModel::LP64);
+        dbg!(&result);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn promote_unsigned_short() {
+        let source = Source::new("test", "a");
+        let exp = literal(&source, 0..1, APrimitive::UnsignedShort);
+
+        let expected = AExpression::Cast {
+            base: Box::new(exp.clone()),
+            target: AType::Primitve(APrimitive::Int),
+        };
+
+        assert_eq!(expected, promote(exp));
+    }
 }
@@ -7,29 +7,31 @@ pub fn determine_type(
     target: (&AType, &Span),
 ) -> Result<AExpression, SemanticError> {
     let res_type = base.result_type();
-    if res_type == target.0 {
+    if res_type.into_ty() == target.0.into_ty() {
         return Ok(base);
     }
 
     match (&res_type, target.0) {
         (AType::Primitve(res_prim), AType::Primitve(target_prim)) => {
             match (&target_prim, &res_prim) {
-                (APrimitive::Float, APrimitive::Float)
-                | (APrimitive::Double, APrimitive::Float)
-                | (APrimitive::LongDouble, APrimitive::Float)
-                | (APrimitive::Double, APrimitive::Double)
-                | (APrimitive::LongDouble, APrimitive::Double)
-                | (APrimitive::LongDouble, APrimitive::LongDouble) => {}
+                // Any arithmetic Value can be converted to _Bool, by comparing it against 0
+                (APrimitive::Bool, _) => {}
+                // Floating-Point Values can be implicitly converted to any other Floating-Point
+                // Type, even if it has less Precision
+                (
+                    APrimitive::Float | APrimitive::Double | APrimitive::LongDouble,
+                    APrimitive::Float | APrimitive::Double | APrimitive::LongDouble,
+                ) => {}
                 (_, APrimitive::Float) | (_, APrimitive::Double) | (_, APrimitive::LongDouble) => {
                     return Err(SemanticError::AmbiguousTypeConversion {
-                        target: SpanData {
+                        target: Box::new(SpanData {
                             span: target.1.clone(),
                             data: target.0.clone(),
-                        },
-                        base: SpanData {
+                        }),
+                        base: Box::new(SpanData {
                             span: base.entire_span(),
                             data: res_type,
-                        },
+                        }),
                     });
                 }
                 _ => {}
@@ -44,6 +46,58 @@ pub fn determine_type(
 
             Ok(casted)
         }
+        // A Pointer converted to _Bool is only false for the Null-Pointer
+        (AType::Pointer(_), AType::Primitve(APrimitive::Bool)) => {
+            let casted = AExpression::Cast {
+                target: target.0.clone(),
+                base: Box::new(base),
+            };
+
+            Ok(casted)
+        }
+        // Dropping the const Qualifier of the pointed to Type would allow the Object to be
+        // modified through the new Pointer
+        (AType::Pointer(res_val), AType::Pointer(target_val))
+            if res_val.is_const() && !target_val.is_const() =>
+        {
+            Err(SemanticError::DiscardedConst {
+                target: Box::new(SpanData {
+                    span: target.1.clone(),
+                    data: target.0.clone(),
+                }),
+                base: Box::new(SpanData {
+                    span: base.entire_span(),
+                    data: res_type,
+                }),
+            })
+        }
+        (AType::Array(arr_ty), AType::Pointer(target_val))
+            if arr_ty.ty.is_const() && !target_val.is_const() =>
+        {
+            Err(SemanticError::DiscardedConst {
+                target: Box::new(SpanData {
+                    span: target.1.clone(),
+                    data: target.0.clone(),
+                }),
+                base: Box::new(SpanData {
+                    span: base.entire_span(),
+                    data: res_type,
+                }),
+            })
+        }
+        // Adding Qualifiers to the pointed to Type is always allowed, but dropping volatile is not
+        (AType::Pointer(res_val), AType::Pointer(target_val))
+            if (target_val.is_volatile() || !res_val.is_volatile())
+                && res_val.as_ref().clone().unqualified()
+                    == target_val.as_ref().clone().unqualified() =>
+        {
+            let casted = AExpression::Cast {
+                target: target.0.clone(),
+                base: Box::new(base),
+            };
+
+            Ok(casted)
+        }
         (AType::Pointer(_), AType::Pointer(target_val))
             if *target_val.into_ty() == AType::Primitve(APrimitive::Void) =>
         {
@@ -64,7 +118,10 @@ pub fn determine_type(
 
             Ok(casted)
         }
-        (AType::Array(arr_ty), AType::Pointer(ptr_ty)) if arr_ty.ty.eq(ptr_ty) => {
+        (AType::Array(arr_ty), AType::Pointer(ptr_ty))
+            if arr_ty.ty.as_ref().clone().unqualified()
+                == ptr_ty.as_ref().clone().unqualified() =>
+        {
             let casted = AExpression::Cast {
                 target: target.0.clone(),
                 base: Box::new(base),
@@ -72,16 +129,18 @@ pub fn determine_type(
 
             Ok(casted)
         }
-        (_, AType::Const(const_ty)) => determine_type(base, (const_ty, target.1)),
+        (_, AType::Const(inner_ty) | AType::Volatile(inner_ty)) => {
+            determine_type(base, (inner_ty, target.1))
+        }
         _ => Err(SemanticError::MismatchedTypes {
-            expected: SpanData {
+            expected: Box::new(SpanData {
                 span: target.1.clone(),
                 data: target.0.clone(),
-            },
-            received: SpanData {
+            }),
+            received: Box::new(SpanData {
                 span: base.entire_span(),
                 data: base.result_type(),
-            },
+            }),
         }),
     }
 }
@@ -99,15 +158,21 @@ mod tests {
         let input_source = Source::new("test", " ");
 
         assert_eq!(
-            Ok(AExpression::Literal(Literal::Integer(SpanData {
-                span: Span::new_source(input_source.clone(), 0..1),
-                data: 0,
-            }))),
-            determine_type(
-                AExpression::Literal(Literal::Integer(SpanData {
+            Ok(AExpression::Literal(Literal::Integer(
+                SpanData {
                     span: Span::new_source(input_source.clone(), 0..1),
                     data: 0,
-                })),
+                },
+                APrimitive::LongInt
+            ))),
+            determine_type(
+                AExpression::Literal(Literal::Integer(
+                    SpanData {
+                        span: Span::new_source(input_source.clone(), 0..1),
+                        data: 0,
+                    },
+                    APrimitive::LongInt
+                )),
                 (
                     &AType::Primitve(APrimitive::LongInt),
                     &Span::new_source(input_source, 0..1)
@@ -122,20 +187,23 @@ mod tests {
 
         assert_eq!(
             Err(SemanticError::AmbiguousTypeConversion {
-                base: SpanData {
+                base: Box::new(SpanData {
                     data: AType::Primitve(APrimitive::Float),
                     span: Span::new_source(input_source.clone(), 0..1),
-                },
-                target: SpanData {
+                }),
+                target: Box::new(SpanData {
                     data: AType::Primitve(APrimitive::Int),
                     span: Span::new_source(input_source.clone(), 0..1),
-                },
+                }),
             }),
             determine_type(
-                AExpression::Literal(Literal::FloatingPoint(SpanData {
-                    span: Span::new_source(input_source.clone(), 0..1),
-                    data: 1.3,
-                })),
+                AExpression::Literal(Literal::FloatingPoint(
+                    SpanData {
+                        span: Span::new_source(input_source.clone(), 0..1),
+                        data: 1.3,
+                    },
+                    APrimitive::Float
+                )),
                 (
                     &AType::Primitve(APrimitive::Int),
                     &Span::new_source(input_source, 0..1)
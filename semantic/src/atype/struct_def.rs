@@ -1,82 +1,367 @@
 use general::{arch::Arch, SpanData};
-use syntax::Identifier;
+use syntax::{Alignment, Expression, Identifier, TypeToken};
 
-use crate::AType;
+use crate::{
+    object_alignment, AAlignment, AExpression, AType, EvaluationValue, SemanticError,
+    TypeDefinitions, VariableContainer,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct StructMember {
-    pub name: Identifier,
+    /// The Name of the Member, which is None for unnamed Bit-Fields that only take up Space
+    pub name: Option<Identifier>,
     pub ty: AType,
+    /// The Width in Bits, if the Member is a Bit-Field
+    pub bit_width: Option<usize>,
+    /// The explicit Alignment of the Member, given by an Alignment-Specifier
+    pub alignment: Option<AAlignment>,
+}
+
+impl StructMember {
+    pub fn parse<VC>(
+        raw_ty: TypeToken,
+        name: Option<Identifier>,
+        raw_width: Option<Expression>,
+        raw_alignment: Option<Alignment>,
+        ty_defs: &TypeDefinitions,
+        vars: &VC,
+    ) -> Result<Self, SemanticError>
+    where
+        VC: VariableContainer,
+    {
+        let ty = AType::parse(raw_ty, ty_defs, vars)?.complete(ty_defs);
+        let alignment = match raw_alignment {
+            Some(raw_alignment) => Some(AAlignment::parse(raw_alignment, ty_defs, vars)?),
+            None => None,
+        };
+        let width_exp = match raw_width {
+            Some(raw_width) => Some(AExpression::parse(raw_width, ty_defs, vars)?),
+            None => None,
+        };
+
+        // Unnamed Bit-Fields have no Name to point at, so their Width is used instead
+        let area = match (&name, &width_exp) {
+            (Some(name), _) => name.0.span.clone(),
+            (None, Some(width_exp)) => width_exp.entire_span(),
+            (None, None) => unreachable!("Only Bit-Fields can be unnamed"),
+        };
+        // This also prevents a Struct from containing itself
+        ty.ensure_complete(&area)?;
+
+        // The Layout of a Struct has to be known at compile-time
+        if let Some(length) = ty.variable_length() {
+            return Err(SemanticError::InvalidArraySize {
+                area: length.entire_span(),
+            });
+        }
+
+        let bit_width = match width_exp {
+            Some(width_exp) => {
+                let width_span = width_exp.entire_span();
+
+                // Bit-Fields are placed into their Storage-Unit and can not be aligned on their own
+                if alignment.is_some() {
+                    return Err(SemanticError::InvalidBitField {
+                        name,
+                        width: width_span,
+                    });
+                }
+
+                // The Width is limited by the smallest Size the Type has on any of the Targets
+                let rank = match ty.clone().ty() {
+                    AType::Primitve(prim) => prim.rank(),
+                    _ => None,
+                };
+                let ty_bits = match rank {
+                    Some(1) => 8,
+                    Some(2) => 16,
+                    Some(3 | 4) => 32,
+                    Some(_) => 64,
+                    None => {
+                        return Err(SemanticError::InvalidBitField {
+                            name,
+                            width: width_span,
+                        })
+                    }
+                };
+
+                // Only unnamed Bit-Fields can have a Width of 0, which ends the current Unit
+                match width_exp.const_evaluate() {
+                    Ok(EvaluationValue::Integer(w))
+                        if (w > 0 || (w == 0 && name.is_none())) && w as u64 <= ty_bits =>
+                    {
+                        Some(w as usize)
+                    }
+                    _ => {
+                        return Err(SemanticError::InvalidBitField {
+                            name,
+                            width: width_span,
+                        })
+                    }
+                }
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            name,
+            ty,
+            bit_width,
+            alignment,
+        })
+    }
+
+    /// The Alignment of the Member, which is the Alignment of its Type unless the Member has a
+    /// stricter explicit Alignment
+    pub fn alignment(&self, arch: &Arch) -> usize {
+        object_alignment(
+            self.ty.alignment(arch) as usize,
+            self.alignment.as_ref(),
+            arch,
+        )
+    }
+
+    /// Whether the Member has the given Name, which is never the Case for unnamed Members
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name.as_ref().is_some_and(|n| n.0.data == name)
+    }
+
+    /// The Position of the Member in its Storage-Unit, if it is a Bit-Field of a Union, where
+    /// every Member starts at the Beginning of the Union
+    pub fn union_bit_field(&self, arch: &Arch) -> Option<BitField> {
+        let width = self.bit_width?;
+        let unit_bits = self.ty.byte_size(arch) as usize * 8;
+
+        Some(BitField {
+            offset: if arch.big_endian() {
+                unit_bits - width
+            } else {
+                0
+            },
+            width,
+        })
+    }
+}
+
+/// The Position of a Bit-Field in the Storage-Unit that contains it, the Storage-Unit is a
+/// Value of the declared Type of the Bit-Field
+#[derive(Debug, PartialEq, Clone)]
+pub struct BitField {
+    /// The Offset of the lowest Bit of the Field in the Storage-Unit
+    pub offset: usize,
+    /// The Number of Bits in the Field
+    pub width: usize,
+}
+
+impl BitField {
+    /// The Mask for the Bits of the Field, after they have been shifted down to the lowest Bit
+    pub fn mask(&self) -> u64 {
+        if self.width >= 64 {
+            u64::MAX
+        } else {
+            (1 << self.width) - 1
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct StructDef {
+    /// The Name of the Struct or Union, which is None for anonymous ones
+    pub name: Option<String>,
     pub members: Vec<StructMember>,
 }
 
 impl StructDef {
     pub fn find_member(&self, name: &Identifier) -> Option<SpanData<AType>> {
-        self.members
-            .iter()
-            .find(|memb| memb.name.0.data == name.0.data)
-            .map(|memb| SpanData {
-                span: memb.name.0.span.clone(),
+        self.named_members()
+            .find(|(memb_name, _)| memb_name.0.data == name.0.data)
+            .map(|(memb_name, memb)| SpanData {
+                span: memb_name.0.span.clone(),
                 data: memb.ty.clone(),
             })
     }
 
-    pub fn entire_size(&self, arch: &Arch) -> usize {
+    /// The Members that can be accessed and initialized, which excludes unnamed Bit-Fields
+    pub fn named_members(&self) -> impl Iterator<Item = (&Identifier, &StructMember)> {
+        self.members
+            .iter()
+            .filter_map(|memb| memb.name.as_ref().map(|name| (name, memb)))
+    }
+
+    /// Calculates the Offset of every Member and the Size of the entire Struct.
+    ///
+    /// Bit-Fields are placed into the Storage-Unit of their Type in Memory-Order and share it with
+    /// the previous Members as long as they fit, which means they start at the lowest Bit on
+    /// Little-Endian Targets and at the highest Bit on Big-Endian Targets like the SH4A. A
+    /// Bit-Field that would cross the Boundary of a Storage-Unit starts at the next aligned
+    /// Storage-Unit instead and a Bit-Field with a Width of 0 makes the next one start there.
+    /// The Offset of a Bit-Field is the Offset of its Storage-Unit.
+    fn layout(&self, arch: &Arch) -> (Vec<(usize, Option<BitField>)>, usize) {
+        let mut result = Vec::with_capacity(self.members.len());
+
+        // The Position of the next free Bit
+        let mut bit_pos: usize = 0;
         let mut size = 0;
         for memb in self.members.iter() {
             let memb_type = &memb.ty;
             let memb_size = memb_type.byte_size(arch) as usize;
             let memb_align = memb_type.alignment(arch) as usize;
 
-            let align_rest = size % memb_align;
-            if align_rest == 0 {
-                size += memb_size;
-            } else {
-                size = size - align_rest + memb_align;
-                size += memb_size;
-            }
+            match memb.bit_width {
+                Some(0) => {
+                    bit_pos = bit_pos.next_multiple_of(memb_align * 8);
+
+                    result.push((
+                        bit_pos / 8,
+                        Some(BitField {
+                            offset: 0,
+                            width: 0,
+                        }),
+                    ));
+                }
+                Some(width) => {
+                    let unit_bits = memb_size * 8;
+                    let align_bits = memb_align * 8;
+
+                    let mut unit_start = bit_pos - bit_pos % align_bits;
+                    if bit_pos + width > unit_start + unit_bits {
+                        unit_start = bit_pos - bit_pos % align_bits + align_bits;
+                        bit_pos = unit_start;
+                    }
+
+                    let position = bit_pos - unit_start;
+                    let offset = if arch.big_endian() {
+                        unit_bits - position - width
+                    } else {
+                        position
+                    };
+
+                    let unit_offset = unit_start / 8;
+                    result.push((unit_offset, Some(BitField { offset, width })));
+
+                    bit_pos += width;
+                    size = size.max(unit_offset + memb_size);
+                }
+                None => {
+                    let memb_align = memb.alignment(arch);
+                    let mut offset = bit_pos.div_ceil(8);
+
+                    let align_rest = offset % memb_align;
+                    if align_rest != 0 {
+                        offset = offset - align_rest + memb_align;
+                    }
+
+                    result.push((offset, None));
+
+                    bit_pos = (offset + memb_size) * 8;
+                    size = size.max(offset + memb_size);
+                }
+            };
         }
 
-        size
+        (result, size)
+    }
+
+    /// The Size of the entire Struct, including the Padding at the End that keeps the Struct
+    /// aligned when it is stored in an Array
+    pub fn entire_size(&self, arch: &Arch) -> usize {
+        let (_, size) = self.layout(arch);
+        size.next_multiple_of(self.alignment(arch))
+    }
+
+    /// The Size of the Definition when used as a Union, which is the Size of the largest Member
+    /// rounded up to the Alignment of the Union
+    pub fn union_size(&self, arch: &Arch) -> usize {
+        let largest = self
+            .sized_members()
+            .map(|m| m.ty.byte_size(arch) as usize)
+            .max()
+            .unwrap_or(0);
+        let alignment = self.alignment(arch);
+
+        match largest % alignment {
+            0 => largest,
+            rest => largest - rest + alignment,
+        }
     }
 
     pub fn alignment(&self, arch: &Arch) -> usize {
-        self.members
-            .iter()
-            .map(|m| m.ty.alignment(arch) as usize)
+        self.sized_members()
+            .map(|m| m.alignment(arch))
             .max()
             .unwrap_or(1)
     }
 
-    pub fn member_offset(&self, name: &str, arch: &Arch) -> Option<usize> {
-        let mut offset = 0;
-        for memb in self.members.iter() {
-            let memb_type = &memb.ty;
-            let memb_size = memb_type.byte_size(arch) as usize;
-            let memb_align = memb_type.alignment(arch) as usize;
-
-            let align_rest = offset % memb_align;
-            if align_rest == 0 {
-                if memb.name.0.data == name {
-                    return Some(offset);
-                }
+    /// The Members that take up Space, which excludes Bit-Fields with a Width of 0
+    pub(crate) fn sized_members(&self) -> impl Iterator<Item = &StructMember> {
+        self.members.iter().filter(|m| m.bit_width != Some(0))
+    }
 
-                offset += memb_size;
-            } else {
-                offset = offset - align_rest + memb_align;
-                if memb.name.0.data == name {
-                    return Some(offset);
-                }
+    /// The IR-Type describing the Layout of a Struct with this Definition.
+    ///
+    /// Bit-Fields are represented by their Storage-Units, where every Unit is only added once,
+    /// even if it is shared by multiple Bit-Fields
+    pub fn to_ir(&self, arch: &Arch) -> ir::Type {
+        let (layout, _) = self.layout(arch);
+
+        let mut members: Vec<(String, ir::Type)> = Vec::with_capacity(self.members.len());
+        // The Offset and Size of the last Storage-Unit
+        let mut last_unit = None;
+        for (memb, (offset, field)) in self.members.iter().zip(layout) {
+            if memb.bit_width == Some(0) {
+                continue;
+            }
 
-                offset += memb_size;
+            // Unnamed Bit-Fields still need their Storage-Unit, even though it is never accessed
+            let memb_name = memb
+                .name
+                .as_ref()
+                .map(|n| n.0.data.clone())
+                .unwrap_or_default();
+            let memb_ty = memb.ty.member_ir(arch);
+            let memb_size = memb.ty.byte_size(arch);
+
+            if field.is_some() {
+                match last_unit {
+                    // Bit-Fields of different Types might share the same Offset, in which case
+                    // the larger Unit covers both of them
+                    Some((unit_offset, unit_size)) if unit_offset == offset => {
+                        if memb_size > unit_size {
+                            let previous = members.last_mut().expect("The Unit was added before");
+                            *previous = (memb_name, memb_ty);
+                            last_unit = Some((offset, memb_size));
+                        }
+                        continue;
+                    }
+                    _ => last_unit = Some((offset, memb_size)),
+                };
             }
+
+            members.push((memb_name, memb_ty));
         }
 
-        None
+        ir::Type::Struct {
+            members,
+            size: self.entire_size(arch),
+            alignment: self.alignment(arch),
+        }
+    }
+
+    pub fn member_offset(&self, name: &str, arch: &Arch) -> Option<usize> {
+        let index = self.members.iter().position(|m| m.has_name(name))?;
+
+        let (layout, _) = self.layout(arch);
+        layout.get(index).map(|(offset, _)| *offset)
+    }
+
+    /// Returns the Position of the Bit-Field with the given Name in its Storage-Unit, if the
+    /// Member is a Bit-Field
+    pub fn bit_field(&self, name: &str, arch: &Arch) -> Option<BitField> {
+        let index = self.members.iter().position(|m| m.has_name(name))?;
+        self.members[index].bit_width?;
+
+        let (mut layout, _) = self.layout(arch);
+        layout.swap_remove(index).1
     }
 }
 
@@ -93,12 +378,15 @@ mod tests {
         let dummy_source = Source::new("test", "testing");
 
         let struct_def = StructDef {
+            name: None,
             members: vec![StructMember {
-                name: Identifier(SpanData {
+                name: Some(Identifier(SpanData {
                     span: Span::new_source(dummy_source.clone(), 0..1),
                     data: "t".to_string(),
-                }),
+                })),
                 ty: AType::Primitve(APrimitive::Int),
+                bit_width: None,
+                alignment: None,
             }],
         };
 
@@ -120,27 +408,34 @@ mod tests {
         let dummy_source = Source::new("test", "testing");
 
         let struct_def = StructDef {
+            name: None,
             members: vec![
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 0..1),
                         data: "t".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 1..2),
                         data: "e".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Char),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source, 2..3),
                         data: "s".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
             ],
         };
@@ -155,34 +450,41 @@ mod tests {
         let dummy_source = Source::new("test", "testing");
 
         let struct_def = StructDef {
+            name: None,
             members: vec![
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 0..1),
                         data: "t".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 1..2),
                         data: "e".to_string(),
-                    }),
+                    })),
                     ty: AType::Pointer(Box::new(AType::Primitve(APrimitive::Char))),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source, 2..3),
                         data: "s".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
             ],
         };
 
         assert_eq!(12, struct_def.entire_size(&Arch::X86));
-        assert_eq!(20, struct_def.entire_size(&Arch::X86_64));
-        assert_eq!(20, struct_def.entire_size(&Arch::AArch64));
+        assert_eq!(24, struct_def.entire_size(&Arch::X86_64));
+        assert_eq!(24, struct_def.entire_size(&Arch::AArch64));
     }
 
     #[test]
@@ -190,27 +492,34 @@ mod tests {
         let dummy_source = Source::new("test", "testing");
 
         let struct_def = StructDef {
+            name: None,
             members: vec![
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 0..1),
                         data: "t".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source.clone(), 1..2),
                         data: "e".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Char),
+                    bit_width: None,
+                    alignment: None,
                 },
                 StructMember {
-                    name: Identifier(SpanData {
+                    name: Some(Identifier(SpanData {
                         span: Span::new_source(dummy_source, 2..3),
                         data: "s".to_string(),
-                    }),
+                    })),
                     ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
                 },
             ],
         };
@@ -227,4 +536,151 @@ mod tests {
         assert_eq!(Some(4), struct_def.member_offset("e", &Arch::AArch64));
         assert_eq!(Some(8), struct_def.member_offset("s", &Arch::AArch64));
     }
+
+    #[test]
+    fn union_size_rounds_up() {
+        let dummy_source = Source::new("test", "testing");
+
+        let struct_def = StructDef {
+            name: None,
+            members: vec![
+                StructMember {
+                    name: Some(Identifier(SpanData {
+                        span: Span::new_source(dummy_source.clone(), 0..1),
+                        data: "t".to_string(),
+                    })),
+                    ty: AType::Array(crate::Array {
+                        size: Some(5),
+                        length: None,
+                        ty: Box::new(AType::Primitve(APrimitive::Char)),
+                    }),
+                    bit_width: None,
+                    alignment: None,
+                },
+                StructMember {
+                    name: Some(Identifier(SpanData {
+                        span: Span::new_source(dummy_source, 1..2),
+                        data: "e".to_string(),
+                    })),
+                    ty: AType::Primitve(APrimitive::Int),
+                    bit_width: None,
+                    alignment: None,
+                },
+            ],
+        };
+
+        assert_eq!(8, struct_def.union_size(&Arch::X86));
+        assert_eq!(8, struct_def.union_size(&Arch::AArch64));
+    }
+
+    fn bit_field_member(name: &str, ty: APrimitive, bit_width: Option<usize>) -> StructMember {
+        let dummy_source = Source::new("test", "testing");
+
+        StructMember {
+            name: Some(Identifier(SpanData {
+                span: Span::new_source(dummy_source, 0..1),
+                data: name.to_string(),
+            })),
+            ty: AType::Primitve(ty),
+            bit_width,
+            alignment: None,
+        }
+    }
+
+    #[test]
+    fn bit_fields_share_unit() {
+        let struct_def = StructDef {
+            name: None,
+            members: vec![
+                bit_field_member("a", APrimitive::UnsignedInt, Some(1)),
+                bit_field_member("b", APrimitive::UnsignedInt, Some(3)),
+                bit_field_member("c", APrimitive::Int, Some(4)),
+                bit_field_member("d", APrimitive::UnsignedInt, Some(20)),
+                bit_field_member("e", APrimitive::Int, None),
+            ],
+        };
+
+        for arch in [Arch::X86, Arch::X86_64, Arch::AArch64] {
+            assert_eq!(8, struct_def.entire_size(&arch));
+
+            assert_eq!(Some(0), struct_def.member_offset("a", &arch));
+            assert_eq!(Some(0), struct_def.member_offset("d", &arch));
+            assert_eq!(Some(4), struct_def.member_offset("e", &arch));
+
+            assert_eq!(
+                Some(BitField {
+                    offset: 0,
+                    width: 1
+                }),
+                struct_def.bit_field("a", &arch)
+            );
+            assert_eq!(
+                Some(BitField {
+                    offset: 4,
+                    width: 4
+                }),
+                struct_def.bit_field("c", &arch)
+            );
+            assert_eq!(
+                Some(BitField {
+                    offset: 8,
+                    width: 20
+                }),
+                struct_def.bit_field("d", &arch)
+            );
+            assert_eq!(None, struct_def.bit_field("e", &arch));
+        }
+    }
+
+    #[test]
+    fn bit_field_starts_new_unit() {
+        let struct_def = StructDef {
+            name: None,
+            members: vec![
+                bit_field_member("a", APrimitive::UnsignedInt, Some(30)),
+                bit_field_member("b", APrimitive::UnsignedInt, Some(4)),
+                bit_field_member("c", APrimitive::Char, None),
+            ],
+        };
+
+        assert_eq!(8, struct_def.entire_size(&Arch::AArch64));
+        assert_eq!(Some(4), struct_def.member_offset("b", &Arch::AArch64));
+        assert_eq!(
+            Some(BitField {
+                offset: 0,
+                width: 4
+            }),
+            struct_def.bit_field("b", &Arch::AArch64)
+        );
+        assert_eq!(Some(5), struct_def.member_offset("c", &Arch::AArch64));
+    }
+
+    #[test]
+    fn bit_field_sized_by_type() {
+        let struct_def = StructDef {
+            name: None,
+            members: vec![
+                bit_field_member("a", APrimitive::UnsignedChar, Some(3)),
+                bit_field_member("b", APrimitive::UnsignedChar, Some(6)),
+            ],
+        };
+
+        assert_eq!(2, struct_def.entire_size(&Arch::AArch64));
+        assert_eq!(Some(1), struct_def.member_offset("b", &Arch::AArch64));
+    }
+
+    #[test]
+    fn aligned_member() {
+        let mut aligned = bit_field_member("x", APrimitive::Int, None);
+        aligned.alignment = Some(AAlignment::Bytes(16));
+
+        let struct_def = StructDef {
+            name: None,
+            members: vec![bit_field_member("c", APrimitive::Char, None), aligned],
+        };
+
+        assert_eq!(16, struct_def.alignment(&Arch::AArch64));
+        assert_eq!(32, struct_def.entire_size(&Arch::AArch64));
+        assert_eq!(Some(16), struct_def.member_offset("x", &Arch::AArch64));
+    }
 }
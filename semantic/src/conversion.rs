@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use ir::{BasicBlock, FunctionDefinition, Program, Variable};
 
-use crate::{AScope, AAST};
+use crate::{AScope, AStatement, AAST};
 
 mod expression;
 
@@ -11,22 +11,35 @@ pub use context::*;
 
 mod function;
 
-pub fn convert(ast: AAST, arch: general::arch::Arch) -> Program {
-    let (global_block, global_vars) = convert_global(
-        ast.global_scope.0.clone(),
-        ConvertContext::base(arch.clone(), HashMap::new()),
+pub fn convert(ast: AAST, unit: &str, arch: general::arch::Arch) -> Program {
+    let function_names: Arc<HashMap<String, String>> = Arc::new(
+        ast.global_scope
+            .0
+            .function_definitions
+            .iter()
+            .map(|(name, (func_dec, _))| (name.clone(), func_dec.ir_name(name, unit)))
+            .filter(|(name, ir_name)| name != ir_name)
+            .collect(),
     );
 
+    let mut program_ctx = ConvertContext::base(arch.clone(), HashMap::new());
+    program_ctx.set_function_names(function_names);
+
+    let mut global_ctx = ConvertContext::base(arch.clone(), HashMap::new());
+    global_ctx.share_program(&program_ctx);
+    let (global_block, global_vars) = convert_global(ast.global_scope.0.clone(), global_ctx);
+
     let mut functions = HashMap::new();
     for (name, (func_dec, func_scope)) in ast.global_scope.0.function_definitions {
-        let return_ty = func_dec.return_ty.clone().to_ir();
+        let name = func_dec.ir_name(&name, unit);
+        let return_ty = func_dec.return_ty.clone().to_value_ir(&arch);
 
         let args = {
             let mut tmp = Vec::new();
 
             for arg in func_dec.arguments.iter() {
                 let name = arg.data.name.clone();
-                let ty = arg.data.ty.clone().to_ir();
+                let ty = arg.data.ty.clone().to_value_ir(&arch);
 
                 tmp.push((name, ty));
             }
@@ -41,6 +54,7 @@ pub fn convert(ast: AAST, arch: general::arch::Arch) -> Program {
             func_dec,
             func_scope,
             arch.clone(),
+            &program_ctx,
         );
 
         functions.insert(
@@ -57,6 +71,8 @@ pub fn convert(ast: AAST, arch: general::arch::Arch) -> Program {
     Program {
         global: global_block,
         functions,
+        constants: program_ctx.constants(),
+        global_alignments: program_ctx.global_alignments(),
     }
 }
 
@@ -65,7 +81,18 @@ fn convert_global(raw_global: AScope, mut ctx: ConvertContext) -> (BasicBlock, V
 
     let result_block = BasicBlock::initial(vec![]);
 
-    let ret_block = raw_global.to_ir(&result_block, &ctx);
+    // The static Variables of all the Functions are stored as Globals as well
+    let static_vars: Vec<AStatement> = raw_global
+        .function_definitions
+        .values()
+        .flat_map(|(_, scope)| scope.nested_statement_iter())
+        .filter(|stmnt| matches!(stmnt, AStatement::StaticVariable { .. }))
+        .collect();
+
+    let mut ret_block = raw_global.to_ir(&result_block, &ctx);
+    for static_var in static_vars {
+        static_var.to_ir(&mut ret_block, &ctx);
+    }
     if result_block.as_ptr() != ret_block.as_ptr() {
         panic!("The Block generated should be one continuos block")
     }
@@ -77,6 +104,8 @@ fn convert_global(raw_global: AScope, mut ctx: ConvertContext) -> (BasicBlock, V
             ir::Statement::Assignment { target, .. } => Some(target),
             _ => None,
         })
+        // Temporary Variables are only used while initializing the Globals
+        .filter(|var| !var.is_tmp())
         .map(|var| (var.name().to_string(), var))
         .collect();
 
@@ -1,16 +1,44 @@
-use std::collections::HashMap;
-use std::sync::{atomic, Arc};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{atomic, Arc, Mutex};
 
-use general::arch::Arch;
+use general::{arch::Arch, Span};
 use ir::BasicBlock;
 
 #[derive(Debug)]
 pub struct ConvertContext {
     arch: Arch,
     loop_ctx: Option<(BasicBlock, BasicBlock)>,
+    /// Whether a Continue jumps forward to the Condition of the Loop, like in a Do-While-Loop,
+    /// instead of back to its Start
+    continue_forward: bool,
+    switch_end: Option<BasicBlock>,
+    /// The Variables holding the Stack-Pointer, that was saved before allocating the variable
+    /// length Arrays of every enclosing Scope, starting with the outermost Scope
+    stack_saves: Vec<String>,
+    /// The Number of saved Stack-Pointers from outside of the current Loop
+    loop_stack_depth: usize,
+    /// The Number of saved Stack-Pointers from outside of the current Switch
+    switch_stack_depth: usize,
     current_tmp: Arc<atomic::AtomicUsize>,
     global: bool,
     global_vars: HashMap<String, ir::Variable>,
+    /// The Blocks for all the Labels in the current Function
+    labels: Arc<HashMap<String, BasicBlock>>,
+    /// The Targets of all the Gotos, which jump back to a Label that comes before them
+    backward_gotos: Arc<HashSet<Span>>,
+    /// The Number of saved Stack-Pointers at every Label in the current Function
+    label_stack_depths: Arc<HashMap<String, usize>>,
+    /// The Names used in the IR for Functions, whose Name differs from the one in the Source,
+    /// like for Functions with internal Linkage
+    function_names: Arc<HashMap<String, String>>,
+    /// The read-only Data of the entire Program with the Name it is stored under
+    constants: Arc<Mutex<HashMap<ir::ConstantData, String>>>,
+    /// The explicit Alignments of the Globals of the entire Program
+    global_alignments: Arc<Mutex<HashMap<String, usize>>>,
+    /// The Name of the Global that is currently being initialized, after which the static Data
+    /// reserved for it is named
+    static_symbol: Option<String>,
 }
 
 impl ConvertContext {
@@ -18,9 +46,21 @@ impl ConvertContext {
         Self {
             arch,
             loop_ctx: None,
+            continue_forward: false,
+            switch_end: None,
+            stack_saves: Vec::new(),
+            loop_stack_depth: 0,
+            switch_stack_depth: 0,
             current_tmp: Arc::new(atomic::AtomicUsize::new(0)),
             global: false,
             global_vars: globals,
+            labels: Arc::new(HashMap::new()),
+            backward_gotos: Arc::new(HashSet::new()),
+            label_stack_depths: Arc::new(HashMap::new()),
+            function_names: Arc::new(HashMap::new()),
+            constants: Arc::new(Mutex::new(HashMap::new())),
+            global_alignments: Arc::new(Mutex::new(HashMap::new())),
+            static_symbol: None,
         }
     }
 
@@ -28,6 +68,72 @@ impl ConvertContext {
         self.global = n_value;
     }
 
+    /// Sets the Blocks for the Labels of the current Function and the Targets of the Gotos,
+    /// which jump back to a Label that comes before them
+    pub fn set_labels(
+        &mut self,
+        labels: HashMap<String, BasicBlock>,
+        backward_gotos: HashSet<Span>,
+    ) {
+        self.labels = Arc::new(labels);
+        self.backward_gotos = Arc::new(backward_gotos);
+    }
+
+    /// Sets the Number of saved Stack-Pointers at every Label of the current Function
+    pub fn set_label_stack_depths(&mut self, depths: HashMap<String, usize>) {
+        self.label_stack_depths = Arc::new(depths);
+    }
+
+    /// Sets the IR-Names for the Functions, whose Name differs from the one in the Source
+    pub fn set_function_names(&mut self, names: Arc<HashMap<String, String>>) {
+        self.function_names = names;
+    }
+    /// The Name of the given Function in the IR
+    pub fn function_name(&self, name: &str) -> String {
+        self.function_names
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Shares the State of the entire Program, like the Function-Names and the read-only Data,
+    /// with the given Context
+    pub fn share_program(&mut self, other: &Self) {
+        self.function_names = other.function_names.clone();
+        self.constants = other.constants.clone();
+        self.global_alignments = other.global_alignments.clone();
+    }
+    /// Returns the Name of the read-only Data with the given Content, which is only added if
+    /// there is no identical Data yet.
+    ///
+    /// The Name is derived from the Content itself, so that the Data of different
+    /// Translation-Units can be merged without their Names colliding
+    pub fn add_constant(&self, data: ir::ConstantData) -> String {
+        let mut constants = self.constants.lock().unwrap();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        data.hash(&mut hasher);
+        let next_name = format!("__const_{}", hasher.finish());
+        constants.entry(data).or_insert(next_name).clone()
+    }
+    /// All the read-only Data of the Program by its Name
+    pub fn constants(&self) -> HashMap<String, ir::ConstantData> {
+        let constants = self.constants.lock().unwrap();
+        constants
+            .iter()
+            .map(|(data, name)| (name.clone(), data.clone()))
+            .collect()
+    }
+
+    /// Records the explicit Alignment of the Global with the given Name
+    pub fn add_global_alignment(&self, name: &str, alignment: usize) {
+        let mut alignments = self.global_alignments.lock().unwrap();
+        alignments.insert(name.to_string(), alignment);
+    }
+    /// The explicit Alignments of all the Globals of the Program by their Name
+    pub fn global_alignments(&self) -> HashMap<String, usize> {
+        self.global_alignments.lock().unwrap().clone()
+    }
+
     pub fn next_tmp(&self) -> usize {
         self.current_tmp.fetch_add(1, atomic::Ordering::SeqCst)
     }
@@ -40,11 +146,65 @@ impl ConvertContext {
         let (_, end) = self.loop_ctx.as_ref()?;
         Some(end)
     }
+    /// The Metadata for the Jump of a Continue in the current Loop
+    pub fn continue_metadata(&self) -> ir::JumpMetadata {
+        if self.continue_forward {
+            ir::JumpMetadata::Branch
+        } else {
+            ir::JumpMetadata::Loop
+        }
+    }
+    /// The Block after the innermost Switch-Statement, if we are currently in a Switch that is
+    /// not nested in another Loop
+    pub fn get_switch_end(&self) -> Option<&BasicBlock> {
+        self.switch_end.as_ref()
+    }
+
+    /// The Variables holding the saved Stack-Pointers of all the enclosing Scopes, starting with
+    /// the outermost one
+    pub fn stack_saves(&self) -> &[String] {
+        &self.stack_saves
+    }
+    /// The Number of saved Stack-Pointers from outside of the current Loop, which are kept when
+    /// leaving it using a Break or Continue
+    pub fn loop_stack_depth(&self) -> usize {
+        self.loop_stack_depth
+    }
+    /// The Number of saved Stack-Pointers from outside of the current Switch, which are kept when
+    /// leaving it using a Break
+    pub fn switch_stack_depth(&self) -> usize {
+        self.switch_stack_depth
+    }
+    /// The Number of saved Stack-Pointers at the Label with the given Name
+    pub fn label_stack_depth(&self, name: &str) -> usize {
+        self.label_stack_depths.get(name).copied().unwrap_or(0)
+    }
 
     pub fn global(&self) -> bool {
         self.global
     }
 
+    /// The Expression to reserve Memory for an Array or Struct. Globals are stored in the static
+    /// Data, because the Stack of the Global-Block is no longer valid once it returns.
+    ///
+    /// The static Data is named after the Global it belongs to, which is already unique across
+    /// all Translation-Units
+    pub fn reserve_memory(&self, size: usize, alignment: usize) -> ir::Expression {
+        if self.global {
+            let symbol = self
+                .static_symbol
+                .as_deref()
+                .expect("Static Data is only reserved while initializing a Global");
+            ir::Expression::StaticAlloc {
+                name: format!("{}_static_{}", symbol, self.next_tmp()),
+                size,
+                alignment,
+            }
+        } else {
+            ir::Expression::StackAlloc { size, alignment }
+        }
+    }
+
     pub fn arch(&self) -> &Arch {
         &self.arch
     }
@@ -77,12 +237,129 @@ impl ConvertContext {
         Self {
             arch: self.arch.clone(),
             loop_ctx: Some((start, end)),
+            continue_forward: false,
+            switch_end: None,
+            stack_saves: self.stack_saves.clone(),
+            loop_stack_depth: self.stack_saves.len(),
+            switch_stack_depth: self.switch_stack_depth,
             current_tmp: self.current_tmp.clone(),
             global: self.global,
             global_vars: self.global_vars.clone(),
+            labels: self.labels.clone(),
+            backward_gotos: self.backward_gotos.clone(),
+            label_stack_depths: self.label_stack_depths.clone(),
+            function_names: self.function_names.clone(),
+            constants: self.constants.clone(),
+            global_alignments: self.global_alignments.clone(),
+            static_symbol: self.static_symbol.clone(),
         }
     }
 
+    /// The Context for the Body of a Do-While-Loop, where a Continue jumps forward to the
+    /// Condition at the End of the Loop
+    pub fn with_do_while_loop(&self, condition: BasicBlock, end: BasicBlock) -> Self {
+        Self {
+            continue_forward: true,
+            ..self.with_loop(condition, end)
+        }
+    }
+
+    /// The Context for the Initialization of the Global with the given Name
+    pub fn with_static_symbol(&self, name: &str) -> Self {
+        Self {
+            arch: self.arch.clone(),
+            loop_ctx: self.loop_ctx.clone(),
+            continue_forward: self.continue_forward,
+            switch_end: self.switch_end.clone(),
+            stack_saves: self.stack_saves.clone(),
+            loop_stack_depth: self.loop_stack_depth,
+            switch_stack_depth: self.switch_stack_depth,
+            current_tmp: self.current_tmp.clone(),
+            global: self.global,
+            global_vars: self.global_vars.clone(),
+            labels: self.labels.clone(),
+            backward_gotos: self.backward_gotos.clone(),
+            label_stack_depths: self.label_stack_depths.clone(),
+            function_names: self.function_names.clone(),
+            constants: self.constants.clone(),
+            global_alignments: self.global_alignments.clone(),
+            static_symbol: Some(name.to_string()),
+        }
+    }
+
+    /// The Context for the Rest of a Scope, after the Stack-Pointer has been saved in the
+    /// Variable with the given Name
+    pub fn with_stack_save(&self, name: String) -> Self {
+        let mut stack_saves = self.stack_saves.clone();
+        stack_saves.push(name);
+
+        Self {
+            arch: self.arch.clone(),
+            loop_ctx: self.loop_ctx.clone(),
+            continue_forward: self.continue_forward,
+            switch_end: self.switch_end.clone(),
+            stack_saves,
+            loop_stack_depth: self.loop_stack_depth,
+            switch_stack_depth: self.switch_stack_depth,
+            current_tmp: self.current_tmp.clone(),
+            global: self.global,
+            global_vars: self.global_vars.clone(),
+            labels: self.labels.clone(),
+            backward_gotos: self.backward_gotos.clone(),
+            label_stack_depths: self.label_stack_depths.clone(),
+            function_names: self.function_names.clone(),
+            constants: self.constants.clone(),
+            global_alignments: self.global_alignments.clone(),
+            static_symbol: self.static_symbol.clone(),
+        }
+    }
+
+    pub fn with_switch(&self, end: BasicBlock) -> Self {
+        Self {
+            arch: self.arch.clone(),
+            loop_ctx: self.loop_ctx.clone(),
+            continue_forward: self.continue_forward,
+            switch_end: Some(end),
+            stack_saves: self.stack_saves.clone(),
+            loop_stack_depth: self.loop_stack_depth,
+            switch_stack_depth: self.stack_saves.len(),
+            current_tmp: self.current_tmp.clone(),
+            global: self.global,
+            global_vars: self.global_vars.clone(),
+            labels: self.labels.clone(),
+            backward_gotos: self.backward_gotos.clone(),
+            label_stack_depths: self.label_stack_depths.clone(),
+            function_names: self.function_names.clone(),
+            constants: self.constants.clone(),
+            global_alignments: self.global_alignments.clone(),
+            static_symbol: self.static_symbol.clone(),
+        }
+    }
+
+    /// Returns the Block for the Label with the given Name
+    pub fn get_label(&self, name: &str) -> BasicBlock {
+        self.labels
+            .get(name)
+            .cloned()
+            .expect("Labels are verified before the Conversion")
+    }
+    /// Whether the Goto with the given Target jumps back to a Label that comes before it
+    pub fn is_backward_goto(&self, target: &Span) -> bool {
+        self.backward_gotos.contains(target)
+    }
+    /// Whether the Block can never be reached, because it was only started after a Goto.
+    ///
+    /// Every other Block is either created with its predecessors or belongs to a Label, which
+    /// might only be reached by a Goto further down in the Function
+    pub fn is_unreachable(&self, block: &BasicBlock) -> bool {
+        block.get_predecessors().is_empty()
+            && !self.labels.values().any(|l| l.as_ptr() == block.as_ptr())
+    }
+    /// All the Blocks for Labels in the current Function
+    pub fn label_blocks(&self) -> Vec<BasicBlock> {
+        self.labels.values().cloned().collect()
+    }
+
     pub fn get_global(&self, name: &str) -> Option<&ir::Variable> {
         self.global_vars.get(name)
     }
@@ -1,4 +1,11 @@
-use crate::{AScope, FunctionDeclaration};
+use std::collections::{HashMap, HashSet};
+
+use general::Span;
+use ir::BlockBuilder;
+
+use crate::{
+    astatement::variable_array, APrimitive, AScope, AStatement, AType, FunctionDeclaration,
+};
 
 use super::ConvertContext;
 
@@ -9,24 +16,43 @@ pub fn convert(
     func_dec: FunctionDeclaration,
     inner_scope: AScope,
     arch: general::arch::Arch,
+    program_ctx: &ConvertContext,
 ) -> ir::BasicBlock {
     // Put the Arguments into the first basic Block and then place a Jump as the last Statement
     // that will jump to the actual function code
 
     let arg_statements = {
         let mut tmp = Vec::new();
+        let mut struct_args = Vec::new();
 
         for tmp_arg in func_dec.arguments.iter() {
             let var_data = &tmp_arg.data;
-            let var_ty = tmp_arg.data.ty.clone().to_ir();
+            let var_ty = tmp_arg.data.ty.clone().to_value_ir(&arch);
             let var = ir::Variable::new(&var_data.name, var_ty);
 
+            if matches!(var.ty, ir::Type::Struct { .. }) {
+                struct_args.push(var.clone());
+            }
+
             tmp.push(ir::Statement::Assignment {
                 target: var,
                 value: ir::Value::Unknown,
             });
         }
 
+        // Struct-Arguments are received as the Address of the Struct, which is then used like
+        // the Address of any other Struct-Variable. This has to come after all the Arguments,
+        // because the Backends expect to receive them first
+        for arg in struct_args {
+            let mut local = arg.next_gen();
+            local.ty = ir::Type::Pointer(Box::new(ir::Type::Void));
+
+            tmp.push(ir::Statement::Assignment {
+                target: local,
+                value: ir::Value::Variable(arg),
+            });
+        }
+
         tmp
     };
 
@@ -49,7 +75,7 @@ pub fn convert(
     let global_weak = global.weak_ptr();
     let head_block = ir::BasicBlock::new(vec![global_weak], init_statements);
 
-    let context = ConvertContext::base(
+    let mut context = ConvertContext::base(
         arch,
         global_vars
             .into_iter()
@@ -57,12 +83,155 @@ pub fn convert(
             .collect(),
     );
 
+    let (labels, backward_gotos) = labels(&inner_scope);
+    context.set_labels(labels, backward_gotos);
+    context.set_label_stack_depths(variable_array::label_stack_depths(&inner_scope));
+    context.share_program(program_ctx);
+
     let head_weak = head_block.weak_ptr();
     let func_block = ir::BasicBlock::new(vec![head_weak], vec![]);
-    inner_scope.to_ir(&func_block, &context);
+    let end_block = inner_scope.to_ir(&func_block, &context);
+
+    // A void Function returns once it reaches its End, while a non-void Function can only reach
+    // it after a Statement that already returns on every Path, like a Switch that returns in all
+    // its Cases, so the Block is unreachable and only returns an undefined Value
+    let ends_in_return = matches!(
+        end_block.get_statements().last(),
+        Some(ir::Statement::Return(_))
+    );
+    if !ends_in_return {
+        let ret_value = undefined_return(&func_dec.return_ty, &end_block, &context);
+        end_block.add_statement(ir::Statement::Return(ret_value));
+    }
+
+    // Now that all the Gotos have been converted, all the predecessors of the Labels are known
+    // and their Phi-Nodes can be filled in
+    let label_blocks = context.label_blocks();
+    for label_block in label_blocks.iter() {
+        label_block.refresh_phis();
+    }
+    remove_unused_label_phis(&func_block, &label_blocks);
 
     // Update Head-Blocks last Jump to the next
     head_block.add_statement(ir::Statement::Jump(func_block, ir::JumpMetadata::Linear));
 
     head_block
 }
+
+/// The Value returned when a Function reaches its End without a Return-Statement, which is
+/// nothing for a void Function and an uninitialized Value of the Return-Type otherwise.
+///
+/// Structs are returned through their Address, so they still get Memory on the Stack
+fn undefined_return(
+    return_ty: &AType,
+    block: &ir::BasicBlock,
+    ctx: &ConvertContext,
+) -> Option<ir::Variable> {
+    let arch = ctx.arch();
+    let ret_var = match return_ty.into_ty() {
+        AType::Primitve(APrimitive::Void) => return None,
+        AType::Struct { .. } | AType::Union { .. } => {
+            let address =
+                ir::Variable::tmp(ctx.next_tmp(), ir::Type::Pointer(Box::new(ir::Type::Void)));
+            block.add_statement(ir::Statement::Assignment {
+                target: address.clone(),
+                value: ir::Value::Expression(ctx.reserve_memory(
+                    return_ty.byte_size(arch) as usize,
+                    return_ty.alignment(arch) as usize,
+                )),
+            });
+
+            let ret_var = ir::Variable::tmp(ctx.next_tmp(), return_ty.clone().to_value_ir(arch))
+                .set_description("Undefined Return-Value");
+            block.add_statement(ir::Statement::Assignment {
+                target: ret_var.clone(),
+                value: ir::Value::Variable(address),
+            });
+            ret_var
+        }
+        _ => {
+            let ret_var = ir::Variable::tmp(ctx.next_tmp(), return_ty.clone().to_value_ir(arch))
+                .set_description("Undefined Return-Value");
+            block.add_statement(ir::Statement::Assignment {
+                target: ret_var.clone(),
+                value: ir::Value::Unknown,
+            });
+            ret_var
+        }
+    };
+
+    Some(ret_var)
+}
+
+/// Removes the Phi-Nodes from the Label-Blocks, whose Variables are never used afterwards.
+///
+/// A Label is only reached through a Goto in code like "if (..) goto fail;", so these Phi-Nodes
+/// would otherwise define new Variables on a Side of a Branch, which never joins up again
+fn remove_unused_label_phis(func_block: &ir::BasicBlock, label_blocks: &[ir::BasicBlock]) {
+    loop {
+        #[allow(deprecated)]
+        let used: HashSet<ir::Variable> = func_block
+            .block_iter()
+            .flat_map(|b| b.get_statements())
+            .flat_map(|s| match &s {
+                // A Phi-Node that only uses itself through a Loop does not count as a use
+                ir::Statement::Assignment {
+                    target,
+                    value: ir::Value::Phi { sources },
+                } => sources
+                    .iter()
+                    .map(|s| s.var.clone())
+                    .filter(|v| v != target)
+                    .collect::<Vec<_>>(),
+                other => other.used_vars().collect(),
+            })
+            .collect();
+
+        let mut removed = false;
+        for label_block in label_blocks {
+            let statements = label_block.get_statements();
+            let before = statements.len();
+            let statements: Vec<_> = statements
+                .into_iter()
+                .filter(|s| match s {
+                    ir::Statement::Assignment {
+                        target,
+                        value: ir::Value::Phi { .. },
+                    } => used.contains(target),
+                    _ => true,
+                })
+                .collect();
+
+            removed |= statements.len() != before;
+            label_block.set_statements(statements);
+        }
+
+        if !removed {
+            break;
+        }
+    }
+}
+
+/// Creates the Blocks for all the Labels in the Function and collects the Targets of all the
+/// Gotos, which jump back to a Label that comes before them
+fn labels(scope: &AScope) -> (HashMap<String, ir::BasicBlock>, HashSet<Span>) {
+    let mut blocks = HashMap::new();
+    let mut backward = HashSet::new();
+
+    for statement in scope.nested_statement_iter() {
+        match statement {
+            AStatement::Label { name } => {
+                let block = BlockBuilder::new(vec![], vec![])
+                    .description(format!("Label {}", name.0.data))
+                    .build();
+                blocks.insert(name.0.data, block);
+            }
+            AStatement::Goto { target } if blocks.contains_key(&target.0.data) => {
+                backward.insert(target.0.span);
+            }
+            _ => {}
+        };
+    }
+
+    (blocks, backward)
+}
@@ -11,19 +11,21 @@ pub enum InvalidOperation {
     FunctionCall,
 }
 
+/// The Types carried by the Errors are boxed, to keep the Error itself small, as it is returned
+/// from nearly every Function of the semantic Analysis
 #[derive(Debug, PartialEq, Clone)]
 pub enum SemanticError {
     MismatchedTypes {
-        expected: SpanData<AType>,
-        received: SpanData<AType>,
+        expected: Box<SpanData<AType>>,
+        received: Box<SpanData<AType>>,
     },
     MismatchedOperationTypes {
-        left: SpanData<AType>,
-        right: SpanData<AType>,
+        left: Box<SpanData<AType>>,
+        right: Box<SpanData<AType>>,
     },
     AmbiguousTypeConversion {
-        target: SpanData<AType>,
-        base: SpanData<AType>,
+        target: Box<SpanData<AType>>,
+        base: Box<SpanData<AType>>,
     },
     InvalidType {},
     InvalidOperation {
@@ -50,16 +52,127 @@ pub enum SemanticError {
         /// The Name of the Field that the Code tried to access
         field_name: Identifier,
         /// The Definition of the Struct itself
-        struct_def: SpanData<StructDef>,
+        struct_def: Box<SpanData<StructDef>>,
     },
     StructAccessOnNonStruct {
         field_name: Identifier,
-        received: SpanData<AType>,
+        received: Box<SpanData<AType>>,
     },
     MismatchedFunctionArgsCount {
         expected: SpanData<usize>,
         received: SpanData<usize>,
     },
+    /// The Value of a Switch-Statement has to be an Integer
+    InvalidSwitchType {
+        received: Box<SpanData<AType>>,
+    },
+    /// The Value of a Case-Label is not an Integer Constant Expression
+    InvalidCaseValue {
+        case: Span,
+    },
+    DuplicateCase {
+        value: SpanData<i64>,
+        previous_case: Span,
+    },
+    DuplicateDefault {
+        default: Span,
+        previous_default: Span,
+    },
+    /// A Goto targets a Label that does not exist in the current Function
+    UnknownLabel {
+        name: Identifier,
+    },
+    /// The same Label was defined more than once in a single Function
+    DuplicateLabel {
+        name: Identifier,
+        previous_definition: Span,
+    },
+    /// The explicit Value of an Enum-Variant is not an Integer Constant Expression
+    InvalidEnumValue {
+        value: Span,
+    },
+    /// One of the Builtins for variable Arguments, like "va_start", was used in an invalid way,
+    /// for example outside of a variadic Function
+    InvalidVarArgs {
+        area: Span,
+    },
+    /// An Initializer-List does not match the Object it initializes, for example because it has
+    /// more Values than the Object has Elements or uses a Designator of the wrong Kind
+    InvalidInitializer {
+        area: Span,
+    },
+    /// The Initializer of a Variable with static Storage-Duration depends on a Variable, that
+    /// only exists while a Function is running, and can therefore not be evaluated once before
+    /// the Program starts
+    NonConstantInitializer {
+        area: Span,
+    },
+    /// A Bit-Field either has a Type that is not an Integer or its Width is not an Integer
+    /// Constant between 1 and the Number of Bits in its Type, where unnamed Bit-Fields can also
+    /// have a Width of 0
+    InvalidBitField {
+        /// The Name of the Bit-Field, which is None for unnamed ones
+        name: Option<Identifier>,
+        width: Span,
+    },
+    /// A Numeric Constant is malformed, for example because it contains a Digit that is not
+    /// valid in its Radix or has an unknown Suffix
+    InvalidNumber {
+        area: Span,
+    },
+    /// A Numeric Constant is too large to be represented by any of the Types it could have
+    NumberOverflow {
+        area: Span,
+    },
+    /// A Value is written to an Object with a const-qualified Type, either by an Assignment or
+    /// by an Increment or Decrement
+    ConstAssignment {
+        /// The Target of the Write
+        target: Span,
+        /// The const-qualified Type of the Target
+        ty: Box<SpanData<AType>>,
+    },
+    /// A Pointer to a const-qualified Type is implicitly converted to a Pointer to a Type
+    /// without the Qualifier, which would allow the pointed to Object to be modified
+    DiscardedConst {
+        target: Box<SpanData<AType>>,
+        base: Box<SpanData<AType>>,
+    },
+    /// The Condition of a Static-Assertion evaluated to zero
+    FailedStaticAssertion {
+        condition: Span,
+        /// The Message given to the Static-Assertion
+        message: SpanData<String>,
+    },
+    /// The Condition of a Static-Assertion is not an Integer Constant Expression
+    InvalidStaticAssertion {
+        condition: Span,
+    },
+    /// The Alignment given to an Alignment-Specifier is not an Integer Constant or not a Power
+    /// of two
+    InvalidAlignment {
+        area: Span,
+    },
+    /// The Size of an Array is not an Integer or negative, or the Array has a variable Length
+    /// where this is not allowed, like in a Global-Variable or a Struct
+    InvalidArraySize {
+        area: Span,
+    },
+    /// A Struct or Union, that has been declared but not defined, is used in a Way that requires
+    /// its Size or Members, like declaring a Variable of it or accessing one of its Fields
+    IncompleteType {
+        area: Span,
+        /// The Name of the Struct or Union
+        name: Identifier,
+    },
+    /// A Tag is used with a different Kind of Type than it was defined as, like "enum S" when S
+    /// is a Struct
+    MismatchedTag {
+        /// The Tag as it is used
+        name: Identifier,
+        /// Where the Tag was previously defined or declared
+        previous_definition: Span,
+    },
     NotImplemented {
         ctx: String,
     },
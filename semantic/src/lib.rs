@@ -1,8 +1,14 @@
 #![allow(clippy::large_enum_variant)]
 
-use std::collections::HashMap;
-
-use general::{Span, SpanData};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+use general::{
+    arch::{Arch, DataModel},
+    Span, SpanData,
+};
 use ir::Program;
 use syntax::{Identifier, AST};
 
@@ -24,6 +30,9 @@ pub use type_definitions::TypeDefinitions;
 mod error;
 pub use error::*;
 
+mod warning;
+pub use warning::*;
+
 mod conversion;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -40,30 +49,80 @@ pub struct AFunctionArg {
     pub ty: AType,
 }
 
+/// The Linkage of a Function, which determines if it can be referred to from other
+/// Translation-Units
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Linkage {
+    /// The Function is visible to all other Translation-Units
+    External,
+    /// The Function is only visible in its own Translation-Unit, which is the Case for
+    /// "static" Functions
+    Internal,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct FunctionDeclaration {
     pub return_ty: AType,
     pub declaration: Span,
     pub arguments: Vec<SpanData<AFunctionArg>>,
     pub var_args: bool,
+    pub linkage: Linkage,
+}
+
+impl FunctionDeclaration {
+    /// The Name that should be used for the Function in the IR.
+    ///
+    /// Functions with internal Linkage get a Name that is unique to their Translation-Unit, so
+    /// that multiple Translation-Units can define a Function with the same Name, even if it
+    /// comes from the same Header
+    pub fn ir_name(&self, name: &str, unit: &str) -> String {
+        match self.linkage {
+            Linkage::External => name.to_string(),
+            Linkage::Internal => {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                unit.hash(&mut hasher);
+                self.declaration.source().name().hash(&mut hasher);
+                let id = hasher.finish();
+                format!("{}_{}", name, id)
+            }
+        }
+    }
 }
 
 /// An Annotated Abstract Syntax Tree
 #[derive(Debug, PartialEq)]
 pub struct AAST {
     pub global_scope: ARootScope,
+    /// All the Warnings that were encountered while parsing
+    pub warnings: Vec<SemanticWarning>,
 }
 
+/// Parses the AST for the Architecture of the Host
 pub fn parse(ast: AST) -> Result<AAST, SemanticError> {
-    let global_scope = ARootScope::parse(ast.global_scope)?;
+    parse_for(ast, &Arch::default())
+}
+
+/// Parses the AST for the given Architecture, whose Data-Model determines the Types of
+/// arithmetic Operations
+pub fn parse_for(ast: AST, arch: &Arch) -> Result<AAST, SemanticError> {
+    let (global_scope, warnings) = ARootScope::parse(ast.global_scope, arch.data_model())?;
 
-    Ok(AAST { global_scope })
+    Ok(AAST {
+        global_scope,
+        warnings,
+    })
 }
 
 pub trait VariableContainer {
     fn get_var(&self, ident: &Identifier) -> Option<&VariableDeclaration>;
 
     fn get_func(&self, ident: &Identifier) -> Option<&FunctionDeclaration>;
+
+    /// Reports a Warning for the Code that is currently being parsed
+    fn warn(&self, warning: SemanticWarning);
+
+    /// The Data-Model of the Target the Code is parsed for
+    fn data_model(&self) -> DataModel;
 }
 
 pub enum FuncOrVar {
@@ -86,10 +145,49 @@ impl VariableContainer for HashMap<String, FuncOrVar> {
             _ => None,
         })
     }
+
+    fn warn(&self, _: SemanticWarning) {}
+
+    fn data_model(&self) -> DataModel {
+        Arch::default().data_model()
+    }
 }
 
 impl AAST {
+    /// Checks all the Static-Assertions that depend on the Target, like the Size of a Struct,
+    /// as these can not be checked while parsing
+    pub fn check_static_assertions(&self, arch: &general::arch::Arch) -> Result<(), SemanticError> {
+        let root = &self.global_scope.0;
+        astatement::static_assert::verify(root, arch)?;
+
+        for (_, body) in root.function_definitions.values() {
+            astatement::static_assert::verify(body, arch)?;
+        }
+
+        Ok(())
+    }
+
     pub fn convert_to_ir(self, arch: general::arch::Arch) -> Program {
-        conversion::convert(self, arch)
+        self.convert_unit_to_ir("", arch)
+    }
+
+    /// Converts the AAST of the Translation-Unit with the given Name into the IR, the Name is
+    /// used to keep the Names of Functions with internal Linkage unique between all the
+    /// Translation-Units of a Program
+    pub fn convert_unit_to_ir(self, unit: &str, arch: general::arch::Arch) -> Program {
+        conversion::convert(self, unit, arch)
+    }
+
+    /// The IR-Names of all the Functions defined in the Translation-Unit with the given Name,
+    /// together with the Span of their Declaration
+    pub fn defined_functions(&self, unit: &str) -> Vec<(String, Span)> {
+        self.global_scope
+            .0
+            .function_definitions
+            .iter()
+            .map(|(name, (func_dec, _))| {
+                (func_dec.ir_name(name, unit), func_dec.declaration.clone())
+            })
+            .collect()
     }
 }
@@ -1,9 +1,13 @@
 use std::collections::{BTreeSet, HashMap};
 
+use general::arch::DataModel;
 use ir::BasicBlock;
 use syntax::Scope;
 
-use crate::{conversion::ConvertContext, AStatement, FunctionDeclaration, SemanticError};
+use crate::{
+    astatement::variable_array, conversion::ConvertContext, AStatement, FunctionDeclaration,
+    SemanticError, SemanticWarning,
+};
 
 mod state;
 pub use state::*;
@@ -14,11 +18,16 @@ pub use nested_iter::*;
 #[derive(Debug, PartialEq)]
 pub struct ARootScope(pub AScope);
 impl ARootScope {
-    pub fn parse(scope: Scope) -> Result<Self, SemanticError> {
-        let initial_state = ParseState::new();
+    /// Parses the Root-Scope for a Target with the given Data-Model, returning it together with
+    /// all the Warnings from the entire Scope
+    pub fn parse(
+        scope: Scope,
+        data_model: DataModel,
+    ) -> Result<(Self, Vec<SemanticWarning>), SemanticError> {
+        let initial_state = ParseState::new(data_model);
 
         let scope = AScope::parse(&initial_state, scope)?;
-        Ok(Self(scope))
+        Ok((Self(scope), initial_state.take_warnings()))
     }
 }
 
@@ -69,8 +78,28 @@ impl AScope {
     pub fn to_ir(self, block: &BasicBlock, ctx: &ConvertContext) -> BasicBlock {
         let mut block = block.clone();
 
+        // The Context after the Stack-Pointer has been saved for the variable length Arrays of
+        // this Scope
+        let mut stack_ctx: Option<ConvertContext> = None;
         for tmp_stmnt in self.statements {
-            tmp_stmnt.to_ir(&mut block, ctx);
+            if stack_ctx.is_none() {
+                if let Some(array) = variable_array::declared_array(&tmp_stmnt) {
+                    stack_ctx = Some(variable_array::save_stack(array, &block, ctx));
+                }
+            }
+
+            tmp_stmnt.to_ir(&mut block, stack_ctx.as_ref().unwrap_or(ctx));
+        }
+
+        // A Scope that already jumps somewhere else has restored the Stack-Pointer already
+        if let Some(stack_ctx) = stack_ctx {
+            let block_ends = matches!(
+                block.get_statements().last(),
+                Some(ir::Statement::Jump(..)) | Some(ir::Statement::Return(_))
+            );
+            if !block_ends {
+                variable_array::restore_stack(ctx.stack_saves().len(), &block, &stack_ctx);
+            }
         }
 
         block
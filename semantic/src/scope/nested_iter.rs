@@ -38,10 +38,22 @@ impl<'s> Iterator for NestedIter<'s> {
                 let iter = body.statements.iter().rev().cloned();
                 self.pending.extend(iter);
             }
+            AStatement::DoWhileLoop { body, .. } => {
+                let iter = body.statements.iter().rev().cloned();
+                self.pending.extend(iter);
+            }
             AStatement::ForLoop { body, .. } => {
                 let iter = body.statements.iter().rev().cloned();
                 self.pending.extend(iter);
             }
+            AStatement::Switch { cases, .. } => {
+                let iter = cases
+                    .iter()
+                    .rev()
+                    .flat_map(|c| c.statements.iter().rev())
+                    .cloned();
+                self.pending.extend(iter);
+            }
             AStatement::SubScope { inner } => {
                 let iter = inner.statements.iter().rev().cloned();
                 self.pending.extend(iter);
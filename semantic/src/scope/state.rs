@@ -1,14 +1,18 @@
 use std::{
+    cell::RefCell,
     collections::HashMap,
     hash::{Hash, Hasher},
 };
 
-use general::{Span, SpanData};
+use general::{
+    arch::{Arch, DataModel},
+    Span, SpanData,
+};
 use syntax::Identifier;
 
 use crate::{
-    AFunctionArg, AScope, AType, FunctionDeclaration, TypeDefinitions, VariableContainer,
-    VariableDeclaration,
+    AFunctionArg, AScope, AType, FunctionDeclaration, Linkage, SemanticWarning, TypeDefinitions,
+    VariableContainer, VariableDeclaration,
 };
 
 mod local;
@@ -21,16 +25,26 @@ pub struct ParseState<'p> {
     type_defs: TypeDefinitions,
     function_definitions: HashMap<String, (FunctionDeclaration, AScope)>,
     return_ty: Option<SpanData<AType>>,
+    /// Whether or not the current Function accepts variable Arguments
+    variadic: bool,
+    /// The Data-Model of the Target, which the usual arithmetic Conversions depend on
+    data_model: DataModel,
+    /// The Warnings are only collected in the outermost State, as Expressions are parsed with
+    /// only a shared Reference to the State
+    warnings: RefCell<Vec<SemanticWarning>>,
 }
 
 impl<'p> ParseState<'p> {
-    pub fn new() -> Self {
+    pub fn new(data_model: DataModel) -> Self {
         Self {
             parent: None,
             local: LocalState::new(),
             type_defs: TypeDefinitions::new(),
             function_definitions: HashMap::new(),
             return_ty: None,
+            variadic: false,
+            data_model,
+            warnings: RefCell::new(Vec::new()),
         }
     }
 
@@ -43,6 +57,9 @@ impl<'p> ParseState<'p> {
             type_defs,
             function_definitions: HashMap::new(),
             return_ty: other.return_ty.clone(),
+            variadic: other.variadic,
+            data_model: other.data_model.clone(),
+            warnings: RefCell::new(Vec::new()),
         }
     }
 
@@ -60,6 +77,13 @@ impl<'p> ParseState<'p> {
         self.return_ty.as_ref()
     }
 
+    pub fn set_variadic(&mut self, variadic: bool) {
+        self.variadic = variadic;
+    }
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
     pub fn is_declared(&self, ident: &Identifier) -> bool {
         if self.local.is_declared(ident) {
             return true;
@@ -102,6 +126,7 @@ impl<'p> ParseState<'p> {
         arguments: Vec<SpanData<AFunctionArg>>,
         var_args: bool,
         return_ty: AType,
+        linkage: Linkage,
     ) {
         self.local.declare_func(
             name,
@@ -110,10 +135,20 @@ impl<'p> ParseState<'p> {
                 arguments,
                 declaration,
                 var_args,
+                linkage,
             },
         );
     }
 
+    /// Marks the Function with the given Name as only being visible in the current
+    /// Translation-Unit
+    pub fn set_internal_linkage(&mut self, name: &Identifier) {
+        self.local.set_func_linkage(name, Linkage::Internal);
+        if let Some((func_dec, _)) = self.function_definitions.get_mut(&name.0.data) {
+            func_dec.linkage = Linkage::Internal;
+        }
+    }
+
     pub fn add_variable_declaration(
         &mut self,
         name: Identifier,
@@ -123,6 +158,28 @@ impl<'p> ParseState<'p> {
         self.local.declare_var(name, ty, declaration)
     }
 
+    /// Marks the local Variable with the given internal Name as being static
+    pub fn mark_static(&mut self, internal_name: String) {
+        self.local.mark_static(internal_name);
+    }
+
+    /// Checks if the Variable with the given internal Name has static Storage-Duration, which
+    /// is the case for Variables at File-Scope and static local Variables
+    pub fn has_static_storage(&self, internal_name: &str) -> bool {
+        if self.local.is_static(internal_name) {
+            return true;
+        }
+        // Only the States outside of any Function have no Return-Type
+        if self.return_ty.is_none() && self.local.declares_internal(internal_name) {
+            return true;
+        }
+
+        match self.parent {
+            Some(p) => p.has_static_storage(internal_name),
+            None => false,
+        }
+    }
+
     pub fn add_function_definition(
         &mut self,
         name: String,
@@ -135,11 +192,16 @@ impl<'p> ParseState<'p> {
     pub fn destructure(self) -> HashMap<String, (FunctionDeclaration, AScope)> {
         self.function_definitions
     }
+
+    /// Takes all the Warnings that were reported to this State or any State based on it
+    pub fn take_warnings(&self) -> Vec<SemanticWarning> {
+        self.warnings.take()
+    }
 }
 
 impl Default for ParseState<'_> {
     fn default() -> Self {
-        Self::new()
+        Self::new(Arch::default().data_model())
     }
 }
 
@@ -181,6 +243,17 @@ impl VariableContainer for ParseState<'_> {
             None => None,
         }
     }
+
+    fn warn(&self, warning: SemanticWarning) {
+        match self.parent {
+            Some(p) => p.warn(warning),
+            None => self.warnings.borrow_mut().push(warning),
+        }
+    }
+
+    fn data_model(&self) -> DataModel {
+        self.data_model.clone()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
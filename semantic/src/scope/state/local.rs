@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use general::Span;
 use syntax::Identifier;
 
-use crate::{AType, FunctionDeclaration, ParseState, VariableDeclaration};
+use crate::{AType, FunctionDeclaration, Linkage, ParseState, VariableDeclaration};
 
 /// The Local State for a Scope
 #[derive(Debug)]
@@ -12,6 +12,8 @@ pub struct LocalState {
     vars: HashMap<String, VariableDeclaration>,
     /// Takes the Source Name as the Key
     func_decs: HashMap<String, FunctionDeclaration>,
+    /// The internal Names of the static Variables declared in this Scope
+    statics: HashSet<String>,
 }
 
 impl LocalState {
@@ -20,6 +22,7 @@ impl LocalState {
         Self {
             vars: HashMap::new(),
             func_decs: HashMap::new(),
+            statics: HashSet::new(),
         }
     }
 
@@ -49,6 +52,17 @@ impl LocalState {
         self.func_decs.get(&ident.0.data)
     }
 
+    /// Checks if the Variable with the given internal Name is declared in this Scope
+    pub fn declares_internal(&self, internal_name: &str) -> bool {
+        self.vars
+            .values()
+            .any(|var| var.internal_name == internal_name)
+    }
+    /// Checks if the Variable with the given internal Name was declared static in this Scope
+    pub fn is_static(&self, internal_name: &str) -> bool {
+        self.statics.contains(internal_name)
+    }
+
     /// Adds a Variable Declaration for this Scope
     pub fn declare_var(&mut self, name: Identifier, ty: AType, decl: Span) -> String {
         let internal_name = ParseState::unique_var_name(&name, &decl);
@@ -68,4 +82,14 @@ impl LocalState {
     pub fn declare_func(&mut self, name: Identifier, func: FunctionDeclaration) {
         self.func_decs.insert(name.0.data, func);
     }
+    /// Marks the Variable with the given internal Name as having static Storage-Duration
+    pub fn mark_static(&mut self, internal_name: String) {
+        self.statics.insert(internal_name);
+    }
+    /// Updates the Linkage of a Function declared in this Scope
+    pub fn set_func_linkage(&mut self, ident: &Identifier, linkage: Linkage) {
+        if let Some(func) = self.func_decs.get_mut(&ident.0.data) {
+            func.linkage = linkage;
+        }
+    }
 }
@@ -4,10 +4,15 @@ use syntax::Identifier;
 
 use crate::AType;
 
-/// This is used to map TypeDef definitions or Struct Names to their respective underlying Types
+/// This is used to map TypeDef definitions and the Names of Structs, Unions and Enums to their
+/// respective underlying Types and the Names of Enum-Variants to their constant Values
 #[derive(Debug, PartialEq, Clone)]
 pub struct TypeDefinitions {
     defined: HashMap<String, AType>,
+    /// The Names of Structs, Unions and Enums live in their own Namespace, so that something
+    /// like "typedef struct node node;" does not replace the Struct itself
+    tags: HashMap<String, AType>,
+    enum_constants: HashMap<String, i64>,
 }
 
 impl TypeDefinitions {
@@ -15,6 +20,8 @@ impl TypeDefinitions {
     pub fn new() -> Self {
         Self {
             defined: HashMap::new(),
+            tags: HashMap::new(),
+            enum_constants: HashMap::new(),
         }
     }
 
@@ -24,6 +31,8 @@ impl TypeDefinitions {
     pub fn based(other: &Self) -> Self {
         Self {
             defined: other.defined.clone(),
+            tags: other.tags.clone(),
+            enum_constants: other.enum_constants.clone(),
         }
     }
 
@@ -36,6 +45,26 @@ impl TypeDefinitions {
     pub fn get_definition(&self, ident: &Identifier) -> Option<&AType> {
         self.defined.get(&ident.0.data)
     }
+
+    /// Adds a new Mapping for the Name of a Struct, Union or Enum to its Type
+    pub fn add_tag(&mut self, ident: Identifier, target: AType) {
+        self.tags.insert(ident.0.data, target);
+    }
+
+    /// Loads the Type of the Struct, Union or Enum with the given Name, if any exists
+    pub fn get_tag(&self, ident: &Identifier) -> Option<&AType> {
+        self.tags.get(&ident.0.data)
+    }
+
+    /// Adds a new Enum-Variant with the given constant Value
+    pub fn add_enum_constant(&mut self, ident: Identifier, value: i64) {
+        self.enum_constants.insert(ident.0.data, value);
+    }
+
+    /// Loads the Value of the Enum-Variant with the given Name, if any exists
+    pub fn get_enum_constant(&self, ident: &Identifier) -> Option<i64> {
+        self.enum_constants.get(&ident.0.data).copied()
+    }
 }
 
 impl Default for TypeDefinitions {
@@ -0,0 +1,17 @@
+use general::SpanData;
+
+use crate::AType;
+
+/// Problems in the Source that do not prevent it from being compiled, but likely result in
+/// different Behaviour than intended
+#[derive(Debug, PartialEq, Clone)]
+pub enum SemanticWarning {
+    /// A Comparison between a signed and an unsigned Operand, where the signed Operand is
+    /// converted to the unsigned Type, so negative Values compare as very large ones
+    SignChangingComparison {
+        /// The signed Operand that gets converted
+        signed: SpanData<AType>,
+        /// The unsigned Operand that determines the Type of the Comparison
+        unsigned: SpanData<AType>,
+    },
+}
@@ -0,0 +1,171 @@
+use general::arch::Arch;
+use ir::{Constant, Expression};
+use semantic::{AAlignment, AStatement, SemanticError};
+
+mod common;
+use common::*;
+
+#[test]
+fn align_of() {
+    let result = parse(
+        "
+unsigned long test() {
+    return _Alignof(long) + _Alignof(char);
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let constants = constants(&statements(&func));
+    assert!(constants.contains(&Constant::U64(8)));
+    assert!(constants.contains(&Constant::U64(1)));
+}
+
+#[test]
+fn align_as_constant() {
+    let result = parse(
+        "
+int test() {
+    _Alignas(16) char buffer[4];
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(4, 16)], stack_allocs(&statements(&func)));
+}
+
+#[test]
+fn align_as_type() {
+    let result = parse(
+        "
+int test() {
+    _Alignas(long) char buffer[4] = {1, 2, 3, 4};
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(4, 8)], stack_allocs(&statements(&func)));
+}
+
+#[test]
+fn align_as_weaker_than_natural() {
+    let result = parse(
+        "
+int test() {
+    _Alignas(1) int values[2];
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(8, 4)], stack_allocs(&statements(&func)));
+}
+
+#[test]
+fn align_as_global() {
+    let result = parse(
+        "
+_Alignas(32) int values[2];
+        ",
+    )
+    .unwrap();
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { alignment, .. }) => {
+            assert_eq!(&Some(AAlignment::Bytes(32)), alignment);
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let static_allocs: Vec<_> = expressions(&ir.global.get_statements())
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::StaticAlloc {
+                size, alignment, ..
+            } => Some((size, alignment)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![(8, 32)], static_allocs);
+}
+
+#[test]
+fn align_as_global_scalar() {
+    let result = parse(
+        "
+_Alignas(32) int value;
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let alignments: Vec<_> = ir.global_alignments.values().copied().collect();
+    assert_eq!(vec![32], alignments);
+}
+
+#[test]
+fn align_as_member() {
+    let result = parse(
+        "
+struct S {
+    char c;
+    _Alignas(16) int x;
+};
+
+unsigned long test() {
+    return sizeof(struct S) + _Alignof(struct S);
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let constants = constants(&statements(&func));
+    assert!(constants.contains(&Constant::U64(32)));
+    assert!(constants.contains(&Constant::U64(16)));
+}
+
+#[test]
+fn align_as_bit_field() {
+    let result = parse(
+        "
+struct S {
+    _Alignas(16) unsigned x : 3;
+};
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::InvalidBitField { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn invalid_alignment() {
+    let result = parse(
+        "
+int test() {
+    _Alignas(3) char buffer[4];
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::InvalidAlignment { .. })),
+        "{:?}",
+        result
+    );
+}
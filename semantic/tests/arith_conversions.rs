@@ -0,0 +1,132 @@
+use ir::Type;
+use semantic::{APrimitive, AType, SemanticWarning};
+
+mod common;
+use common::*;
+
+#[test]
+fn mixed_width_arithmetic() {
+    let result = parse(
+        "
+long test(char c, short s, int i, long l) {
+    return c + s + i + l;
+}
+        ",
+    )
+    .unwrap();
+    assert!(result.warnings.is_empty());
+
+    let func = function_ir(result, "test");
+    let casts = casts(&statements(&func));
+    assert!(casts.contains(&(Type::I8, Type::I32)));
+    assert!(casts.contains(&(Type::I16, Type::I32)));
+    assert!(casts.contains(&(Type::I32, Type::I64)));
+}
+
+#[test]
+fn unsigned_int_arithmetic() {
+    let result = parse(
+        "
+unsigned int test(unsigned int u, int i) {
+    return u / i;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(Type::I32, Type::U32)], casts(&statements(&func)));
+}
+
+#[test]
+fn sign_changing_comparison() {
+    let result = parse(
+        "
+int test(unsigned int u, int i) {
+    return u < i;
+}
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(1, result.warnings.len());
+    match &result.warnings[0] {
+        SemanticWarning::SignChangingComparison { signed, unsigned } => {
+            assert_eq!(AType::Primitve(APrimitive::Int), signed.data);
+            assert_eq!(AType::Primitve(APrimitive::UnsignedInt), unsigned.data);
+
+            assert_eq!("int", signed.data.to_string());
+            assert_eq!("unsigned int", unsigned.data.to_string());
+        }
+    };
+}
+
+#[test]
+fn comparison_without_sign_change() {
+    let result = parse(
+        "
+int test(unsigned int u, unsigned char c, long l) {
+    int first = u < 10;
+    int second = c < -1;
+    int third = l < u;
+    return first + second + third;
+}
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(Vec::<SemanticWarning>::new(), result.warnings);
+}
+
+#[test]
+fn shift_keeps_left_type() {
+    let result = parse(
+        "
+long test(long l, char c) {
+    return l << c;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(Type::I8, Type::I32)], casts(&statements(&func)));
+}
+
+#[test]
+fn unary_operators_keep_promoted_type() {
+    let result = parse(
+        "
+int test(long x, unsigned int u, unsigned char c) {
+    int first = -x < 0u;
+    int second = -1L < 0xFFFFFFFFu;
+    int third = ~c < 1;
+    int fourth = +c < 1;
+    return first + second + third + fourth;
+}
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(Vec::<SemanticWarning>::new(), result.warnings);
+}
+
+#[test]
+fn negated_unsigned_stays_unsigned() {
+    let result = parse(
+        "
+int test(unsigned int u, int i) {
+    return -u < i;
+}
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(1, result.warnings.len());
+    match &result.warnings[0] {
+        SemanticWarning::SignChangingComparison { signed, unsigned } => {
+            assert_eq!(AType::Primitve(APrimitive::Int), signed.data);
+            assert_eq!(AType::Primitve(APrimitive::UnsignedInt), unsigned.data);
+        }
+    };
+}
@@ -0,0 +1,339 @@
+use general::arch::Arch;
+use ir::{BinaryArithmeticOp, BinaryOp, Constant, Expression, Operand, Statement, Type, Value};
+use semantic::{SemanticError, AAST};
+
+mod common;
+use common::*;
+
+#[test]
+fn multi_dimensional() {
+    let result = parse(
+        "
+int test(int i, int j) {
+    int m[4][8];
+    m[i][j] = 3;
+    return m[i][j] + sizeof(m);
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+    assert_eq!(vec![(128, 4)], stack_allocs(&statements));
+    // The Rows are 8 * 4 Bytes long
+    let scales: Vec<_> =
+        binary_operands(&statements, BinaryOp::Arith(BinaryArithmeticOp::Multiply))
+            .into_iter()
+            .map(|(_, right)| right)
+            .collect();
+    assert!(scales.contains(&Operand::Constant(Constant::I64(32))));
+    assert!(scales.contains(&Operand::Constant(Constant::I64(4))));
+    assert!(constants(&statements).contains(&Constant::U64(128)));
+}
+
+#[test]
+fn constant_expression_size() {
+    let result = parse(
+        "
+enum sizes { N = 3 };
+
+int test() {
+    int values[N * 2];
+    long first;
+    char bytes[sizeof(first) + 1];
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(24, 4), (9, 1)], stack_allocs(&statements(&func)));
+}
+
+#[test]
+fn conditional_size() {
+    let result = parse(
+        "
+int test() {
+    int values[1 ? 2 : 3];
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+    assert_eq!(vec![(8, 4)], stack_allocs(&statements));
+    assert!(!expressions(&statements)
+        .iter()
+        .any(|exp| matches!(exp, Expression::DynamicStackAlloc { .. })));
+}
+
+#[test]
+fn target_dependent_size() {
+    let result = parse(
+        "
+struct entry {
+    char name[sizeof(void*)];
+    int value;
+};
+
+_Static_assert(sizeof(struct entry) == 12, \"entry layout\");
+        ",
+    )
+    .unwrap();
+
+    assert!(result.check_static_assertions(&Arch::AArch64).is_ok());
+    assert!(result.check_static_assertions(&Arch::SH4A).is_err());
+}
+
+#[test]
+fn variable_length() {
+    let result = parse(
+        "
+unsigned long test(int n) {
+    int buffer[n];
+    buffer[0] = n;
+    n = 2;
+    return sizeof(buffer);
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+    let allocs: Vec<_> = expressions(&statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::DynamicStackAlloc { size, alignment } => Some((size.ty(), alignment)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![(Type::U64, 4)], allocs);
+
+    // The Size is based on the Length when the Array was declared and not on the later Value
+    let returned = statements
+        .iter()
+        .find_map(|s| match s {
+            Statement::Return(Some(var)) => Some(var.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let size = statements.iter().find_map(|s| match s {
+        Statement::Assignment {
+            target,
+            value: Value::Expression(exp),
+        } if *target == returned => Some(exp.clone()),
+        _ => None,
+    });
+    assert!(
+        matches!(
+            size,
+            Some(Expression::BinaryOp {
+                op: BinaryOp::Arith(BinaryArithmeticOp::Multiply),
+                left: Operand::Variable(_),
+                right: Operand::Constant(Constant::U64(4)),
+            })
+        ),
+        "{:?}",
+        size
+    );
+}
+
+/// Collects the Statements of all the Blocks in the verified Function
+fn verified_blocks(aast: AAST, name: &str) -> Vec<Vec<ir::Statement>> {
+    let func = function_ir(aast, name);
+    func.verify();
+
+    block_statements(&func)
+}
+
+/// The Names of the Variables, that store the Stack-Pointer saved before a variable length Array
+fn stack_saves(blocks: &[Vec<ir::Statement>]) -> Vec<String> {
+    blocks
+        .iter()
+        .flatten()
+        .filter_map(|s| match s {
+            ir::Statement::Assignment {
+                target,
+                value: ir::Value::Expression(ir::Expression::StackSave),
+            } => Some(target.name().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Metadata of the first Jump, that follows every Restore of the Stack-Pointer saved in the
+/// given Variable
+fn restoring_jumps(blocks: &[Vec<ir::Statement>], saved: &str) -> Vec<ir::JumpMetadata> {
+    blocks
+        .iter()
+        .flat_map(|b| {
+            b.iter().enumerate().filter_map(move |(index, s)| match s {
+                ir::Statement::StackRestore { value } if value.name() == saved => {
+                    b[index..].iter().find_map(|s| match s {
+                        ir::Statement::Jump(_, metadata)
+                        | ir::Statement::JumpTrue(_, _, metadata) => Some(metadata.clone()),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn variable_length_in_loop() {
+    let result = parse(
+        "
+int test(int n) {
+    int total = 0;
+    for (int i = 0; i < n; i++) {
+        int values[n];
+        values[i] = i;
+        if (i == 3) {
+            continue;
+        }
+        if (i == 5) {
+            break;
+        }
+        total = total + values[i];
+    }
+    return total;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = verified_blocks(result, "test");
+    let saves = stack_saves(&blocks);
+    assert_eq!(1, saves.len());
+
+    // The Continue, the Break and the End of the Body all release the Array again
+    let mut jumps = restoring_jumps(&blocks, &saves[0]);
+    jumps.sort_by_key(|m| format!("{:?}", m));
+    assert_eq!(
+        vec![
+            ir::JumpMetadata::Loop,
+            ir::JumpMetadata::Loop,
+            ir::JumpMetadata::LoopBreak
+        ],
+        jumps
+    );
+}
+
+#[test]
+fn variable_length_left_by_goto() {
+    let result = parse(
+        "
+int test(int n) {
+    int total = 0;
+again:
+    total = total + 1;
+    if (total < 100) {
+        int values[n];
+        values[0] = total;
+        total = values[0];
+        goto again;
+    }
+    return total;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = verified_blocks(result, "test");
+    let saves = stack_saves(&blocks);
+    assert_eq!(1, saves.len());
+
+    let jumps = restoring_jumps(&blocks, &saves[0]);
+    assert!(jumps.contains(&ir::JumpMetadata::Loop));
+}
+
+#[test]
+fn variable_length_without_scope_exit() {
+    let result = parse(
+        "
+int test(int n) {
+    int values[n];
+    values[0] = n;
+    return values[0];
+}
+        ",
+    )
+    .unwrap();
+
+    // The Memory of the Function-Body is released by returning
+    let blocks = verified_blocks(result, "test");
+    let saves = stack_saves(&blocks);
+    assert_eq!(1, saves.len());
+    assert!(restoring_jumps(&blocks, &saves[0]).is_empty());
+}
+
+#[test]
+fn invalid_sizes() {
+    let negative = parse(
+        "
+int test() {
+    int values[2 - 3];
+    return 0;
+}
+        ",
+    );
+    assert!(
+        matches!(negative, Err(SemanticError::InvalidArraySize { .. })),
+        "{:?}",
+        negative
+    );
+
+    let global = parse(
+        "
+int n = 3;
+int values[n];
+        ",
+    );
+    assert!(
+        matches!(global, Err(SemanticError::InvalidArraySize { .. })),
+        "{:?}",
+        global
+    );
+
+    let member = parse(
+        "
+int test(int n) {
+    struct data {
+        int values[n];
+    };
+    return 0;
+}
+        ",
+    );
+    assert!(
+        matches!(member, Err(SemanticError::InvalidArraySize { .. })),
+        "{:?}",
+        member
+    );
+}
+
+#[test]
+fn variable_length_initializer() {
+    let result = parse(
+        "
+int test(int n) {
+    int values[n] = {1, 2};
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::InvalidInitializer { .. })),
+        "{:?}",
+        result
+    );
+}
@@ -26,6 +26,7 @@ asm(\"mov ${out}, 13\", out);
                         data: "out".to_string(),
                     }),
                     ty: AType::Primitve(APrimitive::Int),
+                    alignment: None,
                 },
                 AStatement::Expression(AExpression::InlineAssembly {
                     span: Span::new_source(source.clone(), 10..13),
@@ -47,6 +48,7 @@ asm(\"mov ${out}, 13\", out);
                 }),
             ],
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
@@ -78,6 +80,7 @@ asm(\"mov ${out}, ${in}\", out, in);
                         data: "out".to_string(),
                     }),
                     ty: AType::Primitve(APrimitive::Int),
+                    alignment: None,
                 },
                 AStatement::Assignment {
                     target: AAssignTarget::Variable {
@@ -91,13 +94,13 @@ asm(\"mov ${out}, ${in}\", out, in);
                             data: AType::Primitve(APrimitive::Int),
                         },
                     },
-                    value: AExpression::Cast {
-                        base: Box::new(AExpression::Literal(Literal::Integer(SpanData {
+                    value: AExpression::Literal(Literal::Integer(
+                        SpanData {
                             span: Span::new_source(source.clone(), 19..21),
                             data: 13,
-                        }))),
-                        target: AType::Primitve(APrimitive::Int),
-                    },
+                        },
+                        APrimitive::Int,
+                    )),
                 },
                 AStatement::Expression(AExpression::InlineAssembly {
                     span: Span::new_source(source.clone(), 23..26),
@@ -128,6 +131,7 @@ asm(\"mov ${out}, ${in}\", out, in);
                 }),
             ],
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
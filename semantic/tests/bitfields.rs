@@ -0,0 +1,190 @@
+use general::arch::Arch;
+use ir::{BinaryBitwiseOp, BinaryOp, Constant, FunctionDefinition, Statement};
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+/// The constant Operands of the bitwise Operations of the given Kind in the Function
+fn bitwise(func: &FunctionDefinition, op: BinaryBitwiseOp) -> Vec<Constant> {
+    right_constants(&statements(func), BinaryOp::Bitwise(op))
+}
+
+#[test]
+fn read_unsigned_bit_field() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    unsigned mode : 3;
+};
+
+unsigned test(struct Flags* flags) {
+    return flags->mode;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftRight).contains(&Constant::U32(1)));
+    assert!(bitwise(&func, BinaryBitwiseOp::And).contains(&Constant::U32(7)));
+}
+
+#[test]
+fn read_signed_bit_field() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    int delta : 4;
+};
+
+int test(struct Flags* flags) {
+    return flags->delta;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftLeft).contains(&Constant::I32(27)));
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftRight).contains(&Constant::I32(28)));
+}
+
+#[test]
+fn read_big_endian_bit_field() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    unsigned mode : 3;
+};
+
+unsigned test(struct Flags* flags) {
+    return flags->mode;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = arch_function_ir(result, "test", Arch::SH4A);
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftRight).contains(&Constant::U32(28)));
+    assert!(bitwise(&func, BinaryBitwiseOp::And).contains(&Constant::U32(7)));
+}
+
+#[test]
+fn unnamed_bit_field() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    unsigned : 3;
+    unsigned mode : 2;
+};
+
+unsigned test(struct Flags* flags) {
+    return flags->mode;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftRight).contains(&Constant::U32(4)));
+    assert!(bitwise(&func, BinaryBitwiseOp::And).contains(&Constant::U32(3)));
+}
+
+#[test]
+fn unnamed_zero_width_starts_new_unit() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    unsigned : 0;
+    unsigned mode : 2;
+};
+
+_Static_assert(sizeof(struct Flags) == 8, \"mode is in the second Unit\");
+        ",
+    )
+    .unwrap();
+
+    assert!(result.check_static_assertions(&Arch::AArch64).is_ok());
+}
+
+#[test]
+fn write_bit_field() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned ready : 1;
+    unsigned mode : 3;
+};
+
+int test() {
+    struct Flags flags;
+    flags.mode = 5;
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert!(bitwise(&func, BinaryBitwiseOp::And).contains(&Constant::U32(4294967281)));
+    assert!(bitwise(&func, BinaryBitwiseOp::And).contains(&Constant::U32(7)));
+    assert!(bitwise(&func, BinaryBitwiseOp::ShiftLeft).contains(&Constant::U32(1)));
+    assert!(
+        !binary_operands(&statements(&func), BinaryOp::Bitwise(BinaryBitwiseOp::Or)).is_empty()
+    );
+    assert_eq!(
+        1,
+        statements(&func)
+            .iter()
+            .filter(|s| matches!(s, Statement::WriteMemory { .. }))
+            .count()
+    );
+}
+
+#[test]
+fn width_too_large() {
+    let result = parse(
+        "
+struct Flags {
+    char mode : 9;
+};
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidBitField { .. })));
+}
+
+#[test]
+fn named_zero_width() {
+    let result = parse(
+        "
+struct Flags {
+    unsigned mode : 0;
+};
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidBitField { .. })));
+}
+
+#[test]
+fn non_integer_type() {
+    let result = parse(
+        "
+struct Flags {
+    float mode : 3;
+};
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidBitField { .. })));
+}
@@ -0,0 +1,167 @@
+use general::arch::Arch;
+use ir::{
+    BinaryLogicOp, BinaryOp, Constant, Expression, Operand, Statement, Type, UnaryArithmeticOp,
+    UnaryOp, Value,
+};
+use semantic::{APrimitive, AStatement, AType, Array};
+
+mod common;
+use common::*;
+
+fn function_statements(content: &str, name: &str) -> Vec<Statement> {
+    let func = arch_function_ir(parse(content).unwrap(), name, Arch::AArch64);
+    statements(&func)
+}
+
+#[test]
+fn integer_to_bool() {
+    let statements = function_statements(
+        "
+_Bool test(int value) {
+    _Bool result = value;
+    return result;
+}
+        ",
+        "test",
+    );
+
+    assert!(assignments(&statements).iter().any(|(target, value)| {
+        target.ty == Type::U8
+            && matches!(
+                value,
+                Value::Expression(Expression::BinaryOp {
+                    op: BinaryOp::Logic(BinaryLogicOp::NotEqual),
+                    left: Operand::Variable(var),
+                    right: Operand::Constant(Constant::I32(0)),
+                }) if var.ty == Type::I32
+            )
+    }));
+}
+
+#[test]
+fn pointer_to_bool() {
+    let statements = function_statements(
+        "
+_Bool test(int* value) {
+    _Bool result = value;
+    return result;
+}
+        ",
+        "test",
+    );
+
+    assert!(assignments(&statements).iter().any(|(target, value)| {
+        target.ty == Type::U8
+            && matches!(
+                value,
+                Value::Expression(Expression::BinaryOp {
+                    op: BinaryOp::Logic(BinaryLogicOp::NotEqual),
+                    left: Operand::Variable(var),
+                    right: Operand::Constant(Constant::I64(0)),
+                }) if var.ty == Type::Pointer(Box::new(Type::I32))
+            )
+    }));
+}
+
+#[test]
+fn comparison_to_bool() {
+    let statements = function_statements(
+        "
+_Bool test(int value) {
+    _Bool result = value < 3;
+    return result;
+}
+        ",
+        "test",
+    );
+
+    // The Result of the Comparison is already either 0 or 1
+    assert!(casts(&statements).contains(&(Type::I32, Type::U8)));
+    assert!(binary_operands(&statements, BinaryOp::Logic(BinaryLogicOp::NotEqual)).is_empty());
+}
+
+#[test]
+fn constant_to_bool() {
+    let statements = function_statements(
+        "
+_Bool test() {
+    _Bool result = 5;
+    return result;
+}
+        ",
+        "test",
+    );
+
+    assert!(assigned_constants(&statements).contains(&Constant::U8(1)));
+    assert!(!constants(&statements).contains(&Constant::U8(5)));
+}
+
+#[test]
+fn bool_increment() {
+    let statements = function_statements(
+        "
+_Bool test(_Bool value) {
+    value++;
+    return value;
+}
+        ",
+        "test",
+    );
+
+    // 1 + 1 results in 2, which is then converted back to 1
+    assert!(has_unary(
+        &statements,
+        UnaryOp::Arith(UnaryArithmeticOp::Increment)
+    ));
+    assert!(
+        right_constants(&statements, BinaryOp::Logic(BinaryLogicOp::NotEqual))
+            .contains(&Constant::U8(0))
+    );
+}
+
+#[test]
+fn bool_decrement() {
+    let statements = function_statements(
+        "
+_Bool test(_Bool value) {
+    --value;
+    return value;
+}
+        ",
+        "test",
+    );
+
+    // Decrementing a _Bool inverts its Value
+    assert!(!has_unary(
+        &statements,
+        UnaryOp::Arith(UnaryArithmeticOp::Decrement)
+    ));
+    assert!(
+        right_constants(&statements, BinaryOp::Logic(BinaryLogicOp::Equal))
+            .contains(&Constant::U8(0))
+    );
+}
+
+#[test]
+fn bool_in_constant_expression() {
+    let result = parse(
+        "
+int values[((_Bool) 7) + ((_Bool) 0) + 1];
+        ",
+    )
+    .unwrap();
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(
+                AType::Array(Array {
+                    size: Some(2),
+                    length: None,
+                    ty: Box::new(AType::Primitve(APrimitive::Int)),
+                }),
+                ty
+            );
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
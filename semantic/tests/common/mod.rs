@@ -0,0 +1,243 @@
+//! The Helpers shared by the Tests of the semantic Analysis, which parse the Code and inspect
+//! the Structure of the generated IR
+#![allow(dead_code)]
+
+use general::{arch::Arch, Source, Span};
+use ir::{
+    BinaryOp, Constant, Expression, FunctionDefinition, Operand, Statement, Type, UnaryOp, Value,
+    Variable,
+};
+use semantic::{SemanticError, AAST};
+
+pub fn parse(content: &str) -> Result<AAST, SemanticError> {
+    parse_source("test", content)
+}
+
+/// Parses the Content as a Source with the given Name, like a separate Translation-Unit
+pub fn parse_source(name: &str, content: &str) -> Result<AAST, SemanticError> {
+    let source = Source::new(name, content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    semantic::parse(syntax_ast)
+}
+
+/// Converts the AAST for AArch64 and returns the IR of the Function with the given Name
+pub fn function_ir(aast: AAST, name: &str) -> FunctionDefinition {
+    arch_function_ir(aast, name, Arch::AArch64)
+}
+
+pub fn arch_function_ir(aast: AAST, name: &str, arch: Arch) -> FunctionDefinition {
+    let mut ir = aast.convert_to_ir(arch);
+    let func = ir.functions.remove(name).unwrap();
+    println!("{}", ir::text_rep::generate_text_rep(&func));
+    func
+}
+
+/// The Statements of every Block in the Function, in the Order of the Blocks
+pub fn block_statements(func: &FunctionDefinition) -> Vec<Vec<Statement>> {
+    #[allow(deprecated)]
+    func.block
+        .block_iter()
+        .map(|b| b.get_statements())
+        .collect()
+}
+
+/// The Statements of all the Blocks in the Function
+pub fn statements(func: &FunctionDefinition) -> Vec<Statement> {
+    block_statements(func).into_iter().flatten().collect()
+}
+
+/// The Targets and Values of all the Assignments in the Statements
+pub fn assignments(statements: &[Statement]) -> Vec<(Variable, Value)> {
+    statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::Assignment { target, value } => Some((target.clone(), value.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Expressions, whose Values are assigned to Variables in the Statements
+pub fn expressions(statements: &[Statement]) -> Vec<Expression> {
+    statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::Assignment {
+                value: Value::Expression(exp),
+                ..
+            } => Some(exp.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Constants, that are directly assigned to Variables in the Statements
+pub fn assigned_constants(statements: &[Statement]) -> Vec<Constant> {
+    statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::Assignment {
+                value: Value::Constant(con),
+                ..
+            } => Some(con.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// All the Constants used in the Statements, either assigned directly or as an Operand
+pub fn constants(statements: &[Statement]) -> Vec<Constant> {
+    let operands = statements.iter().flat_map(|s| match s {
+        Statement::Assignment {
+            value: Value::Expression(exp),
+            ..
+        } => expression_operands(exp),
+        Statement::WriteMemory { target, value, .. } => vec![target.clone(), value.clone()],
+        Statement::Call { arguments, .. } => arguments.clone(),
+        Statement::IndirectCall {
+            target, arguments, ..
+        } => std::iter::once(target.clone())
+            .chain(arguments.iter().cloned())
+            .collect(),
+        _ => Vec::new(),
+    });
+
+    assigned_constants(statements)
+        .into_iter()
+        .chain(operands.filter_map(|op| match op {
+            Operand::Constant(con) => Some(con),
+            Operand::Variable(_) => None,
+        }))
+        .collect()
+}
+
+fn expression_operands(exp: &Expression) -> Vec<Operand> {
+    match exp {
+        Expression::BinaryOp { left, right, .. } => vec![left.clone(), right.clone()],
+        Expression::UnaryOp { base, .. }
+        | Expression::Cast { base, .. }
+        | Expression::AdressOf { base } => vec![base.clone()],
+        Expression::ReadMemory { address, .. } => vec![address.clone()],
+        Expression::FunctionCall { arguments, .. } => arguments.clone(),
+        Expression::IndirectFunctionCall {
+            target, arguments, ..
+        } => std::iter::once(target.clone())
+            .chain(arguments.iter().cloned())
+            .collect(),
+        Expression::DynamicStackAlloc { size, .. } => vec![size.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// The Operands of all the binary Operations of the given Kind in the Statements
+pub fn binary_operands(statements: &[Statement], kind: BinaryOp) -> Vec<(Operand, Operand)> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::BinaryOp { op, left, right } if op == kind => Some((left, right)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The constant right Operands of all the binary Operations of the given Kind in the Statements
+pub fn right_constants(statements: &[Statement], kind: BinaryOp) -> Vec<Constant> {
+    binary_operands(statements, kind)
+        .into_iter()
+        .filter_map(|(_, right)| match right {
+            Operand::Constant(con) => Some(con),
+            Operand::Variable(_) => None,
+        })
+        .collect()
+}
+
+/// Whether any of the Statements performs the given unary Operation
+pub fn has_unary(statements: &[Statement], op: UnaryOp) -> bool {
+    expressions(statements)
+        .iter()
+        .any(|exp| matches!(exp, Expression::UnaryOp { op: unary, .. } if *unary == op))
+}
+
+/// The Types converted from and to by all the Casts in the Statements
+pub fn casts(statements: &[Statement]) -> Vec<(Type, Type)> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::Cast { base, target } => Some((base.ty(), target)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Size and Alignment of all the Stack-Allocations in the Statements
+pub fn stack_allocs(statements: &[Statement]) -> Vec<(usize, usize)> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::StackAlloc { size, alignment } => Some((size, alignment)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Names of the read-only Data, whose Addresses are loaded in the Statements
+pub fn constant_addresses(statements: &[Statement]) -> Vec<String> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::ConstantAddress { name } => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Name, Size and Alignment of all the static Allocations in the Statements
+pub fn static_allocs(statements: &[Statement]) -> Vec<(String, usize, usize)> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::StaticAlloc {
+                name,
+                size,
+                alignment,
+            } => Some((name, size, alignment)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Types read from Memory in the Statements
+pub fn read_types(statements: &[Statement]) -> Vec<Type> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::ReadMemory { read_ty, .. } => Some(read_ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Names of the Global-Variables saved in the Statements
+pub fn saved_globals(statements: &[Statement]) -> Vec<String> {
+    statements
+        .iter()
+        .filter_map(|s| match s {
+            Statement::SaveGlobalVariable { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The Names of the Global-Variables read in the Statements
+pub fn read_globals(statements: &[Statement]) -> Vec<String> {
+    expressions(statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::ReadGlobalVariable { name } => Some(name),
+            _ => None,
+        })
+        .collect()
+}
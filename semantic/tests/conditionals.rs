@@ -1,7 +1,7 @@
 use general::{Source, Span, SpanData};
 use semantic::{
     AAssignTarget, AExpression, APrimitive, ARootScope, AScope, AStatement, AType,
-    FunctionDeclaration, Literal, AAST,
+    FunctionDeclaration, Linkage, Literal, AAST,
 };
 use syntax::Identifier;
 
@@ -29,6 +29,7 @@ void test() {
                 (
                     FunctionDeclaration {
                         var_args: false,
+                        linkage: Linkage::External,
                         return_ty: AType::Primitve(APrimitive::Void),
                         arguments: vec![],
                         declaration: Span::new_source(source.clone(), 6..10),
@@ -48,21 +49,22 @@ void test() {
                                         data: AType::Primitve(APrimitive::Int),
                                     },
                                 },
-                                value: AExpression::Cast {
-                                    target: AType::Primitve(APrimitive::Int),
-                                    base: Box::new(AExpression::Literal(Literal::Integer(
-                                        SpanData {
-                                            span: Span::new_source(source.clone(), 32..33),
-                                            data: 0,
-                                        },
-                                    ))),
-                                },
+                                value: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source.clone(), 32..33),
+                                        data: 0,
+                                    },
+                                    APrimitive::Int,
+                                )),
                             },
                             AStatement::If {
-                                condition: AExpression::Literal(Literal::Integer(SpanData {
-                                    span: Span::new_source(source.clone(), 43..45),
-                                    data: 13,
-                                })),
+                                condition: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source.clone(), 43..45),
+                                        data: 13,
+                                    },
+                                    APrimitive::Int,
+                                )),
                                 body: AScope {
                                     function_definitions: vec![].into_iter().collect(),
                                     statements: vec![AStatement::Assignment {
@@ -77,15 +79,13 @@ void test() {
                                                 data: AType::Primitve(APrimitive::Int),
                                             },
                                         },
-                                        value: AExpression::Cast {
-                                            target: AType::Primitve(APrimitive::Int),
-                                            base: Box::new(AExpression::Literal(Literal::Integer(
-                                                SpanData {
-                                                    span: Span::new_source(source.clone(), 69..71),
-                                                    data: 13,
-                                                },
-                                            ))),
-                                        },
+                                        value: AExpression::Literal(Literal::Integer(
+                                            SpanData {
+                                                span: Span::new_source(source.clone(), 69..71),
+                                                data: 13,
+                                            },
+                                            APrimitive::Int,
+                                        )),
                                     }],
                                 },
                                 else_: None,
@@ -102,15 +102,13 @@ void test() {
                                         data: AType::Primitve(APrimitive::Int),
                                     },
                                 },
-                                value: AExpression::Cast {
-                                    target: AType::Primitve(APrimitive::Int),
-                                    base: Box::new(AExpression::Literal(Literal::Integer(
-                                        SpanData {
-                                            span: Span::new_source(source, 95..96),
-                                            data: 0,
-                                        },
-                                    ))),
-                                },
+                                value: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source, 95..96),
+                                        data: 0,
+                                    },
+                                    APrimitive::Int,
+                                )),
                             },
                         ],
                     },
@@ -119,6 +117,7 @@ void test() {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(syntax_ast);
@@ -153,6 +152,7 @@ void test() {
                 (
                     FunctionDeclaration {
                         var_args: false,
+                        linkage: Linkage::External,
                         return_ty: AType::Primitve(APrimitive::Void),
                         arguments: vec![],
                         declaration: Span::new_source(source.clone(), 6..10),
@@ -172,21 +172,22 @@ void test() {
                                         data: AType::Primitve(APrimitive::Int),
                                     },
                                 },
-                                value: AExpression::Cast {
-                                    target: AType::Primitve(APrimitive::Int),
-                                    base: Box::new(AExpression::Literal(Literal::Integer(
-                                        SpanData {
-                                            span: Span::new_source(source.clone(), 32..33),
-                                            data: 0,
-                                        },
-                                    ))),
-                                },
+                                value: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source.clone(), 32..33),
+                                        data: 0,
+                                    },
+                                    APrimitive::Int,
+                                )),
                             },
                             AStatement::If {
-                                condition: AExpression::Literal(Literal::Integer(SpanData {
-                                    span: Span::new_source(source.clone(), 43..45),
-                                    data: 13,
-                                })),
+                                condition: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source.clone(), 43..45),
+                                        data: 13,
+                                    },
+                                    APrimitive::Int,
+                                )),
                                 body: AScope {
                                     function_definitions: vec![].into_iter().collect(),
                                     statements: vec![AStatement::Assignment {
@@ -201,15 +202,13 @@ void test() {
                                                 data: AType::Primitve(APrimitive::Int),
                                             },
                                         },
-                                        value: AExpression::Cast {
-                                            target: AType::Primitve(APrimitive::Int),
-                                            base: Box::new(AExpression::Literal(Literal::Integer(
-                                                SpanData {
-                                                    span: Span::new_source(source.clone(), 71..73),
-                                                    data: 13,
-                                                },
-                                            ))),
-                                        },
+                                        value: AExpression::Literal(Literal::Integer(
+                                            SpanData {
+                                                span: Span::new_source(source.clone(), 71..73),
+                                                data: 13,
+                                            },
+                                            APrimitive::Int,
+                                        )),
                                     }],
                                 },
                                 else_: Some(AScope {
@@ -226,18 +225,13 @@ void test() {
                                                 data: AType::Primitve(APrimitive::Int),
                                             },
                                         },
-                                        value: AExpression::Cast {
-                                            target: AType::Primitve(APrimitive::Int),
-                                            base: Box::new(AExpression::Literal(Literal::Integer(
-                                                SpanData {
-                                                    span: Span::new_source(
-                                                        source.clone(),
-                                                        110..112,
-                                                    ),
-                                                    data: 23,
-                                                },
-                                            ))),
-                                        },
+                                        value: AExpression::Literal(Literal::Integer(
+                                            SpanData {
+                                                span: Span::new_source(source.clone(), 110..112),
+                                                data: 23,
+                                            },
+                                            APrimitive::Int,
+                                        )),
                                     }],
                                 }),
                             },
@@ -253,15 +247,13 @@ void test() {
                                         data: AType::Primitve(APrimitive::Int),
                                     },
                                 },
-                                value: AExpression::Cast {
-                                    target: AType::Primitve(APrimitive::Int),
-                                    base: Box::new(AExpression::Literal(Literal::Integer(
-                                        SpanData {
-                                            span: Span::new_source(source, 136..137),
-                                            data: 0,
-                                        },
-                                    ))),
-                                },
+                                value: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(source, 136..137),
+                                        data: 0,
+                                    },
+                                    APrimitive::Int,
+                                )),
                             },
                         ],
                     },
@@ -270,6 +262,7 @@ void test() {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(syntax_ast);
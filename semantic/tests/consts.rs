@@ -0,0 +1,239 @@
+use general::arch::Arch;
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+#[test]
+fn initialize_and_read_const() {
+    let result = parse(
+        "
+const int offset = 2;
+
+int test(const int value) {
+    const int result = value * 2;
+    return result + offset;
+}
+        ",
+    );
+
+    let aast = result.unwrap();
+    aast.convert_to_ir(Arch::AArch64);
+}
+
+#[test]
+fn assign_const_variable() {
+    let result = parse(
+        "
+int test() {
+    const int value = 2;
+    value = 3;
+    return value;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn assign_const_pointer() {
+    let result = parse(
+        "
+int test(int* first, int* second) {
+    int* const value = first;
+    value = second;
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn assign_through_pointer_to_const() {
+    let result = parse(
+        "
+int test(const int* value) {
+    *value = 3;
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn assign_const_array_element() {
+    let result = parse(
+        "
+int test() {
+    const int values[2] = {1, 2};
+    values[0] = 3;
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn assign_const_struct_member() {
+    let result = parse(
+        "
+struct point {
+    int x;
+};
+
+int test(const struct point* p) {
+    p->x = 3;
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn increment_const() {
+    let result = parse(
+        "
+int test() {
+    const int value = 2;
+    value++;
+    return value;
+}
+        ",
+    );
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+
+    let result = parse(
+        "
+int test(const int* value) {
+    --(*value);
+    return 0;
+}
+        ",
+    );
+    assert!(
+        matches!(result, Err(SemanticError::ConstAssignment { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn increment_pointer_to_const() {
+    let result = parse(
+        "
+int test(const int* value) {
+    value++;
+    return *value;
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn add_const_to_pointer() {
+    let result = parse(
+        "
+int first(const int* value) {
+    return *value;
+}
+
+int test(int* value) {
+    const int* ptr = value;
+    int result = first(value);
+    int read = *ptr;
+    return result + read;
+}
+        ",
+    );
+
+    let aast = result.unwrap();
+    aast.convert_to_ir(Arch::AArch64);
+}
+
+#[test]
+fn discard_const_in_assignment() {
+    let result = parse(
+        "
+int test(const int* value) {
+    int* ptr = value;
+    return *ptr;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::DiscardedConst { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn discard_const_in_argument() {
+    let result = parse(
+        "
+int first(void* value) {
+    return 0;
+}
+
+int test(const int* value) {
+    return first(value);
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::DiscardedConst { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn discard_const_explicit_cast() {
+    let result = parse(
+        "
+int test(const int* value) {
+    int* ptr = (int*) value;
+    return *ptr;
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
@@ -1,4 +1,8 @@
-use general::{Source, Span};
+use general::{arch::Arch, Source, Span};
+use ir::{Constant, Type};
+
+mod common;
+use common::*;
 
 #[test]
 fn valid() {
@@ -51,3 +55,85 @@ long double double_2;
 
     assert!(result.is_ok());
 }
+
+/// The Return-Type and the returned Constants of the Function with the given Name
+fn sizes(arch: Arch, name: &str) -> (Type, Vec<Constant>) {
+    let input = "
+unsigned long long_size() {
+    return sizeof(long int);
+}
+
+unsigned long long_long_size() {
+    return sizeof(long long int);
+}
+
+unsigned long pointer_size() {
+    return sizeof(int*);
+}
+
+struct mixed {
+    char first;
+    long long second;
+};
+
+unsigned long struct_size() {
+    return sizeof(struct mixed);
+}
+
+long identity(long value) {
+    return value;
+}
+    ";
+    let func = arch_function_ir(parse(input).unwrap(), name, arch);
+
+    (
+        func.return_ty.clone(),
+        assigned_constants(&statements(&func)),
+    )
+}
+
+#[test]
+fn lp64_data_model() {
+    let arch = Arch::AArch64;
+
+    assert_eq!(
+        (Type::U64, vec![Constant::U64(8)]),
+        sizes(arch.clone(), "long_size")
+    );
+    assert_eq!(
+        (Type::U64, vec![Constant::U64(8)]),
+        sizes(arch.clone(), "long_long_size")
+    );
+    assert_eq!(
+        (Type::U64, vec![Constant::U64(8)]),
+        sizes(arch.clone(), "pointer_size")
+    );
+    assert_eq!(
+        (Type::U64, vec![Constant::U64(16)]),
+        sizes(arch.clone(), "struct_size")
+    );
+    assert_eq!(Type::I64, sizes(arch, "identity").0);
+}
+
+#[test]
+fn ilp32_data_model() {
+    let arch = Arch::SH4A;
+
+    assert_eq!(
+        (Type::U32, vec![Constant::U32(4)]),
+        sizes(arch.clone(), "long_size")
+    );
+    assert_eq!(
+        (Type::U32, vec![Constant::U32(8)]),
+        sizes(arch.clone(), "long_long_size")
+    );
+    assert_eq!(
+        (Type::U32, vec![Constant::U32(4)]),
+        sizes(arch.clone(), "pointer_size")
+    );
+    assert_eq!(
+        (Type::U32, vec![Constant::U32(12)]),
+        sizes(arch.clone(), "struct_size")
+    );
+    assert_eq!(Type::I32, sizes(arch, "identity").0);
+}
@@ -0,0 +1,262 @@
+use general::{arch::Arch, Source, Span, SpanData};
+use semantic::{AExpression, APrimitive, AStatement, AType, Array, Literal, SemanticError};
+
+mod common;
+use common::*;
+
+#[test]
+fn enum_variable_is_int() {
+    let result = parse(
+        "
+enum state {
+    idle,
+    running,
+};
+
+enum state current;
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(AType::Primitve(APrimitive::Int), ty);
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn implicit_and_explicit_values() {
+    let content = "
+enum flags {
+    none,
+    first = 1 << 2,
+    second,
+    both = first | second
+};
+
+int x = both;
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast).unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::Assignment { value, .. }) => {
+            assert_eq!(
+                &AExpression::Literal(Literal::Integer(
+                    SpanData {
+                        span: Span::new_source(source, 94..98),
+                        data: 5,
+                    },
+                    APrimitive::Int
+                )),
+                value
+            );
+        }
+        other => panic!("Expected an Assignment, got {:?}", other),
+    };
+}
+
+#[test]
+fn enum_as_array_size() {
+    let result = parse(
+        "
+enum sizes {
+    small = 2,
+    large = small * 4,
+};
+
+int values[large];
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(
+                AType::Array(Array {
+                    size: Some(8),
+                    length: None,
+                    ty: Box::new(AType::Primitve(APrimitive::Int)),
+                }),
+                ty
+            );
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn enum_in_switch_converts_to_ir() {
+    let result = parse(
+        "
+enum state {
+    idle,
+    running,
+    stopped,
+};
+
+int test(enum state current) {
+    switch (current) {
+        case idle:
+            return 1;
+        case running:
+            return 2;
+        default:
+            return 3;
+    }
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    dbg!(&ir);
+
+    assert!(ir.functions.contains_key("test"));
+}
+
+#[test]
+fn duplicate_case_from_enums() {
+    let result = parse(
+        "
+enum state {
+    idle,
+    running = 0,
+};
+
+void test(enum state current) {
+    switch (current) {
+        case idle:
+            break;
+        case running:
+            break;
+    }
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::DuplicateCase { .. })));
+}
+
+#[test]
+fn non_constant_enum_value() {
+    let result = parse(
+        "
+int x = 2;
+enum state {
+    idle = x,
+};
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::InvalidEnumValue { .. })
+    ));
+}
+
+#[test]
+fn typedef_anonymous_enum() {
+    let result = parse(
+        "
+typedef enum { first, second = 5, third } numbers;
+
+numbers x = third;
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::Assignment { value, .. }) => {
+            assert!(matches!(
+                value,
+                AExpression::Literal(Literal::Integer(SpanData { data: 6, .. }, _))
+            ));
+        }
+        other => panic!("Expected an Assignment, got {:?}", other),
+    };
+}
+
+#[test]
+fn anonymous_enum() {
+    let result = parse(
+        "
+enum { first, second };
+
+int x = second;
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::Assignment { value, .. }) => {
+            assert!(matches!(
+                value,
+                AExpression::Literal(Literal::Integer(SpanData { data: 1, .. }, _))
+            ));
+        }
+        other => panic!("Expected an Assignment, got {:?}", other),
+    };
+}
+
+#[test]
+fn enum_definition_with_variable() {
+    let result = parse(
+        "
+enum state { idle, running } current;
+enum state other;
+enum { low, high } level = high;
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let statements = &result.global_scope.0.statements;
+    assert_eq!(3, statements.len());
+    for statement in statements.iter().take(2) {
+        match statement {
+            AStatement::DeclareVar { ty, .. } => {
+                assert_eq!(AType::Primitve(APrimitive::Int), ty);
+            }
+            other => panic!("Expected a Variable Declaration, got {:?}", other),
+        };
+    }
+    assert!(matches!(
+        statements.get(2),
+        Some(AStatement::Assignment {
+            value: AExpression::Literal(Literal::Integer(SpanData { data: 1, .. }, _)),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn enum_tag_of_struct() {
+    let result = parse(
+        "
+struct state {
+    int x;
+};
+
+enum state current;
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::MismatchedTag { .. })));
+}
@@ -1,7 +1,7 @@
 use general::{Source, Span, SpanData};
 use semantic::{
     AExpression, APrimitive, ARootScope, AScope, AStatement, AType, FunctionCall,
-    FunctionDeclaration, Literal, SemanticError, AAST,
+    FunctionDeclaration, Linkage, Literal, SemanticError, AAST,
 };
 use syntax::Identifier;
 
@@ -52,14 +52,14 @@ void other() {
     let input_ast = syntax::parse(input_tokens).unwrap();
 
     let expected = Err(SemanticError::MismatchedTypes {
-        expected: SpanData {
+        expected: Box::new(SpanData {
             span: Span::new_source(input_source.clone(), 14..22),
             data: AType::Primitve(APrimitive::Int),
-        },
-        received: SpanData {
+        }),
+        received: Box::new(SpanData {
             span: Span::new_source(input_source, 53..56),
             data: AType::Primitve(APrimitive::Float),
-        },
+        }),
     });
 
     let result = semantic::parse(input_ast);
@@ -94,6 +94,7 @@ void other() {
                         declaration: Span::new_source(input_source.clone(), 36..41),
                         return_ty: AType::Primitve(APrimitive::Void),
                         var_args: false,
+                        linkage: Linkage::External,
                     },
                     AScope {
                         statements: vec![AStatement::Expression(AExpression::FunctionCall(
@@ -102,11 +103,18 @@ void other() {
                                     span: Span::new_source(input_source.clone(), 50..57),
                                     data: "testing".to_string(),
                                 }),
-                                arguments: vec![AExpression::Literal(Literal::Integer(SpanData {
-                                    span: Span::new_source(input_source, 58..60),
-                                    data: 13,
-                                }))],
+                                arguments: vec![AExpression::Cast {
+                                    base: Box::new(AExpression::Literal(Literal::Integer(
+                                        SpanData {
+                                            span: Span::new_source(input_source, 58..60),
+                                            data: 13,
+                                        },
+                                        APrimitive::Int,
+                                    ))),
+                                    target: AType::Primitve(APrimitive::LongInt),
+                                }],
                                 result_ty: AType::Primitve(APrimitive::Void),
+                                variadic: None,
                             },
                         ))],
                         function_definitions: vec![].into_iter().collect(),
@@ -116,6 +124,7 @@ void other() {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
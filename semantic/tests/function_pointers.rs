@@ -0,0 +1,263 @@
+use general::arch::Arch;
+use ir::{Expression, Statement};
+use semantic::{AExpression, APrimitive, AStatement, AType, Array, SemanticError};
+
+mod common;
+use common::*;
+
+#[test]
+fn function_pointer_type() {
+    let result = parse(
+        "
+int (*fp)(int, char);
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(
+                AType::Pointer(Box::new(AType::Function {
+                    return_ty: Box::new(AType::Primitve(APrimitive::Int)),
+                    arguments: vec![
+                        AType::Primitve(APrimitive::Int),
+                        AType::Primitve(APrimitive::Char)
+                    ],
+                    var_args: false,
+                })),
+                ty
+            );
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn function_name_is_address() {
+    let result = parse(
+        "
+int add(int a, int b) {
+    return a + b;
+}
+
+int test() {
+    int (*fp)(int, int) = add;
+    int (*other)(int, int) = &add;
+    return fp(1, 2) + (*other)(3, 4);
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let func = function_ir(result, "test");
+    let expressions = expressions(&statements(&func));
+
+    let addresses: Vec<_> = expressions
+        .iter()
+        .filter_map(|exp| match exp {
+            Expression::FunctionAddress { name } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec!["add", "add"], addresses);
+    assert_eq!(
+        2,
+        expressions
+            .iter()
+            .filter(|exp| matches!(exp, Expression::IndirectFunctionCall { .. }))
+            .count()
+    );
+}
+
+#[test]
+fn callback_table() {
+    let result = parse(
+        "
+typedef void (*handler)(int value);
+
+struct entry {
+    int id;
+    handler callback;
+};
+
+void log_value(int value);
+
+void dispatch(struct entry* e, int value) {
+    e->callback(value);
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let func = function_ir(result, "dispatch");
+    let calls: Vec<_> = statements(&func)
+        .into_iter()
+        .filter_map(|s| match s {
+            Statement::IndirectCall { arguments, .. } => Some(arguments.len()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![1], calls);
+}
+
+#[test]
+fn qsort_style_comparator() {
+    let result = parse(
+        "
+int compare(const void* a, const void* b) {
+    return 0;
+}
+
+void sort(void* base, int count, int (*cmp)(const void*, const void*));
+
+void test(int* values) {
+    sort(values, 3, compare);
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn mismatched_indirect_call_args() {
+    let result = parse(
+        "
+void test(int (*fp)(int, int)) {
+    fp(1);
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::MismatchedFunctionArgsCount { .. })
+    ));
+}
+
+#[test]
+fn call_non_function() {
+    let result = parse(
+        "
+void test(int value) {
+    value(1);
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::InvalidOperation { .. })
+    ));
+}
+
+#[test]
+fn indirect_call_result_type() {
+    let result = parse(
+        "
+char (*getter)(void);
+
+int test() {
+    return getter();
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let func = result
+        .global_scope
+        .0
+        .function_definitions
+        .get("test")
+        .unwrap();
+    match func.1.statements.first() {
+        Some(AStatement::Return {
+            value: Some(AExpression::Cast { base, .. }),
+        }) => {
+            assert!(matches!(base.as_ref(), AExpression::IndirectCall(_)));
+            assert_eq!(AType::Primitve(APrimitive::Char), base.result_type());
+        }
+        other => panic!("Expected a Return, got {:?}", other),
+    };
+}
+
+#[test]
+fn array_of_function_pointers() {
+    let result = parse(
+        "
+int (*ops[2])(int, int);
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let function_ptr = AType::Pointer(Box::new(AType::Function {
+        return_ty: Box::new(AType::Primitve(APrimitive::Int)),
+        arguments: vec![
+            AType::Primitve(APrimitive::Int),
+            AType::Primitve(APrimitive::Int),
+        ],
+        var_args: false,
+    }));
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(
+                AType::Array(Array {
+                    size: Some(2),
+                    length: None,
+                    ty: Box::new(function_ptr),
+                }),
+                ty
+            );
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn call_from_function_pointer_array() {
+    let result = parse(
+        "
+int add(int a, int b) {
+    return a + b;
+}
+
+int sub(int a, int b) {
+    return a - b;
+}
+
+int test() {
+    int (*ops[2])(int, int);
+    ops[0] = add;
+    ops[1] = sub;
+    return ops[1](4, 3);
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let func = result
+        .global_scope
+        .0
+        .function_definitions
+        .get("test")
+        .unwrap();
+    match func.1.statements.last() {
+        Some(AStatement::Return {
+            value: Some(AExpression::IndirectCall(call)),
+        }) => {
+            assert_eq!(AType::Primitve(APrimitive::Int), call.result_ty);
+        }
+        other => panic!("Expected a Return of an indirect Call, got {:?}", other),
+    };
+
+    result.convert_to_ir(Arch::AArch64);
+}
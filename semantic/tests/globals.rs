@@ -0,0 +1,67 @@
+mod common;
+use common::*;
+
+#[test]
+fn assign_global() {
+    let result = parse(
+        "
+int g = 0;
+
+void test() {
+    g = 5;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(1, saved_globals(&statements(&func)).len());
+}
+
+#[test]
+fn compound_assign_global() {
+    let result = parse(
+        "
+int g = 0;
+
+void test() {
+    g += 2;
+    g++;
+    ++g;
+    g--;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+    let saved = saved_globals(&statements);
+    assert_eq!(4, saved.len());
+    let read = read_globals(&statements);
+    assert!(!read.is_empty());
+    assert!(read.iter().all(|name| saved.contains(name)));
+}
+
+#[test]
+fn global_in_loop_condition() {
+    let result = parse(
+        "
+int g = 3;
+
+void test() {
+    while (g) {
+        g = g - 1;
+    }
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+    let saved = saved_globals(&statements);
+    assert_eq!(1, saved.len());
+    // The Condition reads the same Global that is updated in the Body
+    assert!(read_globals(&statements).contains(&saved[0]));
+}
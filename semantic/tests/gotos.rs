@@ -0,0 +1,239 @@
+use general::{arch::Arch, Source, Span, SpanData};
+use semantic::{
+    APrimitive, ARootScope, AScope, AStatement, AType, FunctionDeclaration, Linkage, SemanticError,
+    AAST,
+};
+use syntax::Identifier;
+
+mod common;
+use common::*;
+
+#[test]
+fn label_and_goto() {
+    let content = "
+void test() {
+start:
+    goto start;
+}
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let expected = Ok(AAST {
+        global_scope: ARootScope(AScope {
+            statements: vec![],
+            function_definitions: vec![(
+                "test".to_string(),
+                (
+                    FunctionDeclaration {
+                        var_args: false,
+                        linkage: Linkage::External,
+                        return_ty: AType::Primitve(APrimitive::Void),
+                        arguments: vec![],
+                        declaration: Span::new_source(source.clone(), 6..10),
+                    },
+                    AScope {
+                        function_definitions: vec![].into_iter().collect(),
+                        statements: vec![
+                            AStatement::Label {
+                                name: Identifier(SpanData {
+                                    span: Span::new_source(source.clone(), 15..20),
+                                    data: "start".to_string(),
+                                }),
+                            },
+                            AStatement::Goto {
+                                target: Identifier(SpanData {
+                                    span: Span::new_source(source, 31..36),
+                                    data: "start".to_string(),
+                                }),
+                            },
+                        ],
+                    },
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        }),
+        warnings: Vec::new(),
+    });
+
+    let result = semantic::parse(syntax_ast);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn gotos_convert_to_ir() {
+    let content = "
+int test(int x) {
+    int result = 0;
+    int i = 0;
+    goto check;
+body:
+    if (i == 3) {
+        goto fail;
+    }
+    result = result + i;
+    i = i + 1;
+check:
+    if (i < x) {
+        goto body;
+    }
+    return result;
+fail:
+    return -1;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+
+    func.verify();
+}
+
+#[test]
+fn backward_and_forward_gotos_verify() {
+    let content = "
+int test(int x) {
+    int tries = 0;
+again:
+    tries = tries + 1;
+    if (tries < x) {
+        goto again;
+    }
+    if (tries == 5) {
+        goto fail;
+    }
+    return tries;
+fail:
+    return 0;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+
+    func.verify();
+}
+
+#[test]
+fn statements_after_goto_are_unreachable() {
+    let content = "
+int test() {
+    int x = 0;
+    goto mid;
+    x = 100;
+mid:
+    x += 1;
+    return x;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+
+    func.verify();
+
+    // The Assignment after the Goto is not part of any reachable Block
+    assert!(!assigned_constants(&statements(&func)).contains(&ir::Constant::I32(100)));
+}
+
+#[test]
+fn label_in_nested_scope() {
+    let content = "
+void test(int x) {
+    if (x) {
+        goto inner;
+    }
+    while (x) {
+    inner:
+        x = x - 1;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn unknown_label() {
+    let content = "
+void test() {
+    goto missing;
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::UnknownLabel { .. })));
+}
+
+#[test]
+fn label_from_other_function() {
+    let content = "
+void first() {
+target:
+    return;
+}
+void second() {
+    goto target;
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::UnknownLabel { .. })));
+}
+
+#[test]
+fn duplicate_label() {
+    let content = "
+void test(int x) {
+done:
+    if (x) {
+    done:
+        return;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::DuplicateLabel { .. })));
+}
+
+#[test]
+fn goto_right_before_label() {
+    let content = "
+int test(int x) {
+again:
+    x = x - 1;
+    if (x > 3) {
+        goto again;
+    }
+    goto done;
+done:
+    return x;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+    func.verify();
+
+    // Every Block ends in exactly one Jump or Return, without any Statements following it
+    for block in block_statements(&func) {
+        let terminators: Vec<_> = block
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| matches!(s, ir::Statement::Jump(..) | ir::Statement::Return(_)))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(vec![block.len() - 1], terminators, "{:?}", block);
+    }
+}
@@ -0,0 +1,190 @@
+use ir::Type;
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+#[test]
+fn self_referential() {
+    let result = parse(
+        "
+struct node {
+    int value;
+    struct node *next;
+};
+
+int second(struct node *first) {
+    first->next->next = first;
+    return first->next->value;
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn mutually_referential() {
+    let result = parse(
+        "
+struct child;
+
+struct parent {
+    struct child *first;
+};
+
+struct child {
+    struct parent *owner;
+    int age;
+};
+
+int first_age(struct parent *p) {
+    struct child *c = p->first;
+    c->owner = p;
+    return p->first->owner->first->age;
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn completed_later() {
+    let result = parse(
+        "
+struct point;
+typedef struct point point_t;
+
+int x(point_t *p);
+
+struct point {
+    int x;
+    int y;
+};
+
+int x(point_t *p) {
+    point_t copy;
+    copy.x = p->x;
+    return copy.x + sizeof(point_t);
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn named_struct_typedef() {
+    let result = parse(
+        "
+typedef struct node {
+    int value;
+    struct node *next;
+} node_t;
+
+int test() {
+    struct node first;
+    node_t *ptr = &first;
+    ptr->next = ptr;
+    return ptr->next->value;
+}
+        ",
+    );
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[test]
+fn incomplete_by_value() {
+    let variable = parse(
+        "
+struct data;
+
+int test() {
+    struct data value;
+    return 0;
+}
+        ",
+    );
+    assert!(
+        matches!(variable, Err(SemanticError::IncompleteType { .. })),
+        "{:?}",
+        variable
+    );
+
+    let member = parse(
+        "
+struct node {
+    struct node inner;
+};
+        ",
+    );
+    assert!(
+        matches!(member, Err(SemanticError::IncompleteType { .. })),
+        "{:?}",
+        member
+    );
+
+    let field = parse(
+        "
+struct data;
+
+int test(struct data *ptr) {
+    return ptr->value;
+}
+        ",
+    );
+    assert!(
+        matches!(field, Err(SemanticError::IncompleteType { .. })),
+        "{:?}",
+        field
+    );
+}
+
+#[test]
+fn incomplete_sizeof() {
+    let result = parse(
+        "
+struct data;
+
+unsigned long test() {
+    return sizeof(struct data);
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::IncompleteType { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn pointer_member_chain() {
+    let result = parse(
+        "
+struct node {
+    int value;
+    struct node *next;
+};
+
+int test(struct node first) {
+    first.next->next->value = 3;
+    return first.next->next->value;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+
+    // Both "next" Pointers are loaded before adding the Offset of the next Member, once for
+    // the Write and once for the Read
+    let pointer_loads = read_types(&statements(&func))
+        .into_iter()
+        .filter(|ty| matches!(ty, Type::Pointer(_)))
+        .count();
+    assert_eq!(4, pointer_loads);
+}
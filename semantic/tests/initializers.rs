@@ -0,0 +1,345 @@
+use general::arch::Arch;
+use ir::{Constant, Statement};
+use semantic::{AExpression, AInitializer, AStatement, AType, SemanticError, AAST};
+
+mod common;
+use common::*;
+
+fn function_statements(aast: &AAST, name: &str) -> Vec<AStatement> {
+    let (_, scope) = aast.global_scope.0.function_definitions.get(name).unwrap();
+    scope.statements.clone()
+}
+
+#[test]
+fn struct_zero_fill() {
+    let result = parse(
+        "
+struct Point {
+    int x;
+    int y;
+    int z;
+};
+
+int test() {
+    struct Point p = { 1 };
+    return p.x;
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let statements = function_statements(&result, "test");
+    let entries = match statements.first() {
+        Some(AStatement::Assignment {
+            value: AExpression::InitializerList(list),
+            ..
+        }) => list.entries.clone(),
+        other => panic!("Expected Initializer-List but got {:?}", other),
+    };
+    assert_eq!(3, entries.len());
+    assert!(matches!(entries[0], Some(AInitializer::Single(_))));
+    assert_eq!(None, entries[1]);
+    assert_eq!(None, entries[2]);
+
+    let func = function_ir(result, "test");
+    let ir_statements = common::statements(&func);
+    assert_eq!(vec![(12, 4)], stack_allocs(&ir_statements));
+    assert_eq!(
+        3,
+        ir_statements
+            .iter()
+            .filter(|s| matches!(s, Statement::WriteMemory { .. }))
+            .count()
+    );
+}
+
+#[test]
+fn designated_members_and_indices() {
+    let result = parse(
+        "
+struct Point {
+    int x;
+    int y;
+};
+
+struct Line {
+    struct Point start;
+    struct Point end;
+};
+
+int test() {
+    struct Line l = { .end.y = 2, .start = { 1 } };
+    int arr[4] = { [2] = 5, 6 };
+    return l.end.y + arr[3];
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let statements = function_statements(&result, "test");
+    let line_entries = match statements.first() {
+        Some(AStatement::Assignment {
+            value: AExpression::InitializerList(list),
+            ..
+        }) => list.entries.clone(),
+        other => panic!("Expected Initializer-List but got {:?}", other),
+    };
+    match &line_entries[1] {
+        Some(AInitializer::List(end)) => {
+            assert_eq!(None, end[0]);
+            assert!(matches!(end[1], Some(AInitializer::Single(_))));
+        }
+        other => panic!("Expected List but got {:?}", other),
+    };
+
+    let arr_entries = match statements.get(1) {
+        Some(AStatement::Assignment {
+            value: AExpression::InitializerList(list),
+            ..
+        }) => list.entries.clone(),
+        other => panic!("Expected Initializer-List but got {:?}", other),
+    };
+    assert_eq!(4, arr_entries.len());
+    assert_eq!(None, arr_entries[0]);
+    assert_eq!(None, arr_entries[1]);
+    assert!(matches!(arr_entries[2], Some(AInitializer::Single(_))));
+    assert!(matches!(arr_entries[3], Some(AInitializer::Single(_))));
+}
+
+#[test]
+fn array_size_from_initializer() {
+    let result = parse(
+        "
+int test() {
+    int arr[] = { 1, 2, [4] = 3 };
+    return arr[0];
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = function_statements(&result, "test");
+    match statements.first() {
+        Some(AStatement::Assignment {
+            value: AExpression::InitializerList(list),
+            ..
+        }) => match &list.ty {
+            AType::Array(arr) => assert_eq!(Some(5), arr.size),
+            other => panic!("Expected Array but got {:?}", other),
+        },
+        other => panic!("Expected Initializer-List but got {:?}", other),
+    };
+}
+
+#[test]
+fn brace_elision() {
+    let result = parse(
+        "
+struct Point {
+    int x;
+    int y;
+};
+
+int test() {
+    struct Point points[2] = { 1, 2, 3 };
+    return points[1].x;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = function_statements(&result, "test");
+    let entries = match statements.first() {
+        Some(AStatement::Assignment {
+            value: AExpression::InitializerList(list),
+            ..
+        }) => list.entries.clone(),
+        other => panic!("Expected Initializer-List but got {:?}", other),
+    };
+    match &entries[1] {
+        Some(AInitializer::List(second)) => {
+            assert!(matches!(second[0], Some(AInitializer::Single(_))));
+            assert_eq!(None, second[1]);
+        }
+        other => panic!("Expected List but got {:?}", other),
+    };
+}
+
+#[test]
+fn compound_literal() {
+    let result = parse(
+        "
+struct Point {
+    int x;
+    int y;
+};
+
+int test() {
+    struct Point p = (struct Point){ .y = 3 };
+    return p.y;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![(8, 4)], stack_allocs(&statements(&func)));
+}
+
+#[test]
+fn global_uses_static_data() {
+    let result = parse(
+        "
+int table[3] = { 1, 2, 3 };
+
+int test() {
+    return table[1];
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let global = ir.global.get_statements();
+
+    // The static Data is named after the Global, instead of the temporary Variable that holds
+    // its Address, which would be the same in every Translation-Unit
+    let saved = saved_globals(&global);
+    let allocs = static_allocs(&global);
+    assert_eq!(1, allocs.len());
+    let (name, size, alignment) = &allocs[0];
+    assert!(name.starts_with(&saved[0]), "{:?} for {:?}", name, saved);
+    assert_eq!((12, 4), (*size, *alignment));
+    assert!(stack_allocs(&global).is_empty());
+}
+
+#[test]
+fn string_initializes_char_array() {
+    let result = parse(
+        "
+char global[10] = \"hey\";
+
+int test() {
+    char arr[10] = \"hey\";
+    char exact[3] = \"hey\";
+    char sized[] = \"ab\";
+    char braced[] = { \"xy\" };
+    return arr[0] + exact[0] + sized[0] + braced[0];
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let statements = function_statements(&result, "test");
+    let lists: Vec<_> = statements
+        .iter()
+        .filter_map(|s| match s {
+            AStatement::Assignment {
+                value: AExpression::InitializerList(list),
+                ..
+            } => Some(list.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(4, lists.len());
+
+    // The terminating 0 is only stored if there is enough Space for it
+    let filled: Vec<_> = lists
+        .iter()
+        .map(|l| l.entries.iter().filter(|e| e.is_some()).count())
+        .collect();
+    assert_eq!(vec![4, 3, 3, 3], filled);
+    assert_eq!(10, lists[0].entries.len());
+
+    let sizes: Vec<_> = lists
+        .iter()
+        .map(|l| l.ty.byte_size(&Arch::AArch64))
+        .collect();
+    assert_eq!(vec![10, 3, 3, 3], sizes);
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let global = ir.global.get_statements();
+    let saved = saved_globals(&global);
+    let allocs = static_allocs(&global);
+    assert_eq!(1, allocs.len());
+    assert!(allocs[0].0.starts_with(&saved[0]));
+    assert_eq!(10, allocs[0].1);
+    assert!(assigned_constants(&global).contains(&Constant::I8(104)));
+}
+
+#[test]
+fn string_too_long_for_array() {
+    let result = parse(
+        "
+int test() {
+    char arr[2] = \"hey\";
+    return arr[0];
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::InvalidInitializer { .. })
+    ));
+}
+
+#[test]
+fn string_for_non_char_array() {
+    let result = parse(
+        "
+int test() {
+    int arr[4] = \"hey\";
+    return arr[0];
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::MismatchedTypes { .. })));
+}
+
+#[test]
+fn too_many_values() {
+    let result = parse(
+        "
+int test() {
+    int arr[2] = { 1, 2, 3 };
+    return arr[0];
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::InvalidInitializer { .. })
+    ));
+}
+
+#[test]
+fn unknown_designated_member() {
+    let result = parse(
+        "
+struct Point {
+    int x;
+    int y;
+};
+
+int test() {
+    struct Point p = { .z = 1 };
+    return p.x;
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::UnknownStructField { .. })
+    ));
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{Source, Span};
 use ir::{BasicBlock, FunctionDefinition, Statement, Value, Variable};
 
@@ -52,6 +54,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{Source, Span};
 use ir::{
     BasicBlock, BinaryArithmeticOp, BinaryOp, Constant, Expression, FunctionDefinition, Operand,
@@ -74,6 +76,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t1_var),
                 value: Operand::Variable(t4_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: t2_var.clone(),
@@ -101,6 +104,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t3_var),
                 value: Operand::Variable(t5_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -121,6 +125,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -231,6 +237,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t1_var),
                 value: Operand::Variable(t18_var),
+                volatile: false,
             },
             // This is related to the first Assign + Update
             Statement::Assignment {
@@ -254,6 +261,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t3_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             Statement::Assignment {
@@ -277,6 +285,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t6_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             Statement::Assignment {
@@ -305,6 +314,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t9_var),
                 value: Operand::Variable(t19_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: tmp1_var.clone(),
@@ -333,6 +343,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t11_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             Statement::Assignment {
@@ -356,6 +367,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t14_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             Statement::Assignment {
@@ -384,6 +396,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t17_var),
                 value: Operand::Variable(t20_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: tmp2_var.clone(),
@@ -409,6 +422,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{Source, Span};
 use ir::{
     BasicBlock, BinaryLogicOp, BinaryOp, Constant, Expression, Operand, PhiEntry, Statement, Type,
@@ -140,6 +142,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     assert_eq!(expected, result);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{arch::Arch, Source, Span};
 use ir::{
     BasicBlock, Constant, Expression, FunctionDefinition, Operand, PhiEntry, Program, Statement,
@@ -121,6 +123,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -275,6 +279,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use std::path::{Path, PathBuf};
 
 use general::{arch::Arch, Source, Span};
@@ -89,6 +91,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -256,6 +260,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -366,6 +372,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -477,6 +485,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{arch::Arch, Source, Span};
 use ir::{
     BasicBlock, Constant, Expression, FunctionDefinition, Operand, Statement, Type, Value,
@@ -37,6 +39,7 @@ void test() {
                     name: "other".to_string(),
                     arguments: vec![],
                     return_ty: Type::I32,
+                    variadic: None,
                 }),
             },
             Statement::Assignment {
@@ -62,6 +65,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -123,6 +128,7 @@ void test() {
                     name: "other".to_string(),
                     return_ty: Type::I32,
                     arguments: vec![Operand::Variable(x_var), Operand::Variable(t0_var)],
+                    variadic: None,
                 }),
             },
             Statement::Assignment {
@@ -148,6 +154,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -203,6 +211,7 @@ void test() {
                     name: "other".to_string(),
                     return_ty: Type::I32,
                     arguments: vec![Operand::Variable(x_var)],
+                    variadic: None,
                 }),
             },
             Statement::Assignment {
@@ -232,6 +241,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -267,6 +278,7 @@ void test() {
             Statement::Call {
                 name: "other".to_string(),
                 arguments: vec![],
+                variadic: None,
             },
             Statement::Return(None),
         ],
@@ -286,6 +298,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
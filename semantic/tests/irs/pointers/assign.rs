@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{arch::Arch, Source, Span};
 use ir::{
     BasicBlock, Constant, Expression, FunctionDefinition, Operand, Program, Statement, Type, Value,
@@ -55,6 +57,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -126,6 +130,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -217,6 +223,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{arch::Arch, Source, Span};
 use ir::{
     BasicBlock, Constant, Expression, FunctionDefinition, Operand, Program, Statement, Type, Value,
@@ -52,6 +54,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(x_var),
                 value: Operand::Variable(t0_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -72,6 +75,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -141,6 +146,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(x_var),
                 value: Operand::Variable(t0_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: y1_var,
@@ -165,6 +171,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{arch::Arch, Source, Span};
 use ir::{
     BasicBlock, BinaryArithmeticOp, BinaryOp, Constant, Expression, FunctionDefinition, Operand,
@@ -81,6 +83,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(Arch::X86_64);
@@ -158,6 +162,8 @@ void test(int arg) {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = aast.convert_to_ir(Arch::X86_64);
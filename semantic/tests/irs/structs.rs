@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{Source, Span};
 use ir::{
     BasicBlock, BinaryArithmeticOp, BinaryOp, Constant, Expression, FunctionDefinition, Operand,
@@ -64,6 +66,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -141,6 +145,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t0_var),
                 value: Operand::Variable(t4_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: t1_var.clone(),
@@ -160,6 +165,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t1_var),
                 value: Operand::Variable(t5_var),
+                volatile: false,
             },
             Statement::Assignment {
                 target: t2_var.clone(),
@@ -179,6 +185,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t2_var),
                 value: Operand::Variable(t3_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -199,6 +206,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -271,6 +280,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t0_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             // Cast the Read Value to an I64
@@ -310,6 +320,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t4_var),
                 value: Operand::Variable(t5_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -330,6 +341,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -423,6 +436,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t2_var),
                 value: Operand::Variable(t3_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -443,6 +457,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -531,6 +547,7 @@ void test() {
                 value: Value::Expression(Expression::ReadMemory {
                     address: Operand::Variable(t2_var),
                     read_ty: Type::I32,
+                    volatile: false,
                 }),
             },
             Statement::SaveVariable { var: tmp_var },
@@ -553,6 +570,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -646,6 +665,7 @@ void test() {
             Statement::WriteMemory {
                 target: Operand::Variable(t2_var),
                 value: ir::Operand::Variable(t3_var),
+                volatile: false,
             },
             Statement::Return(None),
         ],
@@ -666,6 +686,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use general::{Source, Span};
 use ir::{
     BasicBlock, Constant, Expression, Operand, Statement, Type, UnaryArithmeticOp, UnaryOp, Value,
@@ -69,6 +71,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
@@ -148,6 +152,8 @@ void test() {
         )]
         .into_iter()
         .collect(),
+        constants: HashMap::new(),
+        global_alignments: HashMap::new(),
     };
 
     let result = input.convert_to_ir(general::arch::Arch::X86_64);
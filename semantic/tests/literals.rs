@@ -0,0 +1,281 @@
+use general::{arch::Arch, Source, Span, SpanData};
+use ir::{Constant, ConstantData, UnaryArithmeticOp, UnaryOp};
+use semantic::{APrimitive, AStatement, AType, Array, Literal, SemanticError};
+
+mod common;
+use common::*;
+
+fn number_type(raw: &str) -> Result<APrimitive, SemanticError> {
+    let source = Source::new("test", raw);
+    let span: Span = source.into();
+
+    match Literal::parse_number(SpanData {
+        span,
+        data: raw.to_string(),
+    })? {
+        Literal::Integer(_, ty) | Literal::FloatingPoint(_, ty) => Ok(ty),
+        other => panic!("Expected a numeric Literal, got {:?}", other),
+    }
+}
+
+#[test]
+fn integer_constant_types() {
+    assert_eq!(Ok(APrimitive::Int), number_type("2147483647"));
+    assert_eq!(Ok(APrimitive::LongInt), number_type("2147483648"));
+    assert_eq!(Ok(APrimitive::UnsignedInt), number_type("0x80000000"));
+    assert_eq!(Ok(APrimitive::UnsignedInt), number_type("0xFFFF0000u"));
+    assert_eq!(Ok(APrimitive::LongInt), number_type("1L"));
+    assert_eq!(Ok(APrimitive::UnsignedLongInt), number_type("1ul"));
+    assert_eq!(Ok(APrimitive::LongLongInt), number_type("017LL"));
+    assert_eq!(Ok(APrimitive::Int), number_type("0b101"));
+    assert_eq!(
+        Ok(APrimitive::UnsignedLongInt),
+        number_type("0xFFFFFFFFFFFFFFFF")
+    );
+}
+
+#[test]
+fn floating_constant_types() {
+    assert_eq!(Ok(APrimitive::Double), number_type("1.5"));
+    assert_eq!(Ok(APrimitive::Float), number_type("1.5f"));
+    assert_eq!(Ok(APrimitive::LongDouble), number_type("1e3L"));
+    assert_eq!(Ok(APrimitive::Double), number_type("0x1.8p1"));
+}
+
+#[test]
+fn invalid_numeric_constants() {
+    assert!(matches!(
+        number_type("18446744073709551616"),
+        Err(SemanticError::NumberOverflow { .. })
+    ));
+    assert!(matches!(
+        number_type("9223372036854775808"),
+        Err(SemanticError::NumberOverflow { .. })
+    ));
+    assert!(matches!(
+        number_type("09"),
+        Err(SemanticError::InvalidNumber { .. })
+    ));
+    assert!(matches!(
+        number_type("1uu"),
+        Err(SemanticError::InvalidNumber { .. })
+    ));
+}
+
+#[test]
+fn unsigned_hex_constant() {
+    let result = parse(
+        "
+unsigned int test() {
+    return 0xFFFF0000u;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+
+    assert!(assigned_constants(&statements).contains(&Constant::U32(4294901760)));
+    assert!(casts(&statements).is_empty());
+}
+
+#[test]
+fn negative_literal() {
+    let result = parse(
+        "
+int test() {
+    int i = -1;
+    return i;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let statements = statements(&func);
+
+    assert!(assigned_constants(&statements).contains(&Constant::I32(-1)));
+    assert!(!has_unary(
+        &statements,
+        UnaryOp::Arith(UnaryArithmeticOp::Negate)
+    ));
+}
+
+#[test]
+fn char_constant_as_array_size() {
+    let result = parse(
+        "
+int values['c' - 'a' + '\\x01'];
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    match result.global_scope.0.statements.first() {
+        Some(AStatement::DeclareVar { ty, .. }) => {
+            assert_eq!(
+                AType::Array(Array {
+                    size: Some(3),
+                    length: None,
+                    ty: Box::new(AType::Primitve(APrimitive::Int)),
+                }),
+                ty
+            );
+        }
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn char_constant_in_switch() {
+    let result = parse(
+        "
+int test(char c) {
+    switch (c) {
+        case 'a':
+            return 1;
+        case '\\n':
+            return 2;
+    }
+    return 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    assert!(ir.functions.contains_key("test"));
+}
+
+#[test]
+fn wide_string_literal() {
+    let result = parse(
+        "
+int test() {
+    int* text = L\"ab\";
+    return text[1];
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let statements = statements(ir.functions.get("test").unwrap());
+
+    assert_eq!(1, constant_addresses(&statements).len());
+    assert!(stack_allocs(&statements).is_empty());
+
+    // Two Characters and the terminating 0, each stored in 4 Bytes
+    let constants: Vec<_> = ir.constants.values().collect();
+    assert_eq!(
+        vec![&ConstantData::new(
+            vec![97, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0],
+            4
+        )],
+        constants
+    );
+}
+
+#[test]
+fn wide_string_literal_big_endian() {
+    let result = parse(
+        "
+int test() {
+    int* text = L\"ab\";
+    return text[1];
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::SH4A);
+
+    let constants: Vec<_> = ir.constants.values().collect();
+    assert_eq!(
+        vec![&ConstantData::new(
+            vec![0, 0, 0, 97, 0, 0, 0, 98, 0, 0, 0, 0],
+            4
+        )],
+        constants
+    );
+}
+
+#[test]
+fn string_literals_deduplicated() {
+    let result = parse(
+        "
+char* first() {
+    return \"test\";
+}
+
+int main() {
+    char* own = \"test\";
+    char* other = \"other\";
+    char* result = first();
+    return own[0] + result[0] + other[0];
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    dbg!(&ir.constants);
+
+    assert_eq!(2, ir.constants.len());
+    assert!(ir
+        .constants
+        .values()
+        .any(|c| c == &ConstantData::new(b"test\0".to_vec(), 1)));
+    assert!(ir
+        .constants
+        .values()
+        .any(|c| c == &ConstantData::new(b"other\0".to_vec(), 1)));
+
+    // Both Functions use the same Data for the Literal
+    let test_name = ir
+        .constants
+        .iter()
+        .find(|(_, c)| c.content == b"test\0")
+        .map(|(name, _)| name.clone())
+        .unwrap();
+    for name in ["first", "main"] {
+        let statements = statements(ir.functions.get(name).unwrap());
+        assert!(constant_addresses(&statements).contains(&test_name));
+    }
+}
+
+#[test]
+fn string_literal_names_across_units() {
+    let first = parse("char* first() { return \"shared\"; }")
+        .unwrap()
+        .convert_to_ir(Arch::AArch64);
+    let second = parse("char* second() { char* own = \"own\"; return \"shared\"; }")
+        .unwrap()
+        .convert_to_ir(Arch::AArch64);
+
+    // The Names only depend on the Content, so the Data of both Units can be merged
+    let name_of = |ir: &ir::Program, content: &[u8]| {
+        ir.constants
+            .iter()
+            .find(|(_, c)| c.content == content)
+            .map(|(name, _)| name.clone())
+            .unwrap()
+    };
+    assert_eq!(name_of(&first, b"shared\0"), name_of(&second, b"shared\0"));
+    assert_ne!(name_of(&first, b"shared\0"), name_of(&second, b"own\0"));
+}
+
+#[test]
+fn mismatched_string_literal() {
+    let result = parse(
+        "
+int test() {
+    char* text = L\"ab\";
+    return 0;
+}
+        ",
+    );
+
+    assert!(result.is_err());
+}
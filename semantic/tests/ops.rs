@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use general::{Source, Span, SpanData};
 use semantic::{
     AAssignTarget, AExpression, AFunctionArg, AOperator, APrimitive, ARootScope, AScope,
-    AStatement, AType, ArithemticOp, FunctionDeclaration, AAST,
+    AStatement, AType, ArithemticOp, FunctionDeclaration, Linkage, AAST,
 };
 use syntax::Identifier;
 
@@ -28,6 +28,7 @@ void test(int arg_1, int arg_2) {
                 (
                     FunctionDeclaration {
                         var_args: false,
+                        linkage: Linkage::External,
                         return_ty: AType::Primitve(APrimitive::Void),
                         arguments: vec![
                             SpanData {
@@ -101,6 +102,7 @@ void test(int arg_1, int arg_2) {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
@@ -130,6 +132,7 @@ void test(int arg_1, float arg_2) {
                 (
                     FunctionDeclaration {
                         var_args: false,
+                        linkage: Linkage::External,
                         return_ty: AType::Primitve(APrimitive::Void),
                         arguments: vec![
                             SpanData {
@@ -206,6 +209,7 @@ void test(int arg_1, float arg_2) {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
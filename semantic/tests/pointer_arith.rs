@@ -0,0 +1,296 @@
+use ir::{
+    BinaryArithmeticOp, BinaryBitwiseOp, BinaryOp, Constant, Expression, Operand, Statement, Type,
+    UnaryArithmeticOp, UnaryOp,
+};
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+fn arith(statements: &[Statement], op: BinaryArithmeticOp) -> Vec<(Operand, Operand)> {
+    binary_operands(statements, BinaryOp::Arith(op))
+}
+
+/// The Operands of a binary Operation on two I64 Constants
+fn constants_i64(left: i64, right: i64) -> (Operand, Operand) {
+    (
+        Operand::Constant(Constant::I64(left)),
+        Operand::Constant(Constant::I64(right)),
+    )
+}
+
+#[test]
+fn offset_scaled_by_element_size() {
+    let result = parse(
+        "
+int *test(int *p, long i) {
+    return p + i;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(
+        right_constants(&statements, BinaryOp::Arith(BinaryArithmeticOp::Multiply))
+            .contains(&Constant::I64(4))
+    );
+    assert!(!arith(&statements, BinaryArithmeticOp::Add).is_empty());
+}
+
+#[test]
+fn integer_plus_pointer() {
+    let result = parse(
+        "
+struct point {
+    int x;
+    int y;
+    int z;
+};
+
+struct point *test(struct point *p, char i) {
+    return 2 + p - i;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(arith(&statements, BinaryArithmeticOp::Multiply).contains(&constants_i64(2, 12)));
+    assert!(casts(&statements).contains(&(Type::I8, Type::I64)));
+    assert!(!arith(&statements, BinaryArithmeticOp::Sub).is_empty());
+}
+
+#[test]
+fn pointer_difference() {
+    let result = parse(
+        "
+long test(int *a, int *b) {
+    return a - b;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    let pointer_casts = casts(&statements)
+        .into_iter()
+        .filter(|cast| *cast == (Type::Pointer(Box::new(Type::I32)), Type::I64))
+        .count();
+    assert_eq!(2, pointer_casts);
+    assert!(
+        right_constants(&statements, BinaryOp::Bitwise(BinaryBitwiseOp::ShiftRight))
+            .contains(&Constant::I64(2))
+    );
+}
+
+#[test]
+fn pointer_difference_of_structs() {
+    let result = parse(
+        "
+struct point {
+    int x;
+    int y;
+    int z;
+};
+
+long test(struct point *a, struct point *b) {
+    return a - b;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(
+        right_constants(&statements, BinaryOp::Arith(BinaryArithmeticOp::Divide))
+            .contains(&Constant::I64(12))
+    );
+}
+
+#[test]
+fn pointer_difference_mismatched() {
+    let result = parse(
+        "
+long test(int *a, char *b) {
+    return a - b;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::MismatchedOperationTypes { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn void_pointer_arithmetic() {
+    let result = parse(
+        "
+void *test(void *p) {
+    return p + 1;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::MismatchedOperationTypes { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn array_decays() {
+    let result = parse(
+        "
+long test() {
+    int values[4];
+    int *end = values + 4;
+    return end - values;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(arith(&statements, BinaryArithmeticOp::Multiply).contains(&constants_i64(4, 4)));
+    assert!(
+        right_constants(&statements, BinaryOp::Bitwise(BinaryBitwiseOp::ShiftRight))
+            .contains(&Constant::I64(2))
+    );
+}
+
+#[test]
+fn dereference_offset() {
+    let result = parse(
+        "
+char test(char *p) {
+    return *(p + 1);
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert_eq!(vec![Type::I8], read_types(&statements));
+}
+
+#[test]
+fn compare_with_null() {
+    let result = parse(
+        "
+int test(int *p) {
+    return p == 0;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(expressions(&statements).iter().any(|exp| matches!(
+        exp,
+        Expression::Cast {
+            base: Operand::Constant(Constant::I32(0)),
+            target: Type::Pointer(inner),
+        } if **inner == Type::I32
+    )));
+}
+
+#[test]
+fn index_array_of_structs() {
+    let result = parse(
+        "
+struct point {
+    int x;
+    int y;
+};
+
+int test(struct point *points) {
+    return points[2].y;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(arith(&statements, BinaryArithmeticOp::Multiply).contains(&constants_i64(2, 8)));
+    assert!(
+        right_constants(&statements, BinaryOp::Arith(BinaryArithmeticOp::Add))
+            .contains(&Constant::I64(4))
+    );
+}
+
+#[test]
+fn index_nested_arrays() {
+    let result = parse(
+        "
+struct row {
+    int cells[4];
+};
+
+int test(struct row *rows) {
+    return rows[1].cells[2];
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    let multiplies = arith(&statements, BinaryArithmeticOp::Multiply);
+    assert!(multiplies.contains(&constants_i64(1, 16)));
+    assert!(multiplies.contains(&constants_i64(2, 4)));
+}
+
+#[test]
+fn index_array_of_pointers() {
+    let result = parse(
+        "
+int test(int **rows) {
+    return rows[1][2];
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert_eq!(
+        vec![Type::Pointer(Box::new(Type::I32)), Type::I32],
+        read_types(&statements)
+    );
+}
+
+#[test]
+fn increment_and_decrement_scaled() {
+    let result = parse(
+        "
+double *test(double *p) {
+    p++;
+    ++p;
+    p--;
+    --p;
+    return p;
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "test"));
+    assert!(!has_unary(
+        &statements,
+        UnaryOp::Arith(UnaryArithmeticOp::Increment)
+    ));
+    assert!(!has_unary(
+        &statements,
+        UnaryOp::Arith(UnaryArithmeticOp::Decrement)
+    ));
+    for op in [BinaryArithmeticOp::Add, BinaryArithmeticOp::Sub] {
+        let steps = right_constants(&statements, BinaryOp::Arith(op))
+            .into_iter()
+            .filter(|con| *con == Constant::I64(8))
+            .count();
+        assert_eq!(2, steps);
+    }
+}
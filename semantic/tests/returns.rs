@@ -72,3 +72,95 @@ int test() {
 
     assert!(result.is_ok());
 }
+
+#[test]
+fn switch_returning_in_every_case() {
+    let content = "
+int test(int x) {
+    switch (x) {
+        case 1:
+            return 10;
+        case 2:
+        default:
+            return 0;
+    }
+}
+        ";
+    let source = Source::new("test", content);
+    let input_span: Span = source.into();
+    let tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(input_ast).unwrap();
+
+    let ir = result.convert_to_ir(general::arch::Arch::AArch64);
+    let func = ir.functions.get("test").unwrap();
+    println!("{}", ir::text_rep::generate_text_rep(func));
+
+    func.verify();
+
+    // The unreachable End of the Function still returns a Value of the Return-Type
+    #[allow(deprecated)]
+    let returns: Vec<_> = func
+        .block
+        .block_iter()
+        .flat_map(|b| b.get_statements())
+        .filter_map(|s| match s {
+            ir::Statement::Return(value) => Some(value),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(3, returns.len());
+    assert!(returns
+        .iter()
+        .all(|value| matches!(value, Some(var) if var.ty == ir::Type::I32)));
+}
+
+#[test]
+fn switch_without_default_is_missing_return() {
+    let content = "
+int test(int x) {
+    switch (x) {
+        case 1:
+            return 10;
+        case 2:
+            return 0;
+    }
+}
+        ";
+    let source = Source::new("test", content);
+    let input_span: Span = source.into();
+    let tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(input_ast);
+    dbg!(&result);
+
+    assert_eq!(Err(semantic::SemanticError::MissingReturn {}), result);
+}
+
+#[test]
+fn switch_breaking_is_missing_return() {
+    let content = "
+int test(int x) {
+    switch (x) {
+        case 1:
+            if (x) {
+                break;
+            }
+            return 10;
+        default:
+            return 0;
+    }
+}
+        ";
+    let source = Source::new("test", content);
+    let input_span: Span = source.into();
+    let tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(input_ast);
+    dbg!(&result);
+
+    assert_eq!(Err(semantic::SemanticError::MissingReturn {}), result);
+}
@@ -0,0 +1,128 @@
+use ir::{BinaryLogicOp, BinaryOp, Constant, Expression, FunctionDefinition, Statement, Value};
+
+mod common;
+use common::*;
+
+/// The Statements of the Blocks in the Function, skipping the Block that only receives the
+/// Arguments
+fn blocks(func: &FunctionDefinition) -> Vec<Vec<Statement>> {
+    block_statements(func).into_iter().skip(1).collect()
+}
+
+fn reads_memory(block: &[Statement]) -> bool {
+    !read_types(block).is_empty()
+}
+
+/// The Variables in the Block, that are defined by a Phi-Node
+fn phis(block: &[Statement]) -> Vec<ir::Variable> {
+    assignments(block)
+        .into_iter()
+        .filter(|(_, value)| matches!(value, Value::Phi { .. }))
+        .map(|(target, _)| target)
+        .collect()
+}
+
+#[test]
+fn and() {
+    let result = parse(
+        "
+int test(int *value) {
+    return value != ((int*) 0) && *value == 3;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = blocks(&function_ir(result, "test"));
+    assert_eq!(3, blocks.len());
+    // The Pointer is only read, if it is not null
+    assert!(!reads_memory(&blocks[0]));
+    assert!(assigned_constants(&blocks[0]).contains(&Constant::I32(0)));
+    // The Order of the following Blocks is not fixed
+    let following = &blocks[1..];
+    assert_eq!(1, following.iter().filter(|b| reads_memory(b)).count());
+    assert!(following
+        .iter()
+        .any(|b| !reads_memory(b) && !phis(b).is_empty()));
+}
+
+#[test]
+fn or() {
+    let result = parse(
+        "
+int test(int *value) {
+    return value == ((int*) 0) || *value == 3;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = blocks(&function_ir(result, "test"));
+    assert_eq!(3, blocks.len());
+    assert!(!reads_memory(&blocks[0]));
+    assert!(assigned_constants(&blocks[0]).contains(&Constant::I32(1)));
+    // The Order of the following Blocks is not fixed
+    let following = &blocks[1..];
+    assert_eq!(1, following.iter().filter(|b| reads_memory(b)).count());
+    assert!(following
+        .iter()
+        .any(|b| !reads_memory(b) && !phis(b).is_empty()));
+}
+
+#[test]
+fn conditional() {
+    let result = parse(
+        "
+int test(int *value) {
+    return value ? *value : 7;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = blocks(&function_ir(result, "test"));
+    assert_eq!(4, blocks.len());
+    assert!(!reads_memory(&blocks[0]));
+    assert_eq!(1, blocks.iter().filter(|b| reads_memory(b)).count());
+    assert!(blocks.iter().any(|b| !phis(b).is_empty()));
+}
+
+#[test]
+fn while_condition() {
+    let result = parse(
+        "
+int test(int n) {
+    int i = 0;
+    while (i < 10 || n == 0) {
+        i = i + 1;
+    }
+    return i;
+}
+        ",
+    )
+    .unwrap();
+
+    let blocks = blocks(&function_ir(result, "test"));
+    // The Start of the Loop always jumps into the Condition, which is split into multiple Blocks
+    let start = blocks
+        .iter()
+        .find(|b| assigned_constants(b).contains(&Constant::I64(1)))
+        .unwrap();
+    assert!(!expressions(start).iter().any(|exp| matches!(
+        exp,
+        Expression::BinaryOp {
+            op: BinaryOp::Logic(_),
+            ..
+        }
+    )));
+    assert!(blocks.iter().any(|b| {
+        !binary_operands(b, BinaryOp::Logic(BinaryLogicOp::Equal)).is_empty()
+            && binary_operands(b, BinaryOp::Logic(BinaryLogicOp::Less)).is_empty()
+    }));
+    // The combined Condition is merged in a Phi-Node and then used for the Jump into the Body
+    assert!(blocks.iter().any(|b| {
+        let phis = phis(b);
+        b.iter()
+            .any(|s| matches!(s, Statement::JumpTrue(var, ..) if phis.contains(var)))
+    }));
+}
@@ -0,0 +1,174 @@
+use general::arch::Arch;
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+#[test]
+fn passing_assertion() {
+    let result = parse(
+        "
+_Static_assert(2 * 3 == 6, \"math is broken\");
+
+int test() {
+    _Static_assert(1, \"always true\");
+    return 0;
+}
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::AArch64).is_ok());
+    aast.convert_to_ir(Arch::AArch64);
+}
+
+#[test]
+fn failing_assertion() {
+    let result = parse(
+        "
+int test() {
+    _Static_assert(1 + 1 == 3, \"one plus one\");
+    return 0;
+}
+        ",
+    );
+
+    match result {
+        Err(SemanticError::FailedStaticAssertion { message, .. }) => {
+            assert_eq!("one plus one", message.data);
+        }
+        other => panic!("Expected a failed Static-Assertion, got {:?}", other),
+    };
+}
+
+#[test]
+fn conditional_assertion() {
+    let result = parse(
+        "
+_Static_assert(1 ? 1 : 0, \"selects the first\");
+_Static_assert(0 ? 0 : 1, \"selects the second\");
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::AArch64).is_ok());
+
+    let result = parse(
+        "
+_Static_assert(0 ? 1 : 0, \"selects the zero\");
+        ",
+    );
+
+    match result {
+        Err(SemanticError::FailedStaticAssertion { message, .. }) => {
+            assert_eq!("selects the zero", message.data);
+        }
+        other => panic!("Expected a failed Static-Assertion, got {:?}", other),
+    };
+}
+
+#[test]
+fn non_constant_assertion() {
+    let result = parse(
+        "
+int test(int x) {
+    _Static_assert(x, \"not constant\");
+    return 0;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::InvalidStaticAssertion { .. })),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn struct_layout() {
+    let result = parse(
+        "
+struct header {
+    char kind;
+    int length;
+    long address;
+};
+
+_Static_assert(sizeof(struct header) == 16, \"header layout\");
+_Static_assert(_Alignof(struct header) == 8, \"header alignment\");
+
+int test() {
+    _Static_assert(sizeof(long) == 8, \"long size\");
+    return 0;
+}
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::AArch64).is_ok());
+    assert!(aast.check_static_assertions(&Arch::X86_64).is_ok());
+}
+
+#[test]
+fn struct_layout_depends_on_target() {
+    let result = parse(
+        "
+struct header {
+    char kind;
+    int length;
+    long address;
+};
+
+_Static_assert(sizeof(struct header) == 16, \"header layout\");
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::AArch64).is_ok());
+
+    match aast.check_static_assertions(&Arch::SH4A) {
+        Err(SemanticError::FailedStaticAssertion { message, .. }) => {
+            assert_eq!("header layout", message.data);
+        }
+        other => panic!("Expected a failed Static-Assertion, got {:?}", other),
+    };
+}
+
+#[test]
+fn unsigned_arithmetic() {
+    let result = parse(
+        "
+_Static_assert(0xFFFFFFFFu + 1 == 0, \"unsigned int wraps around\");
+_Static_assert((1u << 31) * 2 == 0, \"shifted unsigned int wraps around\");
+_Static_assert(0xFFFFFFFFFFFFFFFFu > 1, \"unsigned long long compares unsigned\");
+_Static_assert((-1 < 1ul) == 0, \"signed operand is converted to unsigned long\");
+_Static_assert(-1u / 2 == 0x7FFFFFFF, \"unsigned int divides unsigned\");
+_Static_assert(-1u >> 31 == 1, \"unsigned int shifts logically\");
+_Static_assert(~0u == 0xFFFFFFFFu, \"complement of unsigned int\");
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::AArch64).is_ok());
+    assert!(aast.check_static_assertions(&Arch::SH4A).is_ok());
+}
+
+#[test]
+fn unsigned_long_depends_on_target() {
+    let result = parse(
+        "
+_Static_assert(0xFFFFFFFFul + 1 == 0, \"unsigned long is 32-Bit\");
+        ",
+    );
+
+    let aast = result.unwrap();
+    assert!(aast.check_static_assertions(&Arch::SH4A).is_ok());
+
+    match aast.check_static_assertions(&Arch::AArch64) {
+        Err(SemanticError::FailedStaticAssertion { message, .. }) => {
+            assert_eq!("unsigned long is 32-Bit", message.data);
+        }
+        other => panic!("Expected a failed Static-Assertion, got {:?}", other),
+    };
+}
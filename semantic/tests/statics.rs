@@ -0,0 +1,201 @@
+use general::arch::Arch;
+use ir::Expression;
+use semantic::{AStatement, Linkage, SemanticError};
+
+mod common;
+use common::*;
+
+#[test]
+fn static_local_is_global() {
+    let result = parse(
+        "
+int counter() {
+    static int count = 0;
+    count = count + 1;
+    return count;
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let (_, func_scope) = result
+        .global_scope
+        .0
+        .function_definitions
+        .get("counter")
+        .unwrap();
+    assert!(matches!(
+        func_scope.statements.first(),
+        Some(AStatement::StaticVariable { .. })
+    ));
+
+    let mut ir = result.convert_to_ir(Arch::AArch64);
+
+    // The Variable is initialized once, like a Global, and then used by the Function
+    let globals = saved_globals(&ir.global.get_statements());
+    assert_eq!(1, globals.len());
+
+    let func = ir.functions.remove("counter").unwrap();
+    println!("{}", ir::text_rep::generate_text_rep(&func));
+    let statements = statements(&func);
+    let mut read = read_globals(&statements);
+    read.dedup();
+    assert_eq!(globals, read);
+    assert_eq!(globals, saved_globals(&statements));
+}
+
+#[test]
+fn static_function_internal_linkage() {
+    let content = "
+static int helper() {
+    return 1;
+}
+
+int test() {
+    return helper();
+}
+        ";
+
+    let first = parse_source("first.c", content).unwrap();
+    let (func_dec, _) = first
+        .global_scope
+        .0
+        .function_definitions
+        .get("helper")
+        .unwrap();
+    assert_eq!(Linkage::Internal, func_dec.linkage);
+
+    let second = parse_source("second.c", content).unwrap();
+
+    let first_ir = first.convert_to_ir(Arch::AArch64);
+    let second_ir = second.convert_to_ir(Arch::AArch64);
+
+    // Only the Function with external Linkage is shared between both
+    let shared: Vec<_> = first_ir
+        .functions
+        .keys()
+        .filter(|name| second_ir.functions.contains_key(*name))
+        .collect();
+    assert_eq!(vec!["test"], shared);
+
+    // The Function with internal Linkage is called under its unique Name
+    let helper_name = first_ir
+        .functions
+        .keys()
+        .find(|name| !second_ir.functions.contains_key(*name))
+        .unwrap();
+    let test_statements = statements(first_ir.functions.get("test").unwrap());
+    let called: Vec<_> = expressions(&test_statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::FunctionCall { name, .. } => Some(name),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![helper_name.clone()], called);
+}
+
+#[test]
+fn static_function_from_shared_header() {
+    let content = "
+static int helper() {
+    return 1;
+}
+        ";
+
+    // Both Translation-Units get the Function from the same Header
+    let first = parse_source("helper.h", content).unwrap();
+    let second = parse_source("helper.h", content).unwrap();
+
+    let first_names: Vec<_> = first
+        .defined_functions("first.c")
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    let second_names: Vec<_> = second
+        .defined_functions("second.c")
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(1, first_names.len());
+    assert_eq!(1, second_names.len());
+    assert_ne!(first_names, second_names);
+
+    let first_ir = first.convert_unit_to_ir("first.c", Arch::AArch64);
+    assert!(first_ir.functions.contains_key(&first_names[0]));
+}
+
+#[test]
+fn static_declaration_keeps_linkage() {
+    let result = parse(
+        "
+static int helper();
+
+int helper() {
+    return 1;
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let (func_dec, _) = result
+        .global_scope
+        .0
+        .function_definitions
+        .get("helper")
+        .unwrap();
+    assert_eq!(Linkage::Internal, func_dec.linkage);
+}
+
+#[test]
+fn static_local_non_constant_initializer() {
+    let result = parse(
+        "
+int first(int a) {
+    static int x = a;
+    return x;
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::NonConstantInitializer { .. })
+    ));
+}
+
+#[test]
+fn static_local_initialized_from_static() {
+    let result = parse(
+        "
+int global = 2;
+
+int first() {
+    static int x = 1;
+    static int *ptr = &x;
+    static int y = global;
+    return *ptr + y;
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let global_statements = ir.global.get_statements();
+
+    // The Globals are initialized in Order, so the Pointer stores the Address of x
+    let globals = saved_globals(&global_statements);
+    assert_eq!(4, globals.len());
+    let addresses: Vec<_> = expressions(&global_statements)
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::GlobalAddress { name } => Some(name),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![globals[1].clone()], addresses);
+}
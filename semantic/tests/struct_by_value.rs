@@ -0,0 +1,66 @@
+use general::arch::Arch;
+
+mod common;
+use common::*;
+
+#[test]
+fn struct_argument_and_return() {
+    let result = parse(
+        "
+struct vec2 {
+    float x;
+    float y;
+};
+
+struct vec2 twice(struct vec2 v) {
+    struct vec2 result;
+    result.x = v.x + v.x;
+    result.y = v.y + v.y;
+    return result;
+}
+        ",
+    )
+    .unwrap();
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    let func = ir.functions.get("twice").unwrap();
+
+    let expected = ir::Type::Struct {
+        members: vec![
+            ("x".to_string(), ir::Type::Float),
+            ("y".to_string(), ir::Type::Float),
+        ],
+        size: 8,
+        alignment: 4,
+    };
+    assert_eq!(expected, func.return_ty);
+    assert_eq!(1, func.arguments.len());
+    assert_eq!(expected, func.arguments[0].1);
+}
+
+#[test]
+fn struct_argument_copied() {
+    let result = parse(
+        "
+struct rect {
+    int x;
+    int y;
+    int w;
+    int h;
+};
+
+int area(struct rect r);
+
+int test() {
+    struct rect r = {1, 2, 3, 4};
+    return area(r);
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+
+    // The Callee is allowed to modify its Argument, so the Caller hands it a copy
+    assert_eq!(vec![(16, 4), (16, 4)], stack_allocs(&statements(&func)));
+}
@@ -1,10 +1,14 @@
-use general::{Source, Span, SpanData};
+use general::{arch::Arch, Source, Span, SpanData};
+use ir::{BinaryArithmeticOp, BinaryOp, Constant};
 use semantic::{
     AAssignTarget, AExpression, APrimitive, ARootScope, AScope, AStatement, AType, Literal,
     SemanticError, StructDef, StructFieldTarget, StructMember, AAST,
 };
 use syntax::Identifier;
 
+mod common;
+use common::*;
+
 #[test]
 fn assign_field() {
     let content = "
@@ -21,12 +25,15 @@ var.first = 1;
     let input_ast = syntax::parse(tokens).unwrap();
 
     let struct_def = StructDef {
+        name: Some("tmp".to_string()),
         members: vec![StructMember {
-            name: Identifier(SpanData {
+            name: Some(Identifier(SpanData {
                 span: Span::new_source(source.clone(), 22..27),
                 data: "first".to_string(),
-            }),
+            })),
             ty: AType::Primitve(APrimitive::Int),
+            bit_width: None,
+            alignment: None,
         }],
     };
 
@@ -43,6 +50,7 @@ var.first = 1;
                         def: struct_def.clone(),
                         area: Span::new_source(source.clone(), 12..31),
                     },
+                    alignment: None,
                 },
                 AStatement::Assignment {
                     target: AAssignTarget::StructField(StructFieldTarget {
@@ -69,17 +77,18 @@ var.first = 1;
                             data: AType::Primitve(APrimitive::Int),
                         },
                     }),
-                    value: AExpression::Cast {
-                        base: Box::new(AExpression::Literal(Literal::Integer(SpanData {
+                    value: AExpression::Literal(Literal::Integer(
+                        SpanData {
                             span: Span::new_source(source, 61..62),
                             data: 1,
-                        }))),
-                        target: AType::Primitve(APrimitive::Int),
-                    },
+                        },
+                        APrimitive::Int,
+                    )),
                 },
             ],
             function_definitions: vec![].into_iter().collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
@@ -104,12 +113,15 @@ var.second = 1;
     let input_ast = syntax::parse(tokens).unwrap();
 
     let struct_def = StructDef {
+        name: Some("tmp".to_string()),
         members: vec![StructMember {
-            name: Identifier(SpanData {
+            name: Some(Identifier(SpanData {
                 span: Span::new_source(source.clone(), 22..27),
                 data: "first".to_string(),
-            }),
+            })),
             ty: AType::Primitve(APrimitive::Int),
+            bit_width: None,
+            alignment: None,
         }],
     };
 
@@ -118,10 +130,10 @@ var.second = 1;
             span: Span::new_source(source.clone(), 53..59),
             data: "second".to_string(),
         }),
-        struct_def: SpanData {
+        struct_def: Box::new(SpanData {
             span: Span::new_source(source, 12..31),
             data: struct_def,
-        },
+        }),
     });
 
     let result = semantic::parse(input_ast);
@@ -129,3 +141,30 @@ var.second = 1;
 
     assert_eq!(expected, result);
 }
+
+#[test]
+fn member_offsets_use_pointer_type() {
+    let content = "
+struct point {
+    int x;
+    int y;
+};
+
+int test() {
+    struct point p;
+    p.y = 1;
+    struct point* ptr = &p;
+    return ptr->y;
+}
+        ";
+    let func = arch_function_ir(parse(content).unwrap(), "test", Arch::SH4A);
+    let statements = statements(&func);
+
+    assert!(
+        right_constants(&statements, BinaryOp::Arith(BinaryArithmeticOp::Add))
+            .contains(&Constant::I32(4))
+    );
+    assert!(!constants(&statements)
+        .iter()
+        .any(|con| matches!(con, Constant::I64(_))));
+}
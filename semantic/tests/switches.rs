@@ -0,0 +1,476 @@
+use general::{arch::Arch, Source, Span, SpanData};
+use ir::{BinaryArithmeticOp, BinaryLogicOp, BinaryOp, Constant, Statement};
+use semantic::{
+    ACaseLabel, AExpression, APrimitive, ARootScope, AScope, AStatement, ASwitchCase, AType,
+    FunctionDeclaration, Linkage, Literal, SemanticError, AAST,
+};
+
+mod common;
+use common::*;
+
+/// The Values compared against in the Guards of the Cases
+fn case_values(statements: &[Statement]) -> Vec<Constant> {
+    right_constants(statements, BinaryOp::Logic(BinaryLogicOp::Equal))
+}
+
+/// Asserts that every Case is compared exactly once, in any Order of the Blocks
+fn assert_cases(expected: &[Constant], statements: &[Statement]) {
+    let values = case_values(statements);
+    assert_eq!(expected.len(), values.len(), "{:?}", values);
+    for value in expected {
+        assert!(values.contains(value), "{:?} in {:?}", value, values);
+    }
+}
+
+fn conditional_jumps(statements: &[Statement]) -> usize {
+    statements
+        .iter()
+        .filter(|s| matches!(s, Statement::JumpTrue(..)))
+        .count()
+}
+
+#[test]
+fn switch_with_constant_cases() {
+    let content = "
+void test() {
+    switch (2) {
+        case 1:
+        case 1 + 1:
+            break;
+        default:
+            return;
+    }
+}
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let expected = Ok(AAST {
+        global_scope: ARootScope(AScope {
+            statements: vec![],
+            function_definitions: vec![(
+                "test".to_string(),
+                (
+                    FunctionDeclaration {
+                        var_args: false,
+                        linkage: Linkage::External,
+                        return_ty: AType::Primitve(APrimitive::Void),
+                        arguments: vec![],
+                        declaration: Span::new_source(source.clone(), 6..10),
+                    },
+                    AScope {
+                        function_definitions: vec![].into_iter().collect(),
+                        statements: vec![AStatement::Switch {
+                            value: AExpression::Literal(Literal::Integer(
+                                SpanData {
+                                    span: Span::new_source(source.clone(), 27..28),
+                                    data: 2,
+                                },
+                                APrimitive::Int,
+                            )),
+                            cases: vec![
+                                ASwitchCase {
+                                    label: ACaseLabel::Value(SpanData {
+                                        span: Span::new_source(source.clone(), 45..46),
+                                        data: 1,
+                                    }),
+                                    statements: vec![],
+                                },
+                                ASwitchCase {
+                                    label: ACaseLabel::Value(SpanData {
+                                        span: Span::new_source(source.clone(), 61..66),
+                                        data: 2,
+                                    }),
+                                    statements: vec![AStatement::Break],
+                                },
+                                ASwitchCase {
+                                    label: ACaseLabel::Default(Span::new_source(
+                                        source.clone(),
+                                        95..102,
+                                    )),
+                                    statements: vec![AStatement::Return { value: None }],
+                                },
+                            ],
+                        }],
+                    },
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        }),
+        warnings: Vec::new(),
+    });
+
+    let result = semantic::parse(syntax_ast);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn switch_converts_to_ir() {
+    let content = "
+int test(int x) {
+    int result = 0;
+    switch (x) {
+        case 0:
+            result = 1;
+        case 1:
+            result = result + 2;
+            break;
+        case 2:
+            return 5;
+        default:
+            result = 3;
+    }
+    return result;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+    let statements = statements(&func);
+
+    // Every Case is entered from its own Guard-Block, so no Block has more than two Successors
+    assert_cases(
+        &[Constant::I32(0), Constant::I32(1), Constant::I32(2)],
+        &statements,
+    );
+    assert_eq!(4, conditional_jumps(&statements));
+    assert!(block_statements(&func)
+        .iter()
+        .all(|b| conditional_jumps(b) <= 1));
+    assert!(assigned_constants(&statements).contains(&Constant::I32(5)));
+
+    func.verify();
+}
+
+#[test]
+fn switch_dispatch_verifies() {
+    let content = "
+int test(int o) {
+    int result = 0;
+    switch (o) {
+        case 1:
+            result = 4;
+            break;
+        case 2:
+            result = 5;
+            break;
+        default:
+            result = 6;
+            break;
+    }
+    return result;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::X86_64);
+
+    func.verify();
+}
+
+#[test]
+fn duplicate_case_value() {
+    let content = "
+void test() {
+    switch (2) {
+        case 2:
+            break;
+        case 1 + 1:
+            break;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::DuplicateCase { .. })));
+}
+
+#[test]
+fn duplicate_default() {
+    let content = "
+void test() {
+    switch (2) {
+        default:
+            break;
+        default:
+            break;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::DuplicateDefault { .. })
+    ));
+}
+
+#[test]
+fn non_constant_case_value() {
+    let content = "
+void test() {
+    int x = 2;
+    switch (2) {
+        case x:
+            break;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::InvalidCaseValue { .. })
+    ));
+}
+
+#[test]
+fn case_value_cast() {
+    let content = "
+int test(int x) {
+    switch (x) {
+        case (char) 300:
+            return 1;
+        case (unsigned char) 511:
+            return 2;
+        case (short int) 70000:
+            return 3;
+    }
+    return 0;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let body = &aast
+        .global_scope
+        .0
+        .function_definitions
+        .get("test")
+        .unwrap()
+        .1;
+    let cases = match body.statements.first() {
+        Some(AStatement::Switch { cases, .. }) => cases,
+        other => panic!("Expected a Switch, got {:?}", other),
+    };
+    let values: Vec<_> = cases
+        .iter()
+        .map(|case| match &case.label {
+            ACaseLabel::Value(value) => value.data,
+            other => panic!("Unexpected Label {:?}", other),
+        })
+        .collect();
+
+    assert_eq!(vec![44, 255, 4464], values);
+}
+
+#[test]
+fn nested_char_switch() {
+    let content = "
+int test(int kind, char c) {
+    switch (kind) {
+        case 1:
+            switch (c) {
+                case 'a':
+                    return 10;
+                case 'b':
+                    return 11;
+                default:
+                    return 12;
+            }
+        case 2:
+            return 20;
+        default:
+            return 0;
+    }
+    return -1;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::AArch64);
+    let statements = statements(&func);
+
+    // The char is promoted to an int before comparing it against the Cases
+    let values = case_values(&statements);
+    assert_eq!(
+        1,
+        values.iter().filter(|v| **v == Constant::I32(97)).count()
+    );
+    assert_eq!(
+        1,
+        values.iter().filter(|v| **v == Constant::I32(98)).count()
+    );
+    assert_eq!(6, conditional_jumps(&statements));
+
+    func.verify();
+}
+
+#[test]
+fn continue_in_switch() {
+    let content = "
+int test(int limit) {
+    int odd = 0;
+    for (int i = 0; i < limit; i = i + 3) {
+        switch (i) {
+            case 0:
+                continue;
+            default:
+                odd = odd + 1;
+        }
+    }
+    return odd;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::AArch64);
+    let statements = statements(&func);
+
+    // The Continue still runs the Update of the For-Loop
+    let updates = right_constants(&statements, BinaryOp::Arith(BinaryArithmeticOp::Add))
+        .into_iter()
+        .filter(|con| *con == Constant::I32(3))
+        .count();
+    assert_eq!(2, updates);
+
+    // The Case with the Continue does not also fall through to the Guard of the next Case
+    assert!(!block_statements(&func).iter().any(|b| b
+        .windows(2)
+        .any(|w| matches!(w, [Statement::Jump(..), Statement::Jump(..)]))));
+
+    func.verify();
+}
+
+#[test]
+fn duplicate_case_after_conversion() {
+    let content = "
+void test(unsigned int x) {
+    switch (x) {
+        case -1:
+            break;
+        case 0xFFFFFFFF:
+            break;
+    }
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::DuplicateCase { .. })));
+}
+
+#[test]
+fn promoted_switch_value() {
+    let content = "
+int test(short s, char c) {
+    switch (s) {
+        case 70000:
+            return 1;
+        case 4464:
+            return 2;
+    }
+    switch (c) {
+        case 255:
+            return 3;
+        case -1:
+            return 4;
+    }
+    return 0;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::AArch64);
+    let statements = statements(&func);
+
+    // The Labels keep their Value, instead of being truncated to the short or char
+    assert_cases(
+        &[
+            Constant::I32(70000),
+            Constant::I32(4464),
+            Constant::I32(255),
+            Constant::I32(-1),
+        ],
+        &statements,
+    );
+
+    func.verify();
+}
+
+#[test]
+fn empty_switch() {
+    let content = "
+int test(int x) {
+    switch (x) {}
+    return 4;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::AArch64);
+    let statements = statements(&func);
+
+    assert_eq!(0, conditional_jumps(&statements));
+    assert!(statements
+        .iter()
+        .any(|s| matches!(s, Statement::Return(Some(_)))));
+
+    func.verify();
+}
+
+#[test]
+fn statements_before_first_case() {
+    let content = "
+int test(int x) {
+    int result = 1;
+    switch (x) {
+        int y;
+        result = 100;
+        case 1:
+            y = 2;
+            result = result + y;
+            break;
+        default:
+            result = 0;
+    }
+    return result;
+}
+        ";
+    let aast = parse(content).unwrap();
+
+    let func = arch_function_ir(aast, "test", Arch::AArch64);
+    let statements = statements(&func);
+
+    // The Assignment before the first Case is never reached, so it is not part of the Function
+    assert!(!constants(&statements).contains(&Constant::I32(100)));
+    assert_eq!(2, conditional_jumps(&statements));
+
+    func.verify();
+}
+
+#[test]
+fn label_before_first_case() {
+    let content = "
+int test(int x) {
+    switch (x) {
+    inside:
+        x = 2;
+        case 1:
+            return x;
+    }
+    goto inside;
+}
+        ";
+    let result = parse(content);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::NotImplemented { .. })));
+}
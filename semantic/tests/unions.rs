@@ -0,0 +1,169 @@
+use general::{arch::Arch, Source, Span};
+use semantic::{AStatement, AType, SemanticError, AAST};
+
+fn declared_type(aast: &AAST, index: usize) -> AType {
+    match aast.global_scope.0.statements.get(index) {
+        Some(AStatement::DeclareVar { ty, .. }) => ty.clone(),
+        other => panic!("Expected a Variable Declaration, got {:?}", other),
+    }
+}
+
+#[test]
+fn union_layout() {
+    let content = "
+union value {
+    char letters[5];
+    int number;
+    short half;
+};
+
+union value var;
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast).unwrap();
+    let ty = declared_type(&result, 0);
+    dbg!(&ty);
+
+    assert!(matches!(ty, AType::Union { .. }));
+
+    assert_eq!(8, ty.byte_size(&Arch::AArch64));
+    assert_eq!(4, ty.alignment(&Arch::AArch64));
+
+    assert_eq!(Some(0), ty.member_offset("letters", &Arch::AArch64));
+    assert_eq!(Some(0), ty.member_offset("number", &Arch::AArch64));
+    assert_eq!(Some(0), ty.member_offset("half", &Arch::AArch64));
+    assert_eq!(None, ty.member_offset("other", &Arch::AArch64));
+}
+
+#[test]
+fn union_in_struct() {
+    let content = "
+union value {
+    int number;
+    char letter;
+};
+struct tagged {
+    char tag;
+    union value inner;
+};
+
+struct tagged var;
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast).unwrap();
+    let ty = declared_type(&result, 0);
+    dbg!(&ty);
+
+    assert_eq!(8, ty.byte_size(&Arch::AArch64));
+    assert_eq!(Some(4), ty.member_offset("inner", &Arch::AArch64));
+}
+
+#[test]
+fn unknown_union_field() {
+    let content = "
+union value {
+    int number;
+};
+
+union value var;
+var.other = 1;
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast);
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::UnknownStructField { .. })
+    ));
+}
+
+#[test]
+fn union_tag_of_struct() {
+    let content = "
+struct value {
+    int number;
+};
+
+union value var;
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast);
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::MismatchedTag { .. })));
+}
+
+#[test]
+fn union_access_converts_to_ir() {
+    let content = "
+union value {
+    int number;
+    char letter;
+};
+
+int test() {
+    union value v;
+    v.letter = 1;
+    v.number = 2;
+    union value* ptr = &v;
+    ptr->number = 3;
+    return v.number + ptr->number;
+}
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+    let aast = semantic::parse(syntax_ast).unwrap();
+
+    let result = aast.convert_to_ir(Arch::AArch64);
+    dbg!(&result);
+
+    assert!(result.functions.contains_key("test"));
+}
+
+#[test]
+fn inline_union_member() {
+    let content = "
+struct Tag { int kind; union { int i; float f; } v; };
+
+struct Tag var;
+
+int test() {
+    var.kind = 1;
+    var.v.f = 2.0;
+    return var.v.i + var.kind;
+}
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+    let syntax_ast = syntax::parse(tokens).unwrap();
+
+    let result = semantic::parse(syntax_ast).unwrap();
+    let ty = declared_type(&result, 0);
+    dbg!(&ty);
+
+    assert_eq!(8, ty.byte_size(&Arch::AArch64));
+    assert_eq!(Some(4), ty.member_offset("v", &Arch::AArch64));
+
+    let ir = result.convert_to_ir(Arch::AArch64);
+    assert!(ir.functions.contains_key("test"));
+}
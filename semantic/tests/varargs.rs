@@ -0,0 +1,133 @@
+use ir::{Expression, Type};
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+#[test]
+fn sum_var_args() {
+    let result = parse(
+        "
+typedef __builtin_va_list va_list;
+
+int sum(int count, ...) {
+    va_list args;
+    __builtin_va_start(args, count);
+
+    int result = 0;
+    for (int i = 0; i < count; i++) {
+        result = result + __builtin_va_arg(args, int);
+    }
+
+    __builtin_va_end(args);
+    return result;
+}
+
+int test() {
+    char c = 'a';
+    return sum(3, 1, 2, c);
+}
+        ",
+    )
+    .unwrap();
+    dbg!(&result);
+
+    let statements = statements(&function_ir(result, "sum"));
+    assert!(expressions(&statements)
+        .iter()
+        .any(|exp| matches!(exp, Expression::VarArgsStart)));
+    assert!(read_types(&statements).contains(&Type::I32));
+}
+
+#[test]
+fn va_start_outside_variadic() {
+    let result = parse(
+        "
+typedef __builtin_va_list va_list;
+
+int test(int count) {
+    va_list args;
+    __builtin_va_start(args, count);
+    return 0;
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidVarArgs { .. })));
+}
+
+#[test]
+fn va_arg_on_non_list() {
+    let result = parse(
+        "
+int test(int count, ...) {
+    int other = 0;
+    return __builtin_va_arg(other, int);
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidVarArgs { .. })));
+}
+
+#[test]
+fn struct_var_arg() {
+    let result = parse(
+        "
+typedef __builtin_va_list va_list;
+
+struct Pair {
+    int first;
+    int second;
+};
+
+int sum_pairs(int count, ...) {
+    va_list args;
+    __builtin_va_start(args, count);
+
+    int result = 0;
+    for (int i = 0; i < count; i++) {
+        struct Pair pair = __builtin_va_arg(args, struct Pair);
+        result = result + pair.first + pair.second;
+    }
+
+    __builtin_va_end(args);
+    return result;
+}
+
+int test() {
+    struct Pair pair;
+    pair.first = 1;
+    pair.second = 2;
+    return sum_pairs(1, pair);
+}
+        ",
+    )
+    .unwrap();
+
+    let statements = statements(&function_ir(result, "sum_pairs"));
+
+    // The Struct is read as the Address of the Caller's Copy
+    assert!(read_types(&statements).contains(&Type::Pointer(Box::new(Type::Void))));
+}
+
+#[test]
+fn void_var_arg() {
+    let result = parse(
+        "
+typedef __builtin_va_list va_list;
+
+int test(int count, ...) {
+    va_list args;
+    __builtin_va_start(args, count);
+    __builtin_va_arg(args, void);
+    return 0;
+}
+        ",
+    );
+    dbg!(&result);
+
+    assert!(matches!(result, Err(SemanticError::InvalidVarArgs { .. })));
+}
@@ -1,7 +1,7 @@
 use general::{Source, Span, SpanData};
 use semantic::{
     AAssignTarget, AExpression, APrimitive, AScope, AStatement, AType, FunctionDeclaration,
-    Literal, AAST,
+    Linkage, Literal, AAST,
 };
 use syntax::Identifier;
 
@@ -34,6 +34,7 @@ int main() {
                     FunctionDeclaration {
                         arguments: vec![],
                         var_args: false,
+                        linkage: Linkage::External,
                         return_ty: AType::Primitve(APrimitive::Int),
                         declaration: Span::new_source(input_source.clone(), 5..9),
                     },
@@ -52,21 +53,22 @@ int main() {
                                         data: AType::Primitve(APrimitive::Int),
                                     },
                                 },
-                                value: AExpression::Cast {
-                                    base: Box::new(AExpression::Literal(Literal::Integer(
-                                        SpanData {
-                                            span: Span::new_source(input_source.clone(), 26..27),
-                                            data: 0,
-                                        },
-                                    ))),
-                                    target: AType::Primitve(APrimitive::Int),
-                                },
+                                value: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(input_source.clone(), 26..27),
+                                        data: 0,
+                                    },
+                                    APrimitive::Int,
+                                )),
                             },
                             AStatement::If {
-                                condition: AExpression::Literal(Literal::Integer(SpanData {
-                                    span: Span::new_source(input_source.clone(), 38..39),
-                                    data: 1,
-                                })),
+                                condition: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(input_source.clone(), 38..39),
+                                        data: 1,
+                                    },
+                                    APrimitive::Int,
+                                )),
                                 body: AScope {
                                     function_definitions: vec![].into_iter().collect(),
                                     statements: vec![
@@ -88,18 +90,16 @@ int main() {
                                                     data: AType::Primitve(APrimitive::Int),
                                                 },
                                             },
-                                            value: AExpression::Cast {
-                                                base: Box::new(AExpression::Literal(
-                                                    Literal::Integer(SpanData {
-                                                        span: Span::new_source(
-                                                            input_source.clone(),
-                                                            59..61,
-                                                        ),
-                                                        data: 13,
-                                                    }),
-                                                )),
-                                                target: AType::Primitve(APrimitive::Int),
-                                            },
+                                            value: AExpression::Literal(Literal::Integer(
+                                                SpanData {
+                                                    span: Span::new_source(
+                                                        input_source.clone(),
+                                                        59..61,
+                                                    ),
+                                                    data: 13,
+                                                },
+                                                APrimitive::Int,
+                                            )),
                                         },
                                         AStatement::Return {
                                             value: Some(AExpression::Variable {
@@ -144,6 +144,7 @@ int main() {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
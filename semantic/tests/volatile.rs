@@ -0,0 +1,136 @@
+use ir::{Expression, FunctionDefinition, Statement};
+use semantic::SemanticError;
+
+mod common;
+use common::*;
+
+/// Whether the Reads from Memory in the Function are volatile
+fn volatile_reads(func: &FunctionDefinition) -> Vec<bool> {
+    expressions(&statements(func))
+        .into_iter()
+        .filter_map(|exp| match exp {
+            Expression::ReadMemory { volatile, .. } => Some(volatile),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the Writes to Memory in the Function are volatile
+fn volatile_writes(func: &FunctionDefinition) -> Vec<bool> {
+    statements(func)
+        .into_iter()
+        .filter_map(|s| match s {
+            Statement::WriteMemory { volatile, .. } => Some(volatile),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn read_through_volatile_pointer() {
+    let result = parse(
+        "
+int test(volatile int* reg, int* other) {
+    int first = *reg;
+    int second = *other;
+    return first;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![true, false], volatile_reads(&func));
+}
+
+#[test]
+fn write_through_volatile_pointer() {
+    let result = parse(
+        "
+void test(volatile int* reg) {
+    *reg = 1;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![true], volatile_writes(&func));
+}
+
+#[test]
+fn volatile_array_element() {
+    let result = parse(
+        "
+int test() {
+    volatile int values[2] = {1, 2};
+    values[1] = 3;
+    return values[0];
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    let accesses: Vec<_> = volatile_reads(&func)
+        .into_iter()
+        .chain(volatile_writes(&func))
+        .collect();
+    assert!(!accesses.is_empty());
+    assert!(accesses.into_iter().all(|volatile| volatile));
+}
+
+#[test]
+fn member_of_volatile_struct() {
+    let result = parse(
+        "
+struct registers {
+    int status;
+    int data;
+};
+
+int test(volatile struct registers* regs) {
+    regs->data = 1;
+    return regs->status;
+}
+        ",
+    )
+    .unwrap();
+
+    let func = function_ir(result, "test");
+    assert_eq!(vec![true], volatile_writes(&func));
+    assert_eq!(vec![true], volatile_reads(&func));
+}
+
+#[test]
+fn add_volatile_to_pointer() {
+    let result = parse(
+        "
+int test(int* value) {
+    volatile int* reg = value;
+    return *reg;
+}
+        ",
+    );
+
+    let func = function_ir(result.unwrap(), "test");
+    assert_eq!(vec![true], volatile_reads(&func));
+}
+
+#[test]
+fn discard_volatile() {
+    let result = parse(
+        "
+int test(volatile int* reg) {
+    int* value = reg;
+    return *value;
+}
+        ",
+    );
+
+    assert!(
+        matches!(result, Err(SemanticError::MismatchedTypes { .. })),
+        "{:?}",
+        result
+    );
+}
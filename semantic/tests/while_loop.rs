@@ -1,7 +1,7 @@
 use general::{Source, Span, SpanData};
 use semantic::{
     AAssignTarget, AExpression, APrimitive, ARootScope, AScope, AStatement, AType,
-    FunctionDeclaration, Literal, SemanticError, AAST,
+    FunctionDeclaration, Linkage, Literal, SemanticError, AAST,
 };
 use syntax::Identifier;
 
@@ -31,6 +31,7 @@ void other() {
                         arguments: vec![],
                         declaration: Span::new_source(input_source.clone(), 6..11),
                         var_args: false,
+                        linkage: Linkage::External,
                     },
                     AScope {
                         statements: vec![
@@ -41,12 +42,16 @@ void other() {
                                     data: "test".to_string(),
                                 }),
                                 ty: AType::Primitve(APrimitive::Int),
+                                alignment: None,
                             },
                             AStatement::WhileLoop {
-                                condition: AExpression::Literal(Literal::Integer(SpanData {
-                                    span: Span::new_source(input_source.clone(), 40..41),
-                                    data: 1,
-                                })),
+                                condition: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(input_source.clone(), 40..41),
+                                        data: 1,
+                                    },
+                                    APrimitive::Int,
+                                )),
                                 body: AScope {
                                     statements: vec![AStatement::Assignment {
                                         target: AAssignTarget::Variable {
@@ -66,15 +71,13 @@ void other() {
                                                 data: AType::Primitve(APrimitive::Int),
                                             },
                                         },
-                                        value: AExpression::Cast {
-                                            base: Box::new(AExpression::Literal(Literal::Integer(
-                                                SpanData {
-                                                    span: Span::new_source(input_source, 60..61),
-                                                    data: 1,
-                                                },
-                                            ))),
-                                            target: AType::Primitve(APrimitive::Int),
-                                        },
+                                        value: AExpression::Literal(Literal::Integer(
+                                            SpanData {
+                                                span: Span::new_source(input_source, 60..61),
+                                                data: 1,
+                                            },
+                                            APrimitive::Int,
+                                        )),
                                     }],
                                     function_definitions: vec![].into_iter().collect(),
                                 },
@@ -87,6 +90,7 @@ void other() {
             .into_iter()
             .collect(),
         }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
@@ -113,14 +117,109 @@ void other() {
     let input_ast = syntax::parse(input_tokens).unwrap();
 
     let expected = Err(SemanticError::MismatchedTypes {
-        expected: SpanData {
+        expected: Box::new(SpanData {
             span: Span::new_source(input_source.clone(), 0..1),
             data: AType::Primitve(APrimitive::Int),
-        },
-        received: SpanData {
+        }),
+        received: Box::new(SpanData {
             span: Span::new_source(input_source, 0..1),
             data: AType::Primitve(APrimitive::Float),
-        },
+        }),
+    });
+
+    let result = semantic::parse(input_ast);
+
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn valid_do_while_loop() {
+    let input_content = "
+void other() {
+    int test;
+    do {
+        test = 1;
+    } while(1);
+}
+        ";
+    let input_source = Source::new("test", input_content);
+    let input_span: Span = input_source.clone().into();
+    let input_tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(input_tokens).unwrap();
+
+    let expected = Ok(AAST {
+        global_scope: ARootScope(AScope {
+            statements: vec![],
+            function_definitions: vec![(
+                "other".to_string(),
+                (
+                    FunctionDeclaration {
+                        return_ty: AType::Primitve(APrimitive::Void),
+                        arguments: vec![],
+                        declaration: Span::new_source(input_source.clone(), 6..11),
+                        var_args: false,
+                        linkage: Linkage::External,
+                    },
+                    AScope {
+                        statements: vec![
+                            AStatement::DeclareVar {
+                                name: "test_2149230751987271372".to_string(),
+                                src: Identifier(SpanData {
+                                    span: Span::new_source(input_source.clone(), 24..28),
+                                    data: "test".to_string(),
+                                }),
+                                ty: AType::Primitve(APrimitive::Int),
+                                alignment: None,
+                            },
+                            AStatement::DoWhileLoop {
+                                condition: AExpression::Literal(Literal::Integer(
+                                    SpanData {
+                                        span: Span::new_source(input_source.clone(), 69..70),
+                                        data: 1,
+                                    },
+                                    APrimitive::Int,
+                                )),
+                                body: AScope {
+                                    statements: vec![AStatement::Assignment {
+                                        target: AAssignTarget::Variable {
+                                            name: "test_2149230751987271372".to_string(),
+                                            src: Identifier(SpanData {
+                                                span: Span::new_source(
+                                                    input_source.clone(),
+                                                    47..51,
+                                                ),
+                                                data: "test".to_string(),
+                                            }),
+                                            ty_info: SpanData {
+                                                span: Span::new_source(
+                                                    input_source.clone(),
+                                                    24..28,
+                                                ),
+                                                data: AType::Primitve(APrimitive::Int),
+                                            },
+                                        },
+                                        value: AExpression::Literal(Literal::Integer(
+                                            SpanData {
+                                                span: Span::new_source(input_source, 54..55),
+                                                data: 1,
+                                            },
+                                            APrimitive::Int,
+                                        )),
+                                    }],
+                                    function_definitions: vec![].into_iter().collect(),
+                                },
+                            },
+                        ],
+                        function_definitions: vec![].into_iter().collect(),
+                    },
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        }),
+        warnings: Vec::new(),
     });
 
     let result = semantic::parse(input_ast);
@@ -129,3 +228,55 @@ void other() {
 
     assert_eq!(expected, result);
 }
+
+#[test]
+fn do_while_condition_cant_see_body() {
+    let input_content = "
+void other() {
+    do {
+        int inner = 1;
+    } while(inner);
+}
+        ";
+    let input_source = Source::new("test", input_content);
+    let input_span: Span = input_source.into();
+    let input_tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(input_tokens).unwrap();
+
+    let result = semantic::parse(input_ast);
+
+    dbg!(&result);
+
+    assert!(matches!(
+        result,
+        Err(SemanticError::UnknownIdentifier { .. })
+    ));
+}
+
+#[test]
+fn do_while_continue_verifies() {
+    let input_content = "
+int test(int x) {
+    int r = 0;
+    do {
+        r = r + 1;
+        if (r == 2) {
+            continue;
+        }
+        x = x - 1;
+    } while (x > 0);
+    return r;
+}
+        ";
+    let input_source = Source::new("test", input_content);
+    let input_span: Span = input_source.into();
+    let input_tokens = tokenizer::tokenize(input_span);
+    let input_ast = syntax::parse(input_tokens).unwrap();
+    let aast = semantic::parse(input_ast).unwrap();
+
+    let ir = aast.convert_to_ir(general::arch::Arch::AArch64);
+    let func = ir.functions.get("test").unwrap();
+    println!("{}", ir::text_rep::generate_text_rep(func));
+
+    func.verify();
+}
@@ -60,9 +60,12 @@ pub enum ExpectedToken {
     Colon,
     /// if
     If,
+    /// while
+    While,
     /// =
     Equal,
     Literal,
     IntegerLiteral,
+    StringLiteral,
     Assignment,
 }
@@ -8,7 +8,9 @@ use general::{Source, Span, SpanData};
 use itertools::PeekNth;
 use tokenizer::{Assignment, Keyword, Operator, Token, TokenData};
 
-use crate::{EOFContext, ExpectedToken, Identifier, SyntaxError, TypeToken};
+use crate::{
+    literal, EOFContext, ExpectedToken, Identifier, LiteralEncoding, SyntaxError, TypeToken,
+};
 
 mod parse_state;
 
@@ -44,16 +46,32 @@ pub enum Expression {
     Literal {
         content: SpanData<String>,
     },
-    /// This represents a string Literal
+    /// This represents a string Literal, where all the Escape-Sequences have already been
+    /// resolved into the Code-Units of its Encoding
     StringLiteral {
-        content: SpanData<String>,
+        content: SpanData<Vec<u32>>,
+        encoding: LiteralEncoding,
     },
+    /// A Character-Constant, with the Value of its single Code-Unit
     CharLiteral {
-        content: SpanData<char>,
+        content: SpanData<u32>,
+        encoding: LiteralEncoding,
     },
     ArrayLiteral {
         parts: SpanData<Vec<Expression>>,
     },
+    /// An Entry in an Initializer-List that explicitly states which Element or Member it
+    /// initializes, like ".x = 1" or "[3] = 5"
+    DesignatedInitializer {
+        designators: Vec<Designator>,
+        value: Box<Self>,
+    },
+    /// A Compound-Literal like "(struct Point){1, 2}", which creates an unnamed Object of the
+    /// given Type
+    CompoundLiteral {
+        target_ty: TypeToken,
+        parts: SpanData<Vec<Expression>>,
+    },
     /// A Type-Cast
     Cast {
         /// The Target Type to Cast to
@@ -93,6 +111,28 @@ pub enum Expression {
         ty: TypeToken,
         area: Span,
     },
+    /// The Alignment of the given Type, like "_Alignof(int)"
+    AlignOf {
+        ty: TypeToken,
+        area: Span,
+    },
+    /// Loads the next variable Argument with the given Type from the List, which is the
+    /// "__builtin_va_arg(list, ty)" Builtin used by the "va_arg" Macro
+    VaArg {
+        list: Box<Expression>,
+        ty: TypeToken,
+        area: Span,
+    },
+}
+
+/// A single Step of a Designation in an Initializer-List
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum Designator {
+    /// Selects the Member of a Struct or Union, like ".x"
+    Member(Identifier),
+    /// Selects the Element of an Array at the given Index, like "[3]"
+    Index(Box<Expression>),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -165,9 +205,11 @@ impl Expression {
         match &self {
             Self::Identifier { ident } => Some(ident.0.span.clone()),
             Self::Literal { content } => Some(content.span.clone()),
-            Self::StringLiteral { content } => Some(content.span.clone()),
-            Self::CharLiteral { content } => Some(content.span.clone()),
+            Self::StringLiteral { content, .. } => Some(content.span.clone()),
+            Self::CharLiteral { content, .. } => Some(content.span.clone()),
             Self::ArrayLiteral { parts } => Some(parts.span.clone()),
+            Self::DesignatedInitializer { value, .. } => value.entire_span(),
+            Self::CompoundLiteral { parts, .. } => Some(parts.span.clone()),
             Self::Operation { left, right, .. } => {
                 let l_span = left.entire_span()?;
                 let r_span = right.entire_span()?;
@@ -190,6 +232,7 @@ impl Expression {
                 Some(condition_span.join(first_span).join(second_span))
             }
             Self::Cast { exp, .. } => exp.entire_span(),
+            Self::SizeOf { area, .. } | Self::AlignOf { area, .. } => Some(area.clone()),
             other => {
                 dbg!(&other);
                 None
@@ -202,6 +245,98 @@ impl Expression {
         (None, None)
     }
 
+    /// Determines the Encoding of a String- or Char-Literal from its Prefix
+    fn literal_encoding(prefix: Option<&str>, span: &Span) -> Result<LiteralEncoding, SyntaxError> {
+        LiteralEncoding::from_prefix(prefix).ok_or_else(|| SyntaxError::UnexpectedToken {
+            expected: None,
+            got: span.clone(),
+        })
+    }
+
+    /// Resolves the Escape-Sequences in the raw Content of a Literal, where an invalid Sequence
+    /// is reported at its Position in the Span of the Literal
+    fn decode_literal(
+        content: &str,
+        prefix: Option<&str>,
+        encoding: LiteralEncoding,
+        span: &Span,
+    ) -> Result<Vec<u32>, SyntaxError> {
+        literal::decode(content, encoding).map_err(|range| {
+            // The Content starts after the Prefix and the opening Quote
+            let offset = prefix.map(|p| p.len()).unwrap_or(0) + 1;
+            let got = span
+                .sub_span(range.start + offset..range.end + offset)
+                .map(|s| s.into())
+                .unwrap_or_else(|| span.clone());
+
+            SyntaxError::UnexpectedToken {
+                expected: None,
+                got,
+            }
+        })
+    }
+
+    /// Parses a String-Literal together with all the String-Literals directly following it, as
+    /// adjacent String-Literals are concatenated into a single one
+    fn parse_string_literal<I>(first: Token, tokens: &mut PeekNth<I>) -> Result<Self, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let mut parts = vec![first];
+        while let Some(Token {
+            data: TokenData::StringLiteral { .. },
+            ..
+        }) = tokens.peek()
+        {
+            parts.push(tokens.next().unwrap());
+        }
+
+        // The Encoding needs to be known before decoding any of the Parts, as an unprefixed Part
+        // takes on the Encoding of the other Parts
+        let mut encoding = LiteralEncoding::Plain;
+        for part in parts.iter() {
+            if let TokenData::StringLiteral { prefix, .. } = &part.data {
+                let part_encoding = Self::literal_encoding(prefix.as_deref(), &part.span)?;
+                encoding =
+                    encoding
+                        .concat(part_encoding)
+                        .ok_or_else(|| SyntaxError::UnexpectedToken {
+                            expected: None,
+                            got: part.span.clone(),
+                        })?;
+            }
+        }
+
+        let mut span: Option<Span> = None;
+        let mut units = Vec::new();
+        for part in parts {
+            if let TokenData::StringLiteral { content, prefix } = &part.data {
+                units.extend(Self::decode_literal(
+                    content,
+                    prefix.as_deref(),
+                    encoding,
+                    &part.span,
+                )?);
+            }
+
+            span = Some(match span {
+                Some(previous) if Arc::ptr_eq(previous.source(), part.span.source()) => {
+                    previous.join(part.span)
+                }
+                Some(previous) => previous,
+                None => part.span,
+            });
+        }
+
+        Ok(Self::StringLiteral {
+            content: SpanData {
+                span: span.expect("There is always at least one Part"),
+                data: units,
+            },
+            encoding,
+        })
+    }
+
     fn parse_single_token(current: Token) -> Result<Self, SyntaxError> {
         match current.data {
             TokenData::Literal { content } => {
@@ -215,49 +350,29 @@ impl Expression {
                     }),
                 }
             }
-            TokenData::StringLiteral { content } => Ok(Self::StringLiteral {
-                content: SpanData {
-                    span: current.span,
-                    data: content,
-                },
-            }),
-            TokenData::CharLiteral { content } => {
-                let mut chars = content.chars();
-                let first_char = chars.next().ok_or(SyntaxError::UnexpectedEOF {
-                    ctx: EOFContext::Expression,
-                })?;
-
-                let value = match first_char {
-                    '\\' => {
-                        let next = chars.next().ok_or(SyntaxError::UnexpectedEOF {
-                            ctx: EOFContext::Expression,
-                        })?;
-
-                        match next {
-                            '0' => '\0',
-                            'n' => '\n',
-                            _ => {
-                                return Err(SyntaxError::UnexpectedToken {
-                                    expected: None,
-                                    got: current.span.sub_span(1..2).unwrap().into(),
-                                })
-                            }
-                        }
+            TokenData::CharLiteral { content, prefix } => {
+                let encoding = Self::literal_encoding(prefix.as_deref(), &current.span)?;
+                let units =
+                    Self::decode_literal(&content, prefix.as_deref(), encoding, &current.span)?;
+
+                // Multi-Character Constants are not supported, so every Char-Literal needs to
+                // fit into a single Code-Unit
+                let value = match units.as_slice() {
+                    [value] => *value,
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: None,
+                            got: current.span,
+                        })
                     }
-                    other => other,
                 };
 
-                if chars.next().is_some() {
-                    return Err(SyntaxError::UnexpectedEOF {
-                        ctx: EOFContext::Expression,
-                    });
-                }
-
                 Ok(Self::CharLiteral {
                     content: SpanData {
                         span: current.span,
                         data: value,
                     },
+                    encoding,
                 })
             }
             _ => Err(SyntaxError::UnexpectedToken {
@@ -267,6 +382,157 @@ impl Expression {
         }
     }
 
+    fn expect_token<I>(
+        tokens: &mut PeekNth<I>,
+        data: TokenData,
+        expected: ExpectedToken,
+    ) -> Result<Token, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Expression,
+        })?;
+        if token.data != data {
+            return Err(SyntaxError::UnexpectedToken {
+                expected: Some(vec![expected]),
+                got: token.span,
+            });
+        }
+
+        Ok(token)
+    }
+
+    /// Parses the Arguments of the "__builtin_va_arg" Builtin, which takes a Type as its second
+    /// Argument and can therefore not be parsed like a normal Function-Call
+    fn parse_va_arg<I>(
+        tokens: &mut PeekNth<I>,
+        start: Span,
+        ctx: ExpressionParseContext,
+    ) -> Result<Self, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        Self::expect_token(tokens, TokenData::OpenParen, ExpectedToken::OpenParen)?;
+
+        let list = Self::parse_internal(tokens, ctx)?;
+
+        Self::expect_token(tokens, TokenData::Comma, ExpectedToken::Comma)?;
+
+        let ty = TypeToken::parse(tokens)?;
+
+        let closing = Self::expect_token(tokens, TokenData::CloseParen, ExpectedToken::CloseParen)?;
+
+        let start_range = start.source_area().start;
+        let end_range = closing.span.source_area().end;
+        let area = Span::new_arc_source(start.source().clone(), start_range..end_range);
+
+        Ok(Self::VaArg {
+            list: Box::new(list),
+            ty,
+            area,
+        })
+    }
+
+    /// Parses the Entries of an Initializer-List, like "{1, .x = 2, [3] = 4}", where the opening
+    /// "{" has already been consumed and is passed as the start
+    fn parse_initializer_list<I>(
+        tokens: &mut PeekNth<I>,
+        start: Span,
+        ctx: ExpressionParseContext,
+    ) -> Result<SpanData<Vec<Self>>, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let mut items = Vec::new();
+        loop {
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Expression,
+            })?;
+            let item = match &peeked.data {
+                TokenData::CloseBrace => break,
+                TokenData::Operator(Operator::Dot) | TokenData::OpenBracket => {
+                    Self::parse_designated(tokens, ctx.sub_expression()?)?
+                }
+                _ => Self::parse_internal(tokens, ctx.sub_expression()?)?,
+            };
+            items.push(item);
+
+            let peeked_seperator_token = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Expression,
+            })?;
+            match &peeked_seperator_token.data {
+                TokenData::Comma => {
+                    let _ = tokens.next();
+                }
+                TokenData::CloseBrace => {}
+                _ => {
+                    let tmp = tokens.next().unwrap();
+                    return Err(SyntaxError::UnexpectedToken {
+                        expected: Some(vec![ExpectedToken::Comma, ExpectedToken::CloseBrace]),
+                        got: tmp.span,
+                    });
+                }
+            };
+        }
+
+        let closing_token =
+            Self::expect_token(tokens, TokenData::CloseBrace, ExpectedToken::CloseBrace)?;
+
+        let entire_range = start.source_area().start..closing_token.span.source_area().end;
+        let entire_span = Span::new_arc_source(start.source().clone(), entire_range);
+        Ok(SpanData {
+            span: entire_span,
+            data: items,
+        })
+    }
+
+    /// Parses a single Entry of an Initializer-List that starts with a Designation, like
+    /// ".pos.x = 1" or "[3] = 5"
+    fn parse_designated<I>(
+        tokens: &mut PeekNth<I>,
+        ctx: ExpressionParseContext,
+    ) -> Result<Self, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let mut designators = Vec::new();
+        loop {
+            let current = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Expression,
+            })?;
+            match current.data {
+                TokenData::Operator(Operator::Dot) => {
+                    let name = Identifier::parse(tokens)?;
+                    designators.push(Designator::Member(name));
+                }
+                TokenData::OpenBracket => {
+                    let index = Self::parse_internal(tokens, ctx.sub_expression()?)?;
+                    Self::expect_token(
+                        tokens,
+                        TokenData::CloseBracket,
+                        ExpectedToken::CloseBracket,
+                    )?;
+                    designators.push(Designator::Index(Box::new(index)));
+                }
+                TokenData::Assign(Assignment::Assign) if !designators.is_empty() => break,
+                _ => {
+                    return Err(SyntaxError::UnexpectedToken {
+                        expected: Some(vec![ExpectedToken::Equal]),
+                        got: current.span,
+                    })
+                }
+            };
+        }
+
+        let value = Self::parse_internal(tokens, ctx)?;
+
+        Ok(Self::DesignatedInitializer {
+            designators,
+            value: Box::new(value),
+        })
+    }
+
     fn parse_exp_list<I>(
         tokens: &mut PeekNth<I>,
         end_tok: TokenData,
@@ -328,6 +594,14 @@ impl Expression {
             let new_last_data = current.data.clone();
 
             match (&current.data, state.get_cloned_last_token_data()) {
+                (TokenData::Literal { content }, Some(TokenData::Operator(_)))
+                | (TokenData::Literal { content }, None)
+                    if content == "__builtin_va_arg" =>
+                {
+                    let entry = Self::parse_va_arg(tokens, current.span, ctx.sub_expression()?)?;
+
+                    state.add_expression(entry);
+                }
                 (TokenData::Literal { .. }, Some(TokenData::Operator(_)))
                 | (TokenData::Literal { .. }, None) => {
                     let entry = Self::parse_single_token(current)?;
@@ -341,7 +615,7 @@ impl Expression {
                     });
                 }
                 (TokenData::StringLiteral { .. }, _) => {
-                    let entry = Self::parse_single_token(current)?;
+                    let entry = Self::parse_string_literal(current, tokens)?;
 
                     state.add_expression(entry);
                 }
@@ -372,6 +646,24 @@ impl Expression {
                     };
                     state.add_expression(inner);
                 }
+                (TokenData::Keyword(Keyword::AlignOf), _) => {
+                    Self::expect_token(tokens, TokenData::OpenParen, ExpectedToken::OpenParen)?;
+
+                    let ty = TypeToken::parse(tokens)?;
+
+                    let closing = Self::expect_token(
+                        tokens,
+                        TokenData::CloseParen,
+                        ExpectedToken::CloseParen,
+                    )?;
+
+                    let start_range = current.span.source_area().start;
+                    let end_range = closing.span.source_area().end;
+                    let area =
+                        Span::new_arc_source(current.span.source().clone(), start_range..end_range);
+
+                    state.add_expression(Expression::AlignOf { ty, area });
+                }
                 (TokenData::Operator(op), _) => {
                     match op {
                         Operator::BitwiseAnd => {
@@ -391,7 +683,11 @@ impl Expression {
                 }
                 (TokenData::OpenParen, _) => {
                     match state.get_cloned_last_token_data() {
-                        Some(TokenData::Literal { .. }) => {
+                        // Calls can be made on Identifiers, but also on the Result of Array
+                        // Accesses, other Calls or Expressions in Parenthesis, like "(*ptr)(1)"
+                        Some(TokenData::Literal { .. })
+                        | Some(TokenData::OpenParen)
+                        | Some(TokenData::OpenBracket) => {
                             let params = Self::parse_exp_list(
                                 tokens,
                                 TokenData::CloseParen,
@@ -417,6 +713,12 @@ impl Expression {
                             state.add_single_op(SingleOperation::FuntionCall(params), span);
                         }
                         _ => {
+                            // A Type can never start with an Operator, so this can not be a Cast
+                            let starts_with_operator = matches!(
+                                tokens.peek().map(|t| &t.data),
+                                Some(TokenData::Operator(_))
+                            );
+
                             let following_tok = {
                                 let mut current_pos = 0;
                                 let mut level = 0;
@@ -442,10 +744,16 @@ impl Expression {
                             match &following_tok {
                                 Some(tok) => {
                                     match &tok.data {
-                                        TokenData::Operator(_)
-                                        | TokenData::Semicolon
-                                        | TokenData::Comma
-                                        | TokenData::CloseParen => {
+                                        data if starts_with_operator
+                                            || matches!(
+                                                data,
+                                                TokenData::Operator(_)
+                                                    | TokenData::Semicolon
+                                                    | TokenData::Comma
+                                                    | TokenData::CloseParen
+                                                    | TokenData::QuestionMark
+                                            ) =>
+                                        {
                                             let exp = Self::parse_internal(
                                                 tokens,
                                                 ctx.sub_expression()?,
@@ -490,17 +798,36 @@ impl Expression {
                                                 }
                                             };
 
-                                            let exp = Self::parse_internal(
-                                                tokens,
-                                                ctx.sub_expression()?,
-                                            )?;
-
-                                            let cast_exp = Self::Cast {
-                                                target_ty,
-                                                exp: Box::new(exp),
-                                            };
-
-                                            state.add_expression(cast_exp);
+                                            // A Type in Parenthesis followed by an
+                                            // Initializer-List is a Compound-Literal
+                                            if matches!(
+                                                tokens.peek().map(|t| &t.data),
+                                                Some(TokenData::OpenBrace)
+                                            ) {
+                                                let _ = tokens.next();
+                                                let parts = Self::parse_initializer_list(
+                                                    tokens,
+                                                    current.span.clone(),
+                                                    ctx.sub_expression()?,
+                                                )?;
+
+                                                state.add_expression(Self::CompoundLiteral {
+                                                    target_ty,
+                                                    parts,
+                                                });
+                                            } else {
+                                                let exp = Self::parse_internal(
+                                                    tokens,
+                                                    ctx.sub_expression()?,
+                                                )?;
+
+                                                let cast_exp = Self::Cast {
+                                                    target_ty,
+                                                    exp: Box::new(exp),
+                                                };
+
+                                                state.add_expression(cast_exp);
+                                            }
                                         }
                                     };
                                 }
@@ -553,55 +880,9 @@ impl Expression {
                     state.add_single_op(SingleOperation::ArrayAccess(Box::new(exp)), span);
                 }
                 (TokenData::OpenBrace, _) => {
-                    let mut items = Vec::new();
-                    while let Ok(exp) = Self::parse_internal(tokens, ctx.sub_expression()?) {
-                        items.push(exp);
-
-                        let peeked_seperator_token =
-                            tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
-                                ctx: EOFContext::Expression,
-                            })?;
-                        match &peeked_seperator_token.data {
-                            TokenData::Comma => {
-                                let _ = tokens.next();
-                            }
-                            TokenData::CloseBrace => {}
-                            _ => {
-                                let tmp = tokens.next().unwrap();
-                                return Err(SyntaxError::UnexpectedToken {
-                                    expected: Some(vec![
-                                        ExpectedToken::Comma,
-                                        ExpectedToken::CloseBrace,
-                                    ]),
-                                    got: tmp.span,
-                                });
-                            }
-                        };
-                    }
-
-                    let closing_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
-                        ctx: EOFContext::Expression,
-                    })?;
-                    match closing_token.data {
-                        TokenData::CloseBrace => {}
-                        _ => {
-                            return Err(SyntaxError::UnexpectedToken {
-                                expected: Some(vec![ExpectedToken::CloseBrace]),
-                                got: closing_token.span,
-                            })
-                        }
-                    };
-
-                    let entire_range =
-                        current.span.source_area().start..closing_token.span.source_area().end;
-                    let entire_span =
-                        Span::new_arc_source(current.span.source().clone(), entire_range);
-                    state.add_expression(Expression::ArrayLiteral {
-                        parts: SpanData {
-                            span: entire_span,
-                            data: items,
-                        },
-                    });
+                    let parts =
+                        Self::parse_initializer_list(tokens, current.span, ctx.sub_expression()?)?;
+                    state.add_expression(Expression::ArrayLiteral { parts });
                 }
                 (TokenData::QuestionMark, _) => {
                     let first = Self::parse_internal(tokens, ctx.sub_expression()?)?;
@@ -748,8 +1029,98 @@ mod tests {
         let expected = Ok(Expression::StringLiteral {
             content: SpanData {
                 span: Span::new_source(source, 0..5),
-                data: "123".to_owned(),
+                data: "123".bytes().map(u32::from).collect(),
+            },
+            encoding: LiteralEncoding::Plain,
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn string_literal_escapes() {
+        let input_content = r#""a\tb\x41\"""#;
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Expression::StringLiteral {
+            content: SpanData {
+                span: Span::new_source(source, 0..12),
+                data: "a\tbA\"".bytes().map(u32::from).collect(),
+            },
+            encoding: LiteralEncoding::Plain,
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn string_literal_concatenation() {
+        let input_content = "\"ab\" L\"c\" \"d\"";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Expression::StringLiteral {
+            content: SpanData {
+                span: Span::new_source(source, 0..13),
+                data: "abcd".bytes().map(u32::from).collect(),
             },
+            encoding: LiteralEncoding::Wide,
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn string_literal_mismatched_prefixes() {
+        let input_content = "u\"a\" U\"b\"";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn char_literal_escapes() {
+        let input_content = r"'\x7f'";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Expression::CharLiteral {
+            content: SpanData {
+                span: Span::new_source(source, 0..6),
+                data: 0x7f,
+            },
+            encoding: LiteralEncoding::Plain,
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn char_literal_invalid_escape() {
+        let input_content = r"'\q'";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Err(SyntaxError::UnexpectedToken {
+            expected: None,
+            got: Span::new_source(source, 1..3),
         });
 
         let result = Expression::parse(&mut input_tokens);
@@ -1076,6 +1447,72 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn conditional_parens_condition() {
+        let input_content = "(1) ? 2 : 3";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Expression::Conditional {
+            condition: Box::new(Expression::Literal {
+                content: SpanData {
+                    span: Span::new_source(source.clone(), 1..2),
+                    data: "1".to_string(),
+                },
+            }),
+            first: Box::new(Expression::Literal {
+                content: SpanData {
+                    span: Span::new_source(source.clone(), 6..7),
+                    data: "2".to_string(),
+                },
+            }),
+            second: Box::new(Expression::Literal {
+                content: SpanData {
+                    span: Span::new_source(source, 10..11),
+                    data: "3".to_string(),
+                },
+            }),
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(None, input_tokens.next());
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn dereference_after_operator() {
+        let input_content = "a || *b";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Expression::Operation {
+            operation: ExpressionOperator::LogicalOr,
+            left: Box::new(Expression::Identifier {
+                ident: Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 0..1),
+                    data: "a".to_string(),
+                }),
+            }),
+            right: Box::new(Expression::SingleOperation {
+                operation: SingleOperation::Dereference,
+                base: Box::new(Expression::Identifier {
+                    ident: Identifier(SpanData {
+                        span: Span::new_source(source, 6..7),
+                        data: "b".to_string(),
+                    }),
+                }),
+            }),
+        });
+
+        let result = Expression::parse(&mut input_tokens);
+
+        assert_eq!(None, input_tokens.next());
+        assert_eq!(expected, result);
+    }
+
     #[test]
     fn parens() {
         let input_content = "(1 + 2)";
@@ -1159,8 +1596,9 @@ mod tests {
             Expression::StringLiteral {
                 content: SpanData {
                     span: Span::new_source(input_source.clone(), 0..1),
-                    data: "1".to_string(),
-                }
+                    data: vec![u32::from(b'1')],
+                },
+                encoding: LiteralEncoding::Plain,
             }
             .entire_span()
         );
@@ -1169,8 +1607,9 @@ mod tests {
             Expression::CharLiteral {
                 content: SpanData {
                     span: Span::new_source(input_source.clone(), 0..1),
-                    data: '1',
-                }
+                    data: u32::from('1'),
+                },
+                encoding: LiteralEncoding::Plain,
             }
             .entire_span()
         );
@@ -1249,4 +1688,25 @@ mod tests {
         assert_eq!(None, iter.next());
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn align_of() {
+        let input = "_Alignof(long)";
+        let source = Source::new("test", input);
+        let tokens = tokenizer::tokenize(source.clone().into());
+
+        let expected = Ok(Expression::AlignOf {
+            ty: TypeToken::Primitive(SpanData {
+                span: Span::new_source(source.clone(), 9..13),
+                data: DataType::Long,
+            }),
+            area: Span::new_source(source, 0..14),
+        });
+
+        let mut iter = peek_nth(tokens);
+        let result = Expression::parse(&mut iter);
+
+        assert_eq!(None, iter.next());
+        assert_eq!(expected, result);
+    }
 }
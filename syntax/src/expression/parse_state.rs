@@ -1,4 +1,4 @@
-use general::{Span, SpanData};
+use general::Span;
 use tokenizer::{Operator, TokenData};
 
 use crate::{Expression, ExpressionOperator, ExpressionReason, SingleOperation, SyntaxError};
@@ -126,7 +126,9 @@ impl RpnOp {
                 RpnOp::SingleOp(SingleOperation::PrefixDecrement)
             }
             (Operator::Decrement, Some(_)) => RpnOp::SingleOp(SingleOperation::SuffixDecrement),
-            (Operator::Multiply, None) => RpnOp::SingleOp(SingleOperation::Dereference),
+            (Operator::Multiply, Some(TokenData::Operator(_))) | (Operator::Multiply, None) => {
+                RpnOp::SingleOp(SingleOperation::Dereference)
+            }
             (Operator::Multiply, _) => RpnOp::Expression(ExpressionOperator::Multiply),
             (Operator::Divide, _) => RpnOp::Expression(ExpressionOperator::Divide),
             (Operator::Modulo, _) => RpnOp::Expression(ExpressionOperator::Modulo),
@@ -296,59 +298,6 @@ impl ParseState {
                                     base: Box::new(left),
                                     field: ident,
                                 },
-                                (right, ConnectionOp::Dot) => match (left, right) {
-                                    (
-                                        Expression::Literal {
-                                            content: left_content,
-                                        },
-                                        Expression::Literal {
-                                            content: right_content,
-                                        },
-                                    ) => {
-                                        let left_str = &left_content.data;
-                                        if left_str.chars().any(|c| !c.is_digit(10)) {
-                                            return Err(SyntaxError::UnexpectedToken {
-                                                expected: None,
-                                                got: left_content.span.clone(),
-                                            });
-                                        }
-
-                                        let right_span = {
-                                            let tmp = &right_content.data;
-                                            if tmp.ends_with('f') || tmp.ends_with('d') {
-                                                right_content
-                                                    .span
-                                                    .sub_span(0..tmp.len() - 1)
-                                                    .unwrap()
-                                            } else {
-                                                right_content.span.sub_span(0..tmp.len()).unwrap()
-                                            }
-                                        };
-
-                                        if right_span.content().chars().any(|c| !c.is_digit(10)) {
-                                            return Err(SyntaxError::UnexpectedToken {
-                                                expected: None,
-                                                got: right_span.into(),
-                                            });
-                                        }
-
-                                        let combined_span =
-                                            left_content.span.join(right_span.into());
-
-                                        Expression::Literal {
-                                            content: SpanData {
-                                                data: combined_span.content().to_owned(),
-                                                span: combined_span,
-                                            },
-                                        }
-                                    }
-                                    (_, _) => {
-                                        return Err(SyntaxError::UnexpectedToken {
-                                            expected: None,
-                                            got: span,
-                                        });
-                                    }
-                                },
                                 (_, _) => {
                                     return Err(SyntaxError::UnexpectedToken {
                                         expected: None,
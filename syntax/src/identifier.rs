@@ -42,7 +42,7 @@ impl Identifier {
         // Validate the Name
         match content.chars().next() {
             Some('0') | Some('1') | Some('2') | Some('3') | Some('4') | Some('5') | Some('6')
-            | Some('7') | Some('8') | Some('9') | Some('_') | Some('-') | None => {
+            | Some('7') | Some('8') | Some('9') | Some('-') | None => {
                 return Err(SyntaxError::UnexpectedToken {
                     expected: None,
                     got: span,
@@ -6,15 +6,21 @@ pub use scope::Scope;
 
 mod statement;
 pub use statement::{
-    AssignTarget, EnumVariant, EnumVariants, FunctionHead, Statement, StructMembers, TypeDefType,
+    Alignment, AssignTarget, CaseLabel, EnumVariant, EnumVariants, FunctionHead, Statement,
+    StructMembers, SwitchCase, TypeDefType,
 };
 
 mod expression;
-pub use expression::{Expression, ExpressionOperator, SingleOperation};
+pub use expression::{Designator, Expression, ExpressionOperator, SingleOperation};
 
 mod identifier;
 pub use identifier::Identifier;
 
+mod literal;
+pub use literal::{
+    parse_number, FloatKind, IntegerLength, LiteralEncoding, NumberError, NumberLiteral,
+};
+
 mod ty;
 pub use ty::{Modifier, TypeToken};
 
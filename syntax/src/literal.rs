@@ -0,0 +1,497 @@
+use std::{iter::Peekable, ops::Range, str::CharIndices};
+
+/// The Encoding of a String- or Char-Literal, which is determined by its Prefix
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum LiteralEncoding {
+    /// No Prefix, the Literal is encoded as UTF-8 in `char`s
+    Plain,
+    /// The "u8" Prefix, the Literal is encoded as UTF-8 in `char`s
+    Utf8,
+    /// The "L" Prefix, every Character is stored in a single `wchar_t`
+    Wide,
+    /// The "u" Prefix, the Literal is encoded as UTF-16 in `char16_t`s
+    Utf16,
+    /// The "U" Prefix, every Character is stored in a single `char32_t`
+    Utf32,
+}
+
+impl LiteralEncoding {
+    /// Determines the Encoding for the given Prefix of a Literal, returns None if the Prefix is
+    /// not a valid one
+    pub fn from_prefix(prefix: Option<&str>) -> Option<Self> {
+        match prefix {
+            None => Some(Self::Plain),
+            Some("u8") => Some(Self::Utf8),
+            Some("L") => Some(Self::Wide),
+            Some("u") => Some(Self::Utf16),
+            Some("U") => Some(Self::Utf32),
+            Some(_) => None,
+        }
+    }
+
+    /// The Encoding of two adjacent String-Literals that are concatenated, which only exists if
+    /// they have the same Prefix or at most one of them has a Prefix
+    pub fn concat(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Self::Plain, other) => Some(other),
+            (own, Self::Plain) => Some(own),
+            (own, other) if own == other => Some(own),
+            _ => None,
+        }
+    }
+
+    /// The largest Value that fits into a single Code-Unit of this Encoding
+    fn max_unit(&self) -> u32 {
+        match self {
+            Self::Plain | Self::Utf8 => u8::MAX as u32,
+            Self::Utf16 => u16::MAX as u32,
+            Self::Wide | Self::Utf32 => u32::MAX,
+        }
+    }
+
+    /// Appends the Code-Units of the given Character in this Encoding
+    fn encode(&self, c: char, units: &mut Vec<u32>) {
+        match self {
+            Self::Plain | Self::Utf8 => {
+                let mut buffer = [0; 4];
+                units.extend(c.encode_utf8(&mut buffer).bytes().map(u32::from));
+            }
+            Self::Utf16 => {
+                let mut buffer = [0; 2];
+                units.extend(c.encode_utf16(&mut buffer).iter().map(|u| u32::from(*u)));
+            }
+            Self::Wide | Self::Utf32 => units.push(c as u32),
+        }
+    }
+}
+
+/// Resolves all the Escape-Sequences in the raw Content of a String- or Char-Literal and returns
+/// the resulting Code-Units in the given Encoding.
+///
+/// # Errors
+/// Returns the Range of the first invalid Escape-Sequence in the raw Content
+pub fn decode(raw: &str, encoding: LiteralEncoding) -> Result<Vec<u32>, Range<usize>> {
+    let mut units = Vec::with_capacity(raw.len());
+
+    let mut chars = raw.char_indices().peekable();
+    while let Some((start, current)) = chars.next() {
+        if current != '\\' {
+            encoding.encode(current, &mut units);
+            continue;
+        }
+
+        let (_, escaped) = chars.next().ok_or(start..raw.len())?;
+        match escaped {
+            '\'' | '"' | '?' | '\\' => encoding.encode(escaped, &mut units),
+            'a' => units.push(0x07),
+            'b' => units.push(0x08),
+            'f' => units.push(0x0c),
+            'n' => units.push(0x0a),
+            'r' => units.push(0x0d),
+            't' => units.push(0x09),
+            'v' => units.push(0x0b),
+            '0'..='7' => {
+                // Octal Escapes consist of at most 3 Digits
+                let first = escaped.to_digit(8).expect("Matched an octal Digit");
+                let value = digits(&mut chars, 8, 2, first).ok_or(start..start + 4)?;
+                let end = end_index(&mut chars, raw);
+
+                if value > encoding.max_unit() {
+                    return Err(start..end);
+                }
+                units.push(value);
+            }
+            'x' => {
+                // Hex Escapes take as many Digits as there are
+                let value = digits(&mut chars, 16, usize::MAX, 0);
+                let end = end_index(&mut chars, raw);
+
+                match value {
+                    Some(value) if end > start + 2 && value <= encoding.max_unit() => {
+                        units.push(value);
+                    }
+                    _ => return Err(start..end),
+                };
+            }
+            'u' | 'U' => {
+                let count = if escaped == 'u' { 4 } else { 8 };
+
+                let mut value: u32 = 0;
+                for _ in 0..count {
+                    let digit = chars
+                        .next()
+                        .and_then(|(_, c)| c.to_digit(16))
+                        .ok_or(start..start + 2)?;
+                    value = value * 16 + digit;
+                }
+                let end = end_index(&mut chars, raw);
+
+                let c = char::from_u32(value).ok_or(start..end)?;
+                encoding.encode(c, &mut units);
+            }
+            _ => return Err(start..start + 1 + escaped.len_utf8()),
+        };
+    }
+
+    Ok(units)
+}
+
+/// Consumes up to `max` Digits in the given Radix and appends them to the initial Value, returns
+/// None if the Value overflows
+fn digits(chars: &mut Peekable<CharIndices>, radix: u32, max: usize, initial: u32) -> Option<u32> {
+    let mut value = initial;
+    for _ in 0..max {
+        let digit = match chars.peek().and_then(|(_, c)| c.to_digit(radix)) {
+            Some(d) => d,
+            None => break,
+        };
+        let _ = chars.next();
+
+        value = value.checked_mul(radix)?.checked_add(digit)?;
+    }
+
+    Some(value)
+}
+
+/// The Index in the raw Content of the next Character that has not been consumed yet
+fn end_index(chars: &mut Peekable<CharIndices>, raw: &str) -> usize {
+    chars.peek().map(|(index, _)| *index).unwrap_or(raw.len())
+}
+
+/// The Length of an Integer-Constant, which is determined by its Suffix
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IntegerLength {
+    /// No Length-Suffix
+    Int,
+    /// The "l" or "L" Suffix
+    Long,
+    /// The "ll" or "LL" Suffix
+    LongLong,
+}
+
+/// The Kind of a Floating-Point-Constant, which is determined by its Suffix
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FloatKind {
+    /// The "f" or "F" Suffix
+    Float,
+    /// No Suffix
+    Double,
+    /// The "l" or "L" Suffix
+    LongDouble,
+}
+
+/// A Numeric Constant with its Value and everything from its Radix and Suffix that is needed to
+/// determine its Type
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NumberLiteral {
+    /// An Integer-Constant
+    Integer {
+        /// The Value of the Constant
+        value: u64,
+        /// Whether or not the Constant was written in Decimal, which restricts the Types it can
+        /// have if it has no "u" Suffix
+        decimal: bool,
+        /// Whether or not the Constant has a "u" Suffix
+        unsigned: bool,
+        /// The Length given by the Suffix of the Constant
+        length: IntegerLength,
+    },
+    /// A Floating-Point-Constant
+    Float {
+        /// The Value of the Constant, already rounded to the Precision of its Kind
+        value: f64,
+        /// The Kind given by the Suffix of the Constant
+        kind: FloatKind,
+    },
+}
+
+/// The Reasons a Numeric Constant can be invalid
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumberError {
+    /// The Constant is malformed, for example because it contains a Digit that is not valid in
+    /// its Radix or has an unknown Suffix
+    Malformed,
+    /// The Value of the Constant is too large to be represented
+    Overflow,
+}
+
+/// Parses the raw Text of a Numeric Constant, which can either be an Integer-Constant in
+/// decimal, hexadecimal ("0x"), octal ("0") or binary ("0b") or a decimal or hexadecimal
+/// Floating-Point-Constant, each with their optional Suffixes
+pub fn parse_number(raw: &str) -> Result<NumberLiteral, NumberError> {
+    if !raw.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return Err(NumberError::Malformed);
+    }
+
+    let hex = raw.starts_with("0x") || raw.starts_with("0X");
+    let is_float = if hex {
+        raw.contains(['.', 'p', 'P'])
+    } else {
+        raw.contains(['.', 'e', 'E'])
+    };
+
+    if is_float {
+        parse_float(raw, hex)
+    } else {
+        parse_integer(raw)
+    }
+}
+
+fn parse_integer(raw: &str) -> Result<NumberLiteral, NumberError> {
+    let (radix, digits) = match raw.get(..2) {
+        Some("0x" | "0X") => (16, &raw[2..]),
+        Some("0b" | "0B") => (2, &raw[2..]),
+        _ if raw.starts_with('0') => (8, raw),
+        _ => (10, raw),
+    };
+
+    let suffix_start = digits.find(['u', 'U', 'l', 'L']).unwrap_or(digits.len());
+    let (digits, suffix) = digits.split_at(suffix_start);
+    if digits.is_empty() {
+        return Err(NumberError::Malformed);
+    }
+
+    let mut value: u64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(radix).ok_or(NumberError::Malformed)?;
+        value = value
+            .checked_mul(radix as u64)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or(NumberError::Overflow)?;
+    }
+
+    // The two Characters of "ll" need to have the same Case
+    if suffix.contains("lL") || suffix.contains("Ll") {
+        return Err(NumberError::Malformed);
+    }
+    let (unsigned, length) = match suffix.to_ascii_lowercase().as_str() {
+        "" => (false, IntegerLength::Int),
+        "u" => (true, IntegerLength::Int),
+        "l" => (false, IntegerLength::Long),
+        "ul" | "lu" => (true, IntegerLength::Long),
+        "ll" => (false, IntegerLength::LongLong),
+        "ull" | "llu" => (true, IntegerLength::LongLong),
+        _ => return Err(NumberError::Malformed),
+    };
+
+    Ok(NumberLiteral::Integer {
+        value,
+        decimal: radix == 10,
+        unsigned,
+        length,
+    })
+}
+
+fn parse_float(raw: &str, hex: bool) -> Result<NumberLiteral, NumberError> {
+    let (number, kind) = match raw.as_bytes().last() {
+        // An "f" is a hex Digit, but hex Constants always end with the decimal Exponent
+        Some(b'f' | b'F') => (&raw[..raw.len() - 1], FloatKind::Float),
+        Some(b'l' | b'L') => (&raw[..raw.len() - 1], FloatKind::LongDouble),
+        _ => (raw, FloatKind::Double),
+    };
+
+    let value = if hex {
+        parse_hex_float(&number[2..])?
+    } else {
+        // The Rust Parser also accepts Things like "inf", but those never start with a Digit
+        number.parse().map_err(|_| NumberError::Malformed)?
+    };
+
+    let value = match kind {
+        FloatKind::Float => value as f32 as f64,
+        FloatKind::Double | FloatKind::LongDouble => value,
+    };
+    if value.is_infinite() {
+        return Err(NumberError::Overflow);
+    }
+
+    Ok(NumberLiteral::Float { value, kind })
+}
+
+/// Parses the Part of a hexadecimal Floating-Point-Constant after the "0x", which always needs
+/// a binary Exponent
+fn parse_hex_float(raw: &str) -> Result<f64, NumberError> {
+    let (mantissa, exponent) = raw.split_once(['p', 'P']).ok_or(NumberError::Malformed)?;
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(NumberError::Malformed);
+    }
+
+    let exponent: i32 = match exponent.strip_prefix('+') {
+        Some(rest) if !rest.starts_with('-') => rest.parse(),
+        _ => exponent.parse(),
+    }
+    .map_err(|_| NumberError::Malformed)?;
+
+    let mut value: f64 = 0.0;
+    for c in whole.chars() {
+        let digit = c.to_digit(16).ok_or(NumberError::Malformed)?;
+        value = value * 16.0 + digit as f64;
+    }
+    let mut scale = 1.0 / 16.0;
+    for c in fraction.chars() {
+        let digit = c.to_digit(16).ok_or(NumberError::Malformed)?;
+        value += digit as f64 * scale;
+        scale /= 16.0;
+    }
+
+    Ok(value * 2f64.powi(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(content: &str) -> Vec<u32> {
+        content.bytes().map(u32::from).collect()
+    }
+
+    #[test]
+    fn simple_escapes() {
+        let result = decode(r#"a\n\t\\\"\'\?\0"#, LiteralEncoding::Plain);
+
+        assert_eq!(Ok(units("a\n\t\\\"'?\0")), result);
+    }
+
+    #[test]
+    fn octal_and_hex() {
+        assert_eq!(
+            Ok(vec![0o101, 0o7, 0o12, u32::from(b'3')]),
+            decode(r"\101\7\0123", LiteralEncoding::Plain)
+        );
+        assert_eq!(
+            Ok(vec![0xff, 0x1]),
+            decode(r"\xff\x1", LiteralEncoding::Plain)
+        );
+        assert_eq!(Ok(vec![0x1234]), decode(r"\x1234", LiteralEncoding::Utf16));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Err(0..5), decode(r"\x100", LiteralEncoding::Plain));
+        assert_eq!(Err(1..5), decode(r"a\777", LiteralEncoding::Plain));
+        assert_eq!(Err(0..2), decode(r"\x", LiteralEncoding::Plain));
+        assert_eq!(Err(0..2), decode(r"\q", LiteralEncoding::Plain));
+    }
+
+    #[test]
+    fn universal_characters() {
+        assert_eq!(Ok(units("é")), decode(r"é", LiteralEncoding::Plain));
+        assert_eq!(
+            Ok(vec![0xd83d, 0xde00]),
+            decode(r"\U0001F600", LiteralEncoding::Utf16)
+        );
+        assert_eq!(
+            Ok(vec![0x1f600]),
+            decode(r"\U0001F600", LiteralEncoding::Utf32)
+        );
+        assert_eq!(Err(0..6), decode(r"\ud800", LiteralEncoding::Utf32));
+    }
+
+    #[test]
+    fn utf8_source() {
+        assert_eq!(Ok(units("grüße")), decode("grüße", LiteralEncoding::Plain));
+        assert_eq!(
+            Ok(vec![u32::from('ü'), u32::from('ß')]),
+            decode("üß", LiteralEncoding::Wide)
+        );
+    }
+
+    #[test]
+    fn concat_encodings() {
+        use LiteralEncoding::*;
+
+        assert_eq!(Some(Wide), Plain.concat(Wide));
+        assert_eq!(Some(Utf8), Utf8.concat(Plain));
+        assert_eq!(Some(Utf16), Utf16.concat(Utf16));
+        assert_eq!(None, Utf16.concat(Wide));
+    }
+
+    fn integer(value: u64, decimal: bool, unsigned: bool, length: IntegerLength) -> NumberLiteral {
+        NumberLiteral::Integer {
+            value,
+            decimal,
+            unsigned,
+            length,
+        }
+    }
+
+    #[test]
+    fn integer_radixes() {
+        use IntegerLength::*;
+
+        assert_eq!(Ok(integer(123, true, false, Int)), parse_number("123"));
+        assert_eq!(Ok(integer(0, false, false, Int)), parse_number("0"));
+        assert_eq!(Ok(integer(0o17, false, false, Int)), parse_number("017"));
+        assert_eq!(Ok(integer(0xff, false, false, Int)), parse_number("0xFf"));
+        assert_eq!(Ok(integer(0b101, false, false, Int)), parse_number("0b101"));
+
+        assert_eq!(Err(NumberError::Malformed), parse_number("019"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("0x"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("0b2"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("12a"));
+    }
+
+    #[test]
+    fn integer_suffixes() {
+        use IntegerLength::*;
+
+        assert_eq!(
+            Ok(integer(0xFFFF0000, false, true, Int)),
+            parse_number("0xFFFF0000u")
+        );
+        assert_eq!(Ok(integer(1, true, false, Long)), parse_number("1L"));
+        assert_eq!(Ok(integer(1, true, true, Long)), parse_number("1lu"));
+        assert_eq!(Ok(integer(1, true, true, Long)), parse_number("1Ul"));
+        assert_eq!(Ok(integer(1, true, false, LongLong)), parse_number("1ll"));
+        assert_eq!(Ok(integer(1, true, true, LongLong)), parse_number("1ULL"));
+
+        assert_eq!(Err(NumberError::Malformed), parse_number("1lL"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("1uu"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("1lul"));
+    }
+
+    #[test]
+    fn integer_overflow() {
+        assert_eq!(
+            Ok(integer(u64::MAX, false, false, IntegerLength::Int)),
+            parse_number("0xffffffffffffffff")
+        );
+        assert_eq!(
+            Err(NumberError::Overflow),
+            parse_number("18446744073709551616")
+        );
+    }
+
+    #[test]
+    fn floats() {
+        let float = |value, kind| Ok(NumberLiteral::Float { value, kind });
+
+        assert_eq!(float(1.5, FloatKind::Double), parse_number("1.5"));
+        assert_eq!(float(0.5, FloatKind::Double), parse_number(".5"));
+        assert_eq!(float(2.0, FloatKind::Double), parse_number("2."));
+        assert_eq!(float(1.5e-3, FloatKind::Double), parse_number("1.5e-3"));
+        assert_eq!(float(1e10, FloatKind::LongDouble), parse_number("1e10L"));
+        assert_eq!(float(0.1f32 as f64, FloatKind::Float), parse_number("0.1f"));
+
+        assert_eq!(Err(NumberError::Malformed), parse_number("1.5x"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("1e"));
+        assert_eq!(Err(NumberError::Overflow), parse_number("1e400"));
+        assert_eq!(Err(NumberError::Overflow), parse_number("1e39f"));
+    }
+
+    #[test]
+    fn hex_floats() {
+        let float = |value, kind| Ok(NumberLiteral::Float { value, kind });
+
+        assert_eq!(float(12.0, FloatKind::Double), parse_number("0x1.8p3"));
+        assert_eq!(float(0.25, FloatKind::Float), parse_number("0x1p-2f"));
+        assert_eq!(float(255.0, FloatKind::Double), parse_number("0xf.fp+4"));
+        assert_eq!(float(0.5, FloatKind::Double), parse_number("0x.8P0"));
+
+        assert_eq!(Err(NumberError::Malformed), parse_number("0x1.8"));
+        assert_eq!(Err(NumberError::Malformed), parse_number("0xp1"));
+        assert_eq!(Err(NumberError::Overflow), parse_number("0x1p1024"));
+    }
+}
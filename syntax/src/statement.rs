@@ -19,8 +19,15 @@ pub use structs::StructMembers;
 mod enums;
 pub use enums::*;
 
+mod switch;
+pub use switch::{CaseLabel, SwitchCase};
+
 mod assign_type;
 
+mod alignment;
+pub use alignment::Alignment;
+mod static_assert;
+
 #[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum TypeDefType {
@@ -33,6 +40,13 @@ pub enum TypeDefType {
         members: structs::StructMembers,
         entire_span: Span,
     },
+    /// An Enum that is defined as part of the TypeDef, like "typedef enum { A, B } E;"
+    EnumDef {
+        name: Option<Identifier>,
+        /// The Span of the "enum" Keyword itself
+        keyword: Span,
+        variants: EnumVariants,
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -61,9 +75,31 @@ pub enum Statement {
         /// The entire Span of the Struct Definition
         definition: Span,
     },
-    EnumDefinition {
+    UnionDefinition {
+        /// The Name of the Union
+        name: Identifier,
+        /// The members of the Union
+        members: StructMembers,
+        /// The entire Span of the Union Definition
+        definition: Span,
+    },
+    /// Declares a Struct without its Members, which can then only be used through Pointers
+    /// until it is defined later on
+    StructDeclaration {
+        name: Identifier,
+    },
+    /// Declares a Union without its Members, like [`Statement::StructDeclaration`]
+    UnionDeclaration {
         name: Identifier,
+    },
+    /// Defines an Enum, whose Name is optional, and possibly declares a Variable of it as well
+    EnumDefinition {
+        name: Option<Identifier>,
+        /// The Span of the "enum" Keyword itself
+        keyword: Span,
         variants: EnumVariants,
+        /// The Declaration of a Variable of the Enum, like in "enum E { A, B } v;"
+        declaration: Option<Box<Self>>,
     },
     VariableDeclaration {
         ty: TypeToken,
@@ -86,11 +122,39 @@ pub enum Statement {
         name: Identifier,
         base_type: TypeDefType,
     },
+    /// A Declaration of a Variable or Function with the "static" Storage-Class
+    Static {
+        /// The Span of the "static" Keyword itself
+        keyword: Span,
+        /// The actual Declaration or Definition
+        inner: Box<Self>,
+    },
+    /// A Declaration of a Variable with an explicit Alignment, like "_Alignas(16) int x[4];"
+    AlignAs {
+        /// The Span of the "_Alignas" Keyword itself
+        keyword: Span,
+        alignment: Alignment,
+        /// The actual Declaration
+        inner: Box<Self>,
+    },
+    /// An Assertion that is checked at compile-time, like
+    /// "_Static_assert(sizeof(int) == 4, "int has to be 4 Bytes");"
+    StaticAssert {
+        /// The Span of the "_Static_assert" Keyword itself
+        keyword: Span,
+        condition: Expression,
+        /// The Message that is reported if the Assertion fails
+        message: SpanData<String>,
+    },
     SingleExpression(Expression),
     WhileLoop {
         condition: Expression,
         scope: Scope,
     },
+    DoWhileLoop {
+        condition: Expression,
+        scope: Scope,
+    },
     ForLoop {
         setup: Vec<Self>,
         condition: Expression,
@@ -102,6 +166,17 @@ pub enum Statement {
         scope: Scope,
         elses: Option<Scope>,
     },
+    Switch {
+        value: Expression,
+        cases: Vec<SwitchCase>,
+    },
+    /// A Label that can be the Target of a Goto
+    Label {
+        name: Identifier,
+    },
+    Goto {
+        target: Identifier,
+    },
     Continue,
     Break,
     Return(Option<Expression>),
@@ -125,6 +200,9 @@ impl Statement {
     where
         I: Iterator<Item = Token>,
     {
+        // An Identifier directly followed by a Colon can only be a Label
+        let is_label = matches!(tokens.peek_nth(1).map(|t| &t.data), Some(TokenData::Colon));
+
         let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
             ctx: EOFContext::Statement,
         })?;
@@ -152,8 +230,14 @@ impl Statement {
 
                 Ok(Self::Return(exp))
             }
+            TokenData::Literal { .. } if is_label => {
+                let name = Identifier::parse(tokens)?;
+                let _ = tokens.next();
+
+                Ok(Self::Label { name })
+            }
             TokenData::Literal { .. } => starting_literal::parse(tokens, is_termination),
-            TokenData::Keyword(Keyword::DataType(_)) => {
+            TokenData::Keyword(Keyword::DataType(_) | Keyword::Const | Keyword::Volatile) => {
                 starting_type::parse(tokens, is_termination)
             }
             TokenData::Operator(tokenizer::Operator::Multiply) => {
@@ -190,7 +274,9 @@ impl Statement {
 
                 Ok(Self::VariableDerefAssignment { target, value })
             }
-            TokenData::Operator(Operator::Increment) | TokenData::Operator(Operator::Decrement) => {
+            TokenData::Operator(Operator::Increment)
+            | TokenData::Operator(Operator::Decrement)
+            | TokenData::OpenParen => {
                 let exp = Expression::parse(tokens)?;
 
                 let ending_tok = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
@@ -206,11 +292,40 @@ impl Statement {
             TokenData::Keyword(Keyword::TypeDef) => {
                 let _ = tokens.next();
 
+                // A Struct or Enum is only defined as part of the TypeDef, if it is followed by
+                // its Members, otherwise it only refers to an existing or incomplete Type
+                let defines_type = match tokens.peek_nth(1).map(|t| &t.data) {
+                    Some(TokenData::OpenBrace) => true,
+                    Some(TokenData::Literal { .. }) => {
+                        matches!(tokens.peek_nth(2).map(|t| &t.data), Some(TokenData::OpenBrace))
+                    }
+                    _ => false,
+                };
+
                 let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
                     ctx: EOFContext::Statement,
                 })?;
                 match &peeked.data {
-                    TokenData::Keyword(Keyword::DataType(DataType::Struct)) => {
+                    TokenData::Keyword(Keyword::DataType(DataType::Enum)) if defines_type => {
+                        let (keyword, enum_name, variants) = enums::parse_head(tokens)?;
+
+                        let n_type_name = Identifier::parse(tokens)?;
+
+                        let term_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                            ctx: EOFContext::Statement,
+                        })?;
+                        is_termination(term_token)?;
+
+                        Ok(Self::TypeDef {
+                            name: n_type_name,
+                            base_type: TypeDefType::EnumDef {
+                                name: enum_name,
+                                keyword,
+                                variants,
+                            },
+                        })
+                    }
+                    TokenData::Keyword(Keyword::DataType(DataType::Struct)) if defines_type => {
                         let _ = tokens.next();
 
                         let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
@@ -265,7 +380,18 @@ impl Statement {
                     _ => {
                         let ty = TypeToken::parse(tokens)?;
 
-                        let n_type_name = Identifier::parse(tokens)?;
+                        let (ty, n_type_name) = if TypeToken::is_function_pointer(tokens) {
+                            TypeToken::parse_function_pointer(ty, tokens)?
+                        } else {
+                            let name = Identifier::parse(tokens)?;
+                            let ty = TypeToken::parse_array_dimensions(ty, tokens)?;
+                            (ty, name)
+                        };
+
+                        let term_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                            ctx: EOFContext::Statement,
+                        })?;
+                        is_termination(term_token)?;
 
                         Ok(Self::TypeDef {
                             name: n_type_name,
@@ -274,6 +400,67 @@ impl Statement {
                     }
                 }
             }
+            TokenData::Keyword(Keyword::Static) => {
+                let keyword = tokens.next().unwrap().span;
+
+                let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                let inner = match &peeked.data {
+                    TokenData::Keyword(Keyword::Const | Keyword::Volatile) => {
+                        starting_type::parse(tokens, is_termination)?
+                    }
+                    _ => Self::parse(tokens, is_termination)?,
+                };
+
+                match &inner {
+                    Self::VariableDeclaration { .. }
+                    | Self::VariableDeclarationAssignment { .. }
+                    | Self::AlignAs { .. }
+                    | Self::FunctionDeclaration(_)
+                    | Self::FunctionDefinition { .. } => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: None,
+                            got: keyword,
+                        })
+                    }
+                };
+
+                Ok(Self::Static {
+                    keyword,
+                    inner: Box::new(inner),
+                })
+            }
+            TokenData::Keyword(Keyword::AlignAs) => {
+                let keyword = tokens.next().unwrap().span;
+
+                let alignment = Alignment::parse(tokens)?;
+                let inner = Self::parse(tokens, is_termination)?;
+
+                match &inner {
+                    Self::VariableDeclaration { .. }
+                    | Self::VariableDeclarationAssignment { .. }
+                    | Self::Static { .. } => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: None,
+                            got: keyword,
+                        })
+                    }
+                };
+
+                Ok(Self::AlignAs {
+                    keyword,
+                    alignment,
+                    inner: Box::new(inner),
+                })
+            }
+            TokenData::Keyword(Keyword::StaticAssert) => {
+                let keyword = tokens.next().unwrap().span;
+
+                static_assert::parse(keyword, tokens, is_termination)
+            }
             TokenData::Keyword(Keyword::ControlFlow(ControlFlow::If)) => {
                 let _ = tokens.next();
 
@@ -391,6 +578,113 @@ impl Statement {
                     scope: inner_scope,
                 })
             }
+            TokenData::Keyword(Keyword::ControlFlow(ControlFlow::Do)) => {
+                let _ = tokens.next();
+
+                let open_brace_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match open_brace_token.data {
+                    TokenData::OpenBrace => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::OpenBrace]),
+                            got: open_brace_token.span,
+                        })
+                    }
+                };
+
+                let inner_scope = Scope::parse(tokens)?;
+
+                let while_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match while_token.data {
+                    TokenData::Keyword(Keyword::ControlFlow(ControlFlow::While)) => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::While]),
+                            got: while_token.span,
+                        })
+                    }
+                };
+
+                let open_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match open_paren_token.data {
+                    TokenData::OpenParen => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::OpenParen]),
+                            got: open_paren_token.span,
+                        })
+                    }
+                };
+
+                let condition_exp = Expression::parse(tokens)?;
+
+                let close_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match close_paren_token.data {
+                    TokenData::CloseParen => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::CloseParen]),
+                            got: close_paren_token.span,
+                        })
+                    }
+                };
+
+                let end_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                is_termination(end_token)?;
+
+                Ok(Self::DoWhileLoop {
+                    condition: condition_exp,
+                    scope: inner_scope,
+                })
+            }
+            TokenData::Keyword(Keyword::ControlFlow(ControlFlow::Switch)) => {
+                let _ = tokens.next();
+
+                let open_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match open_paren_token.data {
+                    TokenData::OpenParen => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::OpenParen]),
+                            got: open_paren_token.span,
+                        })
+                    }
+                };
+
+                let value_exp = Expression::parse(tokens)?;
+
+                let close_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match close_paren_token.data {
+                    TokenData::CloseParen => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::CloseParen]),
+                            got: close_paren_token.span,
+                        })
+                    }
+                };
+
+                let cases = SwitchCase::parse_body(tokens)?;
+
+                Ok(Self::Switch {
+                    value: value_exp,
+                    cases,
+                })
+            }
             TokenData::Keyword(Keyword::ControlFlow(ControlFlow::Break)) => {
                 let _ = tokens.next();
 
@@ -427,6 +721,26 @@ impl Statement {
 
                 Ok(Statement::Continue)
             }
+            TokenData::Keyword(Keyword::ControlFlow(ControlFlow::Goto)) => {
+                let _ = tokens.next();
+
+                let target = Identifier::parse(tokens)?;
+
+                let semi_colon_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                    ctx: EOFContext::Statement,
+                })?;
+                match semi_colon_token.data {
+                    TokenData::Semicolon => {}
+                    _ => {
+                        return Err(SyntaxError::UnexpectedToken {
+                            expected: Some(vec![ExpectedToken::Semicolon]),
+                            got: semi_colon_token.span,
+                        })
+                    }
+                };
+
+                Ok(Statement::Goto { target })
+            }
             TokenData::Keyword(Keyword::ControlFlow(ControlFlow::For)) => {
                 let _ = tokens.next();
 
@@ -509,7 +823,7 @@ mod tests {
     use itertools::peek_nth;
     use tokenizer::DataType;
 
-    use crate::{ExpressionOperator, SingleOperation};
+    use crate::{ExpressionOperator, LiteralEncoding, SingleOperation};
 
     use super::*;
 
@@ -607,20 +921,24 @@ mod tests {
                             span: Span::new_source(source.clone(), 26..29),
                             data: DataType::Int,
                         }),
-                        Identifier(SpanData {
+                        Some(Identifier(SpanData {
                             span: Span::new_source(source.clone(), 30..35),
                             data: "first".to_string(),
-                        }),
+                        })),
+                        None,
+                        None,
                     ),
                     (
                         TypeToken::Primitive(SpanData {
                             span: Span::new_source(source.clone(), 49..52),
                             data: DataType::Int,
                         }),
-                        Identifier(SpanData {
+                        Some(Identifier(SpanData {
                             span: Span::new_source(source.clone(), 53..59),
                             data: "second".to_string(),
-                        }),
+                        })),
+                        None,
+                        None,
                     ),
                 ],
             },
@@ -762,6 +1080,88 @@ mod tests {
 
         assert_eq!(expected, result);
     }
+    #[test]
+    fn declare_multi_dimensional_array() {
+        let input_content = "int test[2][3];";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Statement::VariableDeclaration {
+            ty: TypeToken::ArrayType {
+                base: Box::new(TypeToken::ArrayType {
+                    base: Box::new(TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 0..3),
+                        data: DataType::Int,
+                    })),
+                    size: Some(Box::new(Expression::Literal {
+                        content: SpanData {
+                            span: Span::new_source(source.clone(), 12..13),
+                            data: "3".to_string(),
+                        },
+                    })),
+                }),
+                size: Some(Box::new(Expression::Literal {
+                    content: SpanData {
+                        span: Span::new_source(source.clone(), 9..10),
+                        data: "2".to_string(),
+                    },
+                })),
+            },
+            name: Identifier(SpanData {
+                span: Span::new_source(source, 4..8),
+                data: "test".to_string(),
+            }),
+        });
+
+        let result = Statement::parse(&mut input_tokens, &Statement::default_terminaton());
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn declare_struct() {
+        let input_content = "struct node;";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Statement::StructDeclaration {
+            name: Identifier(SpanData {
+                span: Span::new_source(source, 7..11),
+                data: "node".to_string(),
+            }),
+        });
+
+        let result = Statement::parse(&mut input_tokens, &Statement::default_terminaton());
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn typedef_struct_pointer() {
+        let input_content = "typedef struct node *node_ptr;";
+        let source = Source::new("test", input_content);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(Statement::TypeDef {
+            name: Identifier(SpanData {
+                span: Span::new_source(source.clone(), 21..29),
+                data: "node_ptr".to_string(),
+            }),
+            base_type: TypeDefType::Type(TypeToken::Pointer(Box::new(TypeToken::StructType {
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 15..19),
+                    data: "node".to_string(),
+                }),
+            }))),
+        });
+
+        let result = Statement::parse(&mut input_tokens, &Statement::default_terminaton());
+
+        assert_eq!(expected, result);
+    }
 
     #[test]
     fn declare_array_with_one_value() {
@@ -939,8 +1339,9 @@ mod tests {
             operation: SingleOperation::FuntionCall(vec![Expression::StringLiteral {
                 content: SpanData {
                     span: Span::new_source(source, 5..14),
-                    data: "literal".to_string(),
+                    data: "literal".bytes().map(u32::from).collect(),
                 },
+                encoding: LiteralEncoding::Plain,
             }]),
         }));
 
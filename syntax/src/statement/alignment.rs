@@ -0,0 +1,54 @@
+use itertools::PeekNth;
+use tokenizer::{Keyword, Token, TokenData};
+
+use crate::{EOFContext, ExpectedToken, Expression, SyntaxError, TypeToken};
+
+/// The Argument of an Alignment-Specifier
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum Alignment {
+    /// Uses the Alignment of the given Type, like "_Alignas(double)"
+    Type(TypeToken),
+    /// An explicit Alignment in Bytes, like "_Alignas(16)"
+    Expression(Expression),
+}
+
+impl Alignment {
+    /// Parses the parenthesized Argument following the "_Alignas" Keyword
+    pub fn parse<I>(tokens: &mut PeekNth<I>) -> Result<Self, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let open_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Statement,
+        })?;
+        if open_paren_token.data != TokenData::OpenParen {
+            return Err(SyntaxError::UnexpectedToken {
+                expected: Some(vec![ExpectedToken::OpenParen]),
+                got: open_paren_token.span,
+            });
+        }
+
+        let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Statement,
+        })?;
+        let alignment = match &peeked.data {
+            TokenData::Keyword(Keyword::DataType(_) | Keyword::Const | Keyword::Volatile) => {
+                Self::Type(TypeToken::parse(tokens)?)
+            }
+            _ => Self::Expression(Expression::parse(tokens)?),
+        };
+
+        let close_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Statement,
+        })?;
+        if close_paren_token.data != TokenData::CloseParen {
+            return Err(SyntaxError::UnexpectedToken {
+                expected: Some(vec![ExpectedToken::CloseParen]),
+                got: close_paren_token.span,
+            });
+        }
+
+        Ok(alignment)
+    }
+}
@@ -1,14 +1,15 @@
-use general::SpanData;
+use general::{Span, SpanData};
 use itertools::PeekNth;
-use tokenizer::{Assignment, Token, TokenData};
+use tokenizer::{Assignment, DataType, Keyword, Token, TokenData};
 
-use crate::{EOFContext, ExpectedToken, Identifier, SyntaxError};
+use crate::{EOFContext, ExpectedToken, Expression, Identifier, Statement, SyntaxError, TypeToken};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct EnumVariant {
     pub name: Identifier,
-    pub value: Option<SpanData<u64>>,
+    /// The explicitly assigned Value, which needs to be an integer constant Expression
+    pub value: Option<Expression>,
 }
 
 impl EnumVariant {
@@ -25,43 +26,26 @@ impl EnumVariant {
             TokenData::Assign(Assignment::Assign) => {
                 let _ = tokens.next();
 
-                let value_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
-                    ctx: EOFContext::Statement,
-                })?;
-                match value_token.data {
-                    TokenData::Literal { content } => {
-                        let i_value: u64 =
-                            content.parse().map_err(|_| SyntaxError::UnexpectedToken {
-                                got: value_token.span.clone(),
-                                expected: Some(vec![ExpectedToken::IntegerLiteral]),
-                            })?;
-
-                        Some(SpanData {
-                            span: value_token.span,
-                            data: i_value,
-                        })
-                    }
-                    _ => {
-                        return Err(SyntaxError::UnexpectedToken {
-                            got: value_token.span,
-                            expected: Some(vec![ExpectedToken::Literal]),
-                        })
-                    }
-                }
+                Some(Expression::parse(tokens)?)
             }
             _ => None,
         };
 
-        let next = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        // The last Variant does not need to be followed by a Comma
+        let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
             ctx: EOFContext::Statement,
         })?;
-        match next.data {
-            TokenData::Comma => {}
+        match &peeked.data {
+            TokenData::Comma => {
+                let _ = tokens.next();
+            }
+            TokenData::CloseBrace => {}
             _ => {
+                let next = tokens.next().unwrap();
                 return Err(SyntaxError::UnexpectedToken {
                     got: next.span,
-                    expected: Some(vec![ExpectedToken::Comma]),
-                })
+                    expected: Some(vec![ExpectedToken::Comma, ExpectedToken::CloseBrace]),
+                });
             }
         };
 
@@ -110,3 +94,119 @@ impl EnumVariants {
         Ok(Self { members })
     }
 }
+
+/// Checks if the upcoming Tokens are the Definition of an Enum, meaning that the "enum" Keyword
+/// is followed by the Variants, either directly or after the Name of the Enum
+pub(crate) fn is_definition<I>(tokens: &mut PeekNth<I>) -> bool
+where
+    I: Iterator<Item = Token>,
+{
+    if !matches!(
+        tokens.peek().map(|t| &t.data),
+        Some(TokenData::Keyword(Keyword::DataType(DataType::Enum)))
+    ) {
+        return false;
+    }
+
+    match tokens.peek_nth(1).map(|t| &t.data) {
+        Some(TokenData::OpenBrace) => true,
+        Some(TokenData::Literal { .. }) => {
+            matches!(
+                tokens.peek_nth(2).map(|t| &t.data),
+                Some(TokenData::OpenBrace)
+            )
+        }
+        _ => false,
+    }
+}
+
+/// Parses the "enum" Keyword, the optional Name and the Variants of an Enum-Definition and
+/// returns them in that Order
+pub(crate) fn parse_head<I>(
+    tokens: &mut PeekNth<I>,
+) -> Result<(Span, Option<Identifier>, EnumVariants), SyntaxError>
+where
+    I: Iterator<Item = Token>,
+{
+    let keyword = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+
+    let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    let name = match &peeked.data {
+        TokenData::OpenBrace => None,
+        _ => Some(Identifier::parse(tokens)?),
+    };
+
+    let variants = EnumVariants::parse(tokens)?;
+
+    Ok((keyword.span, name, variants))
+}
+
+/// Parses the Definition of an Enum, which may be directly followed by the Declaration of a
+/// Variable of that Enum, like "enum E { A, B } v;"
+pub(crate) fn parse_definition<I>(tokens: &mut PeekNth<I>) -> Result<Statement, SyntaxError>
+where
+    I: Iterator<Item = Token>,
+{
+    let (keyword, name, variants) = parse_head(tokens)?;
+
+    let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    let declaration = match &peeked.data {
+        TokenData::Semicolon => None,
+        _ => {
+            // An anonymous Enum can not be referred to by its Name, but every Enum is simply an
+            // int anyway
+            let ty = match &name {
+                Some(name) => TypeToken::EnumType { name: name.clone() },
+                None => TypeToken::Primitive(SpanData {
+                    span: keyword.clone(),
+                    data: DataType::Int,
+                }),
+            };
+            let var_name = Identifier::parse(tokens)?;
+
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Statement,
+            })?;
+            let declaration = match &peeked.data {
+                TokenData::Assign(Assignment::Assign) => {
+                    let _ = tokens.next();
+
+                    Statement::VariableDeclarationAssignment {
+                        ty,
+                        name: var_name,
+                        value: Expression::parse(tokens)?,
+                    }
+                }
+                _ => Statement::VariableDeclaration { ty, name: var_name },
+            };
+
+            Some(Box::new(declaration))
+        }
+    };
+
+    let end_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    match end_token.data {
+        TokenData::Semicolon => {}
+        _ => {
+            return Err(SyntaxError::UnexpectedToken {
+                got: end_token.span,
+                expected: Some(vec![ExpectedToken::Semicolon]),
+            });
+        }
+    };
+
+    Ok(Statement::EnumDefinition {
+        name,
+        keyword,
+        variants,
+        declaration,
+    })
+}
@@ -18,12 +18,20 @@ pub fn parse<I>(
 where
     I: Iterator<Item = Token>,
 {
+    // The Name of an Enum is optional, so its Definition can not be parsed as a Type first
+    if enums::is_definition(tokens) {
+        return enums::parse_definition(tokens);
+    }
+
     let ty_tokens = TypeToken::parse(tokens)?;
     let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
         ctx: EOFContext::Statement,
     })?;
     let ty_tokens = match (ty_tokens, &peeked.data) {
-        (TypeToken::StructType { name }, TokenData::OpenBrace) => {
+        (
+            ty @ (TypeToken::StructType { .. } | TypeToken::UnionType { .. }),
+            TokenData::OpenBrace,
+        ) => {
             let start_span = peeked.span.clone();
 
             let members = structs::StructMembers::parse(tokens)?;
@@ -46,78 +54,44 @@ where
                 start_span.source_area().start..end_span.source_area().end,
             );
 
-            return Ok(Statement::StructDefinition {
-                name,
-                members,
-                definition: entire_span,
-            });
+            return match ty {
+                TypeToken::StructType { name } => Ok(Statement::StructDefinition {
+                    name,
+                    members,
+                    definition: entire_span,
+                }),
+                TypeToken::UnionType { name } => Ok(Statement::UnionDefinition {
+                    name,
+                    members,
+                    definition: entire_span,
+                }),
+                _ => unreachable!("We only match on Struct and Union Types"),
+            };
         }
-        (TypeToken::EnumType { name }, TokenData::OpenBrace) => {
-            let variants = enums::EnumVariants::parse(tokens)?;
+        (
+            ty @ (TypeToken::StructType { .. } | TypeToken::UnionType { .. }),
+            TokenData::Semicolon,
+        ) => {
+            let _ = tokens.next();
 
-            let end_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
-                ctx: EOFContext::Statement,
-            })?;
-            match end_token.data {
-                TokenData::Semicolon => {}
-                _ => {
-                    return Err(SyntaxError::UnexpectedToken {
-                        got: end_token.span,
-                        expected: Some(vec![ExpectedToken::Semicolon]),
-                    });
-                }
+            return match ty {
+                TypeToken::StructType { name } => Ok(Statement::StructDeclaration { name }),
+                TypeToken::UnionType { name } => Ok(Statement::UnionDeclaration { name }),
+                _ => unreachable!("We only match on Struct and Union Types"),
             };
-
-            return Ok(Statement::EnumDefinition { name, variants });
         }
         (t, _) => t,
     };
 
-    let name = Identifier::parse(tokens)?;
-
-    let array_peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
-        ctx: EOFContext::Statement,
-    })?;
-    let f_type = match &array_peeked.data {
-        TokenData::OpenBracket => {
-            let _ = tokens.next();
-
-            let tmp_peeked = tokens.peek();
-            match tmp_peeked {
-                Some(tok) if tok.data == TokenData::CloseBracket => {
-                    let _ = tokens.next();
-
-                    TypeToken::ArrayType {
-                        base: Box::new(ty_tokens),
-                        size: None,
-                    }
-                }
-                _ => {
-                    let size_exp = Expression::parse(tokens)?;
-
-                    let close_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
-                        ctx: EOFContext::Statement,
-                    })?;
-                    match close_token.data {
-                        TokenData::CloseBracket => {}
-                        _ => {
-                            return Err(SyntaxError::UnexpectedToken {
-                                expected: Some(vec![ExpectedToken::CloseBracket]),
-                                got: close_token.span,
-                            })
-                        }
-                    };
-
-                    TypeToken::ArrayType {
-                        base: Box::new(ty_tokens),
-                        size: Some(Box::new(size_exp)),
-                    }
-                }
-            }
-        }
-        _ => ty_tokens,
+    let (ty_tokens, name) = if TypeToken::is_function_pointer(tokens) {
+        TypeToken::parse_function_pointer(ty_tokens, tokens)?
+    } else {
+        let name = Identifier::parse(tokens)?;
+        (ty_tokens, name)
     };
 
+    let f_type = TypeToken::parse_array_dimensions(ty_tokens, tokens)?;
+
     let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
         ctx: EOFContext::Statement,
     })?;
@@ -162,20 +136,26 @@ where
 
                 let ty = TypeToken::parse(tokens)?;
 
-                let name_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
-                    ctx: EOFContext::Statement,
-                })?;
-                let name = match name_token.data {
-                    TokenData::Literal { content } => Identifier(SpanData {
-                        span: name_token.span,
-                        data: content,
-                    }),
-                    _ => {
-                        return Err(SyntaxError::UnexpectedToken {
-                            expected: Some(vec![ExpectedToken::Identifier]),
-                            got: name_token.span,
-                        })
-                    }
+                let (ty, name) = if TypeToken::is_function_pointer(tokens) {
+                    TypeToken::parse_function_pointer(ty, tokens)?
+                } else {
+                    let name_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                        ctx: EOFContext::Statement,
+                    })?;
+                    let name = match name_token.data {
+                        TokenData::Literal { content } => Identifier(SpanData {
+                            span: name_token.span,
+                            data: content,
+                        }),
+                        _ => {
+                            return Err(SyntaxError::UnexpectedToken {
+                                expected: Some(vec![ExpectedToken::Identifier]),
+                                got: name_token.span,
+                            })
+                        }
+                    };
+
+                    (ty, name)
                 };
 
                 let arg_span = Span::new_arc_source(source, start..name.0.span.source_area().end);
@@ -0,0 +1,99 @@
+use general::{Span, SpanData};
+use itertools::PeekNth;
+use tokenizer::{Token, TokenData};
+
+use crate::{EOFContext, ExpectedToken, Expression, Statement, SyntaxError};
+
+/// Parses a Static-Assertion, like "_Static_assert(sizeof(int) == 4, "message");", where the
+/// "_Static_assert" Keyword has already been consumed and is passed as the keyword
+pub fn parse<I>(
+    keyword: Span,
+    tokens: &mut PeekNth<I>,
+    is_termination: &dyn Fn(Token) -> Result<(), SyntaxError>,
+) -> Result<Statement, SyntaxError>
+where
+    I: Iterator<Item = Token>,
+{
+    let open_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    if open_paren_token.data != TokenData::OpenParen {
+        return Err(SyntaxError::UnexpectedToken {
+            expected: Some(vec![ExpectedToken::OpenParen]),
+            got: open_paren_token.span,
+        });
+    }
+
+    let condition = Expression::parse(tokens)?;
+
+    let comma_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    if comma_token.data != TokenData::Comma {
+        return Err(SyntaxError::UnexpectedToken {
+            expected: Some(vec![ExpectedToken::Comma]),
+            got: comma_token.span,
+        });
+    }
+
+    let message = parse_message(tokens)?;
+
+    let close_paren_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    if close_paren_token.data != TokenData::CloseParen {
+        return Err(SyntaxError::UnexpectedToken {
+            expected: Some(vec![ExpectedToken::CloseParen]),
+            got: close_paren_token.span,
+        });
+    }
+
+    let end_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    is_termination(end_token)?;
+
+    Ok(Statement::StaticAssert {
+        keyword,
+        condition,
+        message,
+    })
+}
+
+/// The Message is only used for Diagnostics, so it is kept as it was written in the Source,
+/// with all adjacent String-Literals joined together
+fn parse_message<I>(tokens: &mut PeekNth<I>) -> Result<SpanData<String>, SyntaxError>
+where
+    I: Iterator<Item = Token>,
+{
+    let first = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+        ctx: EOFContext::Statement,
+    })?;
+    let mut message = match first.data {
+        TokenData::StringLiteral { content, .. } => content,
+        _ => {
+            return Err(SyntaxError::UnexpectedToken {
+                expected: Some(vec![ExpectedToken::StringLiteral]),
+                got: first.span,
+            })
+        }
+    };
+
+    let mut span = first.span;
+    while let Some(Token {
+        data: TokenData::StringLiteral { .. },
+        ..
+    }) = tokens.peek()
+    {
+        let next = tokens.next().unwrap();
+        if let TokenData::StringLiteral { content, .. } = next.data {
+            message.push_str(&content);
+        }
+        span = span.join(next.span);
+    }
+
+    Ok(SpanData {
+        span,
+        data: message,
+    })
+}
@@ -1,12 +1,24 @@
 use itertools::PeekNth;
-use tokenizer::{Token, TokenData};
+use tokenizer::{Keyword, Token, TokenData};
 
-use crate::{EOFContext, ExpectedToken, Identifier, SyntaxError, TypeToken};
+use crate::{Alignment, EOFContext, ExpectedToken, Expression, Identifier, SyntaxError, TypeToken};
 
-#[derive(Debug, PartialEq)]
+/// A single Member of a Struct, where the third Entry is the Width of the Member if it is a
+/// Bit-Field (`unsigned x : 3;`). Only Bit-Fields can be unnamed (`unsigned : 3;`), in which
+/// case they only take up Space in the Layout. The last Entry is the explicit Alignment of the
+/// Member (`_Alignas(16) int x;`)
+pub type StructMember = (
+    TypeToken,
+    Option<Identifier>,
+    Option<Expression>,
+    Option<Alignment>,
+);
+
+#[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct StructMembers {
-    pub members: Vec<(TypeToken, Identifier)>,
+    /// The Members of the Struct
+    pub members: Vec<StructMember>,
 }
 
 impl StructMembers {
@@ -34,7 +46,39 @@ impl StructMembers {
                 break;
             }
 
-            let (ty, name) = TypeToken::parse_type_identifier(tokens)?;
+            let alignment = match tokens.peek() {
+                Some(Token {
+                    data: TokenData::Keyword(Keyword::AlignAs),
+                    ..
+                }) => {
+                    let _ = tokens.next();
+                    Some(Alignment::parse(tokens)?)
+                }
+                _ => None,
+            };
+
+            let base = TypeToken::parse(tokens)?;
+            let (ty, name) = match tokens.peek() {
+                Some(Token {
+                    data: TokenData::Colon,
+                    ..
+                }) => (base, None),
+                _ => {
+                    let (ty, name) = TypeToken::parse_declarator(base, tokens)?;
+                    (ty, Some(name))
+                }
+            };
+
+            let bit_width = match tokens.peek() {
+                Some(Token {
+                    data: TokenData::Colon,
+                    ..
+                }) => {
+                    let _ = tokens.next();
+                    Some(Expression::parse(tokens)?)
+                }
+                _ => None,
+            };
 
             let semicolon_tok = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
                 ctx: EOFContext::Statement,
@@ -49,7 +93,7 @@ impl StructMembers {
                 }
             };
 
-            members.push((ty, name));
+            members.push((ty, name, bit_width, alignment));
         }
 
         Ok(Self { members })
@@ -57,8 +101,8 @@ impl StructMembers {
 }
 
 impl IntoIterator for StructMembers {
-    type IntoIter = std::vec::IntoIter<(TypeToken, Identifier)>;
-    type Item = (TypeToken, Identifier);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type Item = StructMember;
 
     fn into_iter(self) -> Self::IntoIter {
         self.members.into_iter()
@@ -106,10 +150,12 @@ mod tests {
                     span: Span::new_source(source.clone(), 23..26),
                     data: DataType::Int,
                 }),
-                Identifier(SpanData {
+                Some(Identifier(SpanData {
                     span: Span::new_source(source, 27..32),
                     data: "first".to_string(),
-                }),
+                })),
+                None,
+                None,
             )],
         });
 
@@ -137,20 +183,24 @@ mod tests {
                         span: Span::new_source(source.clone(), 23..26),
                         data: DataType::Int,
                     }),
-                    Identifier(SpanData {
+                    Some(Identifier(SpanData {
                         span: Span::new_source(source.clone(), 27..32),
                         data: "first".to_string(),
-                    }),
+                    })),
+                    None,
+                    None,
                 ),
                 (
                     TypeToken::Primitive(SpanData {
                         span: Span::new_source(source.clone(), 46..49),
                         data: DataType::Int,
                     }),
-                    Identifier(SpanData {
+                    Some(Identifier(SpanData {
                         span: Span::new_source(source, 50..56),
                         data: "second".to_string(),
-                    }),
+                    })),
+                    None,
+                    None,
                 ),
             ],
         });
@@ -160,4 +210,59 @@ mod tests {
         assert_eq!(None, input_tokens.next());
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn unnamed_bit_field() {
+        let input = "
+        {
+            int : 3;
+        }";
+        let source = Source::new("test", input);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let expected = Ok(StructMembers {
+            members: vec![(
+                TypeToken::Primitive(SpanData {
+                    span: Span::new_source(source.clone(), 23..26),
+                    data: DataType::Int,
+                }),
+                None,
+                Some(Expression::Literal {
+                    content: SpanData {
+                        span: Span::new_source(source, 29..30),
+                        data: "3".to_string(),
+                    },
+                }),
+                None,
+            )],
+        });
+
+        let result = StructMembers::parse(&mut input_tokens);
+
+        assert_eq!(None, input_tokens.next());
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn aligned_member() {
+        let input = "
+        {
+            char c;
+            _Alignas(16) int x;
+        }";
+        let source = Source::new("test", input);
+        let input_span: Span = source.clone().into();
+        let mut input_tokens = peek_nth(tokenizer::tokenize(input_span));
+
+        let result = StructMembers::parse(&mut input_tokens).unwrap();
+
+        assert_eq!(None, input_tokens.next());
+        assert_eq!(2, result.members.len());
+        assert_eq!(None, result.members[0].3);
+        assert!(matches!(
+            result.members[1].3,
+            Some(Alignment::Expression(Expression::Literal { .. }))
+        ));
+    }
 }
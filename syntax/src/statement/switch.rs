@@ -0,0 +1,111 @@
+use general::Span;
+use itertools::PeekNth;
+use tokenizer::{ControlFlow, Keyword, Token, TokenData};
+
+use crate::{EOFContext, ExpectedToken, Expression, Statement, SyntaxError};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum CaseLabel {
+    /// A `case` with the Value that it matches against
+    Case(Expression),
+    /// The `default` case, with the Span of the default Keyword
+    Default(Span),
+    /// The Statements before the first Label, which are never reached by the Switch itself
+    Unlabeled,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub struct SwitchCase {
+    pub label: CaseLabel,
+    /// All the Statements following the Label up to the next Label or the end of the Switch,
+    /// which may be empty if the Case directly falls through into the next one
+    pub statements: Vec<Statement>,
+}
+
+impl SwitchCase {
+    /// Parses the entire Body of a Switch-Statement, starting at the opening Curly Brace and
+    /// consuming the closing Curly Brace
+    pub fn parse_body<I>(tokens: &mut PeekNth<I>) -> Result<Vec<Self>, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let open_brace_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Statement,
+        })?;
+        match open_brace_token.data {
+            TokenData::OpenBrace => {}
+            _ => {
+                return Err(SyntaxError::UnexpectedToken {
+                    expected: Some(vec![ExpectedToken::OpenBrace]),
+                    got: open_brace_token.span,
+                })
+            }
+        };
+
+        let mut cases: Vec<Self> = Vec::new();
+        loop {
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Statement,
+            })?;
+
+            match &peeked.data {
+                TokenData::CloseBrace => {
+                    let _ = tokens.next();
+                    break;
+                }
+                TokenData::Keyword(Keyword::ControlFlow(ControlFlow::Case)) => {
+                    let _ = tokens.next();
+
+                    let value = Expression::parse(tokens)?;
+                    Self::parse_colon(tokens)?;
+
+                    cases.push(Self {
+                        label: CaseLabel::Case(value),
+                        statements: Vec::new(),
+                    });
+                }
+                TokenData::Keyword(Keyword::Default_) => {
+                    let default_token = tokens.next().expect("We just peeked it");
+                    Self::parse_colon(tokens)?;
+
+                    cases.push(Self {
+                        label: CaseLabel::Default(default_token.span),
+                        statements: Vec::new(),
+                    });
+                }
+                _ => {
+                    if cases.is_empty() {
+                        cases.push(Self {
+                            label: CaseLabel::Unlabeled,
+                            statements: Vec::new(),
+                        });
+                    }
+                    let current = cases.last_mut().expect("There is always at least one Case");
+
+                    let statement = Statement::parse(tokens, &Statement::default_terminaton())?;
+                    current.statements.push(statement);
+                }
+            };
+        }
+
+        Ok(cases)
+    }
+
+    fn parse_colon<I>(tokens: &mut PeekNth<I>) -> Result<(), SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let colon_token = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+            ctx: EOFContext::Statement,
+        })?;
+        match colon_token.data {
+            TokenData::Colon => Ok(()),
+            _ => Err(SyntaxError::UnexpectedToken {
+                expected: Some(vec![ExpectedToken::Colon]),
+                got: colon_token.span,
+            }),
+        }
+    }
+}
@@ -1,13 +1,14 @@
-use general::SpanData;
+use general::{Span, SpanData};
 use itertools::PeekNth;
 use tokenizer::{DataType, Keyword, Operator, Token, TokenData};
 
-use crate::{EOFContext, ExpectedToken, Expression, Identifier, SyntaxError};
+use crate::{EOFContext, ExpectedToken, Expression, Identifier, StructMembers, SyntaxError};
 
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Modifier {
     Const,
+    Volatile,
     Signed,
     Unsigned,
     Long,
@@ -18,15 +19,22 @@ impl Modifier {
         matches!(
             &token,
             TokenData::Keyword(Keyword::Const)
+                | TokenData::Keyword(Keyword::Volatile)
                 | TokenData::Keyword(Keyword::DataType(DataType::Signed))
                 | TokenData::Keyword(Keyword::DataType(DataType::Unsigned))
                 | TokenData::Keyword(Keyword::DataType(DataType::Long))
         )
     }
 
+    /// Whether or not the Modifier is a Type-Qualifier, which can also be applied to Pointers
+    pub fn is_qualifier(&self) -> bool {
+        matches!(self, Self::Const | Self::Volatile)
+    }
+
     pub fn parse(data: TokenData) -> Option<Self> {
         match data {
             TokenData::Keyword(Keyword::Const) => Some(Self::Const),
+            TokenData::Keyword(Keyword::Volatile) => Some(Self::Volatile),
             TokenData::Keyword(Keyword::DataType(DataType::Signed)) => Some(Self::Signed),
             TokenData::Keyword(Keyword::DataType(DataType::Unsigned)) => Some(Self::Unsigned),
             TokenData::Keyword(Keyword::DataType(DataType::Long)) => Some(Self::Long),
@@ -73,9 +81,43 @@ pub enum TypeToken {
         /// The Name of the Union
         name: Identifier,
     },
+    /// A Struct without a Name, which is defined in place, like the Type of the Member "p" in
+    /// "struct Line { struct { int x; int y; } p; };"
+    AnonymousStruct {
+        /// The Members of the Struct
+        members: StructMembers,
+        /// The Span of the "struct" Keyword itself
+        keyword: Span,
+    },
+    /// A Union without a Name, which is defined in place, like the Type of the Member "v" in
+    /// "struct Tag { int kind; union { int i; float f; } v; };"
+    AnonymousUnion {
+        /// The Members of the Union
+        members: StructMembers,
+        /// The Span of the "union" Keyword itself
+        keyword: Span,
+    },
+    /// A Pointer to a Function with the given Signature
+    FunctionPointer {
+        /// The Type returned by the Function
+        return_ty: Box<Self>,
+        /// The Types of the Arguments of the Function
+        arguments: Vec<Self>,
+        /// Whether or not the Function takes variable Arguments
+        var_args: bool,
+    },
 }
 
 impl TypeToken {
+    /// Checks if the "struct" or "union" Keyword is directly followed by the Members of a
+    /// Definition without a Name
+    fn starts_definition<I>(tokens: &mut PeekNth<I>) -> bool
+    where
+        I: Iterator<Item = Token>,
+    {
+        matches!(tokens.peek().map(|t| &t.data), Some(TokenData::OpenBrace))
+    }
+
     fn parse_ty<I>(tokens: &mut PeekNth<I>) -> Result<Self, SyntaxError>
     where
         I: Iterator<Item = Token>,
@@ -84,6 +126,16 @@ impl TypeToken {
             ctx: EOFContext::Type,
         })?;
         let mut base = match next_tok.data {
+            TokenData::Keyword(Keyword::DataType(DataType::Struct))
+                if Self::starts_definition(tokens) =>
+            {
+                let members = StructMembers::parse(tokens)?;
+
+                TypeToken::AnonymousStruct {
+                    members,
+                    keyword: next_tok.span,
+                }
+            }
             TokenData::Keyword(Keyword::DataType(DataType::Struct)) => {
                 let name = Identifier::parse(tokens)?;
 
@@ -94,6 +146,16 @@ impl TypeToken {
 
                 TypeToken::EnumType { name }
             }
+            TokenData::Keyword(Keyword::DataType(DataType::Union))
+                if Self::starts_definition(tokens) =>
+            {
+                let members = StructMembers::parse(tokens)?;
+
+                TypeToken::AnonymousUnion {
+                    members,
+                    keyword: next_tok.span,
+                }
+            }
             TokenData::Keyword(Keyword::DataType(DataType::Union)) => {
                 let name = Identifier::parse(tokens)?;
 
@@ -125,6 +187,19 @@ impl TypeToken {
                     let _ = tokens.next();
                     base = Self::Pointer(Box::new(base));
                 }
+                // A Qualifier after the "*", like in "int* const", qualifies the Pointer itself
+                TokenData::Keyword(Keyword::Const | Keyword::Volatile)
+                    if matches!(base, Self::Pointer(_) | Self::Composition { .. }) =>
+                {
+                    let next = tokens.next().unwrap();
+                    base = Self::Composition {
+                        modifier: SpanData {
+                            span: next.span,
+                            data: Modifier::parse(next.data).unwrap(),
+                        },
+                        base: Box::new(base),
+                    };
+                }
                 _ => return Ok(base),
             };
         }
@@ -132,6 +207,30 @@ impl TypeToken {
         Ok(base)
     }
 
+    /// Applies a leading Qualifier to the Type it was written in front of, which is the innermost
+    /// Type of any Pointers, so "const int*" becomes a Pointer to a const int instead of a const
+    /// Pointer to an int
+    fn qualify_base(self, modifier: SpanData<Modifier>) -> Self {
+        match self {
+            Self::Pointer(inner) => Self::Pointer(Box::new(inner.qualify_base(modifier))),
+            Self::Composition {
+                modifier: ptr_modifier,
+                base,
+            } if ptr_modifier.data.is_qualifier()
+                && matches!(base.as_ref(), Self::Pointer(_) | Self::Composition { .. }) =>
+            {
+                Self::Composition {
+                    modifier: ptr_modifier,
+                    base: Box::new(base.qualify_base(modifier)),
+                }
+            }
+            other => Self::Composition {
+                modifier,
+                base: Box::new(other),
+            },
+        }
+    }
+
     pub fn parse<I>(tokens: &mut PeekNth<I>) -> Result<Self, SyntaxError>
     where
         I: Iterator<Item = Token>,
@@ -173,10 +272,6 @@ impl TypeToken {
                     ctx: EOFContext::Type,
                 })?;
                 match &peeked.data {
-                    TokenData::Literal { .. } => Ok(Self::Primitive(SpanData {
-                        data: DataType::Long,
-                        span: next.span,
-                    })),
                     TokenData::Keyword(Keyword::DataType(DataType::Int)) => {
                         let _ = tokens.next();
 
@@ -207,14 +302,12 @@ impl TypeToken {
                             },
                         })
                     }
-                    _ => {
-                        let next = tokens.next().unwrap();
-
-                        Err(SyntaxError::UnexpectedToken {
-                            got: next.span,
-                            expected: None,
-                        })
-                    }
+                    // A plain "long", which can be followed by a Name but also by things like a
+                    // Pointer or the closing Parenthesis of "sizeof(long)"
+                    _ => Ok(Self::Primitive(SpanData {
+                        data: DataType::Long,
+                        span: next.span,
+                    })),
                 }
             }
             data if Modifier::is_modifier(data) => {
@@ -222,51 +315,222 @@ impl TypeToken {
 
                 let modif = Modifier::parse(next.data).unwrap();
 
-                let base = Self::parse(tokens)?;
+                // "signed" and "unsigned" on their own, like in "unsigned x", are short for
+                // "signed int" and "unsigned int"
+                let implicit_int = matches!(modif, Modifier::Signed | Modifier::Unsigned)
+                    && !matches!(
+                        tokens.peek().map(|t| &t.data),
+                        Some(TokenData::Keyword(Keyword::DataType(_)))
+                    );
+                let base = if implicit_int {
+                    Self::Primitive(SpanData {
+                        span: next.span.clone(),
+                        data: DataType::Int,
+                    })
+                } else {
+                    Self::parse(tokens)?
+                };
+
+                let modifier = SpanData {
+                    span: next.span,
+                    data: modif,
+                };
+                if modifier.data.is_qualifier() {
+                    return Ok(base.qualify_base(modifier));
+                }
 
                 Ok(Self::Composition {
                     base: Box::new(base),
-                    modifier: SpanData {
-                        span: next.span,
-                        data: modif,
-                    },
+                    modifier,
                 })
             }
             _ => Self::parse_ty(tokens),
         }
     }
 
-    /// This should be used to parse combinations of form "type identifier", as
-    /// this will handle it correctly for you while also accounting for certain
-    /// Problems like Arrays and the like
-    pub fn parse_type_identifier<I>(
+    /// Checks if the next Tokens start the Declarator of a Function-Pointer, like "(*name)"
+    pub fn is_function_pointer<I>(tokens: &mut PeekNth<I>) -> bool
+    where
+        I: Iterator<Item = Token>,
+    {
+        matches!(
+            tokens.peek_nth(0).map(|t| &t.data),
+            Some(TokenData::OpenParen)
+        ) && matches!(
+            tokens.peek_nth(1).map(|t| &t.data),
+            Some(TokenData::Operator(Operator::Multiply))
+        )
+    }
+
+    /// Parses the Declarator of a Function-Pointer, like "(*name)(int, char*)", where the given
+    /// Type is the already parsed Return-Type of the Function.
+    ///
+    /// The Name can be followed by Array-Dimensions, like "(*name[2])(int)", which declares an
+    /// Array of Function-Pointers. The Arguments of the Function can optionally be named, but
+    /// these Names are ignored
+    pub fn parse_function_pointer<I>(
+        return_ty: Self,
         tokens: &mut PeekNth<I>,
     ) -> Result<(Self, Identifier), SyntaxError>
     where
         I: Iterator<Item = Token>,
     {
-        let mut base = Self::parse(tokens)?;
-
-        let ident = Identifier::parse(tokens)?;
+        let expect = |tokens: &mut PeekNth<I>, expected: TokenData, exp_token: ExpectedToken| {
+            let next_tok = tokens.next().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Type,
+            })?;
+            if next_tok.data == expected {
+                Ok(())
+            } else {
+                Err(SyntaxError::UnexpectedToken {
+                    expected: Some(vec![exp_token]),
+                    got: next_tok.span,
+                })
+            }
+        };
 
+        expect(tokens, TokenData::OpenParen, ExpectedToken::OpenParen)?;
+        expect(
+            tokens,
+            TokenData::Operator(Operator::Multiply),
+            ExpectedToken::Operator,
+        )?;
+        let name = Identifier::parse(tokens)?;
+        let sizes = Self::parse_array_sizes(tokens)?;
+        expect(tokens, TokenData::CloseParen, ExpectedToken::CloseParen)?;
+        expect(tokens, TokenData::OpenParen, ExpectedToken::OpenParen)?;
+
+        let mut arguments = Vec::new();
+        let mut var_args = false;
         loop {
-            let peeked = match tokens.peek() {
-                Some(p) => p,
-                None => return Ok((base, ident)),
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Type,
+            })?;
+            match &peeked.data {
+                TokenData::CloseParen => {
+                    let _ = tokens.next();
+                    break;
+                }
+                TokenData::VarArgs => {
+                    let _ = tokens.next();
+                    var_args = true;
+
+                    expect(tokens, TokenData::CloseParen, ExpectedToken::CloseParen)?;
+                    break;
+                }
+                _ => {}
             };
 
+            let arg_ty = Self::parse(tokens)?;
+            // "(void)" is the same as an empty Argument List
+            let is_void = matches!(
+                &arg_ty,
+                Self::Primitive(SpanData {
+                    data: DataType::Void,
+                    ..
+                })
+            );
+            if !is_void || !arguments.is_empty() {
+                arguments.push(arg_ty);
+            }
+
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Type,
+            })?;
+            if let TokenData::Literal { .. } = &peeked.data {
+                let _ = tokens.next();
+            }
+
+            let peeked = tokens.peek().ok_or(SyntaxError::UnexpectedEOF {
+                ctx: EOFContext::Type,
+            })?;
             match &peeked.data {
-                TokenData::OpenBracket => {
+                TokenData::Comma => {
                     let _ = tokens.next();
                 }
-                _ => return Ok((base, ident)),
+                TokenData::CloseParen => {}
+                _ => {
+                    let next_tok = tokens.next().unwrap();
+                    return Err(SyntaxError::UnexpectedToken {
+                        expected: Some(vec![ExpectedToken::Comma, ExpectedToken::CloseParen]),
+                        got: next_tok.span,
+                    });
+                }
             };
+        }
+
+        let ty = Self::FunctionPointer {
+            return_ty: Box::new(return_ty),
+            arguments,
+            var_args,
+        };
+
+        Ok((Self::array_of(ty, sizes), name))
+    }
+
+    /// This should be used to parse combinations of form "type identifier", as
+    /// this will handle it correctly for you while also accounting for certain
+    /// Problems like Arrays and the like
+    pub fn parse_type_identifier<I>(
+        tokens: &mut PeekNth<I>,
+    ) -> Result<(Self, Identifier), SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let base = Self::parse(tokens)?;
+        Self::parse_declarator(base, tokens)
+    }
+
+    /// Parses the Part of a "type identifier" Combination that follows the already parsed
+    /// Base-Type, which is the Name and everything that changes the Type around it
+    pub fn parse_declarator<I>(
+        base: Self,
+        tokens: &mut PeekNth<I>,
+    ) -> Result<(Self, Identifier), SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        if Self::is_function_pointer(tokens) {
+            return Self::parse_function_pointer(base, tokens);
+        }
+
+        let ident = Identifier::parse(tokens)?;
+        let ty = Self::parse_array_dimensions(base, tokens)?;
+
+        Ok((ty, ident))
+    }
+
+    /// Parses all the Dimensions of an Array, like "[4][8]", that follow the Name in a
+    /// Declaration and applies them to the given Base-Type.
+    ///
+    /// The first Dimension is the outermost Array, so "int m[4][8]" is an Array of 4 Arrays
+    /// with 8 Elements each, which are stored in row-major Order
+    pub fn parse_array_dimensions<I>(
+        base: Self,
+        tokens: &mut PeekNth<I>,
+    ) -> Result<Self, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let sizes = Self::parse_array_sizes(tokens)?;
+
+        Ok(Self::array_of(base, sizes))
+    }
+
+    /// Parses the Sizes of all the Dimensions of an Array, where a missing Size is None
+    fn parse_array_sizes<I>(
+        tokens: &mut PeekNth<I>,
+    ) -> Result<Vec<Option<Box<Expression>>>, SyntaxError>
+    where
+        I: Iterator<Item = Token>,
+    {
+        let mut sizes = Vec::new();
+        while let Some(TokenData::OpenBracket) = tokens.peek().map(|p| &p.data) {
+            let _ = tokens.next();
 
             let size_exp = match tokens.peek() {
-                Some(_) => {
-                    let exp = Expression::parse(tokens)?;
-                    Some(Box::new(exp))
-                }
+                Some(tok) if tok.data == TokenData::CloseBracket => None,
+                Some(_) => Some(Box::new(Expression::parse(tokens)?)),
                 None => None,
             };
 
@@ -277,17 +541,28 @@ impl TypeToken {
                 TokenData::CloseBracket => {}
                 _ => {
                     return Err(SyntaxError::UnexpectedToken {
-                        expected: None,
+                        expected: Some(vec![ExpectedToken::CloseBracket]),
                         got: next_tok.span,
                     })
                 }
             };
 
-            base = Self::ArrayType {
-                base: Box::new(base),
-                size: size_exp,
-            };
+            sizes.push(size_exp);
         }
+
+        Ok(sizes)
+    }
+
+    /// Wraps the Base-Type in an Array for every Dimension, with the first Dimension as the
+    /// outermost Array
+    fn array_of(base: Self, sizes: Vec<Option<Box<Expression>>>) -> Self {
+        sizes
+            .into_iter()
+            .rev()
+            .fold(base, |inner, size| Self::ArrayType {
+                base: Box::new(inner),
+                size,
+            })
     }
 }
 
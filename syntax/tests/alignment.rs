@@ -0,0 +1,93 @@
+use general::{Source, Span, SpanData};
+use syntax::{
+    Alignment, DataType, Expression, Identifier, Scope, Statement, SyntaxError, TypeToken, AST,
+};
+
+#[test]
+fn align_as_constant() {
+    let input = "
+_Alignas(16) int buffer;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::AlignAs {
+                keyword: Span::new_source(source.clone(), 1..9),
+                alignment: Alignment::Expression(Expression::Literal {
+                    content: SpanData {
+                        span: Span::new_source(source.clone(), 10..12),
+                        data: "16".to_string(),
+                    },
+                }),
+                inner: Box::new(Statement::VariableDeclaration {
+                    ty: TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 14..17),
+                        data: DataType::Int,
+                    }),
+                    name: Identifier(SpanData {
+                        span: Span::new_source(source, 18..24),
+                        data: "buffer".to_string(),
+                    }),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn align_as_type() {
+    let input = "
+_Alignas(double) static char buffer[8];
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    let statements = result.unwrap().global_scope.statements;
+    match statements.first() {
+        Some(Statement::AlignAs {
+            alignment, inner, ..
+        }) => {
+            assert_eq!(
+                &Alignment::Type(TypeToken::Primitive(SpanData {
+                    span: Span::new_source(source, 10..16),
+                    data: DataType::Double,
+                })),
+                alignment
+            );
+            assert!(matches!(inner.as_ref(), Statement::Static { .. }));
+        }
+        other => panic!("Expected an aligned Declaration, got {:?}", other),
+    };
+}
+
+#[test]
+fn align_as_non_declaration() {
+    let input = "
+_Alignas(4) return 0;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Err(SyntaxError::UnexpectedToken {
+        expected: None,
+        got: Span::new_source(source, 1..9),
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
@@ -0,0 +1,70 @@
+use general::{Source, Span, SpanData};
+use syntax::{
+    AssignTarget, ExpectedToken, Expression, Identifier, Scope, Statement, SyntaxError, AST,
+};
+
+#[test]
+fn simple_do_while() {
+    let input = "
+do {
+    x = 1;
+} while (x);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::DoWhileLoop {
+                condition: Expression::Identifier {
+                    ident: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 26..27),
+                        data: "x".to_string(),
+                    }),
+                },
+                scope: Scope {
+                    statements: vec![Statement::VariableAssignment {
+                        target: AssignTarget::Variable(Identifier(SpanData {
+                            span: Span::new_source(source.clone(), 10..11),
+                            data: "x".to_string(),
+                        })),
+                        value: Expression::Literal {
+                            content: SpanData {
+                                span: Span::new_source(source, 14..15),
+                                data: "1".to_string(),
+                            },
+                        },
+                    }],
+                },
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn do_while_missing_semicolon() {
+    let input = "
+do {
+} while (1)
+int x;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Err(SyntaxError::UnexpectedToken {
+        expected: Some(vec![ExpectedToken::Semicolon]),
+        got: Span::new_source(source, 18..21),
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
@@ -1,5 +1,8 @@
 use general::{Source, Span, SpanData};
-use syntax::{EnumVariant, EnumVariants, Identifier, Scope, Statement, AST};
+use syntax::{
+    DataType, EnumVariant, EnumVariants, Expression, ExpressionOperator, Identifier, Scope,
+    Statement, TypeToken, AST,
+};
 
 #[test]
 fn basic_named_enum() {
@@ -17,10 +20,11 @@ enum test {
     let expected = Ok(AST {
         global_scope: Scope {
             statements: vec![Statement::EnumDefinition {
-                name: Identifier(SpanData {
+                name: Some(Identifier(SpanData {
                     span: Span::new_source(source.clone(), 6..10),
                     data: "test".to_string(),
-                }),
+                })),
+                keyword: Span::new_source(source.clone(), 1..5),
                 variants: EnumVariants {
                     members: vec![
                         EnumVariant {
@@ -46,6 +50,7 @@ enum test {
                         },
                     ],
                 },
+                declaration: None,
             }],
         },
     });
@@ -73,10 +78,11 @@ enum test {
     let expected = Ok(AST {
         global_scope: Scope {
             statements: vec![Statement::EnumDefinition {
-                name: Identifier(SpanData {
+                name: Some(Identifier(SpanData {
                     span: Span::new_source(source.clone(), 6..10),
                     data: "test".to_string(),
-                }),
+                })),
+                keyword: Span::new_source(source.clone(), 1..5),
                 variants: EnumVariants {
                     members: vec![
                         EnumVariant {
@@ -91,9 +97,11 @@ enum test {
                                 span: Span::new_source(source.clone(), 28..34),
                                 data: "second".to_string(),
                             }),
-                            value: Some(SpanData {
-                                span: Span::new_source(source.clone(), 37..38),
-                                data: 0,
+                            value: Some(Expression::Literal {
+                                content: SpanData {
+                                    span: Span::new_source(source.clone(), 37..38),
+                                    data: "0".to_string(),
+                                },
                             }),
                         },
                         EnumVariant {
@@ -105,6 +113,121 @@ enum test {
                         },
                     ],
                 },
+                declaration: None,
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn enum_with_expression_and_no_trailing_comma() {
+    let input = "
+enum test {
+    first = 1 << 2,
+    second
+};
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::EnumDefinition {
+                name: Some(Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 6..10),
+                    data: "test".to_string(),
+                })),
+                keyword: Span::new_source(source.clone(), 1..5),
+                variants: EnumVariants {
+                    members: vec![
+                        EnumVariant {
+                            name: Identifier(SpanData {
+                                span: Span::new_source(source.clone(), 17..22),
+                                data: "first".to_string(),
+                            }),
+                            value: Some(Expression::Operation {
+                                left: Box::new(Expression::Literal {
+                                    content: SpanData {
+                                        span: Span::new_source(source.clone(), 25..26),
+                                        data: "1".to_string(),
+                                    },
+                                }),
+                                right: Box::new(Expression::Literal {
+                                    content: SpanData {
+                                        span: Span::new_source(source.clone(), 30..31),
+                                        data: "2".to_string(),
+                                    },
+                                }),
+                                operation: ExpressionOperator::ShiftLeft,
+                            }),
+                        },
+                        EnumVariant {
+                            name: Identifier(SpanData {
+                                span: Span::new_source(source, 37..43),
+                                data: "second".to_string(),
+                            }),
+                            value: None,
+                        },
+                    ],
+                },
+                declaration: None,
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn anonymous_enum_with_variable() {
+    let input = "
+enum { first } current = first;
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::EnumDefinition {
+                name: None,
+                keyword: Span::new_source(source.clone(), 1..5),
+                variants: EnumVariants {
+                    members: vec![EnumVariant {
+                        name: Identifier(SpanData {
+                            span: Span::new_source(source.clone(), 8..13),
+                            data: "first".to_string(),
+                        }),
+                        value: None,
+                    }],
+                },
+                declaration: Some(Box::new(Statement::VariableDeclarationAssignment {
+                    ty: TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 1..5),
+                        data: DataType::Int,
+                    }),
+                    name: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 16..23),
+                        data: "current".to_string(),
+                    }),
+                    value: Expression::Identifier {
+                        ident: Identifier(SpanData {
+                            span: Span::new_source(source, 26..31),
+                            data: "first".to_string(),
+                        }),
+                    },
+                })),
             }],
         },
     });
@@ -0,0 +1,145 @@
+use general::{Source, Span, SpanData};
+use syntax::{
+    DataType, Expression, Identifier, Scope, SingleOperation, Statement, TypeDefType, TypeToken,
+    AST,
+};
+
+#[test]
+fn declare_function_pointer() {
+    let input = "
+int (*fp)(int, int);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclaration {
+                ty: TypeToken::FunctionPointer {
+                    return_ty: Box::new(TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 1..4),
+                        data: DataType::Int,
+                    })),
+                    arguments: vec![
+                        TypeToken::Primitive(SpanData {
+                            span: Span::new_source(source.clone(), 11..14),
+                            data: DataType::Int,
+                        }),
+                        TypeToken::Primitive(SpanData {
+                            span: Span::new_source(source.clone(), 16..19),
+                            data: DataType::Int,
+                        }),
+                    ],
+                    var_args: false,
+                },
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 7..9),
+                    data: "fp".to_string(),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn call_dereferenced_function_pointer() {
+    let input = "
+(*fp)(1);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::SingleExpression(Expression::SingleOperation {
+                base: Box::new(Expression::SingleOperation {
+                    base: Box::new(Expression::Identifier {
+                        ident: Identifier(SpanData {
+                            span: Span::new_source(source.clone(), 3..5),
+                            data: "fp".to_string(),
+                        }),
+                    }),
+                    operation: SingleOperation::Dereference,
+                }),
+                operation: SingleOperation::FuntionCall(vec![Expression::Literal {
+                    content: SpanData {
+                        span: Span::new_source(source, 7..8),
+                        data: "1".to_string(),
+                    },
+                }]),
+            })],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn typedef_function_pointer_with_names_and_varargs() {
+    let input = "
+typedef int (*cmp)(const void* a, ...);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    match result.unwrap().global_scope.statements.first() {
+        Some(Statement::TypeDef {
+            name,
+            base_type:
+                TypeDefType::Type(TypeToken::FunctionPointer {
+                    arguments,
+                    var_args,
+                    ..
+                }),
+        }) => {
+            assert_eq!("cmp", name.0.data);
+            assert_eq!(1, arguments.len());
+            assert!(var_args);
+        }
+        other => panic!("Expected a TypeDef, got {:?}", other),
+    };
+}
+
+#[test]
+fn call_from_array_of_function_pointers() {
+    let input = "
+table[0](1);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    match result.unwrap().global_scope.statements.first() {
+        Some(Statement::SingleExpression(Expression::SingleOperation {
+            base,
+            operation: SingleOperation::FuntionCall(args),
+        })) => {
+            assert!(matches!(
+                base.as_ref(),
+                Expression::SingleOperation {
+                    operation: SingleOperation::ArrayAccess(_),
+                    ..
+                }
+            ));
+            assert_eq!(1, args.len());
+        }
+        other => panic!("Expected a Function Call, got {:?}", other),
+    };
+}
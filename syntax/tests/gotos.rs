@@ -0,0 +1,58 @@
+use general::{Source, Span, SpanData};
+use syntax::{ExpectedToken, Identifier, Scope, Statement, SyntaxError, AST};
+
+#[test]
+fn label_and_goto() {
+    let input = "
+start:
+goto start;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![
+                Statement::Label {
+                    name: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 1..6),
+                        data: "start".to_string(),
+                    }),
+                },
+                Statement::Goto {
+                    target: Identifier(SpanData {
+                        span: Span::new_source(source, 13..18),
+                        data: "start".to_string(),
+                    }),
+                },
+            ],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn goto_missing_semicolon() {
+    let input = "
+goto start
+int x;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Err(SyntaxError::UnexpectedToken {
+        expected: Some(vec![ExpectedToken::Semicolon]),
+        got: Span::new_source(source, 12..15),
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
@@ -0,0 +1,135 @@
+use general::{Source, Span, SpanData};
+use syntax::{AssignTarget, Designator, Expression, Identifier, Scope, Statement, TypeToken, AST};
+
+#[test]
+fn designated_initializer() {
+    let input = "
+struct P p = { .x = 1, [2] = 3 };
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclarationAssignment {
+                ty: TypeToken::StructType {
+                    name: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 8..9),
+                        data: "P".to_string(),
+                    }),
+                },
+                name: Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 10..11),
+                    data: "p".to_string(),
+                }),
+                value: Expression::ArrayLiteral {
+                    parts: SpanData {
+                        span: Span::new_source(source.clone(), 14..33),
+                        data: vec![
+                            Expression::DesignatedInitializer {
+                                designators: vec![Designator::Member(Identifier(SpanData {
+                                    span: Span::new_source(source.clone(), 17..18),
+                                    data: "x".to_string(),
+                                }))],
+                                value: Box::new(Expression::Literal {
+                                    content: SpanData {
+                                        span: Span::new_source(source.clone(), 21..22),
+                                        data: "1".to_string(),
+                                    },
+                                }),
+                            },
+                            Expression::DesignatedInitializer {
+                                designators: vec![Designator::Index(Box::new(
+                                    Expression::Literal {
+                                        content: SpanData {
+                                            span: Span::new_source(source.clone(), 25..26),
+                                            data: "2".to_string(),
+                                        },
+                                    },
+                                ))],
+                                value: Box::new(Expression::Literal {
+                                    content: SpanData {
+                                        span: Span::new_source(source, 30..31),
+                                        data: "3".to_string(),
+                                    },
+                                }),
+                            },
+                        ],
+                    },
+                },
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn compound_literal() {
+    let input = "
+p = (struct P){1, 2};
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableAssignment {
+                target: AssignTarget::Variable(Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 1..2),
+                    data: "p".to_string(),
+                })),
+                value: Expression::CompoundLiteral {
+                    target_ty: TypeToken::StructType {
+                        name: Identifier(SpanData {
+                            span: Span::new_source(source.clone(), 13..14),
+                            data: "P".to_string(),
+                        }),
+                    },
+                    parts: SpanData {
+                        span: Span::new_source(source.clone(), 5..21),
+                        data: vec![
+                            Expression::Literal {
+                                content: SpanData {
+                                    span: Span::new_source(source.clone(), 16..17),
+                                    data: "1".to_string(),
+                                },
+                            },
+                            Expression::Literal {
+                                content: SpanData {
+                                    span: Span::new_source(source, 19..20),
+                                    data: "2".to_string(),
+                                },
+                            },
+                        ],
+                    },
+                },
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn designator_without_value() {
+    let input = "
+int arr[3] = { [1] 2 };
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert!(result.is_err());
+}
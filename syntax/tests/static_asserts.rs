@@ -0,0 +1,94 @@
+use general::{Source, Span, SpanData};
+use syntax::{
+    DataType, Expression, ExpressionOperator, Scope, Statement, SyntaxError, TypeToken, AST,
+};
+
+#[test]
+fn static_assert() {
+    let input = "
+_Static_assert(sizeof(int) == 4, \"int\" \" size\");
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::StaticAssert {
+                keyword: Span::new_source(source.clone(), 1..15),
+                condition: Expression::Operation {
+                    operation: ExpressionOperator::Equal,
+                    left: Box::new(Expression::SizeOf {
+                        ty: TypeToken::Primitive(SpanData {
+                            span: Span::new_source(source.clone(), 23..26),
+                            data: DataType::Int,
+                        }),
+                        area: Span::new_source(source.clone(), 16..22),
+                    }),
+                    right: Box::new(Expression::Literal {
+                        content: SpanData {
+                            span: Span::new_source(source.clone(), 31..32),
+                            data: "4".to_string(),
+                        },
+                    }),
+                },
+                message: SpanData {
+                    span: Span::new_source(source, 34..47),
+                    data: "int size".to_string(),
+                },
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn static_assert_in_function() {
+    let input = "
+void test() {
+    _Static_assert(1, \"always\");
+}
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    let statements = result.unwrap().global_scope.statements;
+    match statements.first() {
+        Some(Statement::FunctionDefinition { body, .. }) => {
+            assert!(matches!(
+                body.statements.first(),
+                Some(Statement::StaticAssert { .. })
+            ));
+        }
+        other => panic!("Expected a Function-Definition, got {:?}", other),
+    };
+}
+
+#[test]
+fn static_assert_without_message() {
+    let input = "
+_Static_assert(1, 2);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(
+        Err(SyntaxError::UnexpectedToken {
+            expected: Some(vec![syntax::ExpectedToken::StringLiteral]),
+            got: Span::new_source(source, 19..20),
+        }),
+        result
+    );
+}
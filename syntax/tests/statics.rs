@@ -0,0 +1,81 @@
+use general::{Source, Span, SpanData};
+use syntax::{DataType, Identifier, Scope, Statement, SyntaxError, TypeToken, AST};
+
+#[test]
+fn static_variable() {
+    let input = "
+static int counter;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::Static {
+                keyword: Span::new_source(source.clone(), 1..7),
+                inner: Box::new(Statement::VariableDeclaration {
+                    ty: TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 8..11),
+                        data: DataType::Int,
+                    }),
+                    name: Identifier(SpanData {
+                        span: Span::new_source(source, 12..19),
+                        data: "counter".to_string(),
+                    }),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn static_function() {
+    let input = "
+static int helper() {
+    return 0;
+}
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.into();
+    let tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    let statements = result.unwrap().global_scope.statements;
+    match statements.first() {
+        Some(Statement::Static { inner, .. }) => {
+            assert!(matches!(
+                inner.as_ref(),
+                Statement::FunctionDefinition { .. }
+            ));
+        }
+        other => panic!("Expected a static Definition, got {:?}", other),
+    };
+}
+
+#[test]
+fn static_non_declaration() {
+    let input = "
+static return 0;
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Err(SyntaxError::UnexpectedToken {
+        expected: None,
+        got: Span::new_source(source, 1..7),
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
@@ -1,5 +1,5 @@
 use general::{Source, Span, SpanData};
-use syntax::{DataType, Identifier, Scope, Statement, StructMembers, TypeToken, AST};
+use syntax::{DataType, Expression, Identifier, Scope, Statement, StructMembers, TypeToken, AST};
 
 #[test]
 fn named_struct_def() {
@@ -27,20 +27,24 @@ struct test {
                                 span: Span::new_source(source.clone(), 19..22),
                                 data: DataType::Int,
                             }),
-                            Identifier(SpanData {
+                            Some(Identifier(SpanData {
                                 span: Span::new_source(source.clone(), 23..28),
                                 data: "first".to_string(),
-                            }),
+                            })),
+                            None,
+                            None,
                         ),
                         (
                             TypeToken::Primitive(SpanData {
                                 span: Span::new_source(source.clone(), 34..37),
                                 data: DataType::Int,
                             }),
-                            Identifier(SpanData {
+                            Some(Identifier(SpanData {
                                 span: Span::new_source(source.clone(), 38..44),
                                 data: "second".to_string(),
-                            }),
+                            })),
+                            None,
+                            None,
                         ),
                     ],
                 },
@@ -55,3 +59,67 @@ struct test {
     assert_eq!(expected, result);
     assert_eq!(None, tokens.next());
 }
+
+#[test]
+fn struct_bit_fields() {
+    let content = "
+struct flags {
+    int ready : 1;
+    int mode;
+};
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::StructDefinition {
+                name: Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 8..13),
+                    data: "flags".to_string(),
+                }),
+                members: StructMembers {
+                    members: vec![
+                        (
+                            TypeToken::Primitive(SpanData {
+                                span: Span::new_source(source.clone(), 20..23),
+                                data: DataType::Int,
+                            }),
+                            Some(Identifier(SpanData {
+                                span: Span::new_source(source.clone(), 24..29),
+                                data: "ready".to_string(),
+                            })),
+                            Some(Expression::Literal {
+                                content: SpanData {
+                                    span: Span::new_source(source.clone(), 32..33),
+                                    data: "1".to_string(),
+                                },
+                            }),
+                            None,
+                        ),
+                        (
+                            TypeToken::Primitive(SpanData {
+                                span: Span::new_source(source.clone(), 39..42),
+                                data: DataType::Int,
+                            }),
+                            Some(Identifier(SpanData {
+                                span: Span::new_source(source.clone(), 43..47),
+                                data: "mode".to_string(),
+                            })),
+                            None,
+                            None,
+                        ),
+                    ],
+                },
+                definition: Span::new_source(source, 14..51),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
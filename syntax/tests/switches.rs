@@ -0,0 +1,107 @@
+use general::{Source, Span, SpanData};
+use syntax::{CaseLabel, Expression, Identifier, Scope, Statement, SwitchCase, AST};
+
+#[test]
+fn switch_with_fallthrough_and_default() {
+    let input = "
+switch (x) {
+    case 1:
+    case 2:
+        break;
+    default:
+        return;
+}
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::Switch {
+                value: Expression::Identifier {
+                    ident: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 9..10),
+                        data: "x".to_string(),
+                    }),
+                },
+                cases: vec![
+                    SwitchCase {
+                        label: CaseLabel::Case(Expression::Literal {
+                            content: SpanData {
+                                span: Span::new_source(source.clone(), 23..24),
+                                data: "1".to_string(),
+                            },
+                        }),
+                        statements: vec![],
+                    },
+                    SwitchCase {
+                        label: CaseLabel::Case(Expression::Literal {
+                            content: SpanData {
+                                span: Span::new_source(source.clone(), 35..36),
+                                data: "2".to_string(),
+                            },
+                        }),
+                        statements: vec![Statement::Break],
+                    },
+                    SwitchCase {
+                        label: CaseLabel::Default(Span::new_source(source, 57..64)),
+                        statements: vec![Statement::Return(None)],
+                    },
+                ],
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn switch_statement_before_case() {
+    let input = "
+switch (x) {
+    break;
+    case 1:
+        return;
+}
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::Switch {
+                value: Expression::Identifier {
+                    ident: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 9..10),
+                        data: "x".to_string(),
+                    }),
+                },
+                cases: vec![
+                    SwitchCase {
+                        label: CaseLabel::Unlabeled,
+                        statements: vec![Statement::Break],
+                    },
+                    SwitchCase {
+                        label: CaseLabel::Case(Expression::Literal {
+                            content: SpanData {
+                                span: Span::new_source(source, 34..35),
+                                data: "1".to_string(),
+                            },
+                        }),
+                        statements: vec![Statement::Return(None)],
+                    },
+                ],
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
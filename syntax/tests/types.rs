@@ -36,3 +36,155 @@ long long int x;
     assert_eq!(expected, result);
     assert_eq!(None, tokens.next());
 }
+
+#[test]
+fn implicit_unsigned_int() {
+    let input = "
+unsigned x;
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclaration {
+                ty: TypeToken::Composition {
+                    modifier: SpanData {
+                        span: Span::new_source(source.clone(), 1..9),
+                        data: Modifier::Unsigned,
+                    },
+                    base: Box::new(TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 1..9),
+                        data: DataType::Int,
+                    })),
+                },
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 10..11),
+                    data: "x".to_string(),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn pointer_to_const() {
+    let input = "
+const int* x;
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclaration {
+                ty: TypeToken::Pointer(Box::new(TypeToken::Composition {
+                    modifier: SpanData {
+                        span: Span::new_source(source.clone(), 1..6),
+                        data: Modifier::Const,
+                    },
+                    base: Box::new(TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 7..10),
+                        data: DataType::Int,
+                    })),
+                })),
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 12..13),
+                    data: "x".to_string(),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn const_pointer() {
+    let input = "
+int* const x;
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclaration {
+                ty: TypeToken::Composition {
+                    modifier: SpanData {
+                        span: Span::new_source(source.clone(), 6..11),
+                        data: Modifier::Const,
+                    },
+                    base: Box::new(TypeToken::Pointer(Box::new(TypeToken::Primitive(
+                        SpanData {
+                            span: Span::new_source(source.clone(), 1..4),
+                            data: DataType::Int,
+                        },
+                    )))),
+                },
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 12..13),
+                    data: "x".to_string(),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn const_pointer_to_volatile() {
+    let input = "
+volatile int* const x;
+        ";
+    let source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::VariableDeclaration {
+                ty: TypeToken::Composition {
+                    modifier: SpanData {
+                        span: Span::new_source(source.clone(), 15..20),
+                        data: Modifier::Const,
+                    },
+                    base: Box::new(TypeToken::Pointer(Box::new(TypeToken::Composition {
+                        modifier: SpanData {
+                            span: Span::new_source(source.clone(), 1..9),
+                            data: Modifier::Volatile,
+                        },
+                        base: Box::new(TypeToken::Primitive(SpanData {
+                            span: Span::new_source(source.clone(), 10..13),
+                            data: DataType::Int,
+                        })),
+                    }))),
+                },
+                name: Identifier(SpanData {
+                    span: Span::new_source(source, 21..22),
+                    data: "x".to_string(),
+                }),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
@@ -0,0 +1,119 @@
+use general::{Source, Span, SpanData};
+use syntax::{DataType, Identifier, Scope, Statement, StructMembers, TypeToken, AST};
+
+#[test]
+fn named_union_def() {
+    let content = "
+union test {
+    int first;
+    char second;
+};
+        ";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::UnionDefinition {
+                name: Identifier(SpanData {
+                    span: Span::new_source(source.clone(), 7..11),
+                    data: "test".to_string(),
+                }),
+                members: StructMembers {
+                    members: vec![
+                        (
+                            TypeToken::Primitive(SpanData {
+                                span: Span::new_source(source.clone(), 18..21),
+                                data: DataType::Int,
+                            }),
+                            Some(Identifier(SpanData {
+                                span: Span::new_source(source.clone(), 22..27),
+                                data: "first".to_string(),
+                            })),
+                            None,
+                            None,
+                        ),
+                        (
+                            TypeToken::Primitive(SpanData {
+                                span: Span::new_source(source.clone(), 33..37),
+                                data: DataType::Char,
+                            }),
+                            Some(Identifier(SpanData {
+                                span: Span::new_source(source.clone(), 38..44),
+                                data: "second".to_string(),
+                            })),
+                            None,
+                            None,
+                        ),
+                    ],
+                },
+                definition: Span::new_source(source, 12..48),
+            }],
+        },
+    });
+
+    let result = syntax::parse(tokens.by_ref());
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+    assert_eq!(None, tokens.next());
+}
+
+#[test]
+fn inline_union_member() {
+    let content = "struct Tag { int kind; union { int i; float f; } v; };";
+    let source = Source::new("test", content);
+    let span: Span = source.clone().into();
+    let mut tokens = tokenizer::tokenize(span);
+
+    let result = syntax::parse(tokens.by_ref()).unwrap();
+    dbg!(&result);
+
+    let members = match result.global_scope.statements.first() {
+        Some(Statement::StructDefinition { members, .. }) => &members.members,
+        other => panic!("Expected a Struct Definition, got {:?}", other),
+    };
+    assert_eq!(2, members.len());
+
+    let expected_union = TypeToken::AnonymousUnion {
+        members: StructMembers {
+            members: vec![
+                (
+                    TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 31..34),
+                        data: DataType::Int,
+                    }),
+                    Some(Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 35..36),
+                        data: "i".to_string(),
+                    })),
+                    None,
+                    None,
+                ),
+                (
+                    TypeToken::Primitive(SpanData {
+                        span: Span::new_source(source.clone(), 38..43),
+                        data: DataType::Float,
+                    }),
+                    Some(Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 44..45),
+                        data: "f".to_string(),
+                    })),
+                    None,
+                    None,
+                ),
+            ],
+        },
+        keyword: Span::new_source(source.clone(), 23..28),
+    };
+    assert_eq!(expected_union, members[1].0);
+    assert_eq!(
+        Some(Identifier(SpanData {
+            span: Span::new_source(source, 49..50),
+            data: "v".to_string(),
+        })),
+        members[1].1
+    );
+    assert_eq!(None, tokens.next());
+}
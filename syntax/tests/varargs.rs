@@ -0,0 +1,35 @@
+use general::{Source, Span, SpanData};
+use syntax::{DataType, Expression, Identifier, Scope, Statement, TypeToken, AST};
+
+#[test]
+fn builtin_va_arg() {
+    let input = "
+__builtin_va_arg(ap, int);
+        ";
+    let source: Source = Source::new("test", input);
+    let span: Span = source.clone().into();
+    let tokens = tokenizer::tokenize(span);
+
+    let expected = Ok(AST {
+        global_scope: Scope {
+            statements: vec![Statement::SingleExpression(Expression::VaArg {
+                list: Box::new(Expression::Identifier {
+                    ident: Identifier(SpanData {
+                        span: Span::new_source(source.clone(), 18..20),
+                        data: "ap".to_string(),
+                    }),
+                }),
+                ty: TypeToken::Primitive(SpanData {
+                    span: Span::new_source(source.clone(), 22..25),
+                    data: DataType::Int,
+                }),
+                area: Span::new_source(source, 1..26),
+            })],
+        },
+    });
+
+    let result = syntax::parse(tokens);
+    dbg!(&result);
+
+    assert_eq!(expected, result);
+}
@@ -53,6 +53,7 @@ impl TokenIter {
                 | '/'
                 | '%'
                 | '!'
+                | '~'
                 | '&'
                 | '|'
                 | '^'
@@ -60,6 +61,43 @@ impl TokenIter {
                 | '>'
         )
     }
+
+    /// Checks if the pending Text up to the given Index is an Encoding-Prefix of a String- or
+    /// Char-Literal, like the "L" in L"wide"
+    fn is_literal_prefix(&self, end: usize) -> bool {
+        self.state
+            .current_sub(&self.span, end)
+            .map(|sub| matches!(sub.content(), "L" | "u" | "U" | "u8"))
+            .unwrap_or(false)
+    }
+
+    /// Checks if the Character at the given Index is still part of the pending Number, which can
+    /// contain a '.' and a Sign directly after its Exponent, like in "1.5e-3" or ".5"
+    fn continues_number(&self, index: usize, element: char, next: Option<char>) -> bool {
+        let pending = match self.state.current_sub(&self.span, index) {
+            Some(sub) => sub.content().to_owned(),
+            None => return false,
+        };
+        let is_number = pending.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+
+        match element {
+            '.' if pending.is_empty() => matches!(next, Some(c) if c.is_ascii_digit()),
+            '.' => is_number,
+            '+' | '-' => is_number && pending.ends_with(['e', 'E', 'p', 'P']),
+            _ => false,
+        }
+    }
+
+    /// Splits the raw Text of a String- or Char-Literal into its Prefix and its Content between
+    /// the Quotes
+    fn split_literal(raw: &str, quote: char) -> (Option<String>, String) {
+        let start = raw.find(quote).unwrap_or(0);
+
+        let prefix = (start > 0).then(|| raw[..start].to_owned());
+        let content = raw[start + 1..raw.len() - 1].to_owned();
+
+        (prefix, content)
+    }
 }
 
 impl Iterator for TokenIter {
@@ -85,6 +123,7 @@ impl Iterator for TokenIter {
                 }
             };
             n_last_char = element;
+            let next = self.chars.peek().map(|(_, c)| *c);
 
             match (element, self.state.env()) {
                 (' ', Environment::Code)
@@ -97,7 +136,9 @@ impl Iterator for TokenIter {
 
                     self.state.move_start(next_index);
                 }
-                (elem, Environment::Code) if Self::is_seperator(elem) => {
+                (elem, Environment::Code)
+                    if Self::is_seperator(elem) && !self.continues_number(index, elem, next) =>
+                {
                     // Check if this starts a comment
                     if element == '/' {
                         match self.chars.peek() {
@@ -203,7 +244,9 @@ impl Iterator for TokenIter {
                     break;
                 }
                 ('"', Environment::Code) => {
-                    self.state.move_start(index);
+                    if !self.is_literal_prefix(index) {
+                        self.state.move_start(index);
+                    }
                     self.state.switch_env(Environment::StringLiteral);
                 }
                 ('"', Environment::StringLiteral) => {
@@ -212,22 +255,19 @@ impl Iterator for TokenIter {
                     self.state.move_start(index + 1);
                     self.state.switch_env(Environment::Code);
 
-                    let sub_span_length = sub_span.content().len();
-                    let content = sub_span
-                        .sub_span(1..sub_span_length - 1)
-                        .unwrap()
-                        .content()
-                        .to_owned();
+                    let (prefix, content) = Self::split_literal(sub_span.content(), '"');
                     let token = Token {
                         span: sub_span.into(),
-                        data: TokenData::StringLiteral { content },
+                        data: TokenData::StringLiteral { content, prefix },
                     };
 
                     result = Some(token);
                     break;
                 }
                 ('\'', Environment::Code) => {
-                    self.state.move_start(index);
+                    if !self.is_literal_prefix(index) {
+                        self.state.move_start(index);
+                    }
                     self.state.switch_env(Environment::CharLiteral);
                 }
                 ('\'', Environment::CharLiteral) => {
@@ -236,22 +276,19 @@ impl Iterator for TokenIter {
                     self.state.move_start(index + 1);
                     self.state.switch_env(Environment::Code);
 
-                    let sub_span_length = sub_span.content().len();
-                    let content_range = 1..sub_span_length - 1;
-
-                    let content = sub_span
-                        .sub_span(content_range)
-                        .unwrap()
-                        .content()
-                        .to_owned();
+                    let (prefix, content) = Self::split_literal(sub_span.content(), '\'');
                     let token = Token {
                         span: sub_span.into(),
-                        data: TokenData::CharLiteral { content },
+                        data: TokenData::CharLiteral { content, prefix },
                     };
 
                     result = Some(token);
                     break;
                 }
+                ('\\', Environment::StringLiteral) | ('\\', Environment::CharLiteral) => {
+                    // The escaped Character can never end the Literal, even if it is a Quote
+                    let _ = self.chars.next();
+                }
                 ('\n', Environment::SLComment) => {
                     let sub_span = self.state.current_sub(&self.span, index).unwrap();
                     let content = sub_span.content().to_owned();
@@ -292,13 +329,23 @@ impl Iterator for TokenIter {
                     break;
                 }
                 (_, Environment::Code) => {
-                    let end_index = match self.chars.peek() {
-                        Some((index, '"')) => *index,
-                        Some((index, '#')) => *index,
-                        Some((index, tmp)) if Self::is_seperator(*tmp) => *index,
+                    let (end_index, quote) = match self.chars.peek() {
+                        Some((index, '"')) => (*index, true),
+                        Some((index, '#')) => (*index, false),
+                        Some((index, tmp)) if Self::is_seperator(*tmp) => (*index, false),
                         _ => continue,
                     };
 
+                    // A '.' or the Sign of an Exponent do not end a Number
+                    if !quote && self.continues_number(end_index, next.unwrap(), None) {
+                        continue;
+                    }
+
+                    // The Prefix is part of the following String-Literal
+                    if quote && self.is_literal_prefix(end_index) {
+                        continue;
+                    }
+
                     let sub_span = self
                         .state
                         .current_sub(self.span.as_ref(), end_index)
@@ -326,6 +373,8 @@ impl Iterator for TokenIter {
 mod tests {
     use general::Source;
 
+    use crate::Operator;
+
     use super::*;
 
     #[test]
@@ -371,6 +420,7 @@ mod tests {
             span: Span::new_source(input_source, 0..3),
             data: TokenData::CharLiteral {
                 content: "c".to_string(),
+                prefix: None,
             },
         }];
 
@@ -379,4 +429,119 @@ mod tests {
 
         assert_eq!(expected, result_vec);
     }
+
+    #[test]
+    fn escaped_quotes() {
+        let input_source = Source::new("test", r#""a\"b" '\''"#);
+        let input_span: Span = input_source.clone().into();
+
+        let expected = vec![
+            Token {
+                span: Span::new_source(input_source.clone(), 0..6),
+                data: TokenData::StringLiteral {
+                    content: r#"a\"b"#.to_string(),
+                    prefix: None,
+                },
+            },
+            Token {
+                span: Span::new_source(input_source, 7..11),
+                data: TokenData::CharLiteral {
+                    content: r"\'".to_string(),
+                    prefix: None,
+                },
+            },
+        ];
+
+        let result_iter = TokenIter::new(input_span);
+        let result_vec: Vec<_> = result_iter.collect();
+
+        assert_eq!(expected, result_vec);
+    }
+
+    #[test]
+    fn prefixed_literals() {
+        let input_source = Source::new("test", "L\"wide\" u8\"utf\" u'c'");
+        let input_span: Span = input_source.clone().into();
+
+        let expected = vec![
+            Token {
+                span: Span::new_source(input_source.clone(), 0..7),
+                data: TokenData::StringLiteral {
+                    content: "wide".to_string(),
+                    prefix: Some("L".to_string()),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 8..15),
+                data: TokenData::StringLiteral {
+                    content: "utf".to_string(),
+                    prefix: Some("u8".to_string()),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source, 16..20),
+                data: TokenData::CharLiteral {
+                    content: "c".to_string(),
+                    prefix: Some("u".to_string()),
+                },
+            },
+        ];
+
+        let result_iter = TokenIter::new(input_span);
+        let result_vec: Vec<_> = result_iter.collect();
+
+        assert_eq!(expected, result_vec);
+    }
+
+    #[test]
+    fn numbers() {
+        let input_source = Source::new("test", "1.5e-3+.5 0x1.8p+3f s.x");
+        let input_span: Span = input_source.clone().into();
+
+        let expected = vec![
+            Token {
+                span: Span::new_source(input_source.clone(), 0..6),
+                data: TokenData::Literal {
+                    content: "1.5e-3".to_string(),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 6..7),
+                data: TokenData::Operator(Operator::Add),
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 7..9),
+                data: TokenData::Literal {
+                    content: ".5".to_string(),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 10..19),
+                data: TokenData::Literal {
+                    content: "0x1.8p+3f".to_string(),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 20..21),
+                data: TokenData::Literal {
+                    content: "s".to_string(),
+                },
+            },
+            Token {
+                span: Span::new_source(input_source.clone(), 21..22),
+                data: TokenData::Operator(Operator::Dot),
+            },
+            Token {
+                span: Span::new_source(input_source, 22..23),
+                data: TokenData::Literal {
+                    content: "x".to_string(),
+                },
+            },
+        ];
+
+        let result_iter = TokenIter::new(input_span);
+        let result_vec: Vec<_> = result_iter.collect();
+
+        assert_eq!(expected, result_vec);
+    }
 }
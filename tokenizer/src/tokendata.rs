@@ -34,11 +34,17 @@ pub enum TokenData {
     Literal {
         content: String,
     },
+    /// A String-Literal, where the Content is still in its escaped Form as written in the Source
     StringLiteral {
         content: String,
+        /// The Encoding-Prefix, like "L" or "u8", if one was given
+        prefix: Option<String>,
     },
+    /// A Char-Literal, where the Content is still in its escaped Form as written in the Source
     CharLiteral {
         content: String,
+        /// The Encoding-Prefix, like "L" or "u", if one was given
+        prefix: Option<String>,
     },
     CompilerDirective {
         content: String,
@@ -65,8 +71,12 @@ impl Display for TokenData {
             Self::Comment { content } => write!(f, "//{}", content),
             Self::Operator(op) => write!(f, "{}", op),
             Self::Literal { content } => write!(f, "{}", content),
-            Self::StringLiteral { content } => write!(f, "\"{}\"", content),
-            Self::CharLiteral { content } => write!(f, "'{}'", content),
+            Self::StringLiteral { content, prefix } => {
+                write!(f, "{}\"{}\"", prefix.as_deref().unwrap_or(""), content)
+            }
+            Self::CharLiteral { content, prefix } => {
+                write!(f, "{}'{}'", prefix.as_deref().unwrap_or(""), content)
+            }
             Self::CompilerDirective { content } => write!(f, "#{}", content),
         }
     }
@@ -111,10 +121,11 @@ impl Display for Assignment {
 pub enum Keyword {
     DataType(DataType),
     ControlFlow(ControlFlow),
+    AlignAs,
+    AlignOf,
     Auto,
     Const,
     Default_,
-    Bool_,
     Complex_,
     Extern,
     Imaginary_,
@@ -123,6 +134,7 @@ pub enum Keyword {
     Restrict,
     SizeOf,
     Static,
+    StaticAssert,
     TypeDef,
     Volatile,
 }
@@ -132,10 +144,11 @@ impl Display for Keyword {
         match self {
             Self::DataType(dt) => write!(f, "{}", dt),
             Self::ControlFlow(cf) => write!(f, "{}", cf),
+            Self::AlignAs => write!(f, "_Alignas"),
+            Self::AlignOf => write!(f, "_Alignof"),
             Self::Auto => todo!("Format auto"),
             Self::Const => write!(f, "const"),
-            Self::Default_ => todo!("Format default_"),
-            Self::Bool_ => todo!("Format bool_"),
+            Self::Default_ => write!(f, "default"),
             Self::Complex_ => todo!("Format complex_"),
             Self::Extern => todo!("Format extern"),
             Self::Imaginary_ => todo!("Format imaginary"),
@@ -144,6 +157,7 @@ impl Display for Keyword {
             Self::Restrict => todo!("Format restrict"),
             Self::SizeOf => write!(f, "sizeof"),
             Self::Static => write!(f, "static"),
+            Self::StaticAssert => write!(f, "_Static_assert"),
             Self::TypeDef => write!(f, "typedef"),
             Self::Volatile => write!(f, "volatile"),
         }
@@ -154,6 +168,7 @@ impl Display for Keyword {
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum DataType {
     Void,
+    Bool,
     Short,
     Char,
     Int,
@@ -171,6 +186,7 @@ impl Display for DataType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Void => write!(f, "void"),
+            Self::Bool => write!(f, "_Bool"),
             Self::Short => write!(f, "short"),
             Self::Char => write!(f, "char"),
             Self::Int => write!(f, "int"),
@@ -333,6 +349,7 @@ impl<'r, 'a> From<&'r SpanRef<'a>> for TokenData {
             ">>=" => Self::Assign(Assignment::ShiftRight),
 
             "void" => Self::Keyword(Keyword::DataType(DataType::Void)),
+            "_Bool" => Self::Keyword(Keyword::DataType(DataType::Bool)),
             "short" => Self::Keyword(Keyword::DataType(DataType::Short)),
             "char" => Self::Keyword(Keyword::DataType(DataType::Char)),
             "int" => Self::Keyword(Keyword::DataType(DataType::Int)),
@@ -345,6 +362,8 @@ impl<'r, 'a> From<&'r SpanRef<'a>> for TokenData {
             "unsigned" => Self::Keyword(Keyword::DataType(DataType::Unsigned)),
             "signed" => Self::Keyword(Keyword::DataType(DataType::Signed)),
             "const" => Self::Keyword(Keyword::Const),
+            "volatile" => Self::Keyword(Keyword::Volatile),
+            "static" => Self::Keyword(Keyword::Static),
 
             "if" => Self::Keyword(Keyword::ControlFlow(ControlFlow::If)),
             "else" => Self::Keyword(Keyword::ControlFlow(ControlFlow::Else)),
@@ -357,9 +376,13 @@ impl<'r, 'a> From<&'r SpanRef<'a>> for TokenData {
             "break" => Self::Keyword(Keyword::ControlFlow(ControlFlow::Break)),
             "continue" => Self::Keyword(Keyword::ControlFlow(ControlFlow::Continue)),
             "return" => Self::Keyword(Keyword::ControlFlow(ControlFlow::Return)),
+            "default" => Self::Keyword(Keyword::Default_),
 
             "typedef" => Self::Keyword(Keyword::TypeDef),
             "sizeof" => Self::Keyword(Keyword::SizeOf),
+            "_Alignof" => Self::Keyword(Keyword::AlignOf),
+            "_Alignas" => Self::Keyword(Keyword::AlignAs),
+            "_Static_assert" => Self::Keyword(Keyword::StaticAssert),
 
             "+" => Self::Operator(Operator::Add),
             "++" => Self::Operator(Operator::Increment),
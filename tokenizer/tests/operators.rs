@@ -112,3 +112,27 @@ fn operators() {
 
     assert_eq!(expected, result);
 }
+
+#[test]
+fn bitwise_not_before_identifier() {
+    let input = "~c";
+    let source = Source::new("test", input);
+    let input_span: Span = source.clone().into();
+
+    let expected = vec![
+        Token {
+            span: Span::new_source(source.clone(), 0..1),
+            data: TokenData::Operator(Operator::BitwiseNot),
+        },
+        Token {
+            span: Span::new_source(source, 1..2),
+            data: TokenData::Literal {
+                content: "c".to_string(),
+            },
+        },
+    ];
+
+    let result: Vec<_> = tokenizer::tokenize(input_span).collect();
+
+    assert_eq!(expected, result);
+}
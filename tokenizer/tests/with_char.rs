@@ -26,6 +26,7 @@ fn simple_char() {
             span: Span::new_source(source.clone(), 12..15),
             data: TokenData::CharLiteral {
                 content: "c".to_string(),
+                prefix: None,
             },
         },
         Token {
@@ -52,6 +52,7 @@ fn with_string_program() {
             span: Span::new_source(source.clone(), 26..39),
             data: TokenData::StringLiteral {
                 content: "test string".to_string(),
+                prefix: None,
             },
         },
         Token {